@@ -0,0 +1,72 @@
+use crate::{
+    commands::db::get::{maybe_json_value_parser, table_key},
+    utils::DbTool,
+};
+use clap::Parser;
+use reth_db::{export_table, DatabaseEnv, TableViewer, Tables};
+use reth_db_api::{database::Database, table::Table};
+use std::{fs::File, io, ops::Bound, path::PathBuf, sync::Arc};
+use tracing::info;
+
+#[derive(Parser, Debug)]
+/// The arguments for the `reth db export` command
+pub struct Command {
+    /// The table name
+    table: Tables,
+
+    /// The start of the range to export.
+    #[arg(long, value_parser = maybe_json_value_parser)]
+    start_key: Option<String>,
+
+    /// The end of the range to export.
+    #[arg(long, value_parser = maybe_json_value_parser)]
+    end_key: Option<String>,
+
+    /// The file to write the rows to. Defaults to stdout.
+    #[arg(long, short, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+impl Command {
+    /// Execute `db export` command
+    pub fn execute(self, tool: &DbTool<Arc<DatabaseEnv>>) -> eyre::Result<()> {
+        self.table.view(&ExportViewer { tool, args: &self })
+    }
+}
+
+struct ExportViewer<'a, DB: Database> {
+    tool: &'a DbTool<DB>,
+    args: &'a Command,
+}
+
+impl<DB: Database> TableViewer<()> for ExportViewer<'_, DB> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<(), Self::Error> {
+        let start = match self.args.start_key.as_deref() {
+            Some(start) => Bound::Included(table_key::<T>(start)?),
+            None => Bound::Unbounded,
+        };
+        let end = match self.args.end_key.as_deref() {
+            Some(end) => Bound::Included(table_key::<T>(end)?),
+            None => Bound::Unbounded,
+        };
+
+        let provider =
+            self.tool.provider_factory.provider()?.disable_long_read_transaction_safety();
+        let tx = provider.tx_ref();
+
+        match &self.args.output {
+            Some(path) => {
+                let rows = export_table::<T, _>(tx, (start, end), File::create(path)?)?;
+                info!(target: "reth::cli", table = T::NAME, rows, ?path, "Exported table");
+            }
+            // nothing is logged, so the output can be piped
+            None => {
+                export_table::<T, _>(tx, (start, end), io::stdout().lock())?;
+            }
+        }
+
+        Ok(())
+    }
+}
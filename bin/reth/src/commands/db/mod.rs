@@ -11,6 +11,7 @@ use std::io::{self, Write};
 mod checksum;
 mod clear;
 mod diff;
+mod export;
 mod get;
 mod list;
 mod stats;
@@ -36,6 +37,8 @@ pub enum Subcommands {
     List(list::Command),
     /// Calculates the content checksum of a table
     Checksum(checksum::Command),
+    /// Exports the contents of a table as newline-delimited JSON
+    Export(export::Command),
     /// Create a diff between two database tables or two entire databases.
     Diff(diff::Command),
     /// Gets the content of a table for the given key
@@ -88,6 +91,11 @@ impl Command {
                     command.execute(&tool)?;
                 });
             }
+            Subcommands::Export(command) => {
+                db_ro_exec!(self.env, tool, {
+                    command.execute(&tool)?;
+                });
+            }
             Subcommands::Diff(command) => {
                 db_ro_exec!(self.env, tool, {
                     command.execute(&tool)?;
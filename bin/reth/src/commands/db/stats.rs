@@ -4,7 +4,9 @@ use comfy_table::{Cell, Row, Table as ComfyTable};
 use eyre::WrapErr;
 use human_bytes::human_bytes;
 use itertools::Itertools;
-use reth_db::{mdbx, static_file::iter_static_files, DatabaseEnv, TableViewer, Tables};
+use reth_db::{
+    database_stats, mdbx, static_file::iter_static_files, DatabaseEnv, TableViewer, Tables,
+};
 use reth_db_api::database::Database;
 use reth_fs_util as fs;
 use reth_node_core::dirs::{ChainPath, DataDirPath};
@@ -70,37 +72,18 @@ impl Command {
         ]);
 
         tool.provider_factory.db_ref().view(|tx| {
-            let mut db_tables = Tables::ALL.iter().map(|table| table.name()).collect::<Vec<_>>();
-            db_tables.sort();
-            let mut total_size = 0;
-            for db_table in db_tables {
-                let table_db = tx.inner.open_db(Some(db_table)).wrap_err("Could not open db.")?;
-
-                let stats = tx
-                    .inner
-                    .db_stat(&table_db)
-                    .wrap_err(format!("Could not find table: {db_table}"))?;
-
-                // Defaults to 16KB right now but we should
-                // re-evaluate depending on the DB we end up using
-                // (e.g. REDB does not have these options as configurable intentionally)
-                let page_size = stats.page_size() as usize;
-                let leaf_pages = stats.leaf_pages();
-                let branch_pages = stats.branch_pages();
-                let overflow_pages = stats.overflow_pages();
-                let num_pages = leaf_pages + branch_pages + overflow_pages;
-                let table_size = page_size * num_pages;
-
-                total_size += table_size;
+            let stats = database_stats(tx).wrap_err("Could not read table stats.")?;
+            for (db_table, table_stats) in &stats.tables {
                 let mut row = Row::new();
-                row.add_cell(Cell::new(db_table))
-                    .add_cell(Cell::new(stats.entries()))
-                    .add_cell(Cell::new(branch_pages))
-                    .add_cell(Cell::new(leaf_pages))
-                    .add_cell(Cell::new(overflow_pages))
-                    .add_cell(Cell::new(human_bytes(table_size as f64)));
+                row.add_cell(Cell::new(db_table.name()))
+                    .add_cell(Cell::new(table_stats.entries))
+                    .add_cell(Cell::new(table_stats.branch_pages))
+                    .add_cell(Cell::new(table_stats.leaf_pages))
+                    .add_cell(Cell::new(table_stats.overflow_pages))
+                    .add_cell(Cell::new(human_bytes(table_stats.total_size() as f64)));
                 table.add_row(row);
             }
+            let total_size = stats.total_size();
 
             let max_widths = table.column_max_content_widths();
             let mut separator = Row::new();
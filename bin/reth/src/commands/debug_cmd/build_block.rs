@@ -23,7 +23,7 @@ use reth_fs_util as fs;
 use reth_node_api::PayloadBuilderAttributes;
 use reth_payload_builder::database::CachedReads;
 use reth_primitives::{
-    constants::eip4844::{LoadKzgSettingsError, MAINNET_KZG_TRUSTED_SETUP},
+    constants::eip4844::{load_trusted_setup_from_file, MAINNET_KZG_TRUSTED_SETUP},
     revm_primitives::KzgSettings,
     Address, BlobTransaction, BlobTransactionSidecar, Bytes, PooledTransactionsElement,
     SealedBlock, SealedBlockWithSenders, Transaction, TransactionSigned, TxEip4844, B256, U256,
@@ -105,8 +105,7 @@ impl Command {
     /// `MAINNET_KZG_TRUSTED_SETUP`.
     fn kzg_settings(&self) -> eyre::Result<Arc<KzgSettings>> {
         if let Some(ref trusted_setup_file) = self.trusted_setup_file {
-            let trusted_setup = KzgSettings::load_trusted_setup_file(trusted_setup_file)
-                .map_err(LoadKzgSettingsError::KzgError)?;
+            let trusted_setup = load_trusted_setup_from_file(trusted_setup_file)?;
             Ok(Arc::new(trusted_setup))
         } else {
             Ok(Arc::clone(&MAINNET_KZG_TRUSTED_SETUP))
@@ -17,6 +17,7 @@ use reth_db::DatabaseEnv;
 use reth_fs_util as fs;
 use reth_network::NetworkHandle;
 use reth_network_api::NetworkInfo;
+use reth_network_p2p::full_block::FullBlockRequestLimits;
 use reth_node_core::engine::engine_store::{EngineMessageStore, StoredEngineApiMessage};
 use reth_payload_builder::{PayloadBuilderHandle, PayloadBuilderService};
 use reth_provider::{
@@ -159,6 +160,7 @@ impl Command {
             payload_builder,
             None,
             u64::MAX,
+            FullBlockRequestLimits::default(),
             EngineHooks::new(),
         )?;
         info!(target: "reth::cli", "Consensus engine initialized");
@@ -2,8 +2,8 @@
 
 use crate::args::{
     utils::{chain_help, genesis_value_parser, parse_socket_address, SUPPORTED_CHAINS},
-    DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs, PruningArgs,
-    RpcServerArgs, TxPoolArgs,
+    DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, NetworkArgs, NodeDatabaseArgs,
+    PayloadBuilderArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
 };
 use clap::{value_parser, Args, Parser};
 use reth_cli_runner::CliContext;
@@ -91,6 +91,10 @@ pub struct NodeCommand<Ext: clap::Args + fmt::Debug = NoArgs> {
     #[command(flatten)]
     pub db: DatabaseArgs,
 
+    /// All database related arguments that only apply to the running node
+    #[command(flatten)]
+    pub node_db: NodeDatabaseArgs,
+
     /// All dev related arguments with --dev prefix
     #[command(flatten)]
     pub dev: DevArgs,
@@ -145,6 +149,7 @@ impl<Ext: clap::Args + fmt::Debug> NodeCommand<Ext> {
             builder,
             debug,
             db,
+            node_db,
             dev,
             pruning,
             ext,
@@ -163,6 +168,7 @@ impl<Ext: clap::Args + fmt::Debug> NodeCommand<Ext> {
             builder,
             debug,
             db,
+            node_db,
             dev,
             pruning,
         };
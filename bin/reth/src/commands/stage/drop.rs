@@ -35,6 +35,7 @@ impl Command {
         let static_file_segment = match self.stage {
             StageEnum::Headers => Some(StaticFileSegment::Headers),
             StageEnum::Bodies => Some(StaticFileSegment::Transactions),
+            StageEnum::Senders => Some(StaticFileSegment::Senders),
             StageEnum::Execution => Some(StaticFileSegment::Receipts),
             _ => None,
         };
@@ -25,6 +25,8 @@ Automatically-generated CLI reference from `--help` output.
 
 def write_file(file_path, content):
     content = "\n".join([line.rstrip() for line in content.split("\n")])
+    if not content.endswith("\n"):
+        content += "\n"
     with open(file_path, "w") as f:
         f.write(content)
 
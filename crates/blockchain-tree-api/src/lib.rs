@@ -157,6 +157,16 @@ impl std::fmt::Display for BlockValidationKind {
     }
 }
 
+/// The part of the canonical chain that was reverted by
+/// [`BlockchainTreeEngine::make_canonical`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CanonicalReorg {
+    /// Block number and hash of the canonical head before the reorg.
+    pub old_head: BlockNumHash,
+    /// The number of blocks that were reverted.
+    pub depth: u64,
+}
+
 /// All possible outcomes of a canonicalization attempt of [`BlockchainTreeEngine::make_canonical`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CanonicalOutcome {
@@ -172,6 +182,9 @@ pub enum CanonicalOutcome {
     Committed {
         /// The new corresponding canonical head
         head: SealedHeader,
+        /// The reverted part of the previous canonical chain, if the committed chain did not
+        /// extend the previous canonical head.
+        reorg: Option<CanonicalReorg>,
     },
 }
 
@@ -180,7 +193,7 @@ impl CanonicalOutcome {
     pub const fn header(&self) -> &SealedHeader {
         match self {
             Self::AlreadyCanonical { header, .. } => header,
-            Self::Committed { head } => head,
+            Self::Committed { head, .. } => head,
         }
     }
 
@@ -188,7 +201,7 @@ impl CanonicalOutcome {
     pub fn into_header(self) -> SealedHeader {
         match self {
             Self::AlreadyCanonical { header, .. } => header,
-            Self::Committed { head } => head,
+            Self::Committed { head, .. } => head,
         }
     }
 
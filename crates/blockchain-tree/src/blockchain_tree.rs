@@ -7,7 +7,8 @@ use crate::{
 };
 use reth_blockchain_tree_api::{
     error::{BlockchainTreeError, CanonicalError, InsertBlockError, InsertBlockErrorKind},
-    BlockAttachment, BlockStatus, BlockValidationKind, CanonicalOutcome, InsertPayloadOk,
+    BlockAttachment, BlockStatus, BlockValidationKind, CanonicalOutcome, CanonicalReorg,
+    InsertPayloadOk,
 };
 use reth_consensus::{Consensus, ConsensusError};
 use reth_db_api::database::Database;
@@ -1126,6 +1127,7 @@ where
         );
 
         // If chain extends the tip
+        let mut reorg = None;
         let chain_notification = if new_canon_chain.fork_block().hash == old_tip.hash {
             // Commit new canonical chain to database.
             self.commit_canonical_to_database(new_canon_chain.clone(), &mut durations_recorder)?;
@@ -1163,6 +1165,10 @@ where
 
             if let Some(old_canon_chain) = old_canon_chain {
                 self.update_reorg_metrics(old_canon_chain.len() as f64);
+                reorg = Some(CanonicalReorg {
+                    old_head: old_canon_chain.tip().num_hash(),
+                    depth: old_canon_chain.len() as u64,
+                });
 
                 // Insert old canonical chain back into tree.
                 self.insert_unwound_chain(AppendableChain::new(old_canon_chain.clone()));
@@ -1203,7 +1209,8 @@ where
         durations_recorder.record_relative(MakeCanonicalAction::ClearTrieUpdatesForOtherChilds);
 
         // Send notification about new canonical chain and return outcome of canonicalization.
-        let outcome = CanonicalOutcome::Committed { head: chain_notification.tip().header.clone() };
+        let outcome =
+            CanonicalOutcome::Committed { head: chain_notification.tip().header.clone(), reorg };
         let _ = self.canon_state_notification_sender.send(chain_notification);
         Ok(outcome)
     }
@@ -1656,7 +1663,7 @@ mod tests {
 
         assert_eq!(
             tree.make_canonical(fork_block.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: fork_block.header.clone() }
+            CanonicalOutcome::Committed { head: fork_block.header.clone(), reorg: None }
         );
 
         assert_eq!(
@@ -1666,7 +1673,7 @@ mod tests {
 
         assert_eq!(
             tree.make_canonical(canonical_block_1.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: canonical_block_1.header.clone() }
+            CanonicalOutcome::Committed { head: canonical_block_1.header.clone(), reorg: None }
         );
 
         assert_eq!(
@@ -1681,12 +1688,18 @@ mod tests {
 
         assert_eq!(
             tree.make_canonical(sidechain_block_1.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: sidechain_block_1.header.clone() }
+            CanonicalOutcome::Committed {
+                head: sidechain_block_1.header.clone(),
+                reorg: Some(CanonicalReorg { old_head: canonical_block_1.num_hash(), depth: 1 })
+            }
         );
 
         assert_eq!(
             tree.make_canonical(canonical_block_1.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: canonical_block_1.header.clone() }
+            CanonicalOutcome::Committed {
+                head: canonical_block_1.header.clone(),
+                reorg: Some(CanonicalReorg { old_head: sidechain_block_1.num_hash(), depth: 1 })
+            }
         );
 
         assert_eq!(
@@ -1696,7 +1709,10 @@ mod tests {
 
         assert_eq!(
             tree.make_canonical(sidechain_block_2.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: sidechain_block_2.header.clone() }
+            CanonicalOutcome::Committed {
+                head: sidechain_block_2.header.clone(),
+                reorg: Some(CanonicalReorg { old_head: canonical_block_1.num_hash(), depth: 1 })
+            }
         );
 
         assert_eq!(
@@ -1706,7 +1722,10 @@ mod tests {
 
         assert_eq!(
             tree.make_canonical(canonical_block_3.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: canonical_block_3.header.clone() }
+            CanonicalOutcome::Committed {
+                head: canonical_block_3.header.clone(),
+                reorg: Some(CanonicalReorg { old_head: sidechain_block_2.num_hash(), depth: 2 })
+            }
         );
     }
 
@@ -1829,7 +1848,7 @@ mod tests {
 
         assert_eq!(
             tree.make_canonical(block2.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: block2.header.clone() }
+            CanonicalOutcome::Committed { head: block2.header.clone(), reorg: None }
         );
 
         assert_eq!(
@@ -1842,7 +1861,7 @@ mod tests {
 
         assert_eq!(
             tree.make_canonical(block3.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: block3.header.clone() }
+            CanonicalOutcome::Committed { head: block3.header.clone(), reorg: None }
         );
 
         assert_eq!(
@@ -1864,7 +1883,7 @@ mod tests {
 
         assert_eq!(
             tree.make_canonical(block5.hash()).unwrap(),
-            CanonicalOutcome::Committed { head: block5.header.clone() }
+            CanonicalOutcome::Committed { head: block5.header.clone(), reorg: None }
         );
 
         let provider = tree.externals.provider_factory.provider().unwrap();
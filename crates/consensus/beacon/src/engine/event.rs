@@ -1,5 +1,5 @@
 use crate::engine::forkchoice::ForkchoiceStatus;
-use reth_primitives::{SealedBlock, SealedHeader, B256};
+use reth_primitives::{BlockNumHash, SealedBlock, SealedHeader, B256};
 use reth_rpc_types::engine::ForkchoiceState;
 use std::{sync::Arc, time::Duration};
 
@@ -12,6 +12,16 @@ pub enum BeaconConsensusEngineEvent {
     CanonicalBlockAdded(Arc<SealedBlock>, Duration),
     /// A canonical chain was committed, and the elapsed time committing the data
     CanonicalChainCommitted(Box<SealedHeader>, Duration),
+    /// The committed canonical chain did not extend the previous canonical head, and parts of the
+    /// previous canonical chain were reverted.
+    ChainReorged {
+        /// Block number and hash of the canonical head before the reorg.
+        old_head: BlockNumHash,
+        /// Block number and hash of the new canonical head.
+        new_head: BlockNumHash,
+        /// The number of blocks that were reverted.
+        depth: u64,
+    },
     /// The consensus engine is involved in live sync, and has specific progress
     LiveSyncProgress(ConsensusEngineLiveSyncProgress),
     /// A block was added to the fork chain.
@@ -95,7 +95,7 @@ pub(crate) struct ReceivedForkchoiceState {
 }
 
 /// A simplified representation of [`PayloadStatusEnum`] specifically for FCU.
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum ForkchoiceStatus {
     /// The forkchoice state is valid.
     Valid,
@@ -118,6 +118,15 @@ impl ForkchoiceStatus {
         matches!(self, Self::Syncing)
     }
 
+    /// Returns the string representation of the status.
+    pub(crate) const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Valid => "valid",
+            Self::Invalid => "invalid",
+            Self::Syncing => "syncing",
+        }
+    }
+
     /// Converts the general purpose [`PayloadStatusEnum`] into a [`ForkchoiceStatus`].
     pub(crate) const fn from_payload_status(status: &PayloadStatusEnum) -> Self {
         match status {
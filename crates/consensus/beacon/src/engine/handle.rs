@@ -91,6 +91,17 @@ where
         let _ = self.to_engine.send(BeaconEngineMessage::TransitionConfigurationExchanged);
     }
 
+    /// Updates the pipeline run threshold of the beacon consensus engine.
+    ///
+    /// If the gap between the canonical head and a new head exceeds the threshold, the missing
+    /// blocks are synced with the pipeline instead of being downloaded and inserted into the
+    /// blockchain tree. The new threshold is used from the next forkchoice update on.
+    ///
+    /// Setting the threshold to `0` forces the pipeline to be used for any gap.
+    pub fn set_pipeline_run_threshold(&self, threshold: u64) {
+        let _ = self.to_engine.send(BeaconEngineMessage::SetPipelineRunThreshold { threshold });
+    }
+
     /// Creates a new [`BeaconConsensusEngineEvent`] listener stream.
     pub fn event_listener(&self) -> EventStream<BeaconConsensusEngineEvent> {
         self.event_sender.new_listener()
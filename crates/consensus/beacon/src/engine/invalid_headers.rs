@@ -1,8 +1,11 @@
+use reth_db_api::database::Database;
+use reth_errors::ProviderResult;
 use reth_metrics::{
     metrics::{Counter, Gauge},
     Metrics,
 };
 use reth_primitives::{Header, SealedHeader, B256};
+use reth_provider::{InvalidHeadersReader, InvalidHeadersWriter, ProviderFactory};
 use schnellru::{ByLength, LruMap};
 use std::sync::Arc;
 use tracing::warn;
@@ -27,7 +30,14 @@ impl InvalidHeaderCache {
     }
 
     fn insert_entry(&mut self, hash: B256, header: Arc<Header>) {
+        let len = self.headers.len() + usize::from(self.headers.peek(&hash).is_none());
         self.headers.insert(hash, HeaderEntry { header, hit_count: 0 });
+
+        // the least recently used headers are evicted if the cache is full
+        let evicted = len.saturating_sub(self.headers.len());
+        if evicted > 0 {
+            self.metrics.capacity_evictions.increment(evicted as u64);
+        }
     }
 
     /// Returns the invalid ancestor's header if it exists in the cache.
@@ -77,6 +87,43 @@ impl InvalidHeaderCache {
             self.metrics.count.set(self.headers.len() as f64);
         }
     }
+
+    /// Inserts invalid ancestors that were restored from the database.
+    fn restore(&mut self, invalid_ancestors: Vec<SealedHeader>) {
+        for invalid_ancestor in invalid_ancestors {
+            let hash = invalid_ancestor.hash();
+            self.insert_entry(hash, Arc::new(invalid_ancestor.unseal()));
+        }
+        self.metrics.count.set(self.headers.len() as f64);
+    }
+}
+
+/// Persists invalid headers in the database, so that they are still known after a restart.
+pub(crate) struct InvalidHeaderStore<DB> {
+    provider_factory: ProviderFactory<DB>,
+    /// The maximum number of invalid headers kept in the database.
+    max_entries: usize,
+}
+
+impl<DB: Database> InvalidHeaderStore<DB> {
+    pub(crate) const fn new(provider_factory: ProviderFactory<DB>, max_entries: usize) -> Self {
+        Self { provider_factory, max_entries }
+    }
+
+    /// Loads all persisted invalid headers into the given cache.
+    pub(crate) fn load_into(&self, cache: &mut InvalidHeaderCache) -> ProviderResult<()> {
+        let invalid_headers = self.provider_factory.provider()?.invalid_headers()?;
+        cache.restore(invalid_headers);
+        Ok(())
+    }
+
+    /// Persists the given invalid header.
+    pub(crate) fn save(&self, invalid_ancestor: SealedHeader) -> ProviderResult<()> {
+        let provider = self.provider_factory.provider_rw()?;
+        provider.save_invalid_header(invalid_ancestor, self.max_entries)?;
+        provider.commit()?;
+        Ok(())
+    }
 }
 
 struct HeaderEntry {
@@ -98,11 +145,14 @@ struct InvalidHeaderCacheMetrics {
     unique_inserts: Counter,
     /// The number of times a header was evicted from the cache because it was hit too many times.
     hit_evictions: Counter,
+    /// The number of times a header was evicted from the cache because the cache was full.
+    capacity_evictions: Counter,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reth_provider::test_utils::create_test_provider_factory;
 
     #[test]
     fn test_hit_eviction() {
@@ -118,4 +168,47 @@ mod tests {
 
         assert!(cache.get(&header.hash()).is_none());
     }
+
+    #[test]
+    fn test_capacity_eviction() {
+        let mut cache = InvalidHeaderCache::new(2);
+        let headers = (1..=3)
+            .map(|number| Header { number, ..Default::default() }.seal_slow())
+            .collect::<Vec<_>>();
+        for header in &headers {
+            cache.insert(header.clone());
+        }
+
+        // the least recently used header is evicted
+        assert_eq!(cache.headers.len(), 2);
+        assert!(cache.get(&headers[0].hash()).is_none());
+        assert!(cache.get(&headers[1].hash()).is_some());
+        assert!(cache.get(&headers[2].hash()).is_some());
+    }
+
+    #[test]
+    fn test_restore_persisted_invalid_headers() {
+        let provider_factory = create_test_provider_factory();
+        let store = InvalidHeaderStore::new(provider_factory.clone(), 2);
+
+        let invalid = Header { number: 1, ..Default::default() }.seal_slow();
+        let mut cache = InvalidHeaderCache::new(10);
+        cache.insert(invalid.clone());
+        store.save(invalid.clone()).unwrap();
+
+        // simulate a restart with a fresh cache that is restored from the database
+        let mut cache = InvalidHeaderCache::new(10);
+        assert!(cache.get(&invalid.hash()).is_none());
+        store.load_into(&mut cache).unwrap();
+        assert_eq!(cache.get(&invalid.hash()).as_deref(), Some(invalid.header()));
+
+        // the persisted set is bounded, the headers with the lowest block numbers are evicted
+        for number in 2..=3 {
+            store.save(Header { number, ..Default::default() }.seal_slow()).unwrap();
+        }
+        let persisted = provider_factory.provider().unwrap().invalid_headers().unwrap();
+        let mut numbers = persisted.iter().map(|header| header.number).collect::<Vec<_>>();
+        numbers.sort_unstable();
+        assert_eq!(numbers, vec![2, 3]);
+    }
 }
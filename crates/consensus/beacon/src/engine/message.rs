@@ -159,4 +159,10 @@ pub enum BeaconEngineMessage<Engine: EngineTypes> {
     },
     /// Message with exchanged transition configuration.
     TransitionConfigurationExchanged,
+    /// Message with an updated pipeline run threshold.
+    SetPipelineRunThreshold {
+        /// The largest gap to the canonical head for which missing blocks are synced with the
+        /// blockchain tree. `0` forces the pipeline to be used for any gap.
+        threshold: u64,
+    },
 }
@@ -1,7 +1,9 @@
+use crate::engine::forkchoice::ForkchoiceStatus;
 use reth_metrics::{
     metrics::{Counter, Gauge, Histogram},
     Metrics,
 };
+use std::collections::HashMap;
 
 /// Beacon consensus engine metrics.
 #[derive(Metrics)]
@@ -11,6 +13,8 @@ pub(crate) struct EngineMetrics {
     pub(crate) pipeline_runs: Counter,
     /// The total count of forkchoice updated messages received.
     pub(crate) forkchoice_updated_messages: Counter,
+    /// The total count of duplicate forkchoice updated messages answered without processing.
+    pub(crate) forkchoice_updated_duplicates: Counter,
     /// The total count of new payload messages received.
     pub(crate) new_payload_messages: Counter,
     /// Latency for making canonical already canonical block
@@ -21,6 +25,30 @@ pub(crate) struct EngineMetrics {
     pub(crate) make_canonical_error_latency: Histogram,
     /// Latency for all making canonical results
     pub(crate) make_canonical_latency: Histogram,
+    /// Forkchoice updated metrics per resulting status.
+    #[metric(skip)]
+    forkchoice_updated_status: HashMap<ForkchoiceStatus, ForkchoiceUpdatedMetrics>,
+}
+
+impl EngineMetrics {
+    /// Returns existing or initializes a new instance of [`ForkchoiceUpdatedMetrics`] for the
+    /// provided [`ForkchoiceStatus`].
+    pub(crate) fn forkchoice_updated_status_metrics(
+        &mut self,
+        status: ForkchoiceStatus,
+    ) -> &mut ForkchoiceUpdatedMetrics {
+        self.forkchoice_updated_status.entry(status).or_insert_with(|| {
+            ForkchoiceUpdatedMetrics::new_with_labels(&[("status", status.as_str())])
+        })
+    }
+}
+
+/// Forkchoice updated metrics, labeled by the resulting [`ForkchoiceStatus`].
+#[derive(Metrics)]
+#[metrics(scope = "consensus.engine.beacon")]
+pub(crate) struct ForkchoiceUpdatedMetrics {
+    /// Latency for processing a forkchoice update, from receipt until the response was sent
+    pub(crate) forkchoice_updated_latency: Histogram,
 }
 
 /// Metrics for the `EngineSyncController`.
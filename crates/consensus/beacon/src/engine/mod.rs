@@ -1,14 +1,16 @@
-use futures::{stream::BoxStream, Future, StreamExt};
+use futures::{stream::BoxStream, Future, FutureExt, StreamExt};
 use itertools::Either;
 use reth_blockchain_tree_api::{
     error::{BlockchainTreeError, CanonicalError, InsertBlockError, InsertBlockErrorKind},
-    BlockStatus, BlockValidationKind, BlockchainTreeEngine, CanonicalOutcome, InsertPayloadOk,
+    BlockStatus, BlockValidationKind, BlockchainTreeEngine, CanonicalOutcome, CanonicalReorg,
+    InsertPayloadOk,
 };
 use reth_db_api::database::Database;
 use reth_engine_primitives::EngineTypes;
 use reth_errors::{BlockValidationError, ProviderResult, RethError, RethResult};
 use reth_network_p2p::{
     bodies::client::BodiesClient,
+    full_block::FullBlockRequestLimits,
     headers::client::HeadersClient,
     sync::{NetworkSyncUpdater, SyncState},
 };
@@ -21,14 +23,17 @@ use reth_primitives::{
 };
 use reth_provider::{
     BlockIdReader, BlockReader, BlockSource, CanonChainTracker, ChainSpecProvider, ProviderError,
-    StageCheckpointReader,
+    ProviderFactory, StageCheckpointReader,
 };
 use reth_rpc_types::engine::{
     CancunPayloadFields, ExecutionPayload, ForkchoiceState, PayloadStatus, PayloadStatusEnum,
     PayloadValidationError,
 };
 use reth_stages_api::{ControlFlow, Pipeline, PipelineTarget, StageId};
-use reth_tasks::TaskSpawner;
+use reth_tasks::{
+    shutdown::{GracefulShutdown, GracefulShutdownGuard},
+    TaskSpawner,
+};
 use reth_tokio_util::EventSender;
 use std::{
     pin::Pin,
@@ -36,9 +41,12 @@ use std::{
     task::{Context, Poll},
     time::{Duration, Instant},
 };
-use tokio::sync::{
-    mpsc::{self, UnboundedSender},
-    oneshot,
+use tokio::{
+    sync::{
+        mpsc::{self, UnboundedSender},
+        oneshot,
+    },
+    time::Sleep,
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::*;
@@ -53,7 +61,7 @@ pub use error::{
 };
 
 mod invalid_headers;
-use invalid_headers::InvalidHeaderCache;
+use invalid_headers::{InvalidHeaderCache, InvalidHeaderStore};
 
 mod event;
 pub use event::{BeaconConsensusEngineEvent, ConsensusEngineLiveSyncProgress};
@@ -79,8 +87,14 @@ use hooks::{EngineHookContext, EngineHookEvent, EngineHooks, EngineHooksControll
 #[cfg(test)]
 pub mod test_utils;
 
-/// The maximum number of invalid headers that can be tracked by the engine.
-const MAX_INVALID_HEADERS: u32 = 512u32;
+/// The default maximum number of invalid headers that can be tracked by the engine, see
+/// [`BeaconConsensusEngine::with_invalid_headers_cache_size`].
+pub const DEFAULT_MAX_INVALID_HEADERS: u32 = 512u32;
+
+/// The default maximum number of invalid headers that are persisted in the database if
+/// persistence of invalid headers is enabled, see
+/// [`BeaconConsensusEngine::with_persisted_invalid_headers`].
+pub const MAX_PERSISTED_INVALID_HEADERS: usize = 256;
 
 /// The largest gap for which the tree will be used for sync. See docs for `pipeline_run_threshold`
 /// for more information.
@@ -89,6 +103,17 @@ const MAX_INVALID_HEADERS: u32 = 512u32;
 /// If the distance exceeds this threshold, the pipeline will be used for sync.
 pub const MIN_BLOCKS_FOR_PIPELINE_RUN: u64 = EPOCH_SLOTS;
 
+/// The validation error of the `INVALID` status that a payload for the genesis block is rejected
+/// with.
+///
+/// The genesis block is defined by the chain spec and has no parent, so it can never be a valid
+/// payload.
+pub const GENESIS_PAYLOAD_ERROR: &str = "genesis block can not be a payload";
+
+/// The default period for which engine messages are still answered after the shutdown signal was
+/// received, see [`BeaconConsensusEngine::with_graceful_shutdown`].
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
 /// The beacon consensus engine is the driver that switches between historical and live sync.
 ///
 /// The beacon consensus engine is itself driven by messages from the Consensus Layer, which are
@@ -182,6 +207,11 @@ where
     forkchoice_state_tracker: ForkchoiceStateTracker,
     /// The payload store.
     payload_builder: PayloadBuilderHandle<EngineT>,
+    /// The most recent forkchoice state that was successfully applied to the canonical chain.
+    ///
+    /// A repeated forkchoice update with the same state and without payload attributes is
+    /// answered from this without making the head canonical again.
+    last_valid_forkchoice: Option<ForkchoiceState>,
     /// Validator for execution payloads
     payload_validator: ExecutionPayloadValidator,
     /// Current blockchain tree action.
@@ -195,10 +225,13 @@ where
         ForkchoiceState,
         Option<EngineT::PayloadAttributes>,
         oneshot::Sender<RethResult<OnForkChoiceUpdated>>,
+        Instant,
     )>,
     /// Tracks the header of invalid payloads that were rejected by the engine because they're
     /// invalid.
     invalid_headers: InvalidHeaderCache,
+    /// Persists invalid headers in the database, if enabled.
+    invalid_headers_store: Option<InvalidHeaderStore<DB>>,
     /// After downloading a block corresponding to a recent forkchoice update, the engine will
     /// check whether or not we can connect the block to the current canonical chain. If we can't,
     /// we need to download and execute the missing parents of that block.
@@ -210,12 +243,26 @@ where
     /// If the gap is larger than this threshold, the engine will download and execute the missing
     /// blocks using the pipeline. Otherwise, the engine, sync controller, and blockchain tree will
     /// be used to download and execute the missing blocks.
+    ///
+    /// A threshold of `0` forces the pipeline to be used for any gap. The threshold can be updated
+    /// at runtime with [`BeaconConsensusEngineHandle::set_pipeline_run_threshold`].
     pipeline_run_threshold: u64,
+    /// The highest block number the canonical head is allowed to advance to, if any.
+    ///
+    /// Forkchoice updates with a head above this block are answered with `SYNCING` and the head
+    /// is not made canonical, see [`Self::with_max_head_block`].
+    max_head_block: Option<BlockNumber>,
     hooks: EngineHooksController,
     /// Sender for engine events.
     event_sender: EventSender<BeaconConsensusEngineEvent>,
     /// Consensus engine metrics.
     metrics: EngineMetrics,
+    /// Resolves once the node is shutting down, if graceful shutdown is enabled.
+    graceful_shutdown: Option<GracefulShutdown>,
+    /// How long engine messages are answered after the shutdown signal was received.
+    shutdown_grace_period: Duration,
+    /// Set once the shutdown signal was received.
+    shutdown: Option<EngineShutdown>,
 }
 
 impl<DB, BT, Client, EngineT> BeaconConsensusEngine<DB, BT, Client, EngineT>
@@ -244,6 +291,7 @@ where
         payload_builder: PayloadBuilderHandle<EngineT>,
         target: Option<B256>,
         pipeline_run_threshold: u64,
+        download_limits: FullBlockRequestLimits,
         hooks: EngineHooks,
     ) -> RethResult<(Self, BeaconConsensusEngineHandle<EngineT>)> {
         let (to_engine, rx) = mpsc::unbounded_channel();
@@ -258,6 +306,7 @@ where
             payload_builder,
             target,
             pipeline_run_threshold,
+            download_limits,
             to_engine,
             Box::pin(UnboundedReceiverStream::from(rx)),
             hooks,
@@ -289,6 +338,7 @@ where
         payload_builder: PayloadBuilderHandle<EngineT>,
         target: Option<B256>,
         pipeline_run_threshold: u64,
+        download_limits: FullBlockRequestLimits,
         to_engine: UnboundedSender<BeaconEngineMessage<EngineT>>,
         engine_message_stream: BoxStream<'static, BeaconEngineMessage<EngineT>>,
         hooks: EngineHooks,
@@ -303,6 +353,7 @@ where
             max_block,
             blockchain.chain_spec(),
             event_sender.clone(),
+            download_limits,
         );
         let mut this = Self {
             sync,
@@ -313,13 +364,19 @@ where
             handle: handle.clone(),
             forkchoice_state_tracker: Default::default(),
             payload_builder,
-            invalid_headers: InvalidHeaderCache::new(MAX_INVALID_HEADERS),
+            last_valid_forkchoice: None,
+            invalid_headers: InvalidHeaderCache::new(DEFAULT_MAX_INVALID_HEADERS),
+            invalid_headers_store: None,
             blockchain_tree_action: None,
             pending_forkchoice_update: None,
             pipeline_run_threshold,
+            max_head_block: None,
             hooks: EngineHooksController::new(hooks),
             event_sender,
             metrics: EngineMetrics::default(),
+            graceful_shutdown: None,
+            shutdown_grace_period: DEFAULT_SHUTDOWN_GRACE_PERIOD,
+            shutdown: None,
         };
 
         let maybe_pipeline_target = match target {
@@ -335,6 +392,106 @@ where
         Ok((this, handle))
     }
 
+    /// Sets the maximum number of invalid headers that are tracked by the engine.
+    ///
+    /// Once the cache is full, the least recently used invalid header is evicted. This replaces
+    /// the cache, so it must be configured before
+    /// [`with_persisted_invalid_headers`](Self::with_persisted_invalid_headers).
+    pub fn with_invalid_headers_cache_size(mut self, max_length: u32) -> Self {
+        self.invalid_headers = InvalidHeaderCache::new(max_length);
+        self
+    }
+
+    /// Pins the canonical head at or below the given block number.
+    ///
+    /// Unlike the `max_block` of the pipeline, reaching this block does not terminate the engine.
+    /// The engine keeps running, but forkchoice updates with a known head above this block are
+    /// answered with `SYNCING` and the head is not made canonical. Blocks above it can still be
+    /// downloaded and inserted into the tree.
+    ///
+    /// The pipeline doesn't sync past this block either, because the number of an unknown head
+    /// can't be checked before the pipeline runs.
+    pub fn with_max_head_block(mut self, max_head_block: BlockNumber) -> Self {
+        self.max_head_block = Some(max_head_block);
+        self.sync.set_pipeline_max_block(max_head_block);
+        self
+    }
+
+    /// Enables persistence of invalid headers in the database.
+    ///
+    /// Headers that were persisted by a previous run are loaded into the invalid headers cache, so
+    /// that payloads known to be invalid are rejected right away instead of being re-executed after
+    /// a restart. At most `max_entries` headers are kept in the database.
+    pub fn with_persisted_invalid_headers(
+        mut self,
+        provider_factory: ProviderFactory<DB>,
+        max_entries: usize,
+    ) -> RethResult<Self> {
+        let store = InvalidHeaderStore::new(provider_factory, max_entries);
+        store.load_into(&mut self.invalid_headers)?;
+        self.invalid_headers_store = Some(store);
+        Ok(self)
+    }
+
+    /// Enables graceful shutdown of the engine.
+    ///
+    /// Once the shutdown signal is received, the engine stops processing engine messages. Messages
+    /// that were already received or arrive within the grace period are answered with `SYNCING`
+    /// instead of being dropped, so the CL is not left waiting for a response. The engine
+    /// terminates after the grace period.
+    pub fn with_graceful_shutdown(
+        mut self,
+        shutdown: GracefulShutdown,
+        grace_period: Duration,
+    ) -> Self {
+        self.graceful_shutdown = Some(shutdown);
+        self.shutdown_grace_period = grace_period;
+        self
+    }
+
+    /// Starts the shutdown of the engine.
+    ///
+    /// A forkchoice update that is waiting for a hook with database write access is answered with
+    /// `SYNCING`.
+    fn on_shutdown_signal(&mut self, guard: GracefulShutdownGuard) {
+        debug!(
+            target: "consensus::engine",
+            grace_period = ?self.shutdown_grace_period,
+            "Received shutdown signal, draining engine messages"
+        );
+        if let Some((_, _, tx, _)) = self.pending_forkchoice_update.take() {
+            let _ = tx.send(Ok(OnForkChoiceUpdated::syncing()));
+        }
+        self.shutdown = Some(EngineShutdown {
+            deadline: Box::pin(tokio::time::sleep(self.shutdown_grace_period)),
+            _guard: guard,
+        });
+    }
+
+    /// Answers all incoming engine messages with `SYNCING` until the shutdown grace period
+    /// elapsed.
+    ///
+    /// Returns `Poll::Ready` once the engine can terminate.
+    fn poll_shutdown(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        while let Poll::Ready(Some(msg)) = self.engine_message_stream.poll_next_unpin(cx) {
+            match msg {
+                BeaconEngineMessage::ForkchoiceUpdated { tx, .. } => {
+                    let _ = tx.send(Ok(OnForkChoiceUpdated::syncing()));
+                }
+                BeaconEngineMessage::NewPayload { tx, .. } => {
+                    let _ = tx.send(Ok(PayloadStatus::from_status(PayloadStatusEnum::Syncing)));
+                }
+                BeaconEngineMessage::TransitionConfigurationExchanged |
+                BeaconEngineMessage::SetPipelineRunThreshold { .. } => {}
+            }
+        }
+
+        match self.shutdown.as_mut() {
+            Some(shutdown) => shutdown.deadline.as_mut().poll(cx),
+            None => Poll::Ready(()),
+        }
+    }
+
     /// Returns current [`EngineHookContext`] that's used for polling engine hooks.
     fn current_engine_hook_context(&self) -> RethResult<EngineHookContext> {
         Ok(EngineHookContext {
@@ -371,6 +528,11 @@ where
             return Ok(Some(OnForkChoiceUpdated::with_invalid(status)))
         }
 
+        if self.exceeds_max_head_block(state.head_block_hash)? {
+            trace!(target: "consensus::engine", head=?state.head_block_hash, max_head_block=?self.max_head_block, "Head is above the max head block, skipping forkchoice update");
+            return Ok(Some(OnForkChoiceUpdated::syncing()))
+        }
+
         if self.sync.is_pipeline_active() {
             // We can only process new forkchoice updates if the pipeline is idle, since it requires
             // exclusive access to the database
@@ -381,6 +543,21 @@ where
         Ok(None)
     }
 
+    /// Returns `true` if the block with the given hash is known and above the configured
+    /// [`max_head_block`](Self::with_max_head_block).
+    fn exceeds_max_head_block(&self, hash: B256) -> ProviderResult<bool> {
+        let Some(max_head_block) = self.max_head_block else { return Ok(false) };
+        let number = match self
+            .blockchain
+            .header_by_hash(hash)
+            .or_else(|| self.blockchain.buffered_header_by_hash(hash))
+        {
+            Some(header) => Some(header.number),
+            None => self.blockchain.block_number(hash)?,
+        };
+        Ok(number.is_some_and(|number| number > max_head_block))
+    }
+
     /// Process the result of attempting to make forkchoice state head hash canonical.
     ///
     /// # Returns
@@ -399,9 +576,12 @@ where
                     CanonicalOutcome::AlreadyCanonical { head, header } => {
                         self.on_head_already_canonical(head, header, &mut attrs)
                     }
-                    CanonicalOutcome::Committed { head } => {
+                    CanonicalOutcome::Committed { head, reorg } => {
                         // new VALID update that moved the canonical chain forward
                         debug!(target: "consensus::engine", hash=?state.head_block_hash, number=head.number, "Canonicalized new head");
+                        if let Some(reorg) = reorg {
+                            self.on_chain_reorged(head, reorg);
+                        }
                         true
                     }
                 };
@@ -421,15 +601,18 @@ where
                 {
                     trace!(target: "consensus::engine", ?state, "Forkchoice state is inconsistent");
                     invalid_fcu_response
-                } else if let Some(attrs) = attrs {
-                    // the CL requested to build a new payload on top of this new VALID head
-                    let head = outcome.into_header().unseal();
-                    self.process_payload_attributes(attrs, head, state)
                 } else {
-                    OnForkChoiceUpdated::valid(PayloadStatus::new(
-                        PayloadStatusEnum::Valid,
-                        Some(state.head_block_hash),
-                    ))
+                    self.last_valid_forkchoice = Some(state);
+                    if let Some(attrs) = attrs {
+                        // the CL requested to build a new payload on top of this new VALID head
+                        let head = outcome.into_header().unseal();
+                        self.process_payload_attributes(attrs, head, state)
+                    } else {
+                        OnForkChoiceUpdated::valid(PayloadStatus::new(
+                            PayloadStatusEnum::Valid,
+                            Some(state.head_block_hash),
+                        ))
+                    }
                 };
                 Ok(on_updated)
             }
@@ -500,6 +683,7 @@ where
         attrs: Option<EngineT::PayloadAttributes>,
         tx: oneshot::Sender<RethResult<OnForkChoiceUpdated>>,
     ) {
+        let received_at = Instant::now();
         self.metrics.forkchoice_updated_messages.increment(1);
         self.blockchain.on_forkchoice_update_received(&state);
         trace!(target: "consensus::engine", ?state, "Received new forkchoice state update");
@@ -509,26 +693,42 @@ where
                 if let Some(on_updated) = on_updated_result {
                     // Pre-validate forkchoice state update and return if it's invalid
                     // or cannot be processed at the moment.
-                    self.on_forkchoice_updated_status(state, on_updated, tx);
+                    self.on_forkchoice_updated_status(state, on_updated, tx, received_at);
+                } else if self.last_valid_forkchoice.take() == Some(state) && attrs.is_none() {
+                    // This is a duplicate of the last applied forkchoice update, the head is
+                    // already canonical so there's nothing to do.
+                    trace!(target: "consensus::engine", ?state, "Received duplicate forkchoice state update");
+                    self.metrics.forkchoice_updated_duplicates.increment(1);
+                    self.last_valid_forkchoice = Some(state);
+                    let on_updated = OnForkChoiceUpdated::valid(PayloadStatus::new(
+                        PayloadStatusEnum::Valid,
+                        Some(state.head_block_hash),
+                    ));
+                    self.on_forkchoice_updated_status(state, on_updated, tx, received_at);
                 } else if let Some(hook) = self.hooks.active_db_write_hook() {
                     // We can only process new forkchoice updates if no hook with db write is
                     // running, since it requires exclusive access to the
                     // database
                     let replaced_pending =
-                        self.pending_forkchoice_update.replace((state, attrs, tx));
+                        self.pending_forkchoice_update.replace((state, attrs, tx, received_at));
                     warn!(
                         target: "consensus::engine",
                         hook = %hook.name(),
                         head_block_hash = ?state.head_block_hash,
                         safe_block_hash = ?state.safe_block_hash,
                         finalized_block_hash = ?state.finalized_block_hash,
-                        replaced_pending = ?replaced_pending.map(|(state, _, _)| state),
+                        replaced_pending = ?replaced_pending.map(|(state, _, _, _)| state),
                         "Hook is in progress, delaying forkchoice update. \
                         This may affect the performance of your node as a validator."
                     );
                 } else {
                     self.set_blockchain_tree_action(
-                        BlockchainTreeAction::MakeForkchoiceHeadCanonical { state, attrs, tx },
+                        BlockchainTreeAction::MakeForkchoiceHeadCanonical {
+                            state,
+                            attrs,
+                            tx,
+                            received_at,
+                        },
                     );
                 }
             }
@@ -541,15 +741,22 @@ where
     /// Called after the forkchoice update status has been resolved.
     /// Depending on the outcome, the method updates the sync state and notifies the listeners
     /// about new processed FCU.
+    ///
+    /// The latency since the forkchoice update was received is recorded per resulting status.
     fn on_forkchoice_updated_status(
         &mut self,
         state: ForkchoiceState,
         on_updated: OnForkChoiceUpdated,
         tx: oneshot::Sender<RethResult<OnForkChoiceUpdated>>,
+        received_at: Instant,
     ) {
         // send the response to the CL ASAP
         let status = on_updated.forkchoice_status();
         let _ = tx.send(Ok(on_updated));
+        self.metrics
+            .forkchoice_updated_status_metrics(status)
+            .forkchoice_updated_latency
+            .record(received_at.elapsed());
 
         // update the forkchoice state tracker
         self.forkchoice_state_tracker.set_latest(state, status);
@@ -815,6 +1022,27 @@ where
         Ok(Some(self.prepare_invalid_response(header.parent_hash)?))
     }
 
+    /// Keeps track of a new invalid header in the invalid headers cache, and persists it if
+    /// persistence of invalid headers is enabled.
+    fn on_invalid_header(&mut self, header: SealedHeader) {
+        if let Some(store) = &self.invalid_headers_store {
+            if let Err(error) = store.save(header.clone()) {
+                warn!(target: "consensus::engine", hash=?header.hash(), %error, "Failed to persist invalid header");
+            }
+        }
+        self.invalid_headers.insert(header);
+    }
+
+    /// Notifies listeners that committing `head` reverted parts of the previous canonical chain.
+    fn on_chain_reorged(&self, head: &SealedHeader, reorg: &CanonicalReorg) {
+        debug!(target: "consensus::engine", old_head=?reorg.old_head, new_head=?head.num_hash(), depth=reorg.depth, "Canonical chain reorged");
+        self.event_sender.notify(BeaconConsensusEngineEvent::ChainReorged {
+            old_head: reorg.old_head,
+            new_head: head.num_hash(),
+            depth: reorg.depth,
+        });
+    }
+
     /// Record latency metrics for one call to make a block canonical
     /// Takes start time of the call and result of the make canonical call
     ///
@@ -1133,6 +1361,16 @@ where
             }
         };
 
+        if block.number == 0 {
+            // the payload is well formed, but the genesis block is never a valid payload
+            let latest_valid_hash = self
+                .latest_valid_hash_for_invalid_payload(parent_hash)
+                .map_err(BeaconOnNewPayloadError::internal)?;
+            let status =
+                PayloadStatusEnum::Invalid { validation_error: GENESIS_PAYLOAD_ERROR.to_string() };
+            return Ok(Either::Left(PayloadStatus::new(status, latest_valid_hash)))
+        }
+
         let mut lowest_buffered_ancestor = self.lowest_buffered_ancestor_or(block.hash());
         if lowest_buffered_ancestor == block.hash() {
             lowest_buffered_ancestor = block.parent_hash;
@@ -1178,7 +1416,9 @@ where
         ) {
             Ok(attributes) => {
                 // send the payload to the builder and return the receiver for the pending payload
-                // id, initiating payload job is handled asynchronously
+                // id, initiating payload job is handled asynchronously. If the same attributes were
+                // already sent for this head, e.g. because the CL retried the forkchoice update,
+                // the payload builder returns the id of the job that is still in progress.
                 let pending_payload_id = self.payload_builder.send_new_payload(attributes);
 
                 // Client software MUST respond to this method call in the following way:
@@ -1332,6 +1572,13 @@ where
     ) -> Result<(), (B256, CanonicalError)> {
         let Some(target) = self.forkchoice_state_tracker.sync_target_state() else { return Ok(()) };
 
+        // the downloaded head may be above the max head block, which must not become canonical
+        match self.exceeds_max_head_block(target.head_block_hash) {
+            Ok(false) => {}
+            Ok(true) => return Ok(()),
+            Err(err) => return Err((target.head_block_hash, err.into())),
+        }
+
         // optimistically try to make the head of the current FCU target canonical, the sync
         // target might have changed since the block download request was issued
         // (new FCU received)
@@ -1340,7 +1587,10 @@ where
         let elapsed = self.record_make_canonical_latency(start, &make_canonical_result);
         match make_canonical_result {
             Ok(outcome) => {
-                if let CanonicalOutcome::Committed { head } = &outcome {
+                if let CanonicalOutcome::Committed { head, reorg } = &outcome {
+                    if let Some(reorg) = reorg {
+                        self.on_chain_reorged(head, reorg);
+                    }
                     self.event_sender.notify(BeaconConsensusEngineEvent::CanonicalChainCommitted(
                         Box::new(head.clone()),
                         elapsed,
@@ -1374,8 +1624,15 @@ where
                     if let Some(target_hash) =
                         ForkchoiceStateHash::find(&target, inserted.hash).filter(|h| !h.is_head())
                     {
-                        // TODO: do not ignore this
-                        let _ = self.blockchain.make_canonical(*target_hash.as_ref());
+                        match self.blockchain.make_canonical(*target_hash.as_ref()) {
+                            Ok(CanonicalOutcome::Committed { head, reorg: Some(reorg) }) => {
+                                self.on_chain_reorged(&head, &reorg);
+                            }
+                            Ok(_) => {}
+                            Err(err) => {
+                                debug!(target: "consensus::engine", ?err, ?target_hash, "Failed to make the sync target canonical");
+                            }
+                        }
                     }
                 } else if let Some(block_number) = err.optimistic_revert_block_number() {
                     self.sync.set_pipeline_sync_target(PipelineTarget::Unwind(block_number));
@@ -1440,11 +1697,15 @@ where
     /// Updates the internal sync state depending on the pipeline configuration,
     /// the outcome of the pipeline run and the last observed forkchoice state.
     fn on_pipeline_outcome(&mut self, ctrl: ControlFlow) -> RethResult<()> {
+        // The pipeline may have moved the canonical chain, so the next forkchoice update must be
+        // processed in full.
+        self.last_valid_forkchoice = None;
+
         // Pipeline unwound, memorize the invalid block and wait for CL for next sync target.
         if let ControlFlow::Unwind { bad_block, .. } = ctrl {
             warn!(target: "consensus::engine", invalid_hash=?bad_block.hash(), invalid_number=?bad_block.number, "Bad block detected in unwind");
             // update the `invalid_headers` cache with the new invalid header
-            self.invalid_headers.insert(*bad_block);
+            self.on_invalid_header(*bad_block);
             return Ok(())
         }
 
@@ -1453,6 +1714,17 @@ where
             self.set_canonical_head(ctrl.block_number().unwrap_or_default())?;
         }
 
+        // The pipeline stopped at the max head block, so the sync target must not be synced to
+        if let Some(progress) = ctrl.block_number().filter(|progress| {
+            self.max_head_block.is_some_and(|max_head_block| *progress >= max_head_block)
+        }) {
+            trace!(target: "consensus::engine", progress, max_head_block=?self.max_head_block, "Pipeline reached the max head block");
+            self.set_canonical_head(progress)?;
+            self.blockchain.update_block_hashes_and_clear_buffered()?;
+            self.blockchain.connect_buffered_blocks_to_canonical_hashes()?;
+            return Ok(())
+        }
+
         let sync_target_state = match self.forkchoice_state_tracker.sync_target_state() {
             Some(current_state) => current_state,
             None => {
@@ -1602,7 +1874,7 @@ where
         action: BlockchainTreeAction<EngineT>,
     ) -> RethResult<EngineEventOutcome> {
         match action {
-            BlockchainTreeAction::MakeForkchoiceHeadCanonical { state, attrs, tx } => {
+            BlockchainTreeAction::MakeForkchoiceHeadCanonical { state, attrs, tx, received_at } => {
                 let start = Instant::now();
                 let result = self.blockchain.make_canonical(state.head_block_hash);
                 let elapsed = self.record_make_canonical_latency(start, &result);
@@ -1612,7 +1884,7 @@ where
                     Ok(on_updated) => {
                         trace!(target: "consensus::engine", status = ?on_updated, ?state, "Returning forkchoice status");
                         let fcu_status = on_updated.forkchoice_status();
-                        self.on_forkchoice_updated_status(state, on_updated, tx);
+                        self.on_forkchoice_updated_status(state, on_updated, tx, received_at);
 
                         if fcu_status.is_valid() {
                             let tip_number = self.blockchain.canonical_tip().number;
@@ -1666,7 +1938,7 @@ where
                             self.latest_valid_hash_for_invalid_payload(block.parent_hash)?
                         };
                         // keep track of the invalid header
-                        self.invalid_headers.insert(block.header);
+                        self.on_invalid_header(block.header);
                         PayloadStatus::new(
                             PayloadStatusEnum::Invalid { validation_error: error.to_string() },
                             latest_valid_hash,
@@ -1775,7 +2047,7 @@ where
                             let (block, err) = err.split();
                             warn!(target: "consensus::engine", invalid_number=?block.number, invalid_hash=?block.hash(), %err, "Marking block as invalid");
 
-                            self.invalid_headers.insert(block.header);
+                            self.on_invalid_header(block.header);
                         }
                     }
                 }
@@ -1811,6 +2083,22 @@ where
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
 
+        if let Some(shutdown) = this.graceful_shutdown.as_mut() {
+            if let Poll::Ready(guard) = shutdown.poll_unpin(cx) {
+                this.graceful_shutdown = None;
+                this.on_shutdown_signal(guard);
+            }
+        }
+        if this.shutdown.is_some() {
+            // the node is shutting down, no more engine messages are processed
+            if this.poll_shutdown(cx).is_ready() {
+                debug!(target: "consensus::engine", "Shutdown grace period elapsed");
+                this.shutdown = None;
+                return Poll::Ready(Ok(()))
+            }
+            return Poll::Pending
+        }
+
         // Control loop that advances the state
         'main: loop {
             // Poll a running hook with db write access (if any) and CL messages first, draining
@@ -1844,9 +2132,16 @@ where
                 // If the db write hook is no longer active and we have a pending forkchoice update,
                 // process it first.
                 if this.hooks.active_db_write_hook().is_none() {
-                    if let Some((state, attrs, tx)) = this.pending_forkchoice_update.take() {
+                    if let Some((state, attrs, tx, received_at)) =
+                        this.pending_forkchoice_update.take()
+                    {
                         this.set_blockchain_tree_action(
-                            BlockchainTreeAction::MakeForkchoiceHeadCanonical { state, attrs, tx },
+                            BlockchainTreeAction::MakeForkchoiceHeadCanonical {
+                                state,
+                                attrs,
+                                tx,
+                                received_at,
+                            },
                         );
                         continue
                     }
@@ -1880,6 +2175,10 @@ where
                         BeaconEngineMessage::TransitionConfigurationExchanged => {
                             this.blockchain.on_transition_configuration_exchanged();
                         }
+                        BeaconEngineMessage::SetPipelineRunThreshold { threshold } => {
+                            debug!(target: "consensus::engine", threshold, "Updating pipeline run threshold");
+                            this.pipeline_run_threshold = threshold;
+                        }
                     }
                     continue
                 }
@@ -1929,11 +2228,20 @@ where
     }
 }
 
+/// The state of the engine after the shutdown signal was received.
+struct EngineShutdown {
+    /// Elapses at the end of the shutdown grace period.
+    deadline: Pin<Box<Sleep>>,
+    /// Delays the shutdown of the node until the engine terminated.
+    _guard: GracefulShutdownGuard,
+}
+
 enum BlockchainTreeAction<EngineT: EngineTypes> {
     MakeForkchoiceHeadCanonical {
         state: ForkchoiceState,
         attrs: Option<EngineT::PayloadAttributes>,
         tx: oneshot::Sender<RethResult<OnForkChoiceUpdated>>,
+        received_at: Instant,
     },
     InsertNewPayload {
         block: SealedBlock,
@@ -1990,6 +2298,7 @@ mod tests {
     use reth_rpc_types_compat::engine::payload::block_to_payload_v1;
     use reth_stages::{ExecOutput, PipelineError, StageError};
     use reth_stages_api::StageCheckpoint;
+    use reth_tasks::TaskManager;
     use reth_testing_utils::generators::{self, Rng};
     use std::{collections::VecDeque, sync::Arc};
     use tokio::sync::oneshot::error::TryRecvError;
@@ -2026,6 +2335,38 @@ mod tests {
         );
     }
 
+    // Pipeline is used for sync once the threshold is lowered to 0 at runtime.
+    #[tokio::test]
+    async fn pipeline_run_threshold_can_be_updated() {
+        let mut rng = generators::rng();
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::default()
+                .chain(MAINNET.chain)
+                .genesis(MAINNET.genesis.clone())
+                .paris_activated()
+                .build(),
+        );
+
+        let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+            .with_pipeline_exec_outputs(VecDeque::from([Err(StageError::ChannelClosed)]))
+            .with_max_block(1)
+            .build();
+
+        let res = spawn_consensus_engine(consensus_engine);
+
+        env.set_pipeline_run_threshold(0);
+        let _ = env
+            .send_forkchoice_updated(ForkchoiceState {
+                head_block_hash: rng.gen(),
+                ..Default::default()
+            })
+            .await;
+        assert_matches!(
+            res.await,
+            Ok(Err(BeaconConsensusEngineError::Pipeline(n))) if matches!(*n.as_ref(),PipelineError::Stage(StageError::ChannelClosed))
+        );
+    }
+
     // Test that the consensus engine is idle until first forkchoice updated is received.
     #[tokio::test]
     async fn is_idle_until_forkchoice_is_set() {
@@ -2159,6 +2500,59 @@ mod tests {
         assert_matches!(rx.await, Ok(Ok(())));
     }
 
+    // Test that engine messages received after the shutdown signal are answered with `SYNCING`
+    // and that the engine terminates after the grace period.
+    #[tokio::test]
+    async fn answers_messages_with_syncing_on_shutdown() {
+        let mut rng = generators::rng();
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::default()
+                .chain(MAINNET.chain)
+                .genesis(MAINNET.genesis.clone())
+                .paris_activated()
+                .build(),
+        );
+
+        let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+            .with_pipeline_exec_outputs(VecDeque::from([Err(StageError::ChannelClosed)]))
+            .disable_blockchain_tree_sync()
+            .with_max_block(1)
+            .build();
+
+        let manager = TaskManager::current();
+        let executor = manager.executor();
+
+        // the engine is only polled once the shutdown signal was sent
+        let (start_tx, start_rx) = oneshot::channel();
+        executor.spawn_with_signal(|shutdown| async move {
+            shutdown.await;
+            let _ = start_tx.send(());
+        });
+        let (engine_tx, engine_rx) = oneshot::channel();
+        executor.spawn_critical_with_graceful_shutdown_signal("consensus engine", |shutdown| {
+            let engine =
+                consensus_engine.with_graceful_shutdown(shutdown, Duration::from_millis(100));
+            async move {
+                let _ = start_rx.await;
+                let _ = engine_tx.send(engine.await);
+            }
+        });
+
+        let (res, shut_down) = tokio::join!(
+            env.send_forkchoice_updated(ForkchoiceState {
+                head_block_hash: rng.gen(),
+                ..Default::default()
+            }),
+            tokio::task::spawn_blocking(move || {
+                manager.graceful_shutdown_with_timeout(Duration::from_secs(5))
+            })
+        );
+
+        assert_matches!(res, Ok(res) if res.is_syncing());
+        assert!(shut_down.unwrap());
+        assert_matches!(engine_rx.await, Ok(Ok(())));
+    }
+
     fn insert_blocks<'a, DB: Database>(
         provider_factory: ProviderFactory<DB>,
         mut blocks: impl Iterator<Item = &'a SealedBlock>,
@@ -2179,12 +2573,16 @@ mod tests {
 
     mod fork_choice_updated {
         use super::*;
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
         use reth_db::{tables, test_utils::create_test_static_files_dir};
-        use reth_db_api::transaction::DbTxMut;
-        use reth_primitives::U256;
-        use reth_provider::providers::StaticFileProvider;
-        use reth_rpc_types::engine::ForkchoiceUpdateError;
+        use reth_db_api::transaction::{DbTx, DbTxMut};
+        use reth_payload_builder::{Events, PayloadStore};
+        use reth_primitives::{Address, U256};
+        use reth_provider::{providers::StaticFileProvider, ExecutionOutcome};
+        use reth_rpc_types::engine::{ForkchoiceUpdateError, PayloadAttributes};
+        use reth_stages::test_utils::TEST_STAGE_ID;
         use reth_testing_utils::generators::random_block;
+        use tokio::sync::broadcast;
 
         #[tokio::test]
         async fn empty_head() {
@@ -2274,9 +2672,8 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn unknown_head_hash() {
+        async fn reorg_notifies_event_listeners() {
             let mut rng = generators::rng();
-
             let chain_spec = Arc::new(
                 ChainSpecBuilder::default()
                     .chain(MAINNET.chain)
@@ -2286,18 +2683,20 @@ mod tests {
             );
 
             let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
-                .with_pipeline_exec_outputs(VecDeque::from([
-                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(0), done: true }),
-                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(0), done: true }),
-                ]))
-                .disable_blockchain_tree_sync()
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .with_executor_results(vec![ExecutionOutcome::default(); 2])
                 .build();
 
             let genesis = random_block(&mut rng, 0, None, None, Some(0));
-            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), Some(0), Some(0));
+            // two competing children of block 1, the state of the mocked executions is empty
+            let block2 = random_block(&mut rng, 2, Some(block1.hash()), Some(0), Some(0));
+            let fork_block2 = random_block(&mut rng, 2, Some(block1.hash()), Some(0), Some(0));
 
             let (_static_dir, static_dir_path) = create_test_static_files_dir();
-
             insert_blocks(
                 ProviderFactory::new(
                     env.db.as_ref(),
@@ -2307,43 +2706,45 @@ mod tests {
                 [&genesis, &block1].into_iter(),
             );
 
+            let mut events = env.event_listener();
             let mut engine_rx = spawn_consensus_engine(consensus_engine);
 
-            let next_head = random_block(&mut rng, 2, Some(block1.hash()), None, Some(0));
-            let next_forkchoice_state = ForkchoiceState {
-                head_block_hash: next_head.hash(),
+            let forkchoice = |head: &SealedBlock| ForkchoiceState {
+                head_block_hash: head.hash(),
                 finalized_block_hash: block1.hash(),
                 ..Default::default()
             };
+            env.send_forkchoice_retry_on_syncing(forkchoice(&block1)).await.unwrap();
+            for block in [&block2, &fork_block2] {
+                let result = env
+                    .send_new_payload_retry_on_syncing(block_to_payload_v1(block.clone()), None)
+                    .await
+                    .unwrap();
+                assert_eq!(result.status, PayloadStatusEnum::Valid);
+            }
 
-            // if we `await` in the assert, the forkchoice will poll after we've inserted the block,
-            // and it will return VALID instead of SYNCING
-            let invalid_rx = env.send_forkchoice_updated(next_forkchoice_state).await;
-            let (_static_dir, static_dir_path) = create_test_static_files_dir();
-
-            // Insert next head immediately after sending forkchoice update
-            insert_blocks(
-                ProviderFactory::new(
-                    env.db.as_ref(),
-                    chain_spec.clone(),
-                    StaticFileProvider::read_write(static_dir_path).unwrap(),
-                ),
-                std::iter::once(&next_head),
-            );
-
-            let expected_result = ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing);
-            assert_matches!(invalid_rx, Ok(result) => assert_eq!(result, expected_result));
-
-            let result = env.send_forkchoice_retry_on_syncing(next_forkchoice_state).await.unwrap();
-            let expected_result = ForkchoiceUpdated::from_status(PayloadStatusEnum::Valid)
-                .with_latest_valid_hash(next_head.hash());
-            assert_eq!(result, expected_result);
-
+            // extends the canonical chain
+            let result = env.send_forkchoice_retry_on_syncing(forkchoice(&block2)).await.unwrap();
+            assert_eq!(result.payload_status.status, PayloadStatusEnum::Valid);
+            // reverts block 2
+            let result =
+                env.send_forkchoice_retry_on_syncing(forkchoice(&fork_block2)).await.unwrap();
+            assert_eq!(result.payload_status.status, PayloadStatusEnum::Valid);
+
+            let mut reorgs = Vec::new();
+            while let Some(Some(event)) = events.next().now_or_never() {
+                if let BeaconConsensusEngineEvent::ChainReorged { old_head, new_head, depth } =
+                    event
+                {
+                    reorgs.push((old_head, new_head, depth));
+                }
+            }
+            assert_eq!(reorgs, vec![(block2.num_hash(), fork_block2.num_hash(), 1)]);
             assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
         }
 
         #[tokio::test]
-        async fn unknown_finalized_hash() {
+        async fn forkchoice_above_max_head_block_is_syncing() {
             let mut rng = generators::rng();
             let chain_spec = Arc::new(
                 ChainSpecBuilder::default()
@@ -2358,12 +2759,11 @@ mod tests {
                     checkpoint: StageCheckpoint::new(0),
                     done: true,
                 })]))
-                .disable_blockchain_tree_sync()
                 .build();
+            let consensus_engine = consensus_engine.with_max_head_block(0);
 
             let genesis = random_block(&mut rng, 0, None, None, Some(0));
             let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
-
             let (_static_dir, static_dir_path) = create_test_static_files_dir();
 
             insert_blocks(
@@ -2374,70 +2774,563 @@ mod tests {
                 ),
                 [&genesis, &block1].into_iter(),
             );
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(block1.number),
+                    )
+                })
+                .unwrap()
+                .unwrap();
 
-            let engine = spawn_consensus_engine(consensus_engine);
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
 
-            let res = env
-                .send_forkchoice_updated(ForkchoiceState {
-                    head_block_hash: rng.gen(),
-                    finalized_block_hash: block1.hash(),
-                    ..Default::default()
-                })
-                .await;
-            let expected_result = ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing);
-            assert_matches!(res, Ok(result) => assert_eq!(result, expected_result));
-            drop(engine);
+            // the head is above the max head block, so the engine keeps running but doesn't
+            // advance
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block1.hash(),
+                finalized_block_hash: block1.hash(),
+                ..Default::default()
+            };
+            let result = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            let expected_result =
+                ForkchoiceUpdated::new(PayloadStatus::from_status(PayloadStatusEnum::Syncing));
+            assert_eq!(result, expected_result);
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+
+            // the max head block itself can still be made canonical
+            let forkchoice = ForkchoiceState {
+                head_block_hash: genesis.hash(),
+                finalized_block_hash: genesis.hash(),
+                ..Default::default()
+            };
+            let result = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            let expected_result = ForkchoiceUpdated::new(PayloadStatus::new(
+                PayloadStatusEnum::Valid,
+                Some(genesis.hash()),
+            ));
+            assert_eq!(result, expected_result);
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
         }
 
+        // An unknown head above the max head block can't be rejected before it's synced, so the
+        // pipeline must stop at the max head block and not be run again. The second pipeline
+        // output (error) is only consumed if the pipeline runs past the max head block.
         #[tokio::test]
-        async fn forkchoice_updated_pre_merge() {
+        async fn unknown_head_is_not_synced_past_max_head_block() {
             let mut rng = generators::rng();
             let chain_spec = Arc::new(
                 ChainSpecBuilder::default()
                     .chain(MAINNET.chain)
                     .genesis(MAINNET.genesis.clone())
-                    .london_activated()
-                    .paris_at_ttd(U256::from(3))
+                    .paris_activated()
                     .build(),
             );
 
             let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
                 .with_pipeline_exec_outputs(VecDeque::from([
-                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(0), done: true }),
-                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(0), done: true }),
+                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(1), done: true }),
+                    Err(StageError::ChannelClosed),
                 ]))
+                .disable_blockchain_tree_sync()
                 .build();
+            let consensus_engine = consensus_engine.with_max_head_block(1);
 
             let genesis = random_block(&mut rng, 0, None, None, Some(0));
-            let mut block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
-            block1.header.set_difficulty(U256::from(1));
-
-            // a second pre-merge block
-            let mut block2 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
-            block2.header.set_difficulty(U256::from(1));
-
-            // a transition block
-            let mut block3 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
-            block3.header.set_difficulty(U256::from(1));
-
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
             let (_static_dir, static_dir_path) = create_test_static_files_dir();
+
             insert_blocks(
                 ProviderFactory::new(
                     env.db.as_ref(),
                     chain_spec.clone(),
                     StaticFileProvider::read_write(static_dir_path).unwrap(),
                 ),
-                [&genesis, &block1, &block2, &block3].into_iter(),
+                [&genesis, &block1].into_iter(),
             );
 
-            let _engine = spawn_consensus_engine(consensus_engine);
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
 
-            let res = env
-                .send_forkchoice_updated(ForkchoiceState {
-                    head_block_hash: block1.hash(),
-                    finalized_block_hash: block1.hash(),
+            for _ in 0..10 {
+                let forkchoice = ForkchoiceState {
+                    head_block_hash: rng.gen(),
+                    finalized_block_hash: rng.gen(),
                     ..Default::default()
-                })
+                };
+                let result = env.send_forkchoice_updated(forkchoice).await.unwrap();
+                assert_eq!(result, ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing));
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+            }
+
+            // the pipeline stopped at the max head block
+            let checkpoint = env
+                .db
+                .view(|tx| tx.get::<tables::StageCheckpoints>(TEST_STAGE_ID.to_string()))
+                .unwrap()
+                .unwrap();
+            assert_eq!(checkpoint, Some(StageCheckpoint::new(1)));
+        }
+
+        #[tokio::test]
+        async fn valid_forkchoice_records_latency() {
+            let recorder = DebuggingRecorder::new();
+            let snapshotter = recorder.snapshotter();
+            recorder.install().unwrap();
+
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+
+            insert_blocks(
+                ProviderFactory::new(
+                    env.db.as_ref(),
+                    chain_spec.clone(),
+                    StaticFileProvider::read_write(static_dir_path).unwrap(),
+                ),
+                [&genesis, &block1].into_iter(),
+            );
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(block1.number),
+                    )
+                })
+                .unwrap()
+                .unwrap();
+
+            let _engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block1.hash(),
+                finalized_block_hash: block1.hash(),
+                ..Default::default()
+            };
+            let result = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            assert_eq!(result.payload_status.status, PayloadStatusEnum::Valid);
+
+            let valid_samples = snapshotter
+                .snapshot()
+                .into_vec()
+                .into_iter()
+                .find_map(|(key, _, _, value)| {
+                    let key = key.key();
+                    let is_valid_latency = key.name() ==
+                        "consensus.engine.beacon.forkchoice_updated_latency" &&
+                        key.labels().any(|label| {
+                            label.key() == "status" &&
+                                label.value() == ForkchoiceStatus::Valid.as_str()
+                        });
+                    match value {
+                        DebugValue::Histogram(samples) if is_valid_latency => Some(samples),
+                        _ => None,
+                    }
+                })
+                .expect("valid forkchoice latency is recorded");
+            assert!(!valid_samples.is_empty());
+        }
+
+        #[tokio::test]
+        async fn repeated_forkchoice_with_attributes_reuses_payload_job() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+
+            insert_blocks(
+                ProviderFactory::new(
+                    env.db.as_ref(),
+                    chain_spec.clone(),
+                    StaticFileProvider::read_write(static_dir_path).unwrap(),
+                ),
+                [&genesis, &block1].into_iter(),
+            );
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(block1.number),
+                    )
+                })
+                .unwrap()
+                .unwrap();
+
+            let mut payload_events = env.payload_builder.subscribe().await.unwrap();
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block1.hash(),
+                finalized_block_hash: block1.hash(),
+                ..Default::default()
+            };
+            let attributes = PayloadAttributes {
+                timestamp: block1.timestamp + 1,
+                prev_randao: B256::random(),
+                suggested_fee_recipient: Address::random(),
+                withdrawals: None,
+                parent_beacon_block_root: None,
+            };
+
+            let first = env
+                .send_forkchoice_updated_with_attributes(forkchoice, attributes.clone())
+                .await
+                .unwrap();
+            let second =
+                env.send_forkchoice_updated_with_attributes(forkchoice, attributes).await.unwrap();
+            assert!(first.payload_id.is_some());
+            assert_eq!(first, second);
+
+            // only a single build job was started
+            assert_matches!(payload_events.receiver.try_recv(), Ok(Events::Attributes(_)));
+            assert_matches!(
+                payload_events.receiver.try_recv(),
+                Err(broadcast::error::TryRecvError::Empty)
+            );
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+
+        #[tokio::test]
+        async fn repeated_forkchoice_after_resolve_starts_new_payload_job() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+
+            insert_blocks(
+                ProviderFactory::new(
+                    env.db.as_ref(),
+                    chain_spec.clone(),
+                    StaticFileProvider::read_write(static_dir_path).unwrap(),
+                ),
+                [&genesis, &block1].into_iter(),
+            );
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(block1.number),
+                    )
+                })
+                .unwrap()
+                .unwrap();
+
+            let payload_store = PayloadStore::from(env.payload_builder.clone());
+            let mut payload_events = env.payload_builder.subscribe().await.unwrap();
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block1.hash(),
+                finalized_block_hash: block1.hash(),
+                ..Default::default()
+            };
+            let attributes = PayloadAttributes {
+                timestamp: block1.timestamp + 1,
+                prev_randao: B256::random(),
+                suggested_fee_recipient: Address::random(),
+                withdrawals: None,
+                parent_beacon_block_root: None,
+            };
+
+            let first = env
+                .send_forkchoice_updated_with_attributes(forkchoice, attributes.clone())
+                .await
+                .unwrap();
+            let payload_id = first.payload_id.unwrap();
+            assert_matches!(payload_events.receiver.try_recv(), Ok(Events::Attributes(_)));
+
+            // resolving the job terminates it
+            assert!(payload_store.resolve(payload_id).await.is_some());
+            assert!(payload_store.resolve(payload_id).await.is_none());
+
+            // the repeated update must start a new job instead of returning the resolved one
+            let second =
+                env.send_forkchoice_updated_with_attributes(forkchoice, attributes).await.unwrap();
+            assert_eq!(second.payload_id, Some(payload_id));
+            assert_matches!(payload_events.receiver.try_recv(), Ok(Events::Attributes(_)));
+            assert!(payload_store.resolve(payload_id).await.is_some());
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+
+        #[tokio::test]
+        async fn duplicate_forkchoice_is_served_from_cache() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+
+            insert_blocks(
+                ProviderFactory::new(
+                    env.db.as_ref(),
+                    chain_spec.clone(),
+                    StaticFileProvider::read_write(static_dir_path).unwrap(),
+                ),
+                [&genesis, &block1].into_iter(),
+            );
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(block1.number),
+                    )
+                })
+                .unwrap()
+                .unwrap();
+
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block1.hash(),
+                finalized_block_hash: block1.hash(),
+                ..Default::default()
+            };
+            let expected_result = ForkchoiceUpdated::new(PayloadStatus::new(
+                PayloadStatusEnum::Valid,
+                Some(block1.hash()),
+            ));
+
+            let first = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            assert_eq!(first, expected_result);
+
+            // remove the hash to number mapping of the head, making the head canonical again
+            // would now fail and start a sync
+            env.db
+                .update(|tx| tx.delete::<tables::HeaderNumbers>(block1.hash(), None))
+                .unwrap()
+                .unwrap();
+
+            let second = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            assert_eq!(second, expected_result);
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+
+        #[tokio::test]
+        async fn unknown_head_hash() {
+            let mut rng = generators::rng();
+
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([
+                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(0), done: true }),
+                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(0), done: true }),
+                ]))
+                .disable_blockchain_tree_sync()
+                .build();
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+
+            insert_blocks(
+                ProviderFactory::new(
+                    env.db.as_ref(),
+                    chain_spec.clone(),
+                    StaticFileProvider::read_write(static_dir_path).unwrap(),
+                ),
+                [&genesis, &block1].into_iter(),
+            );
+
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let next_head = random_block(&mut rng, 2, Some(block1.hash()), None, Some(0));
+            let next_forkchoice_state = ForkchoiceState {
+                head_block_hash: next_head.hash(),
+                finalized_block_hash: block1.hash(),
+                ..Default::default()
+            };
+
+            // if we `await` in the assert, the forkchoice will poll after we've inserted the block,
+            // and it will return VALID instead of SYNCING
+            let invalid_rx = env.send_forkchoice_updated(next_forkchoice_state).await;
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+
+            // Insert next head immediately after sending forkchoice update
+            insert_blocks(
+                ProviderFactory::new(
+                    env.db.as_ref(),
+                    chain_spec.clone(),
+                    StaticFileProvider::read_write(static_dir_path).unwrap(),
+                ),
+                std::iter::once(&next_head),
+            );
+
+            let expected_result = ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing);
+            assert_matches!(invalid_rx, Ok(result) => assert_eq!(result, expected_result));
+
+            let result = env.send_forkchoice_retry_on_syncing(next_forkchoice_state).await.unwrap();
+            let expected_result = ForkchoiceUpdated::from_status(PayloadStatusEnum::Valid)
+                .with_latest_valid_hash(next_head.hash());
+            assert_eq!(result, expected_result);
+
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+
+        #[tokio::test]
+        async fn unknown_finalized_hash() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .disable_blockchain_tree_sync()
+                .build();
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+
+            insert_blocks(
+                ProviderFactory::new(
+                    env.db.as_ref(),
+                    chain_spec.clone(),
+                    StaticFileProvider::read_write(static_dir_path).unwrap(),
+                ),
+                [&genesis, &block1].into_iter(),
+            );
+
+            let engine = spawn_consensus_engine(consensus_engine);
+
+            let res = env
+                .send_forkchoice_updated(ForkchoiceState {
+                    head_block_hash: rng.gen(),
+                    finalized_block_hash: block1.hash(),
+                    ..Default::default()
+                })
+                .await;
+            let expected_result = ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing);
+            assert_matches!(res, Ok(result) => assert_eq!(result, expected_result));
+            drop(engine);
+        }
+
+        #[tokio::test]
+        async fn forkchoice_updated_pre_merge() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .london_activated()
+                    .paris_at_ttd(U256::from(3))
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([
+                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(0), done: true }),
+                    Ok(ExecOutput { checkpoint: StageCheckpoint::new(0), done: true }),
+                ]))
+                .build();
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let mut block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            block1.header.set_difficulty(U256::from(1));
+
+            // a second pre-merge block
+            let mut block2 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            block2.header.set_difficulty(U256::from(1));
+
+            // a transition block
+            let mut block3 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            block3.header.set_difficulty(U256::from(1));
+
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+            insert_blocks(
+                ProviderFactory::new(
+                    env.db.as_ref(),
+                    chain_spec.clone(),
+                    StaticFileProvider::read_write(static_dir_path).unwrap(),
+                ),
+                [&genesis, &block1, &block2, &block3].into_iter(),
+            );
+
+            let _engine = spawn_consensus_engine(consensus_engine);
+
+            let res = env
+                .send_forkchoice_updated(ForkchoiceState {
+                    head_block_hash: block1.hash(),
+                    finalized_block_hash: block1.hash(),
+                    ..Default::default()
+                })
                 .await;
 
             assert_matches!(res, Ok(result) => {
@@ -2550,6 +3443,38 @@ mod tests {
             assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
         }
 
+        #[tokio::test]
+        async fn new_payload_genesis_block() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let genesis = random_block(&mut rng, 0, None, Some(0), Some(0));
+            let res = env.send_new_payload(block_to_payload_v1(genesis), None).await;
+
+            assert_matches!(
+                res,
+                Ok(PayloadStatus { status: PayloadStatusEnum::Invalid { validation_error }, .. })
+                    if validation_error == GENESIS_PAYLOAD_ERROR
+            );
+
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+
         #[tokio::test]
         async fn payload_known() {
             let mut rng = generators::rng();
@@ -8,7 +8,9 @@ use futures::FutureExt;
 use reth_db_api::database::Database;
 use reth_network_p2p::{
     bodies::client::BodiesClient,
-    full_block::{FetchFullBlockFuture, FetchFullBlockRangeFuture, FullBlockClient},
+    full_block::{
+        FetchFullBlockFuture, FetchFullBlockRangeFuture, FullBlockClient, FullBlockRequestLimits,
+    },
     headers::client::HeadersClient,
 };
 use reth_primitives::{BlockNumber, ChainSpec, SealedBlock, B256};
@@ -77,12 +79,14 @@ where
         max_block: Option<BlockNumber>,
         chain_spec: Arc<ChainSpec>,
         event_sender: EventSender<BeaconConsensusEngineEvent>,
+        download_limits: FullBlockRequestLimits,
     ) -> Self {
         Self {
             full_block_client: FullBlockClient::new(
                 client,
                 Arc::new(EthBeaconConsensus::new(chain_spec)),
-            ),
+            )
+            .with_request_limits(download_limits),
             pipeline_task_spawner,
             pipeline_state: PipelineState::Idle(Some(pipeline)),
             pending_pipeline_target: None,
@@ -108,6 +112,17 @@ where
         self.max_block = Some(block);
     }
 
+    /// Limits the pipeline to the given block number, without terminating the sync once the
+    /// pipeline reaches it, see [`Self::has_reached_max_block`].
+    ///
+    /// Has no effect while the pipeline is running.
+    pub(crate) fn set_pipeline_max_block(&mut self, block: BlockNumber) {
+        let block = self.max_block.map_or(block, |max_block| max_block.min(block));
+        if let PipelineState::Idle(Some(pipeline)) = &mut self.pipeline_state {
+            pipeline.set_max_block(block);
+        }
+    }
+
     /// Cancels all download requests that are in progress and buffered blocks.
     pub(crate) fn clear_block_download_requests(&mut self) {
         self.inflight_full_block_requests.clear();
@@ -555,6 +570,7 @@ mod tests {
                 self.max_block,
                 chain_spec,
                 Default::default(),
+                Default::default(),
             )
         }
     }
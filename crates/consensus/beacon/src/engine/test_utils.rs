@@ -1,7 +1,8 @@
 use crate::{
     engine::hooks::PruneHook, hooks::EngineHooks, BeaconConsensusEngine,
-    BeaconConsensusEngineError, BeaconConsensusEngineHandle, BeaconForkChoiceUpdateError,
-    BeaconOnNewPayloadError, EthBeaconConsensus, MIN_BLOCKS_FOR_PIPELINE_RUN,
+    BeaconConsensusEngineError, BeaconConsensusEngineEvent, BeaconConsensusEngineHandle,
+    BeaconForkChoiceUpdateError, BeaconOnNewPayloadError, EthBeaconConsensus,
+    MIN_BLOCKS_FOR_PIPELINE_RUN,
 };
 use reth_blockchain_tree::{
     config::BlockchainTreeConfig, externals::TreeExternals, BlockchainTree, ShareableBlockchainTree,
@@ -21,7 +22,7 @@ use reth_network_p2p::{
     bodies::client::BodiesClient, headers::client::HeadersClient, sync::NoopSyncStateUpdater,
     test_utils::NoopFullBlockClient,
 };
-use reth_payload_builder::test_utils::spawn_test_payload_service;
+use reth_payload_builder::{test_utils::spawn_test_payload_service, PayloadBuilderHandle};
 use reth_primitives::{BlockNumber, ChainSpec, B256};
 use reth_provider::{
     providers::BlockchainProvider, test_utils::create_test_provider_factory_with_chain_spec,
@@ -30,11 +31,13 @@ use reth_provider::{
 use reth_prune::Pruner;
 use reth_prune_types::PruneModes;
 use reth_rpc_types::engine::{
-    CancunPayloadFields, ExecutionPayload, ForkchoiceState, ForkchoiceUpdated, PayloadStatus,
+    CancunPayloadFields, ExecutionPayload, ForkchoiceState, ForkchoiceUpdated, PayloadAttributes,
+    PayloadStatus,
 };
 use reth_stages::{sets::DefaultStages, test_utils::TestStages, ExecOutput, Pipeline, StageError};
 use reth_static_file::StaticFileProducer;
 use reth_tasks::TokioTaskExecutor;
+use reth_tokio_util::EventStream;
 use std::{collections::VecDeque, sync::Arc};
 use tokio::sync::{oneshot, watch};
 
@@ -54,6 +57,7 @@ pub struct TestEnv<DB> {
     #[allow(dead_code)]
     tip_rx: watch::Receiver<B256>,
     engine_handle: BeaconConsensusEngineHandle<EthEngineTypes>,
+    pub payload_builder: PayloadBuilderHandle<EthEngineTypes>,
 }
 
 impl<DB> TestEnv<DB> {
@@ -61,8 +65,9 @@ impl<DB> TestEnv<DB> {
         db: DB,
         tip_rx: watch::Receiver<B256>,
         engine_handle: BeaconConsensusEngineHandle<EthEngineTypes>,
+        payload_builder: PayloadBuilderHandle<EthEngineTypes>,
     ) -> Self {
-        Self { db, tip_rx, engine_handle }
+        Self { db, tip_rx, engine_handle, payload_builder }
     }
 
     pub async fn send_new_payload<T: Into<ExecutionPayload>>(
@@ -89,6 +94,10 @@ impl<DB> TestEnv<DB> {
         }
     }
 
+    pub fn set_pipeline_run_threshold(&self, threshold: u64) {
+        self.engine_handle.set_pipeline_run_threshold(threshold)
+    }
+
     pub async fn send_forkchoice_updated(
         &self,
         state: ForkchoiceState,
@@ -96,6 +105,19 @@ impl<DB> TestEnv<DB> {
         self.engine_handle.fork_choice_updated(state, None).await
     }
 
+    /// Returns a stream of the events emitted by the consensus engine.
+    pub fn event_listener(&self) -> EventStream<BeaconConsensusEngineEvent> {
+        self.engine_handle.event_listener()
+    }
+
+    pub async fn send_forkchoice_updated_with_attributes(
+        &self,
+        state: ForkchoiceState,
+        attributes: PayloadAttributes,
+    ) -> Result<ForkchoiceUpdated, BeaconForkChoiceUpdateError> {
+        self.engine_handle.fork_choice_updated(state, Some(attributes)).await
+    }
+
     /// Sends the `ForkchoiceUpdated` message to the consensus engine and retries if the engine
     /// is syncing.
     pub async fn send_forkchoice_retry_on_syncing(
@@ -419,9 +441,10 @@ where
             Box::<NoopSyncStateUpdater>::default(),
             None,
             false,
-            payload_builder,
+            payload_builder.clone(),
             None,
             self.base_config.pipeline_run_threshold.unwrap_or(MIN_BLOCKS_FOR_PIPELINE_RUN),
+            Default::default(),
             hooks,
         )
         .expect("failed to create consensus engine");
@@ -430,7 +453,7 @@ where
             engine.sync.set_max_block(max_block)
         }
 
-        (engine, TestEnv::new(provider_factory.db_ref().clone(), tip_rx, handle))
+        (engine, TestEnv::new(provider_factory.db_ref().clone(), tip_rx, handle, payload_builder))
     }
 }
 
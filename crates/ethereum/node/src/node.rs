@@ -21,7 +21,7 @@ use reth_transaction_pool::{
     blobstore::DiskFileBlobStore, EthTransactionPool, TransactionPool,
     TransactionValidationTaskExecutor,
 };
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 /// Type configuration for a regular Ethereum node.
 #[derive(Debug, Default, Clone, Copy)]
@@ -102,10 +102,22 @@ where
 ///
 /// This contains various settings that can be configured and take precedence over the node's
 /// config.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 #[non_exhaustive]
 pub struct EthereumPoolBuilder {
     // TODO add options for txpool args
+    /// The file the local transactions are persisted to across restarts.
+    ///
+    /// Defaults to the transactions backup file of the data dir.
+    local_transactions_path: Option<PathBuf>,
+}
+
+impl EthereumPoolBuilder {
+    /// Sets the file the local transactions are persisted to across restarts.
+    pub fn with_local_transactions_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.local_transactions_path = Some(path.into());
+        self
+    }
 }
 
 impl<Node> PoolBuilder<Node> for EthereumPoolBuilder
@@ -132,7 +144,8 @@ where
         let transaction_pool =
             reth_transaction_pool::Pool::eth_pool(validator, blob_store, pool_config);
         info!(target: "reth::cli", "Transaction pool initialized");
-        let transactions_path = data_dir.txpool_transactions();
+        let transactions_path =
+            self.local_transactions_path.unwrap_or_else(|| data_dir.txpool_transactions());
 
         // spawn txpool maintenance task
         {
@@ -30,3 +30,11 @@ pub enum StorageRootError {
     #[error(transparent)]
     DB(#[from] DatabaseError),
 }
+
+impl From<StorageRootError> for DatabaseError {
+    fn from(err: StorageRootError) -> Self {
+        match err {
+            StorageRootError::DB(err) => err,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Provider trait for populating the EVM environment.
 
 use crate::ConfigureEvmEnv;
-use reth_primitives::{BlockHashOrNumber, Header};
+use reth_primitives::{
+    constants::SLOT_DURATION, BlockHashOrNumber, ChainSpec, Header, SealedHeader,
+};
 use reth_storage_errors::provider::ProviderResult;
 use revm::primitives::{BlockEnv, CfgEnv, CfgEnvWithHandlerCfg, SpecId};
 
@@ -52,6 +54,21 @@ pub trait EvmEnvProvider: Send + Sync {
     where
         EvmConfig: ConfigureEvmEnv;
 
+    /// Fills the [CfgEnvWithHandlerCfg] and [BlockEnv] fields with values for the speculative
+    /// pending block on top of the canonical tip.
+    ///
+    /// The pending block's header is derived from the tip with [`pending_block_header`] and
+    /// returned. Its `parent_hash` is the hash of the tip, whose state the pending block is
+    /// executed on.
+    fn fill_env_for_pending<EvmConfig>(
+        &self,
+        cfg: &mut CfgEnvWithHandlerCfg,
+        block_env: &mut BlockEnv,
+        evm_config: EvmConfig,
+    ) -> ProviderResult<SealedHeader>
+    where
+        EvmConfig: ConfigureEvmEnv;
+
     /// Fills the [BlockEnv] fields with values specific to the given [BlockHashOrNumber].
     fn fill_block_env_at(
         &self,
@@ -87,3 +104,55 @@ pub trait EvmEnvProvider: Send + Sync {
     where
         EvmConfig: ConfigureEvmEnv;
 }
+
+/// Derives the header of the speculative pending block, the child of the given canonical tip.
+///
+/// The timestamp of the pending block can't be known in advance, so it is assumed to be built in
+/// the next slot: the tip's timestamp plus [`SLOT_DURATION`] (12s). The base fee is computed with
+/// the EIP-1559 formula using the chain's base fee params at that timestamp, and the excess blob
+/// gas is updated with the blob gas consumed by the tip.
+///
+/// The returned header is sealed with its own hash. The pending block is executed on top of the
+/// tip's state, which is identified by the header's `parent_hash`.
+pub fn pending_block_header(tip: SealedHeader, chain_spec: &ChainSpec) -> SealedHeader {
+    let (mut header, tip_hash) = tip.split();
+
+    header.parent_hash = tip_hash;
+    header.number += 1;
+    header.timestamp += SLOT_DURATION.as_secs();
+    header.base_fee_per_gas =
+        header.next_block_base_fee(chain_spec.base_fee_params_at_timestamp(header.timestamp));
+    header.excess_blob_gas = header.next_block_excess_blob_gas();
+
+    header.seal_slow()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{B256, MAINNET};
+
+    #[test]
+    fn derives_pending_block_header() {
+        let tip_hash = B256::random();
+        let tip = SealedHeader::new(
+            Header {
+                number: 100,
+                timestamp: 1_700_000_000,
+                gas_limit: 30_000_000,
+                gas_used: 30_000_000,
+                base_fee_per_gas: Some(1_000_000_000),
+                ..Default::default()
+            },
+            tip_hash,
+        );
+
+        let pending = pending_block_header(tip, &MAINNET);
+        assert_eq!(pending.hash(), pending.header().hash_slow());
+        assert_eq!(pending.parent_hash, tip_hash);
+        assert_eq!(pending.number, 101);
+        assert_eq!(pending.timestamp, 1_700_000_012);
+        // a full block increases the base fee by 12.5%
+        assert_eq!(pending.base_fee_per_gas, Some(1_125_000_000));
+    }
+}
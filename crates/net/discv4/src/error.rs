@@ -36,6 +36,9 @@ pub enum Discv4Error {
     /// Failed to receive a command response
     #[error(transparent)]
     Receive(#[from] RecvError),
+    /// Failed to update the local ENR
+    #[error("failed to update the local ENR: {0}")]
+    Enr(enr::Error),
 }
 
 impl<T> From<SendError<T>> for Discv4Error {
@@ -380,10 +380,26 @@ impl Discv4 {
     ///
     /// CAUTION: The value **must** be rlp encoded
     pub fn set_eip868_rlp_pair(&self, key: Vec<u8>, rlp: Bytes) {
-        let cmd = Discv4Command::SetEIP868RLPPair { key, rlp };
+        let cmd = Discv4Command::SetEIP868RLPPair { key, rlp, tx: None };
         self.send_to_service(cmd);
     }
 
+    /// Same as [`Self::set_eip868_rlp_pair`], but returns the error if the pair could not be set,
+    /// e.g. because the [`Enr`] would exceed the maximum size. In that case the [`Enr`] is left
+    /// unchanged.
+    ///
+    /// CAUTION: The value **must** be rlp encoded
+    pub async fn try_set_eip868_rlp_pair(
+        &self,
+        key: Vec<u8>,
+        rlp: Bytes,
+    ) -> Result<(), Discv4Error> {
+        let (tx, rx) = oneshot::channel();
+        let cmd = Discv4Command::SetEIP868RLPPair { key, rlp, tx: Some(tx) };
+        self.to_service.send(cmd)?;
+        rx.await?.map_err(Discv4Error::Enr)
+    }
+
     /// Sets the pair in the EIP-868 [`Enr`] of the node.
     ///
     /// If the key already exists, this will update it.
@@ -1635,10 +1651,19 @@ impl Discv4Service {
                     Discv4Command::BanIp(ip) => {
                         self.ban_ip(ip);
                     }
-                    Discv4Command::SetEIP868RLPPair { key, rlp } => {
+                    Discv4Command::SetEIP868RLPPair { key, rlp, tx } => {
                         debug!(target: "discv4", key=%String::from_utf8_lossy(&key), "Update EIP-868 extension pair");
 
-                        let _ = self.local_eip_868_enr.insert_raw_rlp(key, rlp, &self.secret_key);
+                        let res = self
+                            .local_eip_868_enr
+                            .insert_raw_rlp(key, rlp, &self.secret_key)
+                            .map(|_| ());
+                        if let Err(err) = &res {
+                            debug!(target: "discv4", %err, "Failed to update EIP-868 pair");
+                        }
+                        if let Some(tx) = tx {
+                            let _ = tx.send(res);
+                        }
                     }
                     Discv4Command::SetTcpPort(port) => {
                         debug!(target: "discv4", %port, "Update tcp port");
@@ -1930,7 +1955,7 @@ impl Default for ReceiveCache {
 enum Discv4Command {
     Add(NodeRecord),
     SetTcpPort(u16),
-    SetEIP868RLPPair { key: Vec<u8>, rlp: Bytes },
+    SetEIP868RLPPair { key: Vec<u8>, rlp: Bytes, tx: Option<OneshotSender<Result<(), enr::Error>>> },
     Ban(PeerId, IpAddr),
     BanPeer(PeerId),
     BanIp(IpAddr),
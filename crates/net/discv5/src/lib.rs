@@ -100,7 +100,7 @@ impl Discv5 {
             );
             return
         };
-        if let Err(err) = self.discv5.enr_insert(key_str, &rlp) {
+        if let Err(err) = self.try_set_eip868_in_local_enr(key_str, rlp) {
             error!(target: "discv5",
                 %err,
                 "failed to update local enr"
@@ -108,6 +108,28 @@ impl Discv5 {
         }
     }
 
+    /// Same as [`Self::set_eip868_in_local_enr`], but returns the error if the pair could not be
+    /// set, e.g. because the [`Enr`] would exceed the maximum size. In that case the [`Enr`] is
+    /// left unchanged.
+    pub fn try_set_eip868_in_local_enr(&self, key: &str, rlp: Bytes) -> Result<(), ::enr::Error> {
+        self.discv5.enr_insert(key, &rlp).map(|_| ())
+    }
+
+    /// Returns the error [`Self::try_set_eip868_in_local_enr`] would return for the pair, without
+    /// changing the [`Enr`] of the node.
+    ///
+    /// The pair is set in a copy of the [`Enr`], which is signed with the given key. This must be
+    /// the key of the node.
+    pub fn check_eip868_in_local_enr(
+        &self,
+        key: &str,
+        rlp: Bytes,
+        sk: &SecretKey,
+    ) -> Result<(), ::enr::Error> {
+        let mut enr: Enr<SecretKey> = EnrCombinedKeyWrapper(self.discv5.local_enr()).into();
+        enr.insert_raw_rlp(key, rlp, sk).map(|_| ())
+    }
+
     /// Sets the pair in the EIP-868 [`Enr`] of the node.
     ///
     /// If the key already exists, this will update it.
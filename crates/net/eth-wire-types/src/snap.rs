@@ -0,0 +1,360 @@
+//! Implements the `snap/1` protocol messages.
+//!
+//! Reference: [Ethereum Snapshot Protocol](https://github.com/ethereum/devp2p/blob/master/caps/snap.md).
+//!
+//! Unlike the request-response pairs of the `eth` protocol, the request id is the first field of
+//! the message list itself.
+
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
+use reth_codecs_derive::derive_arbitrary;
+use reth_primitives::{
+    bytes::{Buf, BufMut},
+    Bytes, B256,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A request for the accounts of the state trie with the given root, starting at the
+/// `starting_hash` and ending at the `limit_hash`.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GetAccountRange {
+    /// The id of the request.
+    pub request_id: u64,
+    /// The root hash of the account trie to serve.
+    pub root_hash: B256,
+    /// The account hash of the first account to retrieve.
+    pub starting_hash: B256,
+    /// The account hash after which to stop serving data.
+    pub limit_hash: B256,
+    /// The soft limit at which to stop returning data.
+    pub response_bytes: u64,
+}
+
+/// An account of an [`AccountRange`] response.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AccountData {
+    /// The hash of the account address.
+    pub hash: B256,
+    /// The account body in the slim format.
+    pub body: Bytes,
+}
+
+/// The response to [`GetAccountRange`], containing the consecutive accounts of the range and the
+/// merkle proofs of its boundaries.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AccountRange {
+    /// The id of the request this responds to.
+    pub request_id: u64,
+    /// The accounts of the range, ordered by hash.
+    pub accounts: Vec<AccountData>,
+    /// The trie nodes proving the boundaries of the range.
+    pub proof: Vec<Bytes>,
+}
+
+/// A request for the storage slots of the given accounts.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GetStorageRanges {
+    /// The id of the request.
+    pub request_id: u64,
+    /// The root hash of the account trie to serve.
+    pub root_hash: B256,
+    /// The hashes of the accounts whose storage to retrieve.
+    pub account_hashes: Vec<B256>,
+    /// The storage slot hash of the first slot to retrieve, empty to start at the first slot.
+    pub starting_hash: Bytes,
+    /// The storage slot hash after which to stop serving data, empty to serve all slots.
+    pub limit_hash: Bytes,
+    /// The soft limit at which to stop returning data.
+    pub response_bytes: u64,
+}
+
+/// A storage slot of a [`StorageRanges`] response.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StorageData {
+    /// The hash of the storage slot key.
+    pub hash: B256,
+    /// The RLP encoded value of the storage slot.
+    pub data: Bytes,
+}
+
+/// The response to [`GetStorageRanges`], containing the storage slots of the requested accounts.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StorageRanges {
+    /// The id of the request this responds to.
+    pub request_id: u64,
+    /// The storage slots of each served account, ordered by hash.
+    pub slots: Vec<Vec<StorageData>>,
+    /// The trie nodes proving the boundaries of the last, possibly partial, storage range.
+    pub proof: Vec<Bytes>,
+}
+
+/// A request for contract bytecodes by their hashes.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GetByteCodes {
+    /// The id of the request.
+    pub request_id: u64,
+    /// The code hashes of the bytecodes to retrieve.
+    pub hashes: Vec<B256>,
+    /// The soft limit at which to stop returning data.
+    pub response_bytes: u64,
+}
+
+/// The response to [`GetByteCodes`], containing the requested bytecodes.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ByteCodes {
+    /// The id of the request this responds to.
+    pub request_id: u64,
+    /// The bytecodes, in the order of the request.
+    pub codes: Vec<Bytes>,
+}
+
+/// A request for trie nodes of the state trie or the storage tries by their paths.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GetTrieNodes {
+    /// The id of the request.
+    pub request_id: u64,
+    /// The root hash of the account trie to serve.
+    pub root_hash: B256,
+    /// The path sets of the trie nodes to retrieve.
+    ///
+    /// Each set starts with the path of an account trie node, followed by the paths of the
+    /// storage trie nodes of that account.
+    pub paths: Vec<Vec<Bytes>>,
+    /// The soft limit at which to stop returning data.
+    pub response_bytes: u64,
+}
+
+/// The response to [`GetTrieNodes`], containing the requested trie nodes.
+#[derive_arbitrary(rlp)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrieNodes {
+    /// The id of the request this responds to.
+    pub request_id: u64,
+    /// The trie nodes, in the order of the request.
+    pub nodes: Vec<Bytes>,
+}
+
+/// Represents a message of the `snap/1` protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SnapMessage {
+    /// Represents a `GetAccountRange` request.
+    GetAccountRange(GetAccountRange),
+    /// Represents an `AccountRange` response.
+    AccountRange(AccountRange),
+    /// Represents a `GetStorageRanges` request.
+    GetStorageRanges(GetStorageRanges),
+    /// Represents a `StorageRanges` response.
+    StorageRanges(StorageRanges),
+    /// Represents a `GetByteCodes` request.
+    GetByteCodes(GetByteCodes),
+    /// Represents a `ByteCodes` response.
+    ByteCodes(ByteCodes),
+    /// Represents a `GetTrieNodes` request.
+    GetTrieNodes(GetTrieNodes),
+    /// Represents a `TrieNodes` response.
+    TrieNodes(TrieNodes),
+}
+
+impl SnapMessage {
+    /// Decodes a message, prefixed with its message ID.
+    pub fn decode_message(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let message = match SnapMessageID::decode(buf)? {
+            SnapMessageID::GetAccountRange => Self::GetAccountRange(GetAccountRange::decode(buf)?),
+            SnapMessageID::AccountRange => Self::AccountRange(AccountRange::decode(buf)?),
+            SnapMessageID::GetStorageRanges => {
+                Self::GetStorageRanges(GetStorageRanges::decode(buf)?)
+            }
+            SnapMessageID::StorageRanges => Self::StorageRanges(StorageRanges::decode(buf)?),
+            SnapMessageID::GetByteCodes => Self::GetByteCodes(GetByteCodes::decode(buf)?),
+            SnapMessageID::ByteCodes => Self::ByteCodes(ByteCodes::decode(buf)?),
+            SnapMessageID::GetTrieNodes => Self::GetTrieNodes(GetTrieNodes::decode(buf)?),
+            SnapMessageID::TrieNodes => Self::TrieNodes(TrieNodes::decode(buf)?),
+        };
+        Ok(message)
+    }
+
+    /// Returns the message's ID.
+    pub const fn message_id(&self) -> SnapMessageID {
+        match self {
+            Self::GetAccountRange(_) => SnapMessageID::GetAccountRange,
+            Self::AccountRange(_) => SnapMessageID::AccountRange,
+            Self::GetStorageRanges(_) => SnapMessageID::GetStorageRanges,
+            Self::StorageRanges(_) => SnapMessageID::StorageRanges,
+            Self::GetByteCodes(_) => SnapMessageID::GetByteCodes,
+            Self::ByteCodes(_) => SnapMessageID::ByteCodes,
+            Self::GetTrieNodes(_) => SnapMessageID::GetTrieNodes,
+            Self::TrieNodes(_) => SnapMessageID::TrieNodes,
+        }
+    }
+
+    /// Returns the id of the request or of the request the message responds to.
+    pub const fn request_id(&self) -> u64 {
+        match self {
+            Self::GetAccountRange(msg) => msg.request_id,
+            Self::AccountRange(msg) => msg.request_id,
+            Self::GetStorageRanges(msg) => msg.request_id,
+            Self::StorageRanges(msg) => msg.request_id,
+            Self::GetByteCodes(msg) => msg.request_id,
+            Self::ByteCodes(msg) => msg.request_id,
+            Self::GetTrieNodes(msg) => msg.request_id,
+            Self::TrieNodes(msg) => msg.request_id,
+        }
+    }
+}
+
+impl Encodable for SnapMessage {
+    /// Encodes the message prefixed with its message ID.
+    fn encode(&self, out: &mut dyn BufMut) {
+        self.message_id().encode(out);
+        match self {
+            Self::GetAccountRange(msg) => msg.encode(out),
+            Self::AccountRange(msg) => msg.encode(out),
+            Self::GetStorageRanges(msg) => msg.encode(out),
+            Self::StorageRanges(msg) => msg.encode(out),
+            Self::GetByteCodes(msg) => msg.encode(out),
+            Self::ByteCodes(msg) => msg.encode(out),
+            Self::GetTrieNodes(msg) => msg.encode(out),
+            Self::TrieNodes(msg) => msg.encode(out),
+        }
+    }
+
+    fn length(&self) -> usize {
+        let message_length = match self {
+            Self::GetAccountRange(msg) => msg.length(),
+            Self::AccountRange(msg) => msg.length(),
+            Self::GetStorageRanges(msg) => msg.length(),
+            Self::StorageRanges(msg) => msg.length(),
+            Self::GetByteCodes(msg) => msg.length(),
+            Self::ByteCodes(msg) => msg.length(),
+            Self::GetTrieNodes(msg) => msg.length(),
+            Self::TrieNodes(msg) => msg.length(),
+        };
+        self.message_id().length() + message_length
+    }
+}
+
+/// Represents message IDs for `snap/1` protocol messages.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SnapMessageID {
+    /// Requests a range of accounts.
+    GetAccountRange = 0x00,
+    /// Represents a range of accounts.
+    AccountRange = 0x01,
+    /// Requests the storage ranges of accounts.
+    GetStorageRanges = 0x02,
+    /// Represents the storage ranges of accounts.
+    StorageRanges = 0x03,
+    /// Requests bytecodes.
+    GetByteCodes = 0x04,
+    /// Represents bytecodes.
+    ByteCodes = 0x05,
+    /// Requests trie nodes.
+    GetTrieNodes = 0x06,
+    /// Represents trie nodes.
+    TrieNodes = 0x07,
+}
+
+impl SnapMessageID {
+    /// Returns the max value.
+    pub const fn max() -> u8 {
+        Self::TrieNodes as u8
+    }
+}
+
+impl Encodable for SnapMessageID {
+    fn encode(&self, out: &mut dyn BufMut) {
+        out.put_u8(*self as u8);
+    }
+    fn length(&self) -> usize {
+        1
+    }
+}
+
+impl Decodable for SnapMessageID {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let id = match buf.first().ok_or(alloy_rlp::Error::InputTooShort)? {
+            0x00 => Self::GetAccountRange,
+            0x01 => Self::AccountRange,
+            0x02 => Self::GetStorageRanges,
+            0x03 => Self::StorageRanges,
+            0x04 => Self::GetByteCodes,
+            0x05 => Self::ByteCodes,
+            0x06 => Self::GetTrieNodes,
+            0x07 => Self::TrieNodes,
+            _ => return Err(alloy_rlp::Error::Custom("Invalid message ID")),
+        };
+        buf.advance(1);
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::hex;
+
+    #[test]
+    fn encode_get_account_range() {
+        let msg = SnapMessage::GetAccountRange(GetAccountRange {
+            request_id: 1,
+            root_hash: B256::with_last_byte(1),
+            starting_hash: B256::ZERO,
+            limit_hash: B256::repeat_byte(0xff),
+            response_bytes: 0x0400,
+        });
+        let encoded = alloy_rlp::encode(&msg);
+        assert_eq!(encoded.len(), msg.length());
+
+        // the request id is the first element of the message list
+        assert_eq!(&encoded[..4], hex!("00f86701").as_slice());
+        assert_eq!(SnapMessage::decode_message(&mut &encoded[..]).unwrap(), msg);
+    }
+
+    #[test]
+    fn storage_ranges_roundtrip() {
+        let msg = SnapMessage::StorageRanges(StorageRanges {
+            request_id: 1337,
+            slots: vec![
+                vec![
+                    StorageData { hash: B256::with_last_byte(1), data: Bytes::from_static(&[1]) },
+                    StorageData { hash: B256::with_last_byte(2), data: Bytes::from_static(&[2]) },
+                ],
+                vec![],
+            ],
+            proof: vec![Bytes::from_static(&[0xc0])],
+        });
+        let encoded = alloy_rlp::encode(&msg);
+        assert_eq!(encoded[0], SnapMessageID::StorageRanges as u8);
+        assert_eq!(SnapMessage::decode_message(&mut &encoded[..]).unwrap(), msg);
+        assert_eq!(msg.request_id(), 1337);
+    }
+
+    #[test]
+    fn rejects_unknown_message_id() {
+        let encoded = [SnapMessageID::max() + 1, 0xc0];
+        assert!(SnapMessage::decode_message(&mut &encoded[..]).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+//! Bandwidth accounting and throttling of a [`P2PStream`](crate::P2PStream).
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+use tokio::time::{Instant, Sleep};
+
+/// Keeps track of the bytes sent to and received from a peer.
+///
+/// The counters are shared, so a clone of the meter can be handed out to observe the traffic of
+/// the stream while the stream is in use. The counters include the message ID and are measured
+/// after snappy compression, so they reflect the `p2p` payload size on the wire.
+#[derive(Debug, Clone, Default)]
+pub struct BandwidthMeter {
+    inner: Arc<BandwidthMeterInner>,
+}
+
+#[derive(Debug, Default)]
+struct BandwidthMeterInner {
+    inbound: AtomicU64,
+    outbound: AtomicU64,
+}
+
+impl BandwidthMeter {
+    /// Returns the total number of bytes received from the peer.
+    pub fn inbound_bytes(&self) -> u64 {
+        self.inner.inbound.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total number of bytes sent to the peer.
+    pub fn outbound_bytes(&self) -> u64 {
+        self.inner.outbound.load(Ordering::Relaxed)
+    }
+
+    /// Records that `len` bytes were received from the peer.
+    pub(crate) fn record_inbound(&self, len: usize) {
+        self.inner.inbound.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    /// Records that `len` bytes were sent to the peer.
+    pub(crate) fn record_outbound(&self, len: usize) {
+        self.inner.outbound.fetch_add(len as u64, Ordering::Relaxed);
+    }
+}
+
+/// Paces outgoing messages so that the bytes sent do not exceed the configured rate.
+///
+/// Every sent message delays the next one by the time it takes to send the message at the
+/// configured rate.
+#[derive(Debug)]
+pub(crate) struct BandwidthThrottle {
+    /// The maximum number of bytes per second.
+    bytes_per_sec: u64,
+    /// The earliest time the next message can be sent.
+    next_send: Instant,
+    /// The timer used to wait for `next_send`.
+    delay: Pin<Box<Sleep>>,
+}
+
+impl BandwidthThrottle {
+    /// Creates a new throttle for the given rate.
+    ///
+    /// # Panics
+    ///
+    /// If the rate is `0`.
+    pub(crate) fn new(bytes_per_sec: u64) -> Self {
+        assert!(bytes_per_sec > 0, "bandwidth limit must be greater than 0");
+        let now = Instant::now();
+        Self { bytes_per_sec, next_send: now, delay: Box::pin(tokio::time::sleep_until(now)) }
+    }
+
+    /// Returns the configured rate in bytes per second.
+    pub(crate) const fn bytes_per_sec(&self) -> u64 {
+        self.bytes_per_sec
+    }
+
+    /// Returns [`Poll::Ready`] once the next message can be sent.
+    pub(crate) fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.next_send {
+            return Poll::Ready(())
+        }
+        if self.delay.deadline() != self.next_send {
+            self.delay.as_mut().reset(self.next_send);
+        }
+        ready!(self.delay.as_mut().poll(cx));
+        Poll::Ready(())
+    }
+
+    /// Records that a message of `len` bytes was sent.
+    pub(crate) fn on_send(&mut self, len: usize) {
+        let delay = Duration::from_secs_f64(len as f64 / self.bytes_per_sec as f64);
+        self.next_send = self.next_send.max(Instant::now()) + delay;
+    }
+}
@@ -105,6 +105,17 @@ impl Capability {
     pub fn is_eth(&self) -> bool {
         self.is_eth_v66() || self.is_eth_v67() || self.is_eth_v68()
     }
+
+    /// Returns the `snap/1` capability.
+    pub const fn snap_1() -> Self {
+        Self::new_static("snap", 1)
+    }
+
+    /// Whether this is snap v1.
+    #[inline]
+    pub fn is_snap_v1(&self) -> bool {
+        self.name == "snap" && self.version == 1
+    }
 }
 
 impl fmt::Display for Capability {
@@ -153,6 +164,7 @@ pub struct Capabilities {
     eth_66: bool,
     eth_67: bool,
     eth_68: bool,
+    snap_1: bool,
 }
 
 impl Capabilities {
@@ -191,6 +203,12 @@ impl Capabilities {
     pub const fn supports_eth_v68(&self) -> bool {
         self.eth_68
     }
+
+    /// Whether this peer supports snap v1 protocol.
+    #[inline]
+    pub const fn supports_snap_v1(&self) -> bool {
+        self.snap_1
+    }
 }
 
 impl From<Vec<Capability>> for Capabilities {
@@ -199,6 +217,7 @@ impl From<Vec<Capability>> for Capabilities {
             eth_66: value.iter().any(Capability::is_eth_v66),
             eth_67: value.iter().any(Capability::is_eth_v67),
             eth_68: value.iter().any(Capability::is_eth_v68),
+            snap_1: value.iter().any(Capability::is_snap_v1),
             inner: value,
         }
     }
@@ -218,6 +237,7 @@ impl Decodable for Capabilities {
             eth_66: inner.iter().any(Capability::is_eth_v66),
             eth_67: inner.iter().any(Capability::is_eth_v67),
             eth_68: inner.iter().any(Capability::is_eth_v68),
+            snap_1: inner.iter().any(Capability::is_snap_v1),
             inner,
         })
     }
@@ -30,12 +30,24 @@ pub const MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
 pub struct UnauthedEthStream<S> {
     #[pin]
     inner: S,
+    /// The maximum size of a message, passed on to the [`EthStream`].
+    max_message_size: usize,
 }
 
 impl<S> UnauthedEthStream<S> {
     /// Create a new `UnauthedEthStream` from a type `S` which implements `Stream` and `Sink`.
     pub const fn new(inner: S) -> Self {
-        Self { inner }
+        Self { inner, max_message_size: MAX_MESSAGE_SIZE }
+    }
+
+    /// Sets the maximum size of a message, defaults to [`MAX_MESSAGE_SIZE`].
+    ///
+    /// The limit applies to the `Status` handshake and to the resulting [`EthStream`]. See
+    /// [`EthStream::with_max_message_size`] for the limit of the underlying
+    /// [`P2PStream`](crate::P2PStream).
+    pub const fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
     }
 
     /// Consumes the type and returns the wrapped stream
@@ -99,7 +111,7 @@ where
             }
         }?;
 
-        if their_msg.len() > MAX_MESSAGE_SIZE {
+        if their_msg.len() > self.max_message_size {
             self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
             return Err(EthStreamError::MessageTooBig(their_msg.len()))
         }
@@ -168,7 +180,8 @@ where
 
                 // now we can create the `EthStream` because the peer has successfully completed
                 // the handshake
-                let stream = EthStream::new(version, self.inner);
+                let stream = EthStream::new(version, self.inner)
+                    .with_max_message_size(self.max_message_size);
 
                 Ok((stream, resp))
             }
@@ -189,6 +202,8 @@ where
 pub struct EthStream<S> {
     /// Negotiated eth version.
     version: EthVersion,
+    /// The maximum size of a message that is sent or received.
+    max_message_size: usize,
     #[pin]
     inner: S,
 }
@@ -198,7 +213,24 @@ impl<S> EthStream<S> {
     /// to manually handshake a peer.
     #[inline]
     pub const fn new(version: EthVersion, inner: S) -> Self {
-        Self { version, inner }
+        Self { version, max_message_size: MAX_MESSAGE_SIZE, inner }
+    }
+
+    /// Sets the maximum size of a message that is sent or received, defaults to
+    /// [`MAX_MESSAGE_SIZE`].
+    ///
+    /// Networks with larger blocks may need to raise this. Note that the limit must be raised on
+    /// both ends of a connection, because larger messages are rejected by a peer that uses the
+    /// default.
+    ///
+    /// Messages are also bounded by the payload limit of the underlying
+    /// [`P2PStream`](crate::P2PStream), which defaults to
+    /// [`MAX_PAYLOAD_SIZE`](crate::MAX_PAYLOAD_SIZE) and must be raised as well to exceed it, see
+    /// [`P2PStream::set_max_payload_size`](crate::P2PStream::set_max_payload_size).
+    #[inline]
+    pub const fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
     }
 
     /// Returns the eth version.
@@ -207,6 +239,12 @@ impl<S> EthStream<S> {
         self.version
     }
 
+    /// Returns the maximum size of a message that is sent or received.
+    #[inline]
+    pub const fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
     /// Returns the underlying stream.
     #[inline]
     pub const fn inner(&self) -> &S {
@@ -236,9 +274,11 @@ where
         &mut self,
         item: EthBroadcastMessage,
     ) -> Result<(), EthStreamError> {
-        self.inner.start_send_unpin(Bytes::from(alloy_rlp::encode(
-            ProtocolBroadcastMessage::from(item),
-        )))?;
+        let bytes = alloy_rlp::encode(ProtocolBroadcastMessage::from(item));
+        if bytes.len() > self.max_message_size {
+            return Err(EthStreamError::MessageTooBig(bytes.len()))
+        }
+        self.inner.start_send_unpin(Bytes::from(bytes))?;
 
         Ok(())
     }
@@ -260,7 +300,7 @@ where
             None => return Poll::Ready(None),
         };
 
-        if bytes.len() > MAX_MESSAGE_SIZE {
+        if bytes.len() > *this.max_message_size {
             return Poll::Ready(Some(Err(EthStreamError::MessageTooBig(bytes.len()))))
         }
 
@@ -316,9 +356,12 @@ where
             return Err(EthStreamError::EthHandshakeError(EthHandshakeError::StatusNotInHandshake))
         }
 
-        self.project()
-            .inner
-            .start_send(Bytes::from(alloy_rlp::encode(ProtocolMessage::from(item))))?;
+        let this = self.project();
+        let bytes = alloy_rlp::encode(ProtocolMessage::from(item));
+        if bytes.len() > *this.max_message_size {
+            return Err(EthStreamError::MessageTooBig(bytes.len()))
+        }
+        this.inner.start_send(Bytes::from(bytes))?;
 
         Ok(())
     }
@@ -344,7 +387,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::UnauthedEthStream;
+    use super::{UnauthedEthStream, MAX_MESSAGE_SIZE};
     use crate::{
         broadcast::BlockHashNumber,
         errors::{EthHandshakeError, EthStreamError},
@@ -543,6 +586,38 @@ mod tests {
         handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn can_raise_max_message_size() {
+        // a message that is larger than the default limit
+        let msg = EthMessage::NewPooledTransactionHashes66(vec![B256::ZERO; 400_000].into());
+        let max_message_size = 2 * MAX_MESSAGE_SIZE;
+        let codec = || PassthroughCodec::builder().max_frame_length(usize::MAX).new_codec();
+
+        // the default limit rejects the message on send
+        let (local, _remote) = tokio::io::duplex(1024);
+        let mut stream = EthStream::new(EthVersion::Eth67, codec().framed(local));
+        assert!(matches!(stream.send(msg.clone()).await, Err(EthStreamError::MessageTooBig(_))));
+
+        // a raised limit on both ends accepts it
+        let (local, remote) = tokio::io::duplex(1024 * 1024);
+        let mut local = EthStream::new(EthVersion::Eth67, codec().framed(local))
+            .with_max_message_size(max_message_size);
+        let mut remote = EthStream::new(EthVersion::Eth67, codec().framed(remote))
+            .with_max_message_size(max_message_size);
+        let (sent, received) = tokio::join!(local.send(msg.clone()), remote.next());
+        sent.unwrap();
+        assert_eq!(received.unwrap().unwrap(), msg);
+
+        // the default limit rejects the message on receive
+        let (local, remote) = tokio::io::duplex(1024 * 1024);
+        let mut local = EthStream::new(EthVersion::Eth67, codec().framed(local))
+            .with_max_message_size(max_message_size);
+        let mut remote = EthStream::new(EthVersion::Eth67, codec().framed(remote));
+        let (sent, received) = tokio::join!(local.send(msg), remote.next());
+        sent.unwrap();
+        assert!(matches!(received, Some(Err(EthStreamError::MessageTooBig(_)))));
+    }
+
     #[tokio::test]
     async fn can_write_and_read_ecies() {
         let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
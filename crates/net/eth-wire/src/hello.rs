@@ -146,6 +146,8 @@ pub struct HelloMessageBuilder {
     pub port: Option<u16>,
     /// The secp256k1 public key corresponding to the node's private key.
     pub id: PeerId,
+    /// Whether to advertise the `snap/1` protocol.
+    pub snap: bool,
 }
 
 // === impl HelloMessageBuilder ===
@@ -153,7 +155,14 @@ pub struct HelloMessageBuilder {
 impl HelloMessageBuilder {
     /// Create a new builder to configure a [`HelloMessage`]
     pub const fn new(id: PeerId) -> Self {
-        Self { protocol_version: None, client_version: None, protocols: None, port: None, id }
+        Self {
+            protocol_version: None,
+            client_version: None,
+            protocols: None,
+            port: None,
+            id,
+            snap: false,
+        }
     }
 
     /// Sets the port the client is listening on
@@ -174,6 +183,12 @@ impl HelloMessageBuilder {
         self
     }
 
+    /// Advertises the `snap/1` protocol in addition to the configured protocols.
+    pub const fn snap(mut self) -> Self {
+        self.snap = true;
+        self
+    }
+
     /// Sets client version.
     pub fn client_version(mut self, client_version: impl Into<String>) -> Self {
         self.client_version = Some(client_version.into());
@@ -192,9 +207,11 @@ impl HelloMessageBuilder {
     /// - `protocol_version`: [`ProtocolVersion::V5`]
     /// - `client_version`: [`RETH_CLIENT_VERSION`]
     /// - `capabilities`: All [`EthVersion`]
+    ///
+    /// If enabled, the `snap/1` protocol is added to the protocols.
     pub fn build(self) -> HelloMessageWithProtocols {
-        let Self { protocol_version, client_version, protocols, port, id } = self;
-        HelloMessageWithProtocols {
+        let Self { protocol_version, client_version, protocols, port, id, snap } = self;
+        let mut hello = HelloMessageWithProtocols {
             protocol_version: protocol_version.unwrap_or_default(),
             client_version: client_version.unwrap_or_else(|| RETH_CLIENT_VERSION.to_string()),
             protocols: protocols.unwrap_or_else(|| {
@@ -202,7 +219,11 @@ impl HelloMessageBuilder {
             }),
             port: port.unwrap_or(DEFAULT_DISCOVERY_PORT),
             id,
+        };
+        if snap {
+            let _ = hello.try_add_protocol(Protocol::snap_1());
         }
+        hello
     }
 }
 
@@ -214,7 +235,8 @@ mod tests {
     use secp256k1::{SecretKey, SECP256K1};
 
     use crate::{
-        capability::Capability, p2pstream::P2PMessage, EthVersion, HelloMessage, ProtocolVersion,
+        capability::Capability, p2pstream::P2PMessage, protocol::Protocol, EthVersion,
+        HelloMessage, HelloMessageWithProtocols, ProtocolVersion,
     };
 
     #[test]
@@ -255,6 +277,20 @@ mod tests {
         assert_eq!(hello_encoded.len(), hello.length());
     }
 
+    #[test]
+    fn hello_advertises_snap() {
+        let secret_key = SecretKey::new(&mut rand::thread_rng());
+        let id = pk2id(&secret_key.public_key(SECP256K1));
+
+        let hello = HelloMessageWithProtocols::builder(id).build();
+        assert!(!hello.contains_protocol(&Protocol::snap_1()));
+
+        let hello = HelloMessageWithProtocols::builder(id).snap().build();
+        assert!(hello.contains_protocol(&Protocol::snap_1()));
+        assert!(hello.contains_protocol(&Protocol::eth_68()));
+        assert!(hello.message().capabilities.contains(&Capability::snap_1()));
+    }
+
     #[test]
     fn hello_message_id_prefix() {
         // ensure that the hello message id is prefixed
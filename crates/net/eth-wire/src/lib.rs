@@ -1,4 +1,4 @@
-//! Implementation of the `eth` wire protocol.
+//! Implementation of the `eth` and `snap` wire protocols.
 //!
 //! ## Feature Flags
 //!
@@ -15,6 +15,7 @@
 #![allow(unknown_lints, non_local_definitions)]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
+mod bandwidth;
 pub mod capability;
 mod disconnect;
 pub mod errors;
@@ -24,6 +25,7 @@ pub mod multiplex;
 mod p2pstream;
 mod pinger;
 pub mod protocol;
+mod snapstream;
 
 #[cfg(test)]
 pub mod test_utils;
@@ -34,14 +36,16 @@ pub use tokio_util::codec::{
 };
 
 pub use crate::{
+    bandwidth::BandwidthMeter,
     capability::Capability,
     disconnect::{CanDisconnect, DisconnectReason},
     ethstream::{EthStream, UnauthedEthStream, MAX_MESSAGE_SIZE},
     hello::{HelloMessage, HelloMessageBuilder, HelloMessageWithProtocols},
     p2pstream::{
         DisconnectP2P, P2PMessage, P2PMessageID, P2PStream, ProtocolVersion, UnauthedP2PStream,
-        MAX_RESERVED_MESSAGE_ID,
+        MAX_PAYLOAD_SIZE, MAX_RESERVED_MESSAGE_ID,
     },
+    snapstream::SnapStream,
 };
 
 // Re-export wire types
@@ -1,4 +1,5 @@
 use crate::{
+    bandwidth::{BandwidthMeter, BandwidthThrottle},
     capability::SharedCapabilities,
     disconnect::CanDisconnect,
     errors::{P2PHandshakeError, P2PStreamError},
@@ -27,9 +28,9 @@ use tracing::{debug, trace};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// [`MAX_PAYLOAD_SIZE`] is the maximum size of an uncompressed message payload.
+/// [`MAX_PAYLOAD_SIZE`] is the default maximum size of an uncompressed message payload.
 /// This is defined in [EIP-706](https://eips.ethereum.org/EIPS/eip-706).
-const MAX_PAYLOAD_SIZE: usize = 16 * 1024 * 1024;
+pub const MAX_PAYLOAD_SIZE: usize = 16 * 1024 * 1024;
 
 /// [`MAX_RESERVED_MESSAGE_ID`] is the maximum message ID reserved for the `p2p` subprotocol. If
 /// there are any incoming messages with an ID greater than this, they are subprotocol messages.
@@ -163,7 +164,8 @@ where
             Ok(cap) => Ok(cap),
         }?;
 
-        let stream = P2PStream::new(self.inner, shared_capability);
+        let mut stream = P2PStream::new(self.inner, shared_capability);
+        stream.set_protocol_version(their_hello.protocol_version);
 
         Ok((stream, their_hello))
     }
@@ -240,6 +242,11 @@ pub struct P2PStream<S> {
     /// The supported capability for this stream.
     shared_capabilities: SharedCapabilities,
 
+    /// The `p2p` protocol version negotiated during the handshake.
+    ///
+    /// Messages are only snappy compressed if this is [`ProtocolVersion::V5`] or higher.
+    protocol_version: ProtocolVersion,
+
     /// Outgoing messages buffered for sending to the underlying stream.
     outgoing_messages: VecDeque<Bytes>,
 
@@ -250,6 +257,15 @@ pub struct P2PStream<S> {
     /// Whether this stream is currently in the process of disconnecting by sending a disconnect
     /// message.
     disconnecting: bool,
+
+    /// Tracks the bytes sent and received over this stream.
+    bandwidth: BandwidthMeter,
+
+    /// Paces outgoing messages if an outbound bandwidth limit is configured.
+    throttle: Option<BandwidthThrottle>,
+
+    /// The maximum size of an uncompressed message payload that is sent or received.
+    max_payload_size: usize,
 }
 
 impl<S> P2PStream<S> {
@@ -263,9 +279,13 @@ impl<S> P2PStream<S> {
             decoder: snap::raw::Decoder::new(),
             pinger: Pinger::new(PING_INTERVAL, PING_TIMEOUT),
             shared_capabilities,
+            protocol_version: ProtocolVersion::default(),
             outgoing_messages: VecDeque::new(),
             outgoing_message_buffer_capacity: MAX_P2P_CAPACITY,
             disconnecting: false,
+            bandwidth: BandwidthMeter::default(),
+            throttle: None,
+            max_payload_size: MAX_PAYLOAD_SIZE,
         }
     }
 
@@ -283,6 +303,43 @@ impl<S> P2PStream<S> {
         self.outgoing_message_buffer_capacity = capacity;
     }
 
+    /// Sets the maximum size of an uncompressed message payload that is sent or received, defaults
+    /// to [`MAX_PAYLOAD_SIZE`].
+    ///
+    /// This also bounds the messages of the subprotocols on top of this stream, so it must be
+    /// raised together with their own limits, see for example
+    /// [`EthStream::with_max_message_size`](crate::EthStream::with_max_message_size).
+    pub fn set_max_payload_size(&mut self, max_payload_size: usize) {
+        self.max_payload_size = max_payload_size;
+    }
+
+    /// Returns the maximum size of an uncompressed message payload that is sent or received.
+    pub const fn max_payload_size(&self) -> usize {
+        self.max_payload_size
+    }
+
+    /// Returns the [`BandwidthMeter`] that tracks the traffic of this stream.
+    pub const fn bandwidth_meter(&self) -> &BandwidthMeter {
+        &self.bandwidth
+    }
+
+    /// Limits the outbound traffic of this stream to the given number of bytes per second.
+    ///
+    /// Once the limit is exceeded, sending the next message is delayed until the sent bytes are
+    /// back within the configured rate. `None` removes the limit, which is the default.
+    ///
+    /// # Panics
+    ///
+    /// If the provided limit is `0`.
+    pub fn set_outbound_bandwidth_limit(&mut self, bytes_per_sec: Option<u64>) {
+        self.throttle = bytes_per_sec.map(BandwidthThrottle::new);
+    }
+
+    /// Returns the configured outbound bandwidth limit in bytes per second, if any.
+    pub fn outbound_bandwidth_limit(&self) -> Option<u64> {
+        self.throttle.as_ref().map(BandwidthThrottle::bytes_per_sec)
+    }
+
     /// Returns the shared capabilities for this stream.
     ///
     /// This includes all the shared capabilities that were negotiated during the handshake and
@@ -291,19 +348,130 @@ impl<S> P2PStream<S> {
         &self.shared_capabilities
     }
 
+    /// Returns the `p2p` protocol version negotiated during the handshake.
+    pub const fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Sets the `p2p` protocol version of this stream, which determines whether messages are
+    /// snappy compressed.
+    ///
+    /// [`P2PStream::new`] assumes [`ProtocolVersion::V5`], the handshake sets the version agreed
+    /// on with the peer.
+    pub fn set_protocol_version(&mut self, version: ProtocolVersion) {
+        self.protocol_version = version;
+    }
+
+    /// Returns `true` if messages on this stream are snappy compressed.
+    ///
+    /// Snappy compression is mandatory for peers that agreed on `p2p` version 5 or higher, see
+    /// also <https://github.com/ethereum/devp2p/blob/master/rlpx.md#message-compression>
+    pub const fn is_snappy_enabled(&self) -> bool {
+        self.protocol_version as u8 >= ProtocolVersion::V5 as u8
+    }
+
     /// Returns `true` if the stream has outgoing capacity.
     fn has_outgoing_capacity(&self) -> bool {
         self.outgoing_messages.len() < self.outgoing_message_buffer_capacity
     }
 
-    /// Queues in a _snappy_ encoded [`P2PMessage::Pong`] message.
+    /// Queues in a [`P2PMessage::Pong`] message, _snappy_ encoded if compression is enabled.
     fn send_pong(&mut self) {
-        self.outgoing_messages.push_back(Bytes::from(alloy_rlp::encode(P2PMessage::Pong)));
+        let msg = self.encode_ping_pong(P2PMessage::Pong);
+        self.outgoing_messages.push_back(msg);
     }
 
-    /// Queues in a _snappy_ encoded [`P2PMessage::Ping`] message.
+    /// Queues in a [`P2PMessage::Ping`] message, _snappy_ encoded if compression is enabled.
     fn send_ping(&mut self) {
-        self.outgoing_messages.push_back(Bytes::from(alloy_rlp::encode(P2PMessage::Ping)));
+        let msg = self.encode_ping_pong(P2PMessage::Ping);
+        self.outgoing_messages.push_back(msg);
+    }
+
+    /// Encodes a [`P2PMessage::Ping`] or [`P2PMessage::Pong`] message.
+    ///
+    /// The [`Encodable`] implementation always snappy encodes the empty payload, so without
+    /// compression the message is just the message ID followed by the empty list.
+    fn encode_ping_pong(&self, msg: P2PMessage) -> Bytes {
+        if self.is_snappy_enabled() {
+            return Bytes::from(alloy_rlp::encode(msg))
+        }
+        Bytes::copy_from_slice(&[P2PMessageID::from(msg) as u8, EMPTY_LIST_CODE])
+    }
+
+    /// Returns the payload of the given message, prefixed with a placeholder byte for the message
+    /// ID.
+    ///
+    /// If snappy compression is enabled, the payload is decompressed first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload exceeds the maximum payload size or can't be decompressed,
+    /// for example because a peer that agreed on compression sent an uncompressed message.
+    fn decompress(&mut self, bytes: &[u8]) -> Result<BytesMut, P2PStreamError> {
+        if !self.is_snappy_enabled() {
+            let payload_len = bytes.len() - 1;
+            if payload_len > self.max_payload_size {
+                return Err(P2PStreamError::MessageTooBig {
+                    message_size: payload_len,
+                    max_size: self.max_payload_size,
+                })
+            }
+            return Ok(BytesMut::from(bytes))
+        }
+
+        // first check that the compressed message length does not exceed the max
+        // payload size
+        let decompressed_len = snap::raw::decompress_len(&bytes[1..])?;
+        if decompressed_len > self.max_payload_size {
+            return Err(P2PStreamError::MessageTooBig {
+                message_size: decompressed_len,
+                max_size: self.max_payload_size,
+            })
+        }
+
+        // create a buffer to hold the decompressed message, adding a byte to the length for
+        // the message ID byte, which is the first byte in this buffer
+        let mut decompress_buf = BytesMut::zeroed(decompressed_len + 1);
+
+        // each message following a successful handshake is compressed with snappy, so we need
+        // to decompress the message before we can decode it.
+        self.decoder.decompress(&bytes[1..], &mut decompress_buf[1..]).map_err(|err| {
+            debug!(
+                %err,
+                msg=%hex::encode(&bytes[1..]),
+                "error decompressing p2p message"
+            );
+            err
+        })?;
+
+        Ok(decompress_buf)
+    }
+
+    /// Compresses the payload of the given message if snappy compression is enabled.
+    ///
+    /// The message ID byte is copied as is.
+    fn compress(&mut self, msg: &[u8]) -> Result<BytesMut, P2PStreamError> {
+        if !self.is_snappy_enabled() {
+            return Ok(BytesMut::from(msg))
+        }
+
+        let mut compressed = BytesMut::zeroed(1 + snap::raw::max_compress_len(msg.len() - 1));
+        let compressed_size =
+            self.encoder.compress(&msg[1..], &mut compressed[1..]).map_err(|err| {
+                debug!(
+                    %err,
+                    msg=%hex::encode(&msg[1..]),
+                    "error compressing p2p message"
+                );
+                err
+            })?;
+
+        // truncate the compressed buffer to the actual compressed size (plus one for the message
+        // id)
+        compressed.truncate(compressed_size + 1);
+        compressed[0] = msg[0];
+
+        Ok(compressed)
     }
 }
 
@@ -333,26 +501,11 @@ impl<S> DisconnectP2P for P2PStream<S> {
         let mut buf = Vec::with_capacity(disconnect.length());
         disconnect.encode(&mut buf);
 
-        let mut compressed = vec![0u8; 1 + snap::raw::max_compress_len(buf.len() - 1)];
-        let compressed_size =
-            self.encoder.compress(&buf[1..], &mut compressed[1..]).map_err(|err| {
-                debug!(
-                    %err,
-                    msg=%hex::encode(&buf[1..]),
-                    "error compressing disconnect"
-                );
-                err
-            })?;
-
-        // truncate the compressed buffer to the actual compressed size (plus one for the message
-        // id)
-        compressed.truncate(compressed_size + 1);
-
         // we do not add the capability offset because the disconnect message is a `p2p` reserved
         // message
-        compressed[0] = buf[0];
+        let compressed = self.compress(&buf)?;
 
-        self.outgoing_messages.push_back(compressed.into());
+        self.outgoing_messages.push_back(compressed.freeze());
         self.disconnecting = true;
         Ok(())
     }
@@ -400,6 +553,7 @@ where
                 Some(Err(err)) => return Poll::Ready(Some(Err(err.into()))),
                 None => return Poll::Ready(None),
             };
+            this.bandwidth.record_inbound(bytes.len());
 
             if bytes.is_empty() {
                 // empty messages are not allowed
@@ -428,30 +582,15 @@ where
                 }
             }
 
-            // first check that the compressed message length does not exceed the max
-            // payload size
-            let decompressed_len = snap::raw::decompress_len(&bytes[1..])?;
-            if decompressed_len > MAX_PAYLOAD_SIZE {
-                return Poll::Ready(Some(Err(P2PStreamError::MessageTooBig {
-                    message_size: decompressed_len,
-                    max_size: MAX_PAYLOAD_SIZE,
-                })))
-            }
-
-            // create a buffer to hold the decompressed message, adding a byte to the length for
-            // the message ID byte, which is the first byte in this buffer
-            let mut decompress_buf = BytesMut::zeroed(decompressed_len + 1);
-
-            // each message following a successful handshake is compressed with snappy, so we need
-            // to decompress the message before we can decode it.
-            this.decoder.decompress(&bytes[1..], &mut decompress_buf[1..]).map_err(|err| {
-                debug!(
-                    %err,
-                    msg=%hex::encode(&bytes[1..]),
-                    "error decompressing p2p message"
-                );
-                err
-            })?;
+            let mut decompress_buf = match this.decompress(&bytes) {
+                Ok(buf) => buf,
+                Err(err) => {
+                    // the payload is oversized or not compressed as agreed on in the handshake,
+                    // which is a protocol breach
+                    this.start_disconnect(DisconnectReason::ProtocolBreach)?;
+                    return Poll::Ready(Some(Err(err)))
+                }
+            };
 
             match id {
                 _ if id == P2PMessageID::Ping as u8 => {
@@ -569,10 +708,10 @@ where
     }
 
     fn start_send(self: Pin<&mut Self>, item: Bytes) -> Result<(), Self::Error> {
-        if item.len() > MAX_PAYLOAD_SIZE {
+        if item.len() > self.max_payload_size {
             return Err(P2PStreamError::MessageTooBig {
                 message_size: item.len(),
-                max_size: MAX_PAYLOAD_SIZE,
+                max_size: self.max_payload_size,
             })
         }
 
@@ -586,22 +725,9 @@ where
             return Err(P2PStreamError::SendBufferFull)
         }
 
-        let this = self.project();
-
-        let mut compressed = BytesMut::zeroed(1 + snap::raw::max_compress_len(item.len() - 1));
-        let compressed_size =
-            this.encoder.compress(&item[1..], &mut compressed[1..]).map_err(|err| {
-                debug!(
-                    %err,
-                    msg=%hex::encode(&item[1..]),
-                    "error compressing p2p message"
-                );
-                err
-            })?;
+        let this = self.get_mut();
 
-        // truncate the compressed buffer to the actual compressed size (plus one for the message
-        // id)
-        compressed.truncate(compressed_size + 1);
+        let mut compressed = this.compress(&item)?;
 
         // all messages sent in this stream are subprotocol messages, so we need to switch the
         // message id based on the offset
@@ -618,12 +744,23 @@ where
             match ready!(this.inner.as_mut().poll_flush(cx)) {
                 Err(err) => return Poll::Ready(Err(err.into())),
                 Ok(()) => {
+                    if this.outgoing_messages.is_empty() {
+                        return Poll::Ready(Ok(()))
+                    }
+                    if let Some(throttle) = this.throttle.as_mut() {
+                        ready!(throttle.poll_ready(cx));
+                    }
                     let Some(message) = this.outgoing_messages.pop_front() else {
                         return Poll::Ready(Ok(()))
                     };
+                    let len = message.len();
                     if let Err(err) = this.inner.as_mut().start_send(message) {
                         return Poll::Ready(Err(err.into()))
                     }
+                    this.bandwidth.record_outbound(len);
+                    if let Some(throttle) = this.throttle.as_mut() {
+                        throttle.on_send(len);
+                    }
                 }
             }
         }
@@ -828,7 +965,7 @@ mod tests {
     use super::*;
     use crate::{capability::SharedCapability, test_utils::eth_hello, EthVersion};
     use tokio::net::{TcpListener, TcpStream};
-    use tokio_util::codec::Decoder;
+    use tokio_util::codec::{Decoder, Framed};
 
     #[tokio::test]
     async fn test_can_disconnect() {
@@ -1016,6 +1153,169 @@ mod tests {
         handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_outbound_bandwidth_limit() {
+        const LIMIT: u64 = 20_000;
+        const MESSAGES: usize = 6;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+
+        let handle = tokio::spawn(async move {
+            let (incoming, _) = listener.accept().await.unwrap();
+            let stream = crate::PassthroughCodec::default().framed(incoming);
+
+            let (server_hello, _) = eth_hello();
+
+            let (mut p2p_stream, _) =
+                UnauthedP2PStream::new(stream).handshake(server_hello).await.unwrap();
+            p2p_stream.set_outbound_bandwidth_limit(Some(LIMIT));
+
+            let mut last_len = 0;
+            let start = tokio::time::Instant::now();
+            for _ in 0..MESSAGES {
+                // random bytes can't be compressed, so each message is ~2KB on the wire
+                let mut message = vec![0u8; 2_000];
+                rand::Rng::fill(&mut rand::thread_rng(), &mut message[1..]);
+                let before = p2p_stream.bandwidth_meter().outbound_bytes();
+                p2p_stream.send(Bytes::from(message)).await.unwrap();
+                last_len = p2p_stream.bandwidth_meter().outbound_bytes() - before;
+            }
+            let elapsed = start.elapsed();
+
+            // every message but the last one delays the next send
+            let sent = p2p_stream.bandwidth_meter().outbound_bytes();
+            let expected = Duration::from_secs_f64((sent - last_len) as f64 / LIMIT as f64);
+            assert!(elapsed >= expected, "sends not paced: {elapsed:?} < {expected:?}");
+            sent
+        });
+
+        let outgoing = TcpStream::connect(local_addr).await.unwrap();
+        let sink = crate::PassthroughCodec::default().framed(outgoing);
+
+        let (client_hello, _) = eth_hello();
+
+        let (mut p2p_stream, _) =
+            UnauthedP2PStream::new(sink).handshake(client_hello).await.unwrap();
+        for _ in 0..MESSAGES {
+            p2p_stream.next().await.unwrap().unwrap();
+        }
+
+        let sent = handle.await.unwrap();
+        assert_eq!(p2p_stream.bandwidth_meter().inbound_bytes(), sent);
+        assert_eq!(p2p_stream.bandwidth_meter().outbound_bytes(), 0);
+    }
+
+    /// Performs a handshake over a local TCP connection with the given `p2p` protocol version and
+    /// returns the server and client streams.
+    async fn p2p_stream_pair(
+        version: ProtocolVersion,
+    ) -> (
+        P2PStream<Framed<TcpStream, crate::PassthroughCodec>>,
+        P2PStream<Framed<TcpStream, crate::PassthroughCodec>>,
+    ) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+
+        let handle = tokio::spawn(async move {
+            let (incoming, _) = listener.accept().await.unwrap();
+            let stream = crate::PassthroughCodec::default().framed(incoming);
+
+            let (mut server_hello, _) = eth_hello();
+            server_hello.protocol_version = version;
+
+            let (p2p_stream, _) =
+                UnauthedP2PStream::new(stream).handshake(server_hello).await.unwrap();
+            p2p_stream
+        });
+
+        let outgoing = TcpStream::connect(local_addr).await.unwrap();
+        let sink = crate::PassthroughCodec::default().framed(outgoing);
+
+        let (mut client_hello, _) = eth_hello();
+        client_hello.protocol_version = version;
+
+        let (client, _) = UnauthedP2PStream::new(sink).handshake(client_hello).await.unwrap();
+
+        (handle.await.unwrap(), client)
+    }
+
+    #[tokio::test]
+    async fn test_snappy_negotiated_in_handshake() {
+        let (server, client) = p2p_stream_pair(ProtocolVersion::V5).await;
+        assert!(server.is_snappy_enabled());
+        assert!(client.is_snappy_enabled());
+
+        let (mut server, mut client) = p2p_stream_pair(ProtocolVersion::V4).await;
+        assert_eq!(server.protocol_version(), ProtocolVersion::V4);
+        assert!(!server.is_snappy_enabled());
+        assert!(!client.is_snappy_enabled());
+
+        // v4 peers send uncompressed messages
+        server.inner.send(Bytes::from_static(&[0x10, 0xc2, 0x01, 0x02])).await.unwrap();
+        let msg = client.next().await.unwrap().unwrap();
+        assert_eq!(&msg[..], &[0x00, 0xc2, 0x01, 0x02]);
+
+        let msg = Bytes::from_static(&[0x00, 0xc2, 0x03, 0x04]);
+        client.send(msg.clone()).await.unwrap();
+        assert_eq!(server.next().await.unwrap().unwrap(), msg);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_uncompressed_message() {
+        reth_tracing::init_test_tracing();
+        let (mut server, mut client) = p2p_stream_pair(ProtocolVersion::V5).await;
+
+        // the peer agreed on v5 but sends a message without snappy compression
+        server.inner.send(Bytes::from_static(&[0x10, 0xc2, 0x01, 0x02])).await.unwrap();
+
+        let err = client.next().await.unwrap().unwrap_err();
+        assert!(matches!(err, P2PStreamError::Snap(_)), "unexpected err: {err}");
+        assert!(client.is_disconnecting());
+        client.close().await.unwrap();
+
+        let err = server.next().await.unwrap().unwrap_err();
+        assert_eq!(err.as_disconnected(), Some(DisconnectReason::ProtocolBreach));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_oversized_decompressed_message() {
+        reth_tracing::init_test_tracing();
+        let (mut server, mut client) = p2p_stream_pair(ProtocolVersion::V5).await;
+
+        // snappy header announcing a decompressed length of `MAX_PAYLOAD_SIZE + 1`
+        server.inner.send(Bytes::from_static(&[0x10, 0x81, 0x80, 0x80, 0x08])).await.unwrap();
+
+        let err = client.next().await.unwrap().unwrap_err();
+        match err {
+            P2PStreamError::MessageTooBig { message_size, max_size } => {
+                assert_eq!(message_size, MAX_PAYLOAD_SIZE + 1);
+                assert_eq!(max_size, MAX_PAYLOAD_SIZE);
+            }
+            e => panic!("unexpected err: {e}"),
+        }
+        client.close().await.unwrap();
+
+        let err = server.next().await.unwrap().unwrap_err();
+        assert_eq!(err.as_disconnected(), Some(DisconnectReason::ProtocolBreach));
+    }
+
+    #[tokio::test]
+    async fn test_can_raise_max_payload_size() {
+        let (mut server, mut client) = p2p_stream_pair(ProtocolVersion::V5).await;
+        client.set_max_payload_size(MAX_PAYLOAD_SIZE + 1);
+
+        // passes the size check, but the announced payload is missing
+        server.inner.send(Bytes::from_static(&[0x10, 0x81, 0x80, 0x80, 0x08])).await.unwrap();
+
+        let err = client.next().await.unwrap().unwrap_err();
+        assert!(matches!(err, P2PStreamError::Snap(_)), "unexpected err: {err}");
+
+        let payload = Bytes::from(vec![0x10; MAX_PAYLOAD_SIZE + 1]);
+        let err = server.send(payload).await.unwrap_err();
+        assert!(matches!(err, P2PStreamError::MessageTooBig { .. }), "unexpected err: {err}");
+    }
+
     #[test]
     fn snappy_decode_encode_ping() {
         let snappy_ping = b"\x02\x01\0\xc0";
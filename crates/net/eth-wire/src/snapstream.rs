@@ -0,0 +1,223 @@
+use crate::{
+    errors::EthStreamError, message::MessageError, CanDisconnect, DisconnectReason, SnapMessage,
+    MAX_MESSAGE_SIZE,
+};
+use futures::{ready, Sink};
+use pin_project::pin_project;
+use reth_primitives::bytes::{Bytes, BytesMut};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio_stream::Stream;
+use tracing::debug;
+
+/// A stream that sends and receives messages of the `snap/1` protocol.
+///
+/// The `snap` protocol has no handshake of its own, it runs side by side with the `eth` protocol
+/// on the same connection, so the wrapped stream is usually the
+/// [`ProtocolConnection`](crate::multiplex::ProtocolConnection) of the `snap` capability.
+#[pin_project]
+#[derive(Debug)]
+pub struct SnapStream<S> {
+    /// The maximum size of a message that is sent or received.
+    max_message_size: usize,
+    #[pin]
+    inner: S,
+}
+
+impl<S> SnapStream<S> {
+    /// Creates a new [`SnapStream`] from a provided stream.
+    #[inline]
+    pub const fn new(inner: S) -> Self {
+        Self { max_message_size: MAX_MESSAGE_SIZE, inner }
+    }
+
+    /// Sets the maximum size of a message that is sent or received, defaults to
+    /// [`MAX_MESSAGE_SIZE`].
+    ///
+    /// See [`EthStream::with_max_message_size`](crate::EthStream::with_max_message_size), the
+    /// limit must be raised on both ends of a connection as well.
+    #[inline]
+    pub const fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Returns the maximum size of a message that is sent or received.
+    #[inline]
+    pub const fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
+    /// Returns the underlying stream.
+    #[inline]
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns mutable access to the underlying stream.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Consumes this type and returns the wrapped stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, E> Stream for SnapStream<S>
+where
+    S: Stream<Item = Result<BytesMut, E>> + Unpin,
+    EthStreamError: From<E>,
+{
+    type Item = Result<SnapMessage, EthStreamError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let res = ready!(this.inner.poll_next(cx));
+        let bytes = match res {
+            Some(Ok(bytes)) => bytes,
+            Some(Err(err)) => return Poll::Ready(Some(Err(err.into()))),
+            None => return Poll::Ready(None),
+        };
+
+        if bytes.len() > *this.max_message_size {
+            return Poll::Ready(Some(Err(EthStreamError::MessageTooBig(bytes.len()))))
+        }
+
+        match SnapMessage::decode_message(&mut bytes.as_ref()) {
+            Ok(msg) => Poll::Ready(Some(Ok(msg))),
+            Err(err) => {
+                debug!(%err, len = bytes.len(), "failed to decode snap message");
+                Poll::Ready(Some(Err(EthStreamError::InvalidMessage(MessageError::RlpError(err)))))
+            }
+        }
+    }
+}
+
+impl<S> Sink<SnapMessage> for SnapStream<S>
+where
+    S: CanDisconnect<Bytes> + Unpin,
+    EthStreamError: From<<S as Sink<Bytes>>::Error>,
+{
+    type Error = EthStreamError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: SnapMessage) -> Result<(), Self::Error> {
+        let this = self.project();
+        let bytes = alloy_rlp::encode(item);
+        if bytes.len() > *this.max_message_size {
+            return Err(EthStreamError::MessageTooBig(bytes.len()))
+        }
+        this.inner.start_send(Bytes::from(bytes))?;
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().inner.poll_flush(cx).map_err(Into::into)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().inner.poll_close(cx).map_err(Into::into)
+    }
+}
+
+impl<S> CanDisconnect<SnapMessage> for SnapStream<S>
+where
+    S: CanDisconnect<Bytes> + Send,
+    EthStreamError: From<<S as Sink<Bytes>>::Error>,
+{
+    async fn disconnect(&mut self, reason: DisconnectReason) -> Result<(), EthStreamError> {
+        self.inner.disconnect(reason).await.map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        snap::{ByteCodes, GetByteCodes},
+        PassthroughCodec,
+    };
+    use futures::{SinkExt, StreamExt};
+    use reth_primitives::B256;
+    use tokio_util::codec::Decoder;
+
+    #[tokio::test]
+    async fn can_exchange_snap_messages() {
+        let (local, remote) = tokio::io::duplex(1024);
+        let mut local = SnapStream::new(PassthroughCodec::default().framed(local));
+        let mut remote = SnapStream::new(PassthroughCodec::default().framed(remote));
+
+        let request = SnapMessage::GetByteCodes(GetByteCodes {
+            request_id: 1,
+            hashes: vec![B256::random()],
+            response_bytes: 512,
+        });
+        local.send(request.clone()).await.unwrap();
+        assert_eq!(remote.next().await.unwrap().unwrap(), request);
+
+        let response = SnapMessage::ByteCodes(ByteCodes {
+            request_id: 1,
+            codes: vec![Bytes::from_static(&[0x60, 0x00])],
+        });
+        remote.send(response.clone()).await.unwrap();
+        assert_eq!(local.next().await.unwrap().unwrap(), response);
+    }
+
+    #[tokio::test]
+    async fn can_raise_max_message_size() {
+        // a message that is larger than the default limit
+        let msg = SnapMessage::ByteCodes(ByteCodes {
+            request_id: 1,
+            codes: vec![Bytes::from(vec![0x00; MAX_MESSAGE_SIZE])],
+        });
+        let max_message_size = 2 * MAX_MESSAGE_SIZE;
+        let codec = || PassthroughCodec::builder().max_frame_length(usize::MAX).new_codec();
+
+        // the default limit rejects the message on send
+        let (local, _remote) = tokio::io::duplex(1024);
+        let mut stream = SnapStream::new(codec().framed(local));
+        assert!(matches!(stream.send(msg.clone()).await, Err(EthStreamError::MessageTooBig(_))));
+
+        // a raised limit on both ends accepts it
+        let (local, remote) = tokio::io::duplex(1024 * 1024);
+        let mut local =
+            SnapStream::new(codec().framed(local)).with_max_message_size(max_message_size);
+        let mut remote =
+            SnapStream::new(codec().framed(remote)).with_max_message_size(max_message_size);
+        let (sent, received) = tokio::join!(local.send(msg.clone()), remote.next());
+        sent.unwrap();
+        assert_eq!(received.unwrap().unwrap(), msg);
+
+        // the default limit rejects the message on receive
+        let (local, remote) = tokio::io::duplex(1024 * 1024);
+        let mut local =
+            SnapStream::new(codec().framed(local)).with_max_message_size(max_message_size);
+        let mut remote = SnapStream::new(codec().framed(remote));
+        let (sent, received) = tokio::join!(local.send(msg), remote.next());
+        sent.unwrap();
+        assert!(matches!(received, Some(Err(EthStreamError::MessageTooBig(_)))));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_snap_message() {
+        let (local, remote) = tokio::io::duplex(1024);
+        let mut local = PassthroughCodec::default().framed(local);
+        let mut remote = SnapStream::new(PassthroughCodec::default().framed(remote));
+
+        // not a snap message id
+        local.send(Bytes::from_static(&[0x08, 0xc0])).await.unwrap();
+        assert!(matches!(
+            remote.next().await,
+            Some(Err(EthStreamError::InvalidMessage(MessageError::RlpError(_))))
+        ));
+    }
+}
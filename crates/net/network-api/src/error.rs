@@ -20,3 +20,29 @@ impl From<oneshot::error::RecvError> for NetworkError {
         Self::ChannelClosed
     }
 }
+
+/// Errors when updating the local ENR of the node.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EnrUpdateError {
+    /// The key is managed by the node itself and can't be set.
+    #[error("ENR key {0:?} is reserved")]
+    ReservedKey(String),
+    /// The updated ENR would exceed the maximum ENR size.
+    #[error("ENR exceeds the maximum size of {} bytes", crate::MAX_ENR_SIZE)]
+    ExceedsMaxSize,
+    /// A discovery service failed to update its ENR.
+    #[error("failed to update the ENR of the discovery service: {0}")]
+    Discovery(String),
+    /// The network is not running.
+    #[error(transparent)]
+    Network(#[from] NetworkError),
+}
+
+impl From<enr::Error> for EnrUpdateError {
+    fn from(err: enr::Error) -> Self {
+        match err {
+            enr::Error::ExceedsMaxSize => Self::ExceedsMaxSize,
+            err => Self::Discovery(err.to_string()),
+        }
+    }
+}
@@ -13,12 +13,15 @@
 )]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
-use reth_eth_wire::{capability::Capabilities, DisconnectReason, EthVersion, Status};
+use alloy_primitives::Bytes;
+use reth_eth_wire::{
+    capability::Capabilities, BandwidthMeter, DisconnectReason, EthVersion, Status,
+};
 use reth_rpc_types::NetworkStatus;
 use std::{future::Future, net::SocketAddr, sync::Arc, time::Instant};
 
-pub use error::NetworkError;
-pub use reputation::{Reputation, ReputationChangeKind};
+pub use error::{EnrUpdateError, NetworkError};
+pub use reputation::{PeerPenalties, PeerReputationInfo, Reputation, ReputationChangeKind};
 use reth_network_peers::NodeRecord;
 
 /// The `PeerId` type.
@@ -57,6 +60,12 @@ pub trait PeersInfo: Send + Sync {
     /// Note: this should only include established connections and _not_ ongoing attempts.
     fn num_connected_peers(&self) -> usize;
 
+    /// Returns how many of the connected peers initiated the connection to the node.
+    fn num_inbound_peers(&self) -> usize;
+
+    /// Returns how many of the connected peers the node initiated the connection to.
+    fn num_outbound_peers(&self) -> usize;
+
     /// Returns the Ethereum Node Record of the node.
     fn local_node_record(&self) -> NodeRecord;
 
@@ -64,6 +73,13 @@ pub trait PeersInfo: Send + Sync {
     fn local_enr(&self) -> enr::Enr<enr::secp256k1::SecretKey>;
 }
 
+/// The maximum size of an encoded ENR, see [EIP-778](https://eips.ethereum.org/EIPS/eip-778).
+pub const MAX_ENR_SIZE: usize = 300;
+
+/// ENR keys that are managed by the node itself and can't be set via [`Peers::update_enr`].
+pub const RESERVED_ENR_KEYS: &[&str] =
+    &["id", "secp256k1", "ip", "ip6", "tcp", "tcp6", "udp", "udp6", "eth", "eth2", "opstack"];
+
 /// Provides an API for managing the peers of the network.
 pub trait Peers: PeersInfo {
     /// Adds a peer to the peer set.
@@ -134,11 +150,56 @@ pub trait Peers: PeersInfo {
     /// Send a reputation change for the given peer.
     fn reputation_change(&self, peer_id: PeerId, kind: ReputationChangeKind);
 
+    /// Sets the custom `key` in the local ENR to the RLP encoding of `value`, replacing any
+    /// previous value, so it is advertised to other peers.
+    ///
+    /// The ENR of every running discovery service is updated and re-signed, one service after the
+    /// other, stopping at the first one that rejects the update. This fails if the key is one of
+    /// the [`RESERVED_ENR_KEYS`] or if the updated ENR of a discovery service would exceed
+    /// [`MAX_ENR_SIZE`].
+    fn update_enr(
+        &self,
+        key: String,
+        value: Bytes,
+    ) -> impl Future<Output = Result<(), EnrUpdateError>> + Send;
+
     /// Get the reputation of a peer.
     fn reputation_by_id(
         &self,
         peer_id: PeerId,
     ) -> impl Future<Output = Result<Option<Reputation>, NetworkError>> + Send;
+
+    /// Get the reputation of a peer, whether it is banned and the penalties it received since its
+    /// reputation was last reset.
+    ///
+    /// Returns `None` if the peer is not tracked.
+    ///
+    /// By default, this only reports the reputation of the peer, see [`Self::reputation_by_id`],
+    /// without its ban status and penalties.
+    fn reputation_info_by_id(
+        &self,
+        peer_id: PeerId,
+    ) -> impl Future<Output = Result<Option<PeerReputationInfo>, NetworkError>> + Send {
+        async move {
+            Ok(self
+                .reputation_by_id(peer_id)
+                .await?
+                .map(|reputation| PeerReputationInfo { reputation, ..Default::default() }))
+        }
+    }
+
+    /// Returns `true` if the given peer is marked as trusted.
+    ///
+    /// Trusted peers are exempt from the peer count limits and are reconnected if the connection
+    /// drops.
+    ///
+    /// By default, this only considers the connected [`PeerKind::Trusted`] peers.
+    fn is_trusted_peer(
+        &self,
+        peer_id: PeerId,
+    ) -> impl Future<Output = Result<bool, NetworkError>> + Send {
+        async move { Ok(self.get_trusted_peers().await?.iter().any(|peer| peer.remote_id == peer_id)) }
+    }
 }
 
 /// Represents the kind of peer
@@ -184,6 +245,10 @@ pub struct PeerInfo {
     pub status: Arc<Status>,
     /// The timestamp when the session to that peer has been established.
     pub session_established: Instant,
+    /// The bytes sent to and received from the peer.
+    ///
+    /// The counters are updated while the session is active.
+    pub bandwidth: BandwidthMeter,
 }
 
 /// The direction of the connection.
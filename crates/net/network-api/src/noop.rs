@@ -4,9 +4,10 @@
 //! generic over it.
 
 use crate::{
-    NetworkError, NetworkInfo, PeerId, PeerInfo, PeerKind, Peers, PeersInfo, Reputation,
-    ReputationChangeKind,
+    EnrUpdateError, NetworkError, NetworkInfo, PeerId, PeerInfo, PeerKind, Peers, PeersInfo,
+    Reputation, ReputationChangeKind,
 };
+use alloy_primitives::Bytes;
 use enr::{secp256k1::SecretKey, Enr};
 use reth_eth_wire::{DisconnectReason, ProtocolVersion};
 use reth_network_peers::NodeRecord;
@@ -58,6 +59,14 @@ impl PeersInfo for NoopNetwork {
         0
     }
 
+    fn num_inbound_peers(&self) -> usize {
+        0
+    }
+
+    fn num_outbound_peers(&self) -> usize {
+        0
+    }
+
     fn local_node_record(&self) -> NodeRecord {
         NodeRecord::new(self.local_addr(), PeerId::random())
     }
@@ -97,6 +106,10 @@ impl Peers for NoopNetwork {
 
     fn reputation_change(&self, _peer_id: PeerId, _kind: ReputationChangeKind) {}
 
+    async fn update_enr(&self, _key: String, _value: Bytes) -> Result<(), EnrUpdateError> {
+        Ok(())
+    }
+
     async fn reputation_by_id(&self, _peer_id: PeerId) -> Result<Option<Reputation>, NetworkError> {
         Ok(None)
     }
@@ -50,3 +50,62 @@ impl ReputationChangeKind {
         matches!(self, Self::Dropped)
     }
 }
+
+/// The reputation of a peer, whether it is banned and the penalties it received since its
+/// reputation was last reset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeerReputationInfo {
+    /// The current reputation score of the peer.
+    pub reputation: Reputation,
+    /// Whether the peer is currently banned.
+    pub banned: bool,
+    /// The penalties the peer received since its reputation was last reset.
+    pub penalties: PeerPenalties,
+}
+
+/// The number of penalties a peer received, by [`ReputationChangeKind`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeerPenalties {
+    /// Number of [`ReputationChangeKind::BadMessage`] penalties.
+    pub bad_message: u32,
+    /// Number of [`ReputationChangeKind::BadBlock`] penalties.
+    pub bad_block: u32,
+    /// Number of [`ReputationChangeKind::BadTransactions`] penalties.
+    pub bad_transactions: u32,
+    /// Number of [`ReputationChangeKind::BadAnnouncement`] penalties.
+    pub bad_announcement: u32,
+    /// Number of [`ReputationChangeKind::AlreadySeenTransaction`] penalties.
+    pub already_seen_transaction: u32,
+    /// Number of [`ReputationChangeKind::Timeout`] penalties.
+    pub timeout: u32,
+    /// Number of [`ReputationChangeKind::BadProtocol`] penalties.
+    pub bad_protocol: u32,
+    /// Number of [`ReputationChangeKind::FailedToConnect`] penalties.
+    pub failed_to_connect: u32,
+    /// Number of [`ReputationChangeKind::Dropped`] penalties.
+    pub dropped: u32,
+    /// Number of [`ReputationChangeKind::Other`] penalties.
+    pub other: u32,
+}
+
+impl PeerPenalties {
+    /// Counts a penalty of the given kind.
+    ///
+    /// [`ReputationChangeKind::Reset`] is not a penalty and is ignored.
+    pub fn record(&mut self, kind: ReputationChangeKind) {
+        let counter = match kind {
+            ReputationChangeKind::BadMessage => &mut self.bad_message,
+            ReputationChangeKind::BadBlock => &mut self.bad_block,
+            ReputationChangeKind::BadTransactions => &mut self.bad_transactions,
+            ReputationChangeKind::BadAnnouncement => &mut self.bad_announcement,
+            ReputationChangeKind::AlreadySeenTransaction => &mut self.already_seen_transaction,
+            ReputationChangeKind::Timeout => &mut self.timeout,
+            ReputationChangeKind::BadProtocol => &mut self.bad_protocol,
+            ReputationChangeKind::FailedToConnect => &mut self.failed_to_connect,
+            ReputationChangeKind::Dropped => &mut self.dropped,
+            ReputationChangeKind::Other(_) => &mut self.other,
+            ReputationChangeKind::Reset => return,
+        };
+        *counter = counter.saturating_add(1);
+    }
+}
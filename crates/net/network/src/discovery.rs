@@ -7,16 +7,18 @@ use crate::{
 };
 use enr::Enr;
 use futures::StreamExt;
-use reth_discv4::{DiscoveryUpdate, Discv4, Discv4Config};
+use reth_discv4::{error::Discv4Error, DiscoveryUpdate, Discv4, Discv4Config};
 use reth_discv5::{DiscoveredPeer, Discv5};
 use reth_dns_discovery::{
     DnsDiscoveryConfig, DnsDiscoveryHandle, DnsDiscoveryService, DnsNodeRecordUpdate, DnsResolver,
 };
+use reth_network_api::EnrUpdateError;
 use reth_network_peers::PeerId;
-use reth_primitives::{EnrForkIdEntry, ForkId, NodeRecord};
+use reth_primitives::{Bytes, EnrForkIdEntry, ForkId, NodeRecord};
 use secp256k1::SecretKey;
 use std::{
     collections::VecDeque,
+    future::Future,
     net::{IpAddr, SocketAddr},
     pin::Pin,
     sync::Arc,
@@ -43,6 +45,8 @@ pub struct Discovery {
     discovered_nodes: LruMap<PeerId, SocketAddr>,
     /// Local ENR of the discovery v4 service (discv5 ENR has same [`PeerId`]).
     local_enr: NodeRecord,
+    /// The key the local ENRs are signed with.
+    secret_key: SecretKey,
     /// Handler to interact with the Discovery v4 service
     discv4: Option<Discv4>,
     /// All KAD table updates from the discv4 service.
@@ -120,6 +124,7 @@ impl Discovery {
         Ok(Self {
             discovery_listeners: Default::default(),
             local_enr,
+            secret_key: sk,
             discv4,
             discv4_updates,
             _discv4_service,
@@ -153,6 +158,45 @@ impl Discovery {
         // todo: update discv5 enr
     }
 
+    /// Sets a custom key-value pair in the ENR of discv5 and discv4.
+    ///
+    /// The pair is checked against a copy of the discv5 ENR first. discv4 is updated next, and
+    /// discv5 only once discv4 accepted the pair, so the two ENRs don't diverge if either service
+    /// rejects it. The returned future resolves once both were updated, with the error of the
+    /// service that rejected the update.
+    ///
+    /// CAUTION: The value **must** be rlp encoded
+    pub(crate) fn set_enr_pair(
+        &self,
+        key: String,
+        rlp: Bytes,
+    ) -> impl Future<Output = Result<(), EnrUpdateError>> + Send + 'static {
+        let discv5_check = match &self.discv5 {
+            Some(discv5) => discv5.check_eip868_in_local_enr(&key, rlp.clone(), &self.secret_key),
+            None => Ok(()),
+        };
+        let discv4 = self.discv4.clone();
+        let discv5 = self.discv5.clone();
+        async move {
+            discv5_check?;
+            if let Some(discv4) = discv4 {
+                discv4
+                    .try_set_eip868_rlp_pair(key.clone().into_bytes(), rlp.clone())
+                    .await
+                    .map_err(|err| match err {
+                        Discv4Error::Enr(err) => err.into(),
+                        Discv4Error::Send | Discv4Error::Receive(_) => {
+                            reth_network_api::NetworkError::ChannelClosed.into()
+                        }
+                    })?;
+            }
+            if let Some(discv5) = discv5 {
+                discv5.try_set_eip868_in_local_enr(&key, rlp)?;
+            }
+            Ok(())
+        }
+    }
+
     /// Bans the [`IpAddr`] in the discovery service.
     pub(crate) fn ban_ip(&self, ip: IpAddr) {
         if let Some(discv4) = &self.discv4 {
@@ -306,6 +350,7 @@ impl Discovery {
                 udp_port: 0,
                 id: PeerId::random(),
             },
+            secret_key: SecretKey::new(&mut rand::thread_rng()),
             discv4: Default::default(),
             discv4_updates: Default::default(),
             discv5: None,
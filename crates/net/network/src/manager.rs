@@ -107,6 +107,10 @@ pub struct NetworkManager<C> {
     /// This is updated via internal events and shared via `Arc` with the [`NetworkHandle`]
     /// Updated by the `NetworkWorker` and loaded by the `NetworkService`.
     num_active_peers: Arc<AtomicUsize>,
+    /// Tracks the number of established inbound sessions, shared with the [`NetworkHandle`].
+    num_inbound_peers: Arc<AtomicUsize>,
+    /// Tracks the number of established outbound sessions, shared with the [`NetworkHandle`].
+    num_outbound_peers: Arc<AtomicUsize>,
     /// Metrics for the Network
     metrics: NetworkMetrics,
     /// Disconnect metrics for the Network
@@ -226,6 +230,8 @@ where
         let discv4 = discovery.discv4();
 
         let num_active_peers = Arc::new(AtomicUsize::new(0));
+        let num_inbound_peers = Arc::new(AtomicUsize::new(0));
+        let num_outbound_peers = Arc::new(AtomicUsize::new(0));
 
         let sessions = SessionManager::new(
             secret_key,
@@ -248,6 +254,8 @@ where
 
         let handle = NetworkHandle::new(
             Arc::clone(&num_active_peers),
+            Arc::clone(&num_inbound_peers),
+            Arc::clone(&num_outbound_peers),
             listener_address,
             to_manager_tx,
             secret_key,
@@ -269,6 +277,8 @@ where
             to_transactions_manager: None,
             to_eth_request_handler: None,
             num_active_peers,
+            num_inbound_peers,
+            num_outbound_peers,
             metrics: Default::default(),
             disconnect_metrics: Default::default(),
         })
@@ -590,6 +600,12 @@ where
             NetworkHandleMessage::GetReputationById(peer_id, tx) => {
                 let _ = tx.send(self.swarm.state_mut().peers().get_reputation(&peer_id));
             }
+            NetworkHandleMessage::GetReputationInfoById(peer_id, tx) => {
+                let _ = tx.send(self.swarm.state().peers().get_reputation_info(&peer_id));
+            }
+            NetworkHandleMessage::IsTrustedPeer(peer_id, tx) => {
+                let _ = tx.send(self.swarm.state().peers().is_trusted(&peer_id));
+            }
             NetworkHandleMessage::FetchClient(tx) => {
                 let _ = tx.send(self.fetch_client());
             }
@@ -615,6 +631,12 @@ where
                 let _ = tx.send(self.swarm.sessions().get_peer_infos_by_ids(peers));
             }
             NetworkHandleMessage::AddRlpxSubProtocol(proto) => self.add_rlpx_sub_protocol(proto),
+            NetworkHandleMessage::SetEnrPair { key, rlp, tx } => {
+                let update = self.swarm.state().set_enr_pair(key, rlp);
+                tokio::spawn(async move {
+                    let _ = tx.send(update.await);
+                });
+            }
             NetworkHandleMessage::GetTransactionsHandle(tx) => {
                 if let Some(ref tx_inner) = self.to_transactions_manager {
                     let _ = tx_inner.send(NetworkTransactionEvent::GetTransactionsHandle(tx));
@@ -863,15 +885,15 @@ where
         }
     }
 
-    /// Updates the metrics for active,established connections
+    /// Updates the metrics and the shared counters for active,established connections
     #[inline]
     fn update_active_connection_metrics(&self) {
-        self.metrics
-            .incoming_connections
-            .set(self.swarm.state().peers().num_inbound_connections() as f64);
-        self.metrics
-            .outgoing_connections
-            .set(self.swarm.state().peers().num_outbound_connections() as f64);
+        let num_inbound = self.swarm.state().peers().num_inbound_connections();
+        let num_outbound = self.swarm.state().peers().num_outbound_connections();
+        self.num_inbound_peers.store(num_inbound, Ordering::Relaxed);
+        self.num_outbound_peers.store(num_outbound, Ordering::Relaxed);
+        self.metrics.incoming_connections.set(num_inbound as f64);
+        self.metrics.outgoing_connections.set(num_outbound as f64);
     }
 
     /// Updates the metrics for pending connections
@@ -8,16 +8,17 @@ use parking_lot::Mutex;
 use reth_discv4::Discv4;
 use reth_eth_wire::{DisconnectReason, NewBlock, NewPooledTransactionHashes, SharedTransactions};
 use reth_network_api::{
-    NetworkError, NetworkInfo, PeerInfo, PeerKind, Peers, PeersInfo, Reputation,
-    ReputationChangeKind,
+    EnrUpdateError, NetworkError, NetworkInfo, PeerInfo, PeerKind, PeerReputationInfo, Peers,
+    PeersInfo, Reputation, ReputationChangeKind, RESERVED_ENR_KEYS,
 };
 use reth_network_p2p::sync::{NetworkSyncUpdater, SyncState, SyncStateProvider};
 use reth_network_peers::PeerId;
-use reth_primitives::{Head, NodeRecord, TransactionSigned, B256};
+use reth_primitives::{Bytes, Head, NodeRecord, TransactionSigned, B256};
 use reth_rpc_types::NetworkStatus;
 use reth_tokio_util::{EventSender, EventStream};
 use secp256k1::SecretKey;
 use std::{
+    collections::BTreeMap,
     net::SocketAddr,
     sync::{
         atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
@@ -46,6 +47,8 @@ impl NetworkHandle {
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         num_active_peers: Arc<AtomicUsize>,
+        num_inbound_peers: Arc<AtomicUsize>,
+        num_outbound_peers: Arc<AtomicUsize>,
         listener_address: Arc<Mutex<SocketAddr>>,
         to_manager_tx: UnboundedSender<NetworkHandleMessage>,
         secret_key: SecretKey,
@@ -59,6 +62,8 @@ impl NetworkHandle {
     ) -> Self {
         let inner = NetworkInner {
             num_active_peers,
+            num_inbound_peers,
+            num_outbound_peers,
             to_manager_tx,
             listener_address,
             secret_key,
@@ -71,6 +76,7 @@ impl NetworkHandle {
             tx_gossip_disabled,
             discv4,
             event_sender,
+            enr_pairs: Default::default(),
         };
         Self { inner: Arc::new(inner) }
     }
@@ -87,6 +93,27 @@ impl NetworkHandle {
         &self.inner.peers
     }
 
+    /// Builds the local ENR including the given custom key-value pairs.
+    fn build_local_enr(
+        &self,
+        pairs: &BTreeMap<String, Bytes>,
+    ) -> Result<Enr<SecretKey>, enr::Error> {
+        let local_node_record = self.local_node_record();
+        let mut builder = Enr::builder();
+        builder.ip(local_node_record.address);
+        if local_node_record.address.is_ipv4() {
+            builder.udp4(local_node_record.udp_port);
+            builder.tcp4(local_node_record.tcp_port);
+        } else {
+            builder.udp6(local_node_record.udp_port);
+            builder.tcp6(local_node_record.tcp_port);
+        }
+        for (key, rlp) in pairs {
+            builder.add_value_rlp(key, rlp.clone());
+        }
+        builder.build(&self.inner.secret_key)
+    }
+
     fn manager(&self) -> &UnboundedSender<NetworkHandleMessage> {
         &self.inner.to_manager_tx
     }
@@ -216,6 +243,14 @@ impl PeersInfo for NetworkHandle {
         self.inner.num_active_peers.load(Ordering::Relaxed)
     }
 
+    fn num_inbound_peers(&self) -> usize {
+        self.inner.num_inbound_peers.load(Ordering::Relaxed)
+    }
+
+    fn num_outbound_peers(&self) -> usize {
+        self.inner.num_outbound_peers.load(Ordering::Relaxed)
+    }
+
     fn local_node_record(&self) -> NodeRecord {
         if let Some(discv4) = &self.inner.discv4 {
             discv4.node_record()
@@ -237,17 +272,7 @@ impl PeersInfo for NetworkHandle {
     }
 
     fn local_enr(&self) -> Enr<SecretKey> {
-        let local_node_record = self.local_node_record();
-        let mut builder = Enr::builder();
-        builder.ip(local_node_record.address);
-        if local_node_record.address.is_ipv4() {
-            builder.udp4(local_node_record.udp_port);
-            builder.tcp4(local_node_record.tcp_port);
-        } else {
-            builder.udp6(local_node_record.udp_port);
-            builder.tcp6(local_node_record.tcp_port);
-        }
-        builder.build(&self.inner.secret_key).expect("valid enr")
+        self.build_local_enr(&self.inner.enr_pairs.lock()).expect("valid enr")
     }
 }
 
@@ -309,11 +334,50 @@ impl Peers for NetworkHandle {
         self.send_message(NetworkHandleMessage::ReputationChange(peer_id, kind));
     }
 
+    async fn update_enr(&self, key: String, value: Bytes) -> Result<(), EnrUpdateError> {
+        if RESERVED_ENR_KEYS.contains(&key.as_str()) {
+            return Err(EnrUpdateError::ReservedKey(key))
+        }
+        let rlp = Bytes::from(alloy_rlp::encode(&value));
+
+        let (tx, rx) = oneshot::channel();
+        let _ = self.manager().send(NetworkHandleMessage::SetEnrPair {
+            key: key.clone(),
+            rlp: rlp.clone(),
+            tx,
+        });
+        rx.await.map_err(NetworkError::from)??;
+
+        // the ENRs of the discovery services contain all entries of the local ENR, so this can
+        // only fail if discovery is disabled
+        let mut pairs = self.inner.enr_pairs.lock();
+        let mut updated = pairs.clone();
+        updated.insert(key, rlp);
+        self.build_local_enr(&updated)?;
+        *pairs = updated;
+        Ok(())
+    }
+
     async fn reputation_by_id(&self, peer_id: PeerId) -> Result<Option<Reputation>, NetworkError> {
         let (tx, rx) = oneshot::channel();
         let _ = self.manager().send(NetworkHandleMessage::GetReputationById(peer_id, tx));
         Ok(rx.await?)
     }
+
+    async fn reputation_info_by_id(
+        &self,
+        peer_id: PeerId,
+    ) -> Result<Option<PeerReputationInfo>, NetworkError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.manager().send(NetworkHandleMessage::GetReputationInfoById(peer_id, tx));
+        Ok(rx.await?)
+    }
+
+    async fn is_trusted_peer(&self, peer_id: PeerId) -> Result<bool, NetworkError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.manager().send(NetworkHandleMessage::IsTrustedPeer(peer_id, tx));
+        Ok(rx.await?)
+    }
 }
 
 impl NetworkInfo for NetworkHandle {
@@ -373,6 +437,10 @@ impl NetworkSyncUpdater for NetworkHandle {
 struct NetworkInner {
     /// Number of active peer sessions the node's currently handling.
     num_active_peers: Arc<AtomicUsize>,
+    /// Number of established inbound sessions.
+    num_inbound_peers: Arc<AtomicUsize>,
+    /// Number of established outbound sessions.
+    num_outbound_peers: Arc<AtomicUsize>,
     /// Sender half of the message channel to the [`crate::NetworkManager`].
     to_manager_tx: UnboundedSender<NetworkHandleMessage>,
     /// The local address that accepts incoming connections.
@@ -397,6 +465,8 @@ struct NetworkInner {
     discv4: Option<Discv4>,
     /// Sender for high level network events.
     event_sender: EventSender<NetworkEvent>,
+    /// Custom key-value pairs advertised in the local ENR, values are RLP encoded.
+    enr_pairs: Mutex<BTreeMap<String, Bytes>>,
 }
 
 /// Provides event subscription for the network.
@@ -470,6 +540,11 @@ pub(crate) enum NetworkHandleMessage {
     GetPeerInfosByPeerKind(PeerKind, oneshot::Sender<Vec<PeerInfo>>),
     /// Gets the reputation for a specific peer via a oneshot sender.
     GetReputationById(PeerId, oneshot::Sender<Option<Reputation>>),
+    /// Gets the reputation, ban status and recent penalties for a specific peer via a oneshot
+    /// sender.
+    GetReputationInfoById(PeerId, oneshot::Sender<Option<PeerReputationInfo>>),
+    /// Checks whether a specific peer is trusted via a oneshot sender.
+    IsTrustedPeer(PeerId, oneshot::Sender<bool>),
     /// Retrieves the `TransactionsHandle` via a oneshot sender.
     GetTransactionsHandle(oneshot::Sender<Option<TransactionsHandle>>),
     /// Initiates a graceful shutdown of the network via a oneshot sender.
@@ -480,4 +555,13 @@ pub(crate) enum NetworkHandleMessage {
     DiscoveryListener(UnboundedSender<DiscoveryEvent>),
     /// Adds an additional `RlpxSubProtocol`.
     AddRlpxSubProtocol(RlpxSubProtocol),
+    /// Sets a custom key-value pair in the ENR of the discovery services.
+    SetEnrPair {
+        /// The ENR key.
+        key: String,
+        /// The RLP encoded value.
+        rlp: Bytes,
+        /// Receives the result of the update.
+        tx: oneshot::Sender<Result<(), EnrUpdateError>>,
+    },
 }
@@ -13,7 +13,7 @@ use crate::{
 use futures::StreamExt;
 use reth_eth_wire::{errors::EthStreamError, DisconnectReason};
 use reth_net_common::ban_list::BanList;
-use reth_network_api::{PeerKind, ReputationChangeKind};
+use reth_network_api::{PeerKind, PeerPenalties, PeerReputationInfo, ReputationChangeKind};
 use reth_network_peers::PeerId;
 use reth_primitives::{ForkId, NodeRecord};
 use std::{
@@ -411,11 +411,25 @@ impl PeersManager {
         }
     }
 
+    /// Returns `true` if the given peer is marked as trusted.
+    pub(crate) fn is_trusted(&self, peer_id: &PeerId) -> bool {
+        self.trusted_peer_ids.contains(peer_id)
+    }
+
     /// Returns the tracked reputation for a peer.
     pub(crate) fn get_reputation(&self, peer_id: &PeerId) -> Option<i32> {
         self.peers.get(peer_id).map(|peer| peer.reputation)
     }
 
+    /// Returns the reputation, the ban status and the recent penalties of a tracked peer.
+    pub(crate) fn get_reputation_info(&self, peer_id: &PeerId) -> Option<PeerReputationInfo> {
+        self.peers.get(peer_id).map(|peer| PeerReputationInfo {
+            reputation: peer.reputation,
+            banned: peer.is_banned() || self.ban_list.is_banned_peer(peer_id),
+            penalties: peer.penalties,
+        })
+    }
+
     /// Apply the corresponding reputation change to the given peer.
     ///
     /// If the peer is a trusted peer, it will be exempt from reputation slashing for certain
@@ -446,6 +460,9 @@ impl PeersManager {
                         reputation_change = MAX_TRUSTED_PEER_REPUTATION_CHANGE;
                     }
                 }
+                if reputation_change < 0 {
+                    peer.penalties.record(rep);
+                }
                 peer.apply_reputation(reputation_change)
             }
         } else {
@@ -603,8 +620,12 @@ impl PeersManager {
                     backoff_until = Some(backoff_time);
                 } else {
                     // If the error was not a backoff error, we reduce the peer's reputation
-                    let reputation_change = self.reputation_weights.change(reputation_change);
-                    peer.reputation = peer.reputation.saturating_add(reputation_change.as_i32());
+                    let kind = reputation_change;
+                    let reputation_change = self.reputation_weights.change(kind).as_i32();
+                    if reputation_change < 0 {
+                        peer.penalties.record(kind);
+                    }
+                    peer.reputation = peer.reputation.saturating_add(reputation_change);
                 };
 
                 self.connection_info.decr_state(peer.state);
@@ -749,16 +770,17 @@ impl PeersManager {
     }
 
     /// Removes the tracked node from the trusted set.
+    ///
+    /// This also removes peers that were only marked as trusted by id, see
+    /// [`Self::add_trusted_peer_id`].
     pub(crate) fn remove_peer_from_trusted_set(&mut self, peer_id: PeerId) {
-        let Entry::Occupied(mut entry) = self.peers.entry(peer_id) else { return };
-        if !entry.get().is_trusted() {
-            return
-        }
-
-        let peer = entry.get_mut();
-        peer.kind = PeerKind::Basic;
-
         self.trusted_peer_ids.remove(&peer_id);
+
+        if let Some(peer) = self.peers.get_mut(&peer_id) {
+            if peer.is_trusted() {
+                peer.kind = PeerKind::Basic;
+            }
+        }
     }
 
     /// Returns the idle peer with the highest reputation.
@@ -1031,6 +1053,8 @@ pub struct Peer {
     backed_off: bool,
     /// Counts number of times the peer was backed off due to a severe [`BackoffKind`].
     severe_backoff_counter: u8,
+    /// The penalties the peer received since its reputation was last reset.
+    penalties: PeerPenalties,
 }
 
 // === impl Peer ===
@@ -1059,6 +1083,7 @@ impl Peer {
             kind: Default::default(),
             backed_off: false,
             severe_backoff_counter: 0,
+            penalties: Default::default(),
         }
     }
 
@@ -1070,6 +1095,7 @@ impl Peer {
     /// [`ReputationChangeOutcome::None`].
     fn reset_reputation(&mut self) -> ReputationChangeOutcome {
         self.reputation = DEFAULT_REPUTATION;
+        self.penalties = Default::default();
 
         ReputationChangeOutcome::None
     }
@@ -1112,7 +1138,8 @@ impl Peer {
     /// Unbans the peer by resetting its reputation
     #[inline]
     fn unban(&mut self) {
-        self.reputation = DEFAULT_REPUTATION
+        self.reputation = DEFAULT_REPUTATION;
+        self.penalties = Default::default();
     }
 
     /// Returns whether this peer is trusted
@@ -1556,7 +1583,7 @@ mod tests {
         DisconnectReason,
     };
     use reth_net_common::ban_list::BanList;
-    use reth_network_api::{Direction, ReputationChangeKind};
+    use reth_network_api::{Direction, PeerPenalties, ReputationChangeKind};
     use reth_network_peers::PeerId;
     use reth_primitives::B512;
     use std::{
@@ -2097,6 +2124,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn remove_trusted_peers() {
+        let mut peers = PeersManager::default();
+
+        // trusted by id only, without a known address
+        let trusted_id = PeerId::random();
+        peers.add_trusted_peer_id(trusted_id);
+        assert!(peers.is_trusted(&trusted_id));
+        peers.remove_peer_from_trusted_set(trusted_id);
+        assert!(!peers.is_trusted(&trusted_id));
+
+        // trusted with an address, the peer is kept as a basic peer
+        let trusted = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        peers.add_trusted_peer(trusted, socket_addr);
+        assert!(peers.is_trusted(&trusted));
+        peers.remove_peer_from_trusted_set(trusted);
+        assert!(!peers.is_trusted(&trusted));
+        assert_eq!(peers.peers.get(&trusted).unwrap().kind, PeerKind::Basic);
+    }
+
     #[tokio::test]
     async fn accept_incoming_trusted_unknown_peer_address() {
         let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 99)), 8008);
@@ -2196,6 +2244,37 @@ mod tests {
         assert_eq!(peers.connection_info.num_inbound, 1);
     }
 
+    #[tokio::test]
+    async fn test_reputation_info() {
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let mut peers = PeersManager::default();
+        assert_eq!(peers.get_reputation_info(&peer), None);
+
+        peers.add_peer(peer, socket_addr, None);
+        peers.apply_reputation_change(&peer, ReputationChangeKind::Timeout);
+        peers.apply_reputation_change(&peer, ReputationChangeKind::Timeout);
+        peers.apply_reputation_change(&peer, ReputationChangeKind::BadMessage);
+
+        let info = peers.get_reputation_info(&peer).unwrap();
+        assert_eq!(info.reputation, peers.get_reputation(&peer).unwrap());
+        assert!(!info.banned);
+        assert_eq!(
+            info.penalties,
+            PeerPenalties { timeout: 2, bad_message: 1, ..Default::default() }
+        );
+
+        peers.apply_reputation_change(&peer, ReputationChangeKind::BadProtocol);
+        let info = peers.get_reputation_info(&peer).unwrap();
+        assert!(info.banned);
+        assert_eq!(info.penalties.bad_protocol, 1);
+
+        peers.apply_reputation_change(&peer, ReputationChangeKind::Reset);
+        let info = peers.get_reputation_info(&peer).unwrap();
+        assert_eq!(info.reputation, DEFAULT_REPUTATION);
+        assert_eq!(info.penalties, PeerPenalties::default());
+    }
+
     #[tokio::test]
     async fn test_reputation_change_trusted_peer() {
         let peer = PeerId::random();
@@ -53,6 +53,11 @@ pub struct SessionsConfig {
     pub protocol_breach_request_timeout: Duration,
     /// The timeout after which a pending session attempt is considered failed.
     pub pending_session_timeout: Duration,
+    /// The maximum number of bytes per second sent to a single peer.
+    ///
+    /// Sending to a peer that exceeds this rate is delayed. By default, the outbound bandwidth is
+    /// not limited.
+    pub peer_bandwidth_limit: Option<u64>,
 }
 
 impl Default for SessionsConfig {
@@ -72,6 +77,7 @@ impl Default for SessionsConfig {
             initial_internal_request_timeout: INITIAL_REQUEST_TIMEOUT,
             protocol_breach_request_timeout: PROTOCOL_BREACH_REQUEST_TIMEOUT,
             pending_session_timeout: PENDING_SESSION_TIMEOUT,
+            peer_bandwidth_limit: None,
         }
     }
 }
@@ -103,6 +109,14 @@ impl SessionsConfig {
         }
         self
     }
+
+    /// Sets the maximum number of bytes per second sent to a single peer.
+    ///
+    /// `None` disables the limit, a configured limit must be greater than `0`.
+    pub const fn with_peer_bandwidth_limit(mut self, limit: Option<u64>) -> Self {
+        self.peer_bandwidth_limit = limit;
+        self
+    }
 }
 
 /// Limits for sessions.
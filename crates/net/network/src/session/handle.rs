@@ -9,7 +9,7 @@ use reth_ecies::ECIESError;
 use reth_eth_wire::{
     capability::{Capabilities, CapabilityMessage},
     errors::EthStreamError,
-    DisconnectReason, EthVersion, Status,
+    BandwidthMeter, DisconnectReason, EthVersion, Status,
 };
 use reth_network_api::PeerInfo;
 use reth_network_peers::PeerId;
@@ -75,6 +75,8 @@ pub struct ActiveSessionHandle {
     pub(crate) local_addr: Option<SocketAddr>,
     /// The Status message the peer sent for the `eth` handshake
     pub(crate) status: Arc<Status>,
+    /// Tracks the bytes sent to and received from the peer.
+    pub(crate) bandwidth: BandwidthMeter,
 }
 
 // === impl ActiveSessionHandle ===
@@ -135,6 +137,11 @@ impl ActiveSessionHandle {
         self.remote_addr
     }
 
+    /// Returns the [`BandwidthMeter`] that tracks the traffic of this session.
+    pub const fn bandwidth(&self) -> &BandwidthMeter {
+        &self.bandwidth
+    }
+
     /// Extracts the [`PeerInfo`] from the session handle.
     pub(crate) fn peer_info(&self) -> PeerInfo {
         PeerInfo {
@@ -147,6 +154,7 @@ impl ActiveSessionHandle {
             eth_version: self.version,
             status: self.status.clone(),
             session_established: self.established,
+            bandwidth: self.bandwidth.clone(),
         }
     }
 }
@@ -81,6 +81,8 @@ pub struct SessionManager {
     fork_filter: ForkFilter,
     /// Size of the command buffer per session.
     session_command_buffer: usize,
+    /// The maximum number of bytes per second sent to a single peer.
+    peer_bandwidth_limit: Option<u64>,
     /// The executor for spawned tasks.
     executor: Box<dyn TaskSpawner>,
     /// All pending session that are currently handshaking, exchanging `Hello`s.
@@ -139,6 +141,7 @@ impl SessionManager {
             hello_message,
             fork_filter,
             session_command_buffer: config.session_command_buffer,
+            peer_bandwidth_limit: config.peer_bandwidth_limit,
             executor,
             pending_sessions: Default::default(),
             active_sessions: Default::default(),
@@ -419,7 +422,7 @@ impl SessionManager {
                 local_addr,
                 peer_id,
                 capabilities,
-                conn,
+                mut conn,
                 status,
                 direction,
                 client_id,
@@ -464,6 +467,9 @@ impl SessionManager {
                 // negotiated version
                 let version = conn.version();
 
+                conn.inner_mut().set_outbound_bandwidth_limit(self.peer_bandwidth_limit);
+                let bandwidth = conn.inner().bandwidth_meter().clone();
+
                 let session = ActiveSession {
                     next_id: 0,
                     remote_peer_id: peer_id,
@@ -501,6 +507,7 @@ impl SessionManager {
                     client_version: Arc::clone(&client_version),
                     remote_addr,
                     local_addr,
+                    bandwidth,
                 };
 
                 self.active_sessions.insert(peer_id, handle);
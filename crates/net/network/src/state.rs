@@ -17,12 +17,13 @@ use rand::seq::SliceRandom;
 use reth_eth_wire::{
     capability::Capabilities, BlockHashNumber, DisconnectReason, NewBlockHashes, Status,
 };
-use reth_network_api::PeerKind;
+use reth_network_api::{EnrUpdateError, PeerKind};
 use reth_network_peers::PeerId;
-use reth_primitives::{ForkId, B256};
+use reth_primitives::{Bytes, ForkId, B256};
 use reth_provider::BlockNumReader;
 use std::{
     collections::{HashMap, VecDeque},
+    future::Future,
     net::{IpAddr, SocketAddr},
     sync::{
         atomic::{AtomicU64, AtomicUsize},
@@ -238,6 +239,17 @@ where
         self.discovery.update_fork_id(fork_id)
     }
 
+    /// Sets a custom key-value pair in the ENR of the discovery services.
+    ///
+    /// See [`Discovery::set_enr_pair`].
+    pub(crate) fn set_enr_pair(
+        &self,
+        key: String,
+        rlp: Bytes,
+    ) -> impl Future<Output = Result<(), EnrUpdateError>> + Send + 'static {
+        self.discovery.set_enr_pair(key, rlp)
+    }
+
     /// Invoked after a `NewBlock` message was received by the peer.
     ///
     /// This will keep track of blocks we know a peer has
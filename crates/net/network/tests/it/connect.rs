@@ -71,6 +71,11 @@ async fn test_establish_connections() {
         // also await the established session on both target
         futures::future::join(listener1.next(), listener2.next()).await;
 
+        assert_eq!(handle0.num_outbound_peers(), 2);
+        assert_eq!(handle0.num_inbound_peers(), 0);
+        assert_eq!(handle1.num_inbound_peers(), 1);
+        assert_eq!(handle2.num_inbound_peers(), 1);
+
         let net = handle.terminate().await;
 
         assert_eq!(net.peers()[0].num_peers(), 2);
@@ -0,0 +1,80 @@
+use reth_network::{NetworkConfigBuilder, NetworkHandle, NetworkManager};
+use reth_network_api::{EnrUpdateError, Peers, PeersInfo, MAX_ENR_SIZE};
+use reth_primitives::Bytes;
+use reth_provider::test_utils::NoopProvider;
+use secp256k1::SecretKey;
+
+/// Spawns a network without discovery.
+async fn spawn_network() -> NetworkHandle {
+    let secret_key = SecretKey::new(&mut rand::thread_rng());
+    let config = NetworkConfigBuilder::new(secret_key)
+        .disable_discovery()
+        .listener_port(0)
+        .build(NoopProvider::default());
+    let network = NetworkManager::new(config).await.unwrap();
+    let handle = network.handle().clone();
+    tokio::task::spawn(network);
+    handle
+}
+
+/// Spawns a network with discv4 enabled.
+async fn spawn_network_with_discv4() -> NetworkHandle {
+    let secret_key = SecretKey::new(&mut rand::thread_rng());
+    let config = NetworkConfigBuilder::new(secret_key)
+        .disable_dns_discovery()
+        .discovery_port(0)
+        .listener_port(0)
+        .build(NoopProvider::default());
+    let network = NetworkManager::new(config).await.unwrap();
+    let handle = network.handle().clone();
+    tokio::task::spawn(network);
+    handle
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_update_enr() {
+    let handle = spawn_network().await;
+
+    let value = Bytes::from_static(b"appchain-v1");
+    handle.update_enr("appchain".to_string(), value.clone()).await.unwrap();
+    let enr = handle.local_enr();
+    assert_eq!(enr.get_raw_rlp("appchain").unwrap(), &alloy_rlp::encode(&value)[..]);
+
+    // values that don't fit into the ENR are rejected and not advertised
+    let oversized = Bytes::from(vec![0xff; MAX_ENR_SIZE]);
+    assert_eq!(
+        handle.update_enr("oversized".to_string(), oversized).await,
+        Err(EnrUpdateError::ExceedsMaxSize)
+    );
+    let enr = handle.local_enr();
+    assert!(enr.get_raw_rlp("oversized").is_none());
+    assert!(enr.get_raw_rlp("appchain").is_some());
+
+    // keys managed by the node can't be overridden
+    assert_eq!(
+        handle.update_enr("ip".to_string(), value).await,
+        Err(EnrUpdateError::ReservedKey("ip".to_string()))
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_update_enr_with_discovery() {
+    // find the largest value that still fits into an ENR without the entries of discovery
+    let handle = spawn_network().await;
+    let mut len = MAX_ENR_SIZE;
+    while handle.update_enr("appchain".to_string(), Bytes::from(vec![0xff; len])).await.is_err() {
+        len -= 1;
+    }
+
+    // the ENR of discv4 also advertises the fork id, so the same value doesn't fit anymore
+    let handle = spawn_network_with_discv4().await;
+    assert_eq!(
+        handle.update_enr("appchain".to_string(), Bytes::from(vec![0xff; len])).await,
+        Err(EnrUpdateError::ExceedsMaxSize)
+    );
+    assert!(handle.local_enr().get_raw_rlp("appchain").is_none());
+
+    let value = Bytes::from_static(b"appchain-v1");
+    handle.update_enr("appchain".to_string(), value.clone()).await.unwrap();
+    assert_eq!(handle.local_enr().get_raw_rlp("appchain").unwrap(), &alloy_rlp::encode(&value)[..]);
+}
@@ -1,5 +1,6 @@
 mod big_pooled_txs_req;
 mod connect;
+mod enr;
 mod multiplex;
 mod requests;
 mod session;
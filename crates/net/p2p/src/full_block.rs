@@ -21,17 +21,50 @@ use std::{
 };
 use tracing::debug;
 
+/// Limits for the size of the individual requests sent when downloading a range of full blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FullBlockRequestLimits {
+    /// The maximum number of headers to request from a peer at a time.
+    pub max_headers_per_request: u64,
+    /// The maximum number of bodies to request from a peer at a time.
+    pub max_bodies_per_request: u64,
+}
+
+impl FullBlockRequestLimits {
+    /// Creates new limits, both limits are at least 1.
+    pub fn new(max_headers_per_request: u64, max_bodies_per_request: u64) -> Self {
+        Self {
+            max_headers_per_request: max_headers_per_request.max(1),
+            max_bodies_per_request: max_bodies_per_request.max(1),
+        }
+    }
+}
+
+impl Default for FullBlockRequestLimits {
+    /// No limits, the entire range is requested at once.
+    fn default() -> Self {
+        Self { max_headers_per_request: u64::MAX, max_bodies_per_request: u64::MAX }
+    }
+}
+
 /// A Client that can fetch full blocks from the network.
 #[derive(Debug, Clone)]
 pub struct FullBlockClient<Client> {
     client: Client,
     consensus: Arc<dyn Consensus>,
+    limits: FullBlockRequestLimits,
 }
 
 impl<Client> FullBlockClient<Client> {
     /// Creates a new instance of `FullBlockClient`.
     pub fn new(client: Client, consensus: Arc<dyn Consensus>) -> Self {
-        Self { client, consensus }
+        Self { client, consensus, limits: FullBlockRequestLimits::default() }
+    }
+
+    /// Sets the limits for the size of the requests sent when downloading block ranges.
+    pub const fn with_request_limits(mut self, limits: FullBlockRequestLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Returns a client with Test consensus
@@ -80,23 +113,20 @@ where
         count: u64,
     ) -> FetchFullBlockRangeFuture<Client> {
         let client = self.client.clone();
-        FetchFullBlockRangeFuture {
+        let mut future = FetchFullBlockRangeFuture {
             start_hash: hash,
             count,
-            request: FullBlockRangeRequest {
-                headers: Some(client.get_headers(HeadersRequest {
-                    start: hash.into(),
-                    limit: count,
-                    direction: HeadersDirection::Falling,
-                })),
-                bodies: None,
-            },
+            limits: self.limits,
+            request: FullBlockRangeRequest { headers: None, bodies: None },
             client,
+            received_headers: Vec::new(),
             headers: None,
             pending_headers: VecDeque::new(),
             bodies: HashMap::new(),
             consensus: Arc::clone(&self.consensus),
-        }
+        };
+        future.request.headers = Some(future.client.get_headers(future.next_headers_request()));
+        future
     }
 }
 
@@ -374,8 +404,12 @@ where
     start_hash: B256,
     /// How many blocks to fetch: `len([start_hash, ..]) == count`
     count: u64,
+    /// Limits for the size of the individual headers and bodies requests.
+    limits: FullBlockRequestLimits,
     /// Requests for headers and bodies that are in progress.
     request: FullBlockRangeRequest<Client>,
+    /// Headers received so far in falling order, until all `count` headers are downloaded.
+    received_headers: Vec<SealedHeader>,
     /// Fetched headers.
     headers: Option<Vec<SealedHeader>>,
     /// The next headers to request bodies for. This is drained as responses are received.
@@ -414,10 +448,34 @@ where
         }
     }
 
-    /// Returns the remaining hashes for the bodies request, based on the headers that still exist
-    /// in the `root_map`.
+    /// Returns the remaining hashes for the next bodies request, based on the headers that still
+    /// exist in the `root_map`.
+    ///
+    /// This returns at most `max_bodies_per_request` hashes.
     fn remaining_bodies_hashes(&self) -> Vec<B256> {
-        self.pending_headers.iter().map(|h| h.hash()).collect()
+        self.pending_headers
+            .iter()
+            .take(self.limits.max_bodies_per_request.try_into().unwrap_or(usize::MAX))
+            .map(|h| h.hash())
+            .collect()
+    }
+
+    /// Returns the hash of the highest header of the next batch of headers, which is the parent of
+    /// the lowest header received so far.
+    fn next_headers_start(&self) -> B256 {
+        self.received_headers.last().map_or(self.start_hash, |h| h.parent_hash)
+    }
+
+    /// Returns the request for the next batch of headers.
+    ///
+    /// This requests at most `max_headers_per_request` headers.
+    fn next_headers_request(&self) -> HeadersRequest {
+        let remaining = self.count - self.received_headers.len() as u64;
+        HeadersRequest {
+            start: self.next_headers_start().into(),
+            limit: remaining.min(self.limits.max_headers_per_request),
+            direction: HeadersDirection::Falling,
+        }
     }
 
     /// Returns the [`SealedBlock`]s if the request is complete and valid.
@@ -487,16 +545,23 @@ where
             headers.map(|h| h.into_iter().map(|h| h.seal_slow()).collect::<Vec<_>>()).split();
 
         // fill in the response if it's the correct length
-        if headers_falling.len() == self.count as usize {
+        if headers_falling.len() as u64 == self.next_headers_request().limit {
             // sort headers from highest to lowest block number
             headers_falling.sort_unstable_by_key(|h| Reverse(h.number));
 
             // check the starting hash
-            if headers_falling[0].hash() != self.start_hash {
+            if headers_falling[0].hash() != self.next_headers_start() {
                 // received a different header than requested
                 self.client.report_bad_message(peer);
             } else {
-                let headers_rising = headers_falling.iter().rev().cloned().collect::<Vec<_>>();
+                // include the lowest header of the previous batch so the batches are validated
+                // against each other
+                let headers_rising = headers_falling
+                    .iter()
+                    .rev()
+                    .chain(self.received_headers.last())
+                    .cloned()
+                    .collect::<Vec<_>>();
                 // ensure the downloaded headers are valid
                 if let Err(err) = self.consensus.validate_header_range(&headers_rising) {
                     debug!(target: "downloaders", %err, ?self.start_hash, "Received bad header response");
@@ -504,8 +569,12 @@ where
                     return
                 }
 
-                // get the bodies request so it can be polled later
-                let hashes = headers_falling.iter().map(|h| h.hash()).collect::<Vec<_>>();
+                self.received_headers.extend(headers_falling);
+                if self.received_headers.len() as u64 != self.count {
+                    // more headers to download
+                    return
+                }
+                let headers_falling = std::mem::take(&mut self.received_headers);
 
                 // populate the pending headers
                 self.pending_headers = headers_falling.clone().into();
@@ -513,6 +582,7 @@ where
                 // set the actual request if it hasn't been started yet
                 if !self.has_bodies_request_started() {
                     // request the bodies for the downloaded headers
+                    let hashes = self.remaining_bodies_hashes();
                     self.request.bodies = Some(self.client.get_block_bodies(hashes));
                 }
 
@@ -569,12 +639,9 @@ where
                     }
 
                     if this.headers.is_none() {
-                        // did not receive a correct response yet, retry
-                        this.request.headers = Some(this.client.get_headers(HeadersRequest {
-                            start: this.start_hash.into(),
-                            limit: this.count,
-                            direction: HeadersDirection::Falling,
-                        }));
+                        // did not receive all headers yet, request the next batch or retry
+                        this.request.headers =
+                            Some(this.client.get_headers(this.next_headers_request()));
                     }
                 }
                 // This branch handles block body responses from peers - it first inserts the
@@ -742,10 +809,56 @@ enum RangeResponseResult {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::TestFullBlockClient;
+    use crate::{download::DownloadClient, priority::Priority, test_utils::TestFullBlockClient};
     use futures::StreamExt;
+    use parking_lot::Mutex;
+    use reth_network_peers::PeerId;
     use std::ops::Range;
 
+    /// A client that records the size of every headers and bodies request.
+    #[derive(Clone, Debug, Default)]
+    struct RecordingClient {
+        inner: TestFullBlockClient,
+        header_limits: Arc<Mutex<Vec<u64>>>,
+        body_counts: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl DownloadClient for RecordingClient {
+        fn report_bad_message(&self, peer_id: PeerId) {
+            self.inner.report_bad_message(peer_id)
+        }
+
+        fn num_connected_peers(&self) -> usize {
+            self.inner.num_connected_peers()
+        }
+    }
+
+    impl HeadersClient for RecordingClient {
+        type Output = <TestFullBlockClient as HeadersClient>::Output;
+
+        fn get_headers_with_priority(
+            &self,
+            request: HeadersRequest,
+            priority: Priority,
+        ) -> Self::Output {
+            self.header_limits.lock().push(request.limit);
+            self.inner.get_headers_with_priority(request, priority)
+        }
+    }
+
+    impl BodiesClient for RecordingClient {
+        type Output = <TestFullBlockClient as BodiesClient>::Output;
+
+        fn get_block_bodies_with_priority(
+            &self,
+            hashes: Vec<B256>,
+            priority: Priority,
+        ) -> Self::Output {
+            self.body_counts.lock().push(hashes.len());
+            self.inner.get_block_bodies_with_priority(hashes, priority)
+        }
+    }
+
     #[tokio::test]
     async fn download_single_full_block() {
         let client = TestFullBlockClient::default();
@@ -864,4 +977,25 @@ mod tests {
             assert_eq!(block.header.number, expected_number);
         }
     }
+
+    #[tokio::test]
+    async fn download_full_block_range_respects_request_limits() {
+        let client = RecordingClient::default();
+        let (header, _) = insert_headers_into_client(&client.inner, 0..50);
+        let limits = FullBlockRequestLimits::new(8, 5);
+        let full_block_client =
+            FullBlockClient::test_client(client.clone()).with_request_limits(limits);
+
+        let received = full_block_client.get_full_block_range(header.hash(), 30).await;
+        assert_eq!(received.len(), 30);
+        for (i, block) in received.iter().enumerate() {
+            assert_eq!(block.header.number, header.number - i as u64);
+        }
+
+        let header_limits = client.header_limits.lock().clone();
+        assert_eq!(header_limits, vec![8, 8, 8, 6]);
+        let body_counts = client.body_counts.lock().clone();
+        assert!(body_counts.iter().all(|count| *count <= 5), "{body_counts:?}");
+        assert_eq!(body_counts.len(), 6);
+    }
 }
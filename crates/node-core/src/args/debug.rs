@@ -2,7 +2,7 @@
 
 use clap::Args;
 use reth_primitives::B256;
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 /// Parameters for debugging purposes
 #[derive(Debug, Clone, Args, PartialEq, Eq, Default)]
@@ -28,6 +28,12 @@ pub struct DebugArgs {
     #[arg(long = "debug.max-block", help_heading = "Debug")]
     pub max_block: Option<u64>,
 
+    /// Keeps the node running, but does not advance the canonical head past the specified block.
+    ///
+    /// Forkchoice updates with a newer head are answered with `SYNCING`.
+    #[arg(long = "debug.max-head-block", help_heading = "Debug", value_name = "BLOCK")]
+    pub max_head_block: Option<u64>,
+
     /// Runs a fake consensus client that advances the chain using recent block hashes
     /// on Etherscan. If specified, requires an `ETHERSCAN_API_KEY` environment variable.
     #[arg(
@@ -61,6 +67,27 @@ pub struct DebugArgs {
     /// will be written to specified location.
     #[arg(long = "debug.engine-api-store", help_heading = "Debug", value_name = "PATH")]
     pub engine_api_store: Option<PathBuf>,
+
+    /// Persist the headers of payloads rejected as invalid by the engine, so that they are still
+    /// rejected right away after a restart.
+    #[arg(long = "debug.persist-invalid-payloads", help_heading = "Debug")]
+    pub persist_invalid_payloads: bool,
+
+    /// The maximum number of invalid headers the engine keeps track of.
+    ///
+    /// Payloads descending from a tracked invalid header are rejected without being re-executed.
+    #[arg(long = "debug.invalid-headers-cache-size", help_heading = "Debug", value_name = "SIZE")]
+    pub invalid_headers_cache_size: Option<u32>,
+
+    /// How long the engine keeps answering engine API messages with `SYNCING` after the shutdown
+    /// signal was received, e.g. `2s`.
+    #[arg(
+        long = "debug.engine-shutdown-grace-period",
+        help_heading = "Debug",
+        value_name = "DURATION",
+        value_parser = humantime::parse_duration
+    )]
+    pub engine_shutdown_grace_period: Option<Duration>,
 }
 
 #[cfg(test)]
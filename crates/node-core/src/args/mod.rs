@@ -20,6 +20,10 @@ pub use debug::DebugArgs;
 mod database;
 pub use database::DatabaseArgs;
 
+/// NodeDatabaseArgs struct for configuring the database of a running node
+mod node_database;
+pub use node_database::NodeDatabaseArgs;
+
 /// LogArgs struct for configuring the logger
 mod log;
 pub use log::{ColorMode, LogArgs};
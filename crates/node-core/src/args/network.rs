@@ -95,6 +95,13 @@ pub struct NetworkArgs {
     #[arg(long)]
     pub max_inbound_peers: Option<usize>,
 
+    /// Maximum number of bytes per second sent to a single peer.
+    ///
+    /// Sending to a peer that exceeds this rate is delayed. By default, the bandwidth is not
+    /// limited.
+    #[arg(long = "max-peer-bandwidth", value_name = "BYTES_PER_SEC", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_peer_bandwidth: Option<u64>,
+
     /// Experimental, for usage in research. Sets the max accumulated byte size of transactions
     /// to pack in one response.
     /// Spec'd at 2MiB.
@@ -154,7 +161,9 @@ impl NetworkArgs {
             ))
             .external_ip_resolver(self.nat)
             .sessions_config(
-                SessionsConfig::default().with_upscaled_event_buffer(peers_config.max_peers()),
+                SessionsConfig::default()
+                    .with_upscaled_event_buffer(peers_config.max_peers())
+                    .with_peer_bandwidth_limit(self.max_peer_bandwidth),
             )
             .peer_config(peers_config)
             .boot_nodes(chain_bootnodes.clone())
@@ -229,6 +238,7 @@ impl Default for NetworkArgs {
             port: DEFAULT_DISCOVERY_PORT,
             max_outbound_peers: None,
             max_inbound_peers: None,
+            max_peer_bandwidth: None,
             soft_limit_byte_size_pooled_transactions_response:
                 SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESPONSE,
             soft_limit_byte_size_pooled_transactions_response_on_pack_request: DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ,
@@ -393,6 +403,24 @@ mod tests {
         assert_eq!(args.max_inbound_peers, Some(15));
     }
 
+    #[test]
+    fn parse_max_peer_bandwidth_args() {
+        let args = CommandParser::<NetworkArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.max_peer_bandwidth, None);
+
+        let args =
+            CommandParser::<NetworkArgs>::parse_from(["reth", "--max-peer-bandwidth", "100000"])
+                .args;
+        assert_eq!(args.max_peer_bandwidth, Some(100_000));
+
+        assert!(CommandParser::<NetworkArgs>::try_parse_from([
+            "reth",
+            "--max-peer-bandwidth",
+            "0"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn parse_trusted_peer_args() {
         let args =
@@ -0,0 +1,132 @@
+//! clap [Args](clap::Args) for database configuration that only applies to a running node
+
+use clap::Args;
+use reth_provider::providers::StaticFileValidation;
+
+/// Parameters for database configuration that only apply to a running node.
+///
+/// Unlike [`DatabaseArgs`](crate::args::DatabaseArgs), these are not shared with the other
+/// commands that open the database.
+#[derive(Debug, Args, PartialEq, Eq, Default, Clone, Copy)]
+#[command(next_help_heading = "Database")]
+pub struct NodeDatabaseArgs {
+    /// Number of upper account trie levels to read into the page cache when the node starts. This
+    /// speeds up the first state root calculations and proofs after a cold start.
+    #[arg(long = "db.trie-warmup-depth", value_name = "DEPTH")]
+    pub trie_warmup_depth: Option<usize>,
+    /// Validate the static files against the overlapping database entries on startup. A sample
+    /// of the entries is checked, stale or mismatched static files are rejected.
+    #[arg(long = "db.validate-static-files")]
+    pub validate_static_files: bool,
+    /// Check every overlapping entry when validating the static files.
+    #[arg(long = "db.validate-static-files-full", requires = "validate_static_files")]
+    pub validate_static_files_full: bool,
+    /// Number of recently read headers, blocks and block body indices to keep in memory. This
+    /// speeds up repeated reads of recent blocks, for example by RPC. Disabled by default.
+    #[arg(long = "db.block-cache-size", value_name = "BLOCKS")]
+    pub block_cache_size: Option<u32>,
+    /// Remove the ommers of post-merge blocks from the database on startup. Post-merge blocks
+    /// can't have ommers, so any stored ones are stray rows that only take up space.
+    #[arg(long = "db.remove-post-merge-ommers")]
+    pub remove_post_merge_ommers: bool,
+}
+
+impl NodeDatabaseArgs {
+    /// Returns how the static files should be validated against the database on startup, if at
+    /// all.
+    pub const fn static_file_validation(&self) -> Option<StaticFileValidation> {
+        if !self.validate_static_files {
+            return None
+        }
+        if self.validate_static_files_full {
+            Some(StaticFileValidation::Full)
+        } else {
+            Some(StaticFileValidation::Sampled)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_default_node_database_args() {
+        let default_args = NodeDatabaseArgs::default();
+        let args = CommandParser::<NodeDatabaseArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, default_args);
+    }
+
+    #[test]
+    fn test_command_parser_trie_warmup_depth() {
+        let cmd = CommandParser::<NodeDatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.trie-warmup-depth",
+            "3",
+        ])
+        .unwrap();
+        assert_eq!(cmd.args.trie_warmup_depth, Some(3));
+    }
+
+    #[test]
+    fn test_command_parser_static_file_validation() {
+        let cmd = CommandParser::<NodeDatabaseArgs>::try_parse_from(["reth"]).unwrap();
+        assert_eq!(cmd.args.static_file_validation(), None);
+
+        let cmd = CommandParser::<NodeDatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.validate-static-files",
+        ])
+        .unwrap();
+        assert_eq!(cmd.args.static_file_validation(), Some(StaticFileValidation::Sampled));
+
+        let cmd = CommandParser::<NodeDatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.validate-static-files",
+            "--db.validate-static-files-full",
+        ])
+        .unwrap();
+        assert_eq!(cmd.args.static_file_validation(), Some(StaticFileValidation::Full));
+
+        let result = CommandParser::<NodeDatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.validate-static-files-full",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_command_parser_block_cache_size() {
+        let cmd = CommandParser::<NodeDatabaseArgs>::try_parse_from(["reth"]).unwrap();
+        assert_eq!(cmd.args.block_cache_size, None);
+
+        let cmd = CommandParser::<NodeDatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.block-cache-size",
+            "128",
+        ])
+        .unwrap();
+        assert_eq!(cmd.args.block_cache_size, Some(128));
+    }
+
+    #[test]
+    fn test_command_parser_remove_post_merge_ommers() {
+        let cmd = CommandParser::<NodeDatabaseArgs>::try_parse_from(["reth"]).unwrap();
+        assert!(!cmd.args.remove_post_merge_ommers);
+
+        let cmd = CommandParser::<NodeDatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.remove-post-merge-ommers",
+        ])
+        .unwrap();
+        assert!(cmd.args.remove_post_merge_ommers);
+    }
+}
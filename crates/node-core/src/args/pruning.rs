@@ -40,6 +40,7 @@ impl PruningArgs {
                         .into_iter()
                         .collect(),
                 ),
+                watched_senders: Default::default(),
             },
         })
     }
@@ -17,6 +17,7 @@ use std::{
     ffi::OsStr,
     net::{IpAddr, Ipv4Addr},
     path::PathBuf,
+    time::Duration,
 };
 
 /// Default max number of subscriptions per connection.
@@ -146,6 +147,11 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.max-logs-per-response", alias = "rpc-max-logs-per-response", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64))]
     pub rpc_max_logs_per_response: ZeroAsNoneU64,
 
+    /// Maximum number of address and topic combinations a single log filter may specify. (0 = no
+    /// limit)
+    #[arg(long = "rpc.max-filter-combinations", alias = "rpc-max-filter-combinations", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_FILTER_COMBINATIONS as u64))]
+    pub rpc_max_filter_combinations: ZeroAsNoneU64,
+
     /// Maximum gas limit for `eth_call` and call tracing RPC methods.
     #[arg(
         long = "rpc.gascap",
@@ -156,6 +162,60 @@ pub struct RpcServerArgs {
     )]
     pub rpc_gas_cap: u64,
 
+    /// Maximum depth of calls recorded by call traces of the `trace` and `debug` namespaces.
+    /// Deeper calls are executed, but not recorded.
+    #[arg(long = "rpc.max-trace-depth", value_name = "DEPTH")]
+    pub rpc_max_trace_depth: Option<usize>,
+
+    /// Maximum number of traces a single `trace_filter` request may page through with `count`. (0
+    /// = no limit)
+    #[arg(long = "rpc.max-trace-filter-count", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_TRACE_FILTER_COUNT))]
+    pub rpc_max_trace_filter_count: ZeroAsNoneU64,
+
+    /// Maximum number of accounts a single `reth_getAccountData` request may read. (0 = no limit)
+    #[arg(long = "rpc.max-account-data-requests", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_ACCOUNT_DATA_REQUESTS))]
+    pub rpc_max_account_data_requests: ZeroAsNoneU64,
+
+    /// Disable custom JS tracers of the `debug` namespace. The native tracers remain available.
+    #[arg(long = "rpc.disable-js-tracers")]
+    pub rpc_disable_js_tracers: bool,
+
+    /// Maximum number of `newHeads` notifications queued for a subscriber. Subscribers that fall
+    /// further behind are disconnected from the subscription.
+    #[arg(long = "rpc.max-buffered-new-heads", value_name = "COUNT", default_value_t = constants::DEFAULT_MAX_BUFFERED_NEW_HEADS)]
+    pub rpc_max_buffered_new_heads: usize,
+
+    /// Maximum number of `logs` notifications queued for a subscriber. Subscribers that fall
+    /// further behind are disconnected from the subscription.
+    #[arg(long = "rpc.max-buffered-logs", value_name = "COUNT", default_value_t = constants::DEFAULT_MAX_BUFFERED_LOGS)]
+    pub rpc_max_buffered_logs: usize,
+
+    /// Maximum number of `newPendingTransactions` notifications queued for a subscriber.
+    /// Subscribers that fall further behind are disconnected from the subscription.
+    #[arg(long = "rpc.max-buffered-pending-txs", value_name = "COUNT", default_value_t = constants::DEFAULT_MAX_BUFFERED_PENDING_TRANSACTIONS)]
+    pub rpc_max_buffered_pending_txs: usize,
+
+    /// Default timeout for RPC calls, e.g. `30s`. Calls that exceed their timeout are answered
+    /// with an error and abandoned.
+    #[arg(long = "rpc.timeout", value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub rpc_timeout: Option<Duration>,
+
+    /// Timeouts for individual RPC methods that override `--rpc.timeout`, e.g.
+    /// `debug_traceBlockByNumber=2m,eth_blockNumber=1s`.
+    #[arg(
+        long = "rpc.method-timeout",
+        value_name = "METHOD=DURATION",
+        value_parser = parse_method_timeout,
+        value_delimiter = ','
+    )]
+    pub rpc_method_timeouts: Vec<(String, Duration)>,
+
+    /// Abort `eth_call` and `eth_estimateGas` executions once their request is dropped, e.g.
+    /// because the client disconnected. This makes the calls slower, since their executions are
+    /// inspected. Always enabled if an RPC timeout is configured.
+    #[arg(long = "rpc.cancel-dropped-calls")]
+    pub rpc_cancel_dropped_calls: bool,
+
     /// State cache configuration.
     #[command(flatten)]
     pub rpc_state_cache: RpcStateCacheArgs,
@@ -285,13 +345,32 @@ impl Default for RpcServerArgs {
             rpc_max_tracing_requests: constants::default_max_tracing_requests(),
             rpc_max_blocks_per_filter: constants::DEFAULT_MAX_BLOCKS_PER_FILTER.into(),
             rpc_max_logs_per_response: (constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64).into(),
+            rpc_max_filter_combinations: (constants::DEFAULT_MAX_FILTER_COMBINATIONS as u64).into(),
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
+            rpc_max_trace_depth: None,
+            rpc_max_trace_filter_count: constants::DEFAULT_MAX_TRACE_FILTER_COUNT.into(),
+            rpc_max_account_data_requests: constants::DEFAULT_MAX_ACCOUNT_DATA_REQUESTS.into(),
+            rpc_disable_js_tracers: false,
+            rpc_max_buffered_new_heads: constants::DEFAULT_MAX_BUFFERED_NEW_HEADS,
+            rpc_max_buffered_logs: constants::DEFAULT_MAX_BUFFERED_LOGS,
+            rpc_max_buffered_pending_txs: constants::DEFAULT_MAX_BUFFERED_PENDING_TRANSACTIONS,
+            rpc_timeout: None,
+            rpc_method_timeouts: Vec::new(),
+            rpc_cancel_dropped_calls: false,
             gas_price_oracle: GasPriceOracleArgs::default(),
             rpc_state_cache: RpcStateCacheArgs::default(),
         }
     }
 }
 
+/// Parses a `METHOD=DURATION` pair for `--rpc.method-timeout`.
+fn parse_method_timeout(arg: &str) -> Result<(String, Duration), String> {
+    let (method, timeout) =
+        arg.split_once('=').ok_or_else(|| format!("expected METHOD=DURATION, got {arg}"))?;
+    let timeout = humantime::parse_duration(timeout).map_err(|err| err.to_string())?;
+    Ok((method.trim().to_string(), timeout))
+}
+
 /// clap value parser for [`RpcModuleSelection`].
 #[derive(Clone, Debug, Default)]
 #[non_exhaustive]
@@ -348,6 +427,33 @@ mod tests {
         assert_eq!(apis, expected);
     }
 
+    #[test]
+    fn test_rpc_method_timeouts_args() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.timeout",
+            "30s",
+            "--rpc.method-timeout",
+            "debug_traceBlockByNumber=2m,eth_blockNumber=500ms",
+        ])
+        .args;
+        assert_eq!(args.rpc_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(
+            args.rpc_method_timeouts,
+            vec![
+                ("debug_traceBlockByNumber".to_string(), Duration::from_secs(120)),
+                ("eth_blockNumber".to_string(), Duration::from_millis(500)),
+            ]
+        );
+
+        let args = CommandParser::<RpcServerArgs>::try_parse_from([
+            "reth",
+            "--rpc.method-timeout",
+            "eth_blockNumber",
+        ]);
+        assert!(args.is_err());
+    }
+
     #[test]
     fn test_rpc_server_eth_call_bundle_args() {
         let args = CommandParser::<RpcServerArgs>::parse_from([
@@ -9,6 +9,8 @@ use reth_transaction_pool::{
     REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
     TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
 };
+use std::path::PathBuf;
+
 /// Parameters for debugging purposes
 #[derive(Debug, Clone, Args, PartialEq, Eq)]
 #[command(next_help_heading = "TxPool")]
@@ -63,6 +65,14 @@ pub struct TxPoolArgs {
     /// Flag to toggle local transaction propagation.
     #[arg(long = "txpool.no-local-transactions-propagation")]
     pub no_local_transactions_propagation: bool,
+    /// Reject non-local transactions with a nonce gap instead of queueing them.
+    #[arg(long = "txpool.reject-nonce-gaps")]
+    pub reject_nonce_gaps: bool,
+    /// Path to a KZG trusted setup file used to validate blob transactions.
+    ///
+    /// Defaults to the embedded mainnet setup.
+    #[arg(long = "txpool.kzg-trusted-setup", value_name = "PATH")]
+    pub kzg_trusted_setup: Option<PathBuf>,
 }
 
 impl Default for TxPoolArgs {
@@ -82,6 +92,8 @@ impl Default for TxPoolArgs {
             no_locals: false,
             locals: Default::default(),
             no_local_transactions_propagation: false,
+            reject_nonce_gaps: false,
+            kzg_trusted_setup: None,
         }
     }
 }
@@ -116,6 +128,7 @@ impl RethTransactionPoolConfig for TxPoolArgs {
                 default_price_bump: self.price_bump,
                 replace_blob_tx_price_bump: self.blob_transaction_price_bump,
             },
+            reject_nonce_gaps: self.reject_nonce_gaps,
         }
     }
 }
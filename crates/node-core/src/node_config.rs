@@ -2,21 +2,23 @@
 
 use crate::{
     args::{
-        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs,
-        PruningArgs, RpcServerArgs, TxPoolArgs,
+        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, NetworkArgs, NodeDatabaseArgs,
+        PayloadBuilderArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
     },
     dirs::{ChainPath, DataDirPath},
     metrics::prometheus_exporter,
     utils::get_single_header,
 };
+use eyre::WrapErr;
 use metrics_exporter_prometheus::PrometheusHandle;
 use once_cell::sync::Lazy;
 use reth_config::config::PruneConfig;
 use reth_db_api::{database::Database, database_metrics::DatabaseMetrics};
 use reth_network_p2p::headers::client::HeadersClient;
 use reth_primitives::{
-    constants::eip4844::MAINNET_KZG_TRUSTED_SETUP, kzg::KzgSettings, BlockHashOrNumber,
-    BlockNumber, ChainSpec, Head, SealedHeader, B256, MAINNET,
+    constants::eip4844::{load_trusted_setup_from_file, MAINNET_KZG_TRUSTED_SETUP},
+    kzg::KzgSettings,
+    BlockHashOrNumber, BlockNumber, ChainSpec, Head, SealedHeader, B256, MAINNET,
 };
 use reth_provider::{
     providers::StaticFileProvider, BlockHashReader, HeaderProvider, ProviderFactory,
@@ -140,6 +142,9 @@ pub struct NodeConfig {
     /// All database related arguments
     pub db: DatabaseArgs,
 
+    /// All database related arguments that only apply to the running node
+    pub node_db: NodeDatabaseArgs,
+
     /// All dev related arguments with --dev prefix
     pub dev: DevArgs,
 
@@ -227,6 +232,12 @@ impl NodeConfig {
         self
     }
 
+    /// Set the database args that only apply to the running node
+    pub const fn with_node_db(mut self, node_db: NodeDatabaseArgs) -> Self {
+        self.node_db = node_db;
+        self
+    }
+
     /// Set the dev args for the node
     pub const fn with_dev(mut self, dev: DevArgs) -> Self {
         self.dev = dev;
@@ -287,9 +298,17 @@ impl NodeConfig {
         Ok(max_block)
     }
 
-    /// Loads '`MAINNET_KZG_TRUSTED_SETUP`'
+    /// Loads the KZG trusted setup from the configured file, or falls back to
+    /// '`MAINNET_KZG_TRUSTED_SETUP`'.
     pub fn kzg_settings(&self) -> eyre::Result<Arc<KzgSettings>> {
-        Ok(Arc::clone(&MAINNET_KZG_TRUSTED_SETUP))
+        if let Some(ref path) = self.txpool.kzg_trusted_setup {
+            let settings = load_trusted_setup_from_file(path).wrap_err_with(|| {
+                format!("failed to load KZG trusted setup from {}", path.display())
+            })?;
+            Ok(Arc::new(settings))
+        } else {
+            Ok(Arc::clone(&MAINNET_KZG_TRUSTED_SETUP))
+        }
     }
 
     /// Installs the prometheus recorder.
@@ -440,6 +459,7 @@ impl Default for NodeConfig {
             builder: PayloadBuilderArgs::default(),
             debug: DebugArgs::default(),
             db: DatabaseArgs::default(),
+            node_db: NodeDatabaseArgs::default(),
             dev: DevArgs::default(),
             pruning: PruningArgs::default(),
             datadir: DatadirArgs::default(),
@@ -353,16 +353,38 @@ where
     /// between the database and static files. **It may execute a pipeline unwind if it fails this
     /// check.**
     pub async fn create_provider_factory(&self) -> eyre::Result<ProviderFactory<DB>> {
-        let factory = ProviderFactory::new(
+        let mut factory = ProviderFactory::new(
             self.right().clone(),
             self.chain_spec(),
             StaticFileProvider::read_write(self.data_dir().static_files())?,
         )
         .with_static_files_metrics();
 
+        if let Some(max_blocks) = self.node_config().node_db.block_cache_size {
+            info!(target: "reth::cli", max_blocks, "Enabling block cache.");
+            factory = factory.with_block_cache(max_blocks);
+        }
+
         let has_receipt_pruning =
             self.toml_config().prune.as_ref().map_or(false, |a| a.has_receipts_pruning());
 
+        if let Some(validation) = self.node_config().node_db.static_file_validation() {
+            info!(target: "reth::cli", ?validation, "Validating static files.");
+            factory
+                .static_file_provider()
+                .validate_against_database(&factory.provider()?, validation)?;
+        }
+
+        // post-merge blocks can't have ommers, so any stored ones are stray rows
+        if self.node_config().node_db.remove_post_merge_ommers &&
+            factory.provider()?.has_post_merge_ommers()?
+        {
+            let provider_rw = factory.provider_rw()?;
+            let removed = provider_rw.remove_post_merge_ommers()?;
+            provider_rw.commit()?;
+            info!(target: "reth::cli", removed, "Removed ommers of post-merge blocks.");
+        }
+
         info!(target: "reth::cli", "Verifying storage consistency.");
 
         // Check for consistency between database and static files. If it fails, it unwinds to
@@ -20,6 +20,7 @@ use reth_consensus::Consensus;
 use reth_consensus_debug_client::{DebugConsensusClient, EtherscanBlockProvider, RpcBlockProvider};
 use reth_exex::ExExManagerHandle;
 use reth_network::NetworkEvents;
+use reth_network_p2p::full_block::FullBlockRequestLimits;
 use reth_node_api::{FullNodeComponents, FullNodeTypes};
 use reth_node_core::{
     dirs::{ChainPath, DataDirPath},
@@ -34,8 +35,9 @@ use reth_provider::providers::BlockchainProvider;
 use reth_rpc_engine_api::EngineApi;
 use reth_rpc_types::engine::ClientVersionV1;
 use reth_tasks::TaskExecutor;
-use reth_tracing::tracing::{debug, info};
+use reth_tracing::tracing::{debug, info, warn};
 use reth_transaction_pool::TransactionPool;
+use reth_trie::TrieWarmup;
 use std::{future::Future, sync::Arc};
 use tokio::sync::{mpsc::unbounded_channel, oneshot};
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -135,6 +137,20 @@ where
         let sync_metrics_listener = reth_stages::MetricsListener::new(sync_metrics_rx);
         ctx.task_executor().spawn_critical("stages metrics listener task", sync_metrics_listener);
 
+        if let Some(depth) = ctx.node_config().node_db.trie_warmup_depth {
+            debug!(target: "reth::cli", depth, "Spawning trie warmup task");
+            let provider_factory = ctx.provider_factory().clone();
+            ctx.task_executor().spawn_blocking(Box::pin(async move {
+                let result = provider_factory.provider().and_then(|provider| {
+                    Ok(TrieWarmup::new(provider.tx_ref()).with_depth(depth).run()?)
+                });
+                match result {
+                    Ok(nodes) => info!(target: "reth::cli", depth, nodes, "Trie warmup finished"),
+                    Err(err) => warn!(target: "reth::cli", %err, "Trie warmup failed"),
+                }
+            }));
+        }
+
         // fetch the head block from the database
         let head = ctx.lookup_head()?;
 
@@ -308,6 +324,12 @@ where
         info!(target: "reth::cli", prune_config=?ctx.prune_config().unwrap_or_default(), "Pruner initialized");
         hooks.add(PruneHook::new(pruner, Box::new(ctx.task_executor().clone())));
 
+        // Live sync downloads use the same request limits as the pipeline's downloaders
+        let download_limits = FullBlockRequestLimits::new(
+            ctx.toml_config().stages.headers.downloader_request_limit,
+            ctx.toml_config().stages.bodies.downloader_request_limit,
+        );
+
         // Configure the consensus engine
         let (beacon_consensus_engine, beacon_engine_handle) = BeaconConsensusEngine::with_channel(
             client,
@@ -320,10 +342,32 @@ where
             node_adapter.components.payload_builder().clone(),
             initial_target,
             reth_beacon_consensus::MIN_BLOCKS_FOR_PIPELINE_RUN,
+            download_limits,
             consensus_engine_tx,
             Box::pin(consensus_engine_stream),
             hooks,
         )?;
+        let beacon_consensus_engine = beacon_consensus_engine.with_invalid_headers_cache_size(
+            ctx.node_config()
+                .debug
+                .invalid_headers_cache_size
+                .unwrap_or(reth_beacon_consensus::DEFAULT_MAX_INVALID_HEADERS),
+        );
+        let beacon_consensus_engine = match ctx.node_config().debug.max_head_block {
+            Some(max_head_block) => {
+                info!(target: "reth::cli", max_head_block, "Pinning the canonical head");
+                beacon_consensus_engine.with_max_head_block(max_head_block)
+            }
+            None => beacon_consensus_engine,
+        };
+        let beacon_consensus_engine = if ctx.node_config().debug.persist_invalid_payloads {
+            beacon_consensus_engine.with_persisted_invalid_headers(
+                ctx.provider_factory().clone(),
+                reth_beacon_consensus::MAX_PERSISTED_INVALID_HEADERS,
+            )?
+        } else {
+            beacon_consensus_engine
+        };
         info!(target: "reth::cli", "Consensus engine initialized");
 
         let events = stream_select!(
@@ -388,10 +432,20 @@ where
         // Run consensus engine to completion
         let (tx, rx) = oneshot::channel();
         info!(target: "reth::cli", "Starting consensus engine");
-        ctx.task_executor().spawn_critical_blocking("consensus engine", async move {
-            let res = beacon_consensus_engine.await;
-            let _ = tx.send(res);
-        });
+        let shutdown_grace_period = ctx
+            .node_config()
+            .debug
+            .engine_shutdown_grace_period
+            .unwrap_or(reth_beacon_consensus::DEFAULT_SHUTDOWN_GRACE_PERIOD);
+        ctx.task_executor().spawn_critical_blocking_with_graceful_shutdown_signal(
+            "consensus engine",
+            |shutdown| async move {
+                let res = beacon_consensus_engine
+                    .with_graceful_shutdown(shutdown, shutdown_grace_period)
+                    .await;
+                let _ = tx.send(res);
+            },
+        );
 
         if let Some(maybe_custom_etherscan_url) = ctx.node_config().debug.etherscan.clone() {
             info!(target: "reth::cli", "Using etherscan as consensus client");
@@ -295,6 +295,16 @@ impl<DB> NodeState<DB> {
 
                 info!(number=head.number, hash=?head.hash(), ?elapsed, "Canonical chain committed");
             }
+            BeaconConsensusEngineEvent::ChainReorged { old_head, new_head, depth } => {
+                info!(
+                    old_number=old_head.number,
+                    old_hash=?old_head.hash,
+                    new_number=new_head.number,
+                    new_hash=?new_head.hash,
+                    depth,
+                    "Canonical chain reorged"
+                );
+            }
             BeaconConsensusEngineEvent::ForkBlockAdded(block) => {
                 info!(number=block.number, hash=?block.hash(), "Block added to fork chain");
             }
@@ -12,7 +12,7 @@ pub use alloy_eips::eip4844::{
 mod trusted_setup {
     use crate::kzg::KzgSettings;
     use once_cell::sync::Lazy;
-    use std::{io::Write, sync::Arc};
+    use std::{io::Write, path::Path, sync::Arc};
 
     /// KZG trusted setup
     pub static MAINNET_KZG_TRUSTED_SETUP: Lazy<Arc<KzgSettings>> = Lazy::new(|| {
@@ -37,6 +37,17 @@ mod trusted_setup {
         KzgSettings::load_trusted_setup_file(file.path()).map_err(LoadKzgSettingsError::KzgError)
     }
 
+    /// Loads the trusted setup parameters from the file at the given path and returns the
+    /// [`KzgSettings`].
+    ///
+    /// The file is expected to be in the format of the [ceremony output](https://github.com/ethereum/c-kzg-4844/blob/main/src/trusted_setup.txt).
+    /// The setup is validated while loading, so this fails if the file is malformed.
+    pub fn load_trusted_setup_from_file(
+        path: impl AsRef<Path>,
+    ) -> Result<KzgSettings, LoadKzgSettingsError> {
+        KzgSettings::load_trusted_setup_file(path.as_ref()).map_err(LoadKzgSettingsError::KzgError)
+    }
+
     /// Error type for loading the trusted setup.
     #[derive(Debug, thiserror::Error)]
     pub enum LoadKzgSettingsError {
@@ -52,10 +63,52 @@ mod trusted_setup {
     #[cfg(test)]
     mod tests {
         use super::*;
+        use crate::{hex, kzg::Blob, transaction::generate_blob_sidecar, TxEip4844};
 
         #[test]
         fn ensure_load_kzg_settings() {
             let _settings = Arc::clone(&MAINNET_KZG_TRUSTED_SETUP);
         }
+
+        /// Writes the mainnet setup in the trusted setup file format.
+        fn mainnet_trusted_setup_file() -> tempfile::NamedTempFile {
+            let g1 = &revm_primitives::kzg::G1_POINTS.0;
+            let g2 = &revm_primitives::kzg::G2_POINTS.0;
+            let mut file = tempfile::NamedTempFile::new().unwrap();
+            writeln!(file, "{}\n{}", g1.len(), g2.len()).unwrap();
+            for point in g1.iter() {
+                writeln!(file, "{}", hex::encode(point)).unwrap();
+            }
+            for point in g2.iter() {
+                writeln!(file, "{}", hex::encode(point)).unwrap();
+            }
+            file
+        }
+
+        #[test]
+        fn load_kzg_settings_from_file() {
+            let file = mainnet_trusted_setup_file();
+            let settings = load_trusted_setup_from_file(file.path()).unwrap();
+
+            let json: serde_json::Value =
+                serde_json::from_str(include_str!("../transaction/blob_data/blob1.json")).unwrap();
+            let blob = Blob::from_hex(json["data"].as_str().unwrap()).unwrap();
+            let sidecar = generate_blob_sidecar(vec![blob]);
+            let tx = TxEip4844 {
+                blob_versioned_hashes: sidecar.versioned_hashes().collect(),
+                ..Default::default()
+            };
+
+            tx.validate_blob(&sidecar, &settings).unwrap();
+        }
+
+        #[test]
+        fn load_malformed_kzg_settings_from_file() {
+            let mut file = tempfile::NamedTempFile::new().unwrap();
+            writeln!(file, "4096\n65\nnot a point").unwrap();
+
+            let err = load_trusted_setup_from_file(file.path()).unwrap_err();
+            assert!(matches!(err, LoadKzgSettingsError::KzgError(_)));
+        }
     }
 }
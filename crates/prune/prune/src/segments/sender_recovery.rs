@@ -4,18 +4,28 @@ use crate::{
 };
 use reth_db::tables;
 use reth_db_api::database::Database;
+use reth_primitives::Address;
 use reth_provider::{DatabaseProviderRW, TransactionsProvider};
 use reth_prune_types::{PruneMode, PruneProgress, PruneSegment};
+use std::collections::BTreeSet;
 use tracing::{instrument, trace};
 
 #[derive(Debug)]
 pub struct SenderRecovery {
     mode: PruneMode,
+    /// Transaction senders that are never pruned.
+    watched_senders: BTreeSet<Address>,
 }
 
 impl SenderRecovery {
     pub const fn new(mode: PruneMode) -> Self {
-        Self { mode }
+        Self { mode, watched_senders: BTreeSet::new() }
+    }
+
+    /// Retains the transaction senders of the given addresses.
+    pub fn with_watched_senders(mut self, watched_senders: BTreeSet<Address>) -> Self {
+        self.watched_senders = watched_senders;
+        self
     }
 }
 
@@ -49,7 +59,7 @@ impl<DB: Database> Segment<DB> for SenderRecovery {
         let (pruned, done) = provider.prune_table_with_range::<tables::TransactionSenders>(
             tx_range,
             &mut limiter,
-            |_| false,
+            |(_, sender)| self.watched_senders.contains(sender),
             |row| last_pruned_transaction = row.0,
         )?;
         trace!(target: "pruner", %pruned, %done, "Pruned transaction senders");
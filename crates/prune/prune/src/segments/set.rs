@@ -45,6 +45,7 @@ impl<DB: Database> SegmentSet<DB> {
             account_history,
             storage_history,
             receipts_log_filter,
+            watched_senders,
         } = prune_modes;
 
         Self::default()
@@ -58,7 +59,10 @@ impl<DB: Database> SegmentSet<DB> {
             // Transaction lookup
             .segment_opt(transaction_lookup.map(TransactionLookup::new))
             // Sender recovery
-            .segment_opt(sender_recovery.map(SenderRecovery::new))
+            .segment_opt(
+                sender_recovery
+                    .map(|mode| SenderRecovery::new(mode).with_watched_senders(watched_senders)),
+            )
             // Account history
             .segment_opt(account_history.map(AccountHistory::new))
             // Storage history
@@ -1,5 +1,7 @@
 use crate::{PruneMode, ReceiptsLogPruneConfig};
+use alloy_primitives::Address;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeSet;
 
 /// Minimum distance from the tip necessary for the node to work correctly:
 /// 1. Minimum 2 epochs (32 blocks per epoch) required to handle any reorg according to the
@@ -43,6 +45,10 @@ pub struct PruneModes {
     /// The [`BlockNumber`](`crate::BlockNumber`) represents the starting block from which point
     /// onwards the receipts are preserved.
     pub receipts_log_filter: ReceiptsLogPruneConfig,
+    /// Transaction senders that are retained even if `sender_recovery` pruning is enabled, e.g.
+    /// to keep the senders of the transactions sent by the node operator.
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub watched_senders: BTreeSet<Address>,
 }
 
 impl PruneModes {
@@ -60,6 +66,7 @@ impl PruneModes {
             account_history: Some(PruneMode::Full),
             storage_history: Some(PruneMode::Full),
             receipts_log_filter: Default::default(),
+            watched_senders: Default::default(),
         }
     }
 }
@@ -1,8 +1,12 @@
 use reth_primitives::{
-    keccak256, proofs::AccountProof, Account, Address, BlockNumber, Bytecode, Bytes, StorageKey,
-    B256, U256,
+    keccak256,
+    proofs::{storage_root_unhashed, AccountProof},
+    Account, Address, BlockNumber, Bytecode, Bytes, StorageKey, B256, U256,
+};
+use reth_storage_api::{
+    AccountRange, AccountRangeEntry, AccountReader, BlockHashReader, StateProvider,
+    StateRootProvider,
 };
-use reth_storage_api::{AccountReader, BlockHashReader, StateProvider, StateRootProvider};
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::updates::TrieUpdates;
 use revm::db::BundleState;
@@ -93,4 +97,24 @@ impl StateProvider for StateProviderTest {
     fn proof(&self, _address: Address, _keys: &[B256]) -> ProviderResult<AccountProof> {
         unimplemented!("proof generation is not supported")
     }
+
+    fn account_range(&self, start: B256, limit: usize) -> ProviderResult<AccountRange> {
+        let mut accounts = self
+            .accounts
+            .iter()
+            .map(|(address, (storage, account))| AccountRangeEntry {
+                hashed_address: keccak256(address),
+                account: *account,
+                storage_root: storage_root_unhashed(
+                    storage.iter().map(|(key, value)| (*key, *value)),
+                ),
+            })
+            .filter(|entry| entry.hashed_address >= start)
+            .collect::<Vec<_>>();
+        accounts.sort_unstable_by_key(|entry| entry.hashed_address);
+
+        let next = accounts.get(limit).map(|entry| entry.hashed_address);
+        accounts.truncate(limit);
+        Ok(AccountRange { accounts, next })
+    }
 }
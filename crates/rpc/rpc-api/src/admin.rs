@@ -1,7 +1,7 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_network_peers::AnyNode;
+use reth_network_peers::{AnyNode, PeerId};
 use reth_primitives::NodeRecord;
-use reth_rpc_types::{admin::NodeInfo, PeerInfo};
+use reth_rpc_types::{admin::NodeInfo, PeerInfo, ReputationInfo};
 
 /// Admin namespace rpc interface that gives access to several non-standard RPC methods.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
@@ -18,15 +18,20 @@ pub trait AdminApi {
     fn remove_peer(&self, record: AnyNode) -> RpcResult<bool>;
 
     /// Adds the given node record to the trusted peerset.
+    ///
+    /// Trusted peers are exempt from the peer count limits and are reconnected if the connection
+    /// drops.
+    ///
+    /// Returns false if the peer was already trusted.
     #[method(name = "addTrustedPeer")]
-    fn add_trusted_peer(&self, record: AnyNode) -> RpcResult<bool>;
+    async fn add_trusted_peer(&self, record: AnyNode) -> RpcResult<bool>;
 
     /// Removes a remote node from the trusted peer set, but it does not disconnect it
     /// automatically.
     ///
-    /// Returns true if the peer was successfully removed.
+    /// Returns true if the peer was successfully removed, false if it was not trusted.
     #[method(name = "removeTrustedPeer")]
-    fn remove_trusted_peer(&self, record: AnyNode) -> RpcResult<bool>;
+    async fn remove_trusted_peer(&self, record: AnyNode) -> RpcResult<bool>;
 
     /// The peers administrative property can be queried for all the information known about the
     /// connected remote nodes at the networking granularity. These include general information
@@ -35,6 +40,13 @@ pub trait AdminApi {
     #[method(name = "peers")]
     async fn peers(&self) -> RpcResult<Vec<PeerInfo>>;
 
+    /// Returns the current reputation of the given peer, whether it is banned and the penalties it
+    /// received since its reputation was last reset.
+    ///
+    /// Returns `None` if the peer is not known to the node.
+    #[method(name = "peerReputation")]
+    async fn peer_reputation(&self, peer_id: PeerId) -> RpcResult<Option<ReputationInfo>>;
+
     /// Creates an RPC subscription which serves events received from the network.
     #[subscription(
         name = "peerEvents",
@@ -1,11 +1,15 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_primitives::{Address, BlockId, BlockNumberOrTag, Bytes, B256};
 use reth_rpc_types::{
-    trace::geth::{
-        BlockTraceResult, GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace,
-        TraceResult,
+    trace::{
+        geth::{
+            BlockTraceResult, GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace,
+            TraceResult,
+        },
+        parity::StateDiff,
     },
-    Bundle, RichBlock, StateContext, TransactionRequest,
+    AccountRangeResult, Bundle, RichBlock, StateContext, TransactionRequest,
+    TransactionTraceOverrides,
 };
 
 /// Debug rpc interface.
@@ -85,13 +89,24 @@ pub trait DebugApi {
     /// exact same manner as it was executed on the network. It will replay any transaction that
     /// may have been executed prior to this one before it will finally attempt to execute the
     /// transaction that corresponds to the given hash.
+    ///
+    /// The optional overrides re-run the transaction with a different sender or gas limit, in
+    /// which case the trace diverges from the actual on-chain execution.
     #[method(name = "traceTransaction")]
     async fn debug_trace_transaction(
         &self,
         tx_hash: B256,
         opts: Option<GethDebugTracingOptions>,
+        overrides: Option<TransactionTraceOverrides>,
     ) -> RpcResult<GethTrace>;
 
+    /// Re-executes the transaction with the given hash and returns the state diff it produced.
+    ///
+    /// All transactions prior to this one in its block are replayed first, so the diff only
+    /// contains the changes made by the transaction itself.
+    #[method(name = "replayTransaction")]
+    async fn debug_replay_transaction(&self, tx_hash: B256) -> RpcResult<StateDiff>;
+
     /// The `debug_traceCall` method lets you run an `eth_call` within the context of the given
     /// block execution using the final state of parent block as the base.
     ///
@@ -101,12 +116,17 @@ pub trait DebugApi {
     /// The trace can be configured similar to `debug_traceTransaction`,
     /// see [GethDebugTracingOptions]. The method returns the same output as
     /// `debug_traceTransaction`.
+    ///
+    /// If a `transaction_index` is provided, the call is executed on top of the state after the
+    /// first `transaction_index` transactions of the block, like the transaction index of
+    /// `debug_storageRangeAt`.
     #[method(name = "traceCall")]
     async fn debug_trace_call(
         &self,
         request: TransactionRequest,
         block_number: Option<BlockId>,
         opts: Option<GethDebugTracingCallOptions>,
+        transaction_index: Option<usize>,
     ) -> RpcResult<GethTrace>;
 
     /// The `debug_traceCallMany` method lets you run an `eth_callMany` within the context of the
@@ -141,18 +161,19 @@ pub trait DebugApi {
     /// Enumerates all accounts at a given block with paging capability. `maxResults` are returned
     /// in the page and the items have keys that come after the `start` key (hashed address).
     ///
-    /// If incompletes is false, then accounts for which the key preimage (i.e: the address) doesn't
-    /// exist in db are skipped. NB: geth by default does not store preimages.
+    /// Accounts are always keyed by their hashed address since preimages are not stored, so
+    /// `incompletes` has no effect. Storage slots are not dumped, only the storage root of each
+    /// account is returned, so `nostorage` has no effect either.
     #[method(name = "accountRange")]
     async fn debug_account_range(
         &self,
-        block_number: BlockNumberOrTag,
+        block_id: BlockId,
         start: Bytes,
         max_results: u64,
         nocode: bool,
         nostorage: bool,
         incompletes: bool,
-    ) -> RpcResult<()>;
+    ) -> RpcResult<AccountRangeResult>;
 
     /// Turns on block profiling for the given duration and writes profile data to disk. It uses a
     /// profile rate of 1 for most accurate information. If a different rate is desired, set the
@@ -16,6 +16,29 @@ use reth_rpc_types::{
     BlockOverrides, Filter, Log, RichBlock, SyncStatus, TransactionRequest,
 };
 
+/// The `engine_` methods served by [`EngineApiServer`], returned by
+/// [`EngineApiServer::exchange_capabilities`].
+///
+/// `engine_exchangeCapabilities` itself is not listed, as required by the spec. This must be kept
+/// in sync with the methods of the trait, in the same order.
+pub const ENGINE_CAPABILITIES: &[&str] = &[
+    "engine_newPayloadV1",
+    "engine_newPayloadV2",
+    "engine_newPayloadV3",
+    "engine_newPayloadV4",
+    "engine_forkchoiceUpdatedV1",
+    "engine_forkchoiceUpdatedV2",
+    "engine_forkchoiceUpdatedV3",
+    "engine_getPayloadV1",
+    "engine_getPayloadV2",
+    "engine_getPayloadV3",
+    "engine_getPayloadV4",
+    "engine_getPayloadBodiesByHashV1",
+    "engine_getPayloadBodiesByRangeV1",
+    "engine_exchangeTransitionConfigurationV1",
+    "engine_getClientVersionV1",
+];
+
 // NOTE: We can't use associated types in the `EngineApi` trait because of jsonrpsee, so we use a
 // generic here. It would be nice if the rpc macro would understand which types need to have serde.
 // By default, if the trait has a generic, the rpc macro will add e.g. `Engine: DeserializeOwned` to
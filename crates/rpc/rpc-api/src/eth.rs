@@ -173,6 +173,10 @@ pub trait EthApi {
     async fn header_by_hash(&self, hash: B256) -> RpcResult<Option<Header>>;
 
     /// Executes a new message call immediately without creating a transaction on the block chain.
+    ///
+    /// If a `transaction_index` is provided, the call is executed on top of the state after the
+    /// first `transaction_index` transactions of the block, like the transaction index of
+    /// `debug_storageRangeAt`.
     #[method(name = "call")]
     async fn call(
         &self,
@@ -180,6 +184,7 @@ pub trait EthApi {
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
+        transaction_index: Option<usize>,
     ) -> RpcResult<Bytes>;
 
     /// Simulate arbitrary number of transactions at an arbitrary blockchain index, with the
@@ -35,6 +35,8 @@ mod txpool;
 mod validation;
 mod web3;
 
+pub use engine::ENGINE_CAPABILITIES;
+
 /// re-export of all server traits
 pub use servers::*;
 
@@ -1,5 +1,5 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_rpc_types::PeerCount;
+use reth_rpc_types::{PeerCount, PeerCountByDirection};
 
 /// Net rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "net"))]
@@ -13,6 +13,11 @@ pub trait NetApi {
     #[method(name = "peerCount")]
     fn peer_count(&self) -> RpcResult<PeerCount>;
 
+    /// Returns the number of peers connected to node, split into inbound and outbound
+    /// connections.
+    #[method(name = "peerCountByDirection")]
+    fn peer_count_by_direction(&self) -> RpcResult<PeerCountByDirection>;
+
     /// Returns true if client is actively listening for network connections.
     /// Otherwise false.
     #[method(name = "listening")]
@@ -1,5 +1,9 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_primitives::{Address, BlockId, U256};
+use reth_primitives::{Address, BlockId, Bytes, TxHash, U256};
+use reth_rpc_types::{
+    AccountData, AccountDataRequest, GasPriceDistribution, HardforkInfo, InclusionEstimate,
+    InvalidPayload, TotalIssuance,
+};
 use std::collections::HashMap;
 
 /// Reth API namespace for reth-specific methods
@@ -12,4 +16,59 @@ pub trait RethApi {
         &self,
         block_id: BlockId,
     ) -> RpcResult<HashMap<Address, U256>>;
+
+    /// Returns the balance, nonce, code hash and the requested storage slots of each of the given
+    /// accounts at the requested blocks.
+    ///
+    /// This is equivalent to separate `eth_getBalance`, `eth_getTransactionCount` and
+    /// `eth_getStorageAt` calls, but served in a single round-trip.
+    #[method(name = "getAccountData")]
+    async fn reth_get_account_data(
+        &self,
+        requests: Vec<AccountDataRequest>,
+    ) -> RpcResult<Vec<AccountData>>;
+
+    /// Returns an estimate of how many blocks it will take until the pooled transaction with the
+    /// given hash is included.
+    ///
+    /// Returns `None` if the transaction is not in the pool.
+    #[method(name = "estimateInclusion")]
+    async fn reth_estimate_inclusion(
+        &self,
+        tx_hash: TxHash,
+    ) -> RpcResult<Option<InclusionEstimate>>;
+
+    /// Returns the cumulative ETH issuance and burn since genesis up to the given block.
+    #[method(name = "getTotalIssuance")]
+    async fn reth_get_total_issuance(&self, block_id: BlockId) -> RpcResult<TotalIssuance>;
+
+    /// Returns the minimum, maximum, median and the requested percentiles of the effective gas
+    /// prices paid by the transactions in the given block, together with its base fee.
+    ///
+    /// Percentiles are in the range `0..=100` and computed with the nearest-rank method.
+    #[method(name = "getGasPriceDistribution")]
+    async fn reth_get_gas_price_distribution(
+        &self,
+        block_id: BlockId,
+        percentiles: Option<Vec<f64>>,
+    ) -> RpcResult<GasPriceDistribution>;
+
+    /// Sets a custom key-value pair in the ENR advertised by the node.
+    ///
+    /// The value is RLP encoded as a byte string. Keys managed by the node itself, e.g. `ip` or
+    /// `eth`, are rejected, as are values that would exceed the maximum ENR size.
+    #[method(name = "updateEnr")]
+    async fn reth_update_enr(&self, key: String, value: Bytes) -> RpcResult<()>;
+
+    /// Returns the payloads that were rejected as invalid by the consensus engine and persisted
+    /// in the database.
+    ///
+    /// This is empty unless persistence of invalid payloads is enabled.
+    #[method(name = "getInvalidPayloads")]
+    async fn reth_get_invalid_payloads(&self) -> RpcResult<Vec<InvalidPayload>>;
+
+    /// Returns the hardforks of the node's chain spec in activation order, with their activation
+    /// conditions and whether they are active at the current head.
+    #[method(name = "getHardforkSchedule")]
+    async fn reth_get_hardfork_schedule(&self) -> RpcResult<Vec<HardforkInfo>>;
 }
@@ -7,7 +7,7 @@ use reth_rpc_types::{
         opcode::{BlockOpcodeGas, TransactionOpcodeGas},
         parity::*,
     },
-    BlockOverrides, Index, TransactionRequest,
+    AnyTransactionReceipt, BlockOverrides, Index, TransactionRequest, TransactionTraceOverrides,
 };
 use std::collections::HashSet;
 
@@ -70,6 +70,16 @@ pub trait TraceApi {
         block_id: BlockId,
     ) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>>;
 
+    /// Returns all transaction receipts of the given block, each with the value transfers of the
+    /// transaction's internal calls under the `internalTransfers` field.
+    ///
+    /// The transfers are extracted by re-executing the entire block.
+    #[method(name = "blockReceipts")]
+    async fn trace_block_receipts(
+        &self,
+        block_id: BlockId,
+    ) -> RpcResult<Option<Vec<AnyTransactionReceipt>>>;
+
     /// Returns traces matching given filter.
     ///
     /// This is similar to `eth_getLogs` but for traces.
@@ -90,10 +100,14 @@ pub trait TraceApi {
     ) -> RpcResult<Option<LocalizedTransactionTrace>>;
 
     /// Returns all traces of given transaction.
+    ///
+    /// The optional overrides re-run the transaction with a different sender or gas limit, in
+    /// which case the traces diverge from the actual on-chain execution.
     #[method(name = "transaction")]
     async fn trace_transaction(
         &self,
         hash: B256,
+        overrides: Option<TransactionTraceOverrides>,
     ) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>>;
 
     /// Returns all opcodes with their count and combined gas usage for the given transaction in no
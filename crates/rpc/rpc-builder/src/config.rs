@@ -1,6 +1,6 @@
 use crate::{
-    auth::AuthServerConfig, error::RpcError, EthConfig, IpcServerBuilder, RpcModuleConfig,
-    RpcServerConfig, TransportRpcModuleConfig,
+    auth::AuthServerConfig, error::RpcError, EthConfig, IpcServerBuilder, RpcMethodTimeouts,
+    RpcModuleConfig, RpcServerConfig, TransportRpcModuleConfig,
 };
 use jsonrpsee::server::ServerBuilder;
 use reth_node_core::{args::RpcServerArgs, utils::get_or_create_jwt_secret_from_path};
@@ -91,7 +91,20 @@ impl RethRpcServerConfig for RpcServerArgs {
             .max_tracing_requests(self.rpc_max_tracing_requests)
             .max_blocks_per_filter(self.rpc_max_blocks_per_filter.unwrap_or_max())
             .max_logs_per_response(self.rpc_max_logs_per_response.unwrap_or_max() as usize)
+            .max_filter_combinations(self.rpc_max_filter_combinations.unwrap_or_max() as usize)
             .rpc_gas_cap(self.rpc_gas_cap)
+            .max_trace_depth(self.rpc_max_trace_depth)
+            .max_trace_filter_count(self.rpc_max_trace_filter_count.unwrap_or_max())
+            .max_account_data_requests(self.rpc_max_account_data_requests.unwrap_or_max())
+            .disable_js_tracers(self.rpc_disable_js_tracers)
+            .cancel_dropped_calls(
+                self.rpc_cancel_dropped_calls ||
+                    self.rpc_timeout.is_some() ||
+                    !self.rpc_method_timeouts.is_empty(),
+            )
+            .max_buffered_new_heads(self.rpc_max_buffered_new_heads)
+            .max_buffered_logs(self.rpc_max_buffered_logs)
+            .max_buffered_pending_transactions(self.rpc_max_buffered_pending_txs)
             .state_cache(self.state_cache_config())
             .gpo_config(self.gas_price_oracle_config())
     }
@@ -161,7 +174,17 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 
     fn rpc_server_config(&self) -> RpcServerConfig {
-        let mut config = RpcServerConfig::default().with_jwt_secret(self.rpc_secret_key());
+        let mut timeouts = RpcMethodTimeouts::default();
+        if let Some(timeout) = self.rpc_timeout {
+            timeouts = timeouts.with_default(timeout);
+        }
+        for (method, timeout) in &self.rpc_method_timeouts {
+            timeouts = timeouts.with_method(method.clone(), *timeout);
+        }
+
+        let mut config = RpcServerConfig::default()
+            .with_jwt_secret(self.rpc_secret_key())
+            .with_method_timeouts(timeouts);
 
         if self.http {
             let socket_address = SocketAddr::new(self.http_addr, self.http_port);
@@ -216,7 +239,7 @@ impl RethRpcServerConfig for RpcServerArgs {
 mod tests {
     use clap::{Args, Parser};
     use reth_node_core::args::RpcServerArgs;
-    use reth_rpc::eth::RPC_DEFAULT_GAS_CAP;
+    use reth_rpc::eth::{EthPubSubConfig, RPC_DEFAULT_GAS_CAP};
     use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
     use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 
@@ -244,6 +267,61 @@ mod tests {
         assert!(args.is_err());
     }
 
+    #[test]
+    fn test_rpc_max_trace_depth() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.eth_config().max_trace_depth, None);
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.max-trace-depth", "64"])
+                .args;
+        assert_eq!(args.eth_config().max_trace_depth, Some(64));
+    }
+
+    #[test]
+    fn test_rpc_disable_js_tracers() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert!(!args.eth_config().disable_js_tracers);
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.disable-js-tracers"]).args;
+        assert!(args.eth_config().disable_js_tracers);
+    }
+
+    #[test]
+    fn test_rpc_cancel_dropped_calls() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert!(!args.eth_config().cancel_dropped_calls);
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.cancel-dropped-calls"]).args;
+        assert!(args.eth_config().cancel_dropped_calls);
+
+        // calls that time out are dropped
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.timeout", "30s"]).args;
+        assert!(args.eth_config().cancel_dropped_calls);
+    }
+
+    #[test]
+    fn test_rpc_max_buffered_notifications() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.eth_config().pubsub_config(), EthPubSubConfig::default());
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.max-buffered-new-heads",
+            "16",
+            "--rpc.max-buffered-pending-txs",
+            "512",
+        ])
+        .args;
+        let config = args.eth_config().pubsub_config();
+        assert_eq!(config.max_buffered_new_heads, 16);
+        assert_eq!(config.max_buffered_logs, EthPubSubConfig::default().max_buffered_logs);
+        assert_eq!(config.max_buffered_pending_transactions, 512);
+    }
+
     #[test]
     fn test_transport_rpc_module_config() {
         let args = CommandParser::<RpcServerArgs>::parse_from([
@@ -338,12 +416,15 @@ mod tests {
             "0",
             "--rpc-max-logs-per-response",
             "0",
+            "--rpc-max-filter-combinations",
+            "0",
         ])
         .args;
 
         let config = args.eth_config().filter_config();
         assert_eq!(config.max_blocks_per_filter, Some(u64::MAX));
         assert_eq!(config.max_logs_per_response, Some(usize::MAX));
+        assert_eq!(config.max_filter_combinations, Some(usize::MAX));
     }
 
     #[test]
@@ -354,11 +435,14 @@ mod tests {
             "100",
             "--rpc-max-logs-per-response",
             "200",
+            "--rpc-max-filter-combinations",
+            "300",
         ])
         .args;
 
         let config = args.eth_config().filter_config();
         assert_eq!(config.max_blocks_per_filter, Some(100));
         assert_eq!(config.max_logs_per_response, Some(200));
+        assert_eq!(config.max_filter_combinations, Some(300));
     }
 }
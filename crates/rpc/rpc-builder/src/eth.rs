@@ -2,12 +2,15 @@ use reth_rpc::{
     eth::{
         cache::{EthStateCache, EthStateCacheConfig},
         gas_oracle::GasPriceOracleConfig,
-        EthFilterConfig, FeeHistoryCacheConfig, RPC_DEFAULT_GAS_CAP,
+        EthFilterConfig, EthPubSubConfig, FeeHistoryCacheConfig, RPC_DEFAULT_GAS_CAP,
     },
-    EthApi, EthFilter, EthPubSub,
+    DebugApiConfig, EthApi, EthFilter, EthPubSub, RethApiConfig, TraceApiConfig,
 };
 use reth_rpc_server_types::constants::{
-    default_max_tracing_requests, DEFAULT_MAX_BLOCKS_PER_FILTER, DEFAULT_MAX_LOGS_PER_RESPONSE,
+    default_max_tracing_requests, DEFAULT_MAX_ACCOUNT_DATA_REQUESTS, DEFAULT_MAX_BLOCKS_PER_FILTER,
+    DEFAULT_MAX_BUFFERED_LOGS, DEFAULT_MAX_BUFFERED_NEW_HEADS,
+    DEFAULT_MAX_BUFFERED_PENDING_TRANSACTIONS, DEFAULT_MAX_FILTER_COMBINATIONS,
+    DEFAULT_MAX_LOGS_PER_RESPONSE, DEFAULT_MAX_TRACE_FILTER_COUNT,
 };
 use reth_tasks::pool::BlockingTaskPool;
 use serde::{Deserialize, Serialize};
@@ -40,6 +43,9 @@ pub struct EthConfig {
     pub max_blocks_per_filter: u64,
     /// Maximum number of logs that can be returned in a single response in `eth_getLogs` calls.
     pub max_logs_per_response: usize,
+    /// Maximum number of address and topic combinations a single filter may specify in
+    /// `eth_getLogs` calls.
+    pub max_filter_combinations: usize,
     /// Gas limit for `eth_call` and call tracing RPC methods.
     ///
     /// Defaults to [`RPC_DEFAULT_GAS_CAP`]
@@ -49,6 +55,30 @@ pub struct EthConfig {
     pub stale_filter_ttl: std::time::Duration,
     /// Settings for the fee history cache
     pub fee_history_cache: FeeHistoryCacheConfig,
+    /// Maximum depth of calls recorded by `trace_*` and `debug_trace*` call traces.
+    ///
+    /// Calls nested deeper than this are not recorded, unlimited if `None`.
+    pub max_trace_depth: Option<usize>,
+    /// Maximum number of traces a single `trace_filter` request may page through with `count`.
+    pub max_trace_filter_count: u64,
+    /// Maximum number of accounts a single `reth_getAccountData` request may read.
+    pub max_account_data_requests: u64,
+    /// Whether custom JS tracers are rejected by the `debug` namespace.
+    pub disable_js_tracers: bool,
+    /// Whether `eth_call` and `eth_estimateGas` executions are aborted once their request is
+    /// dropped.
+    ///
+    /// This requires inspecting the executions, which makes them slower.
+    pub cancel_dropped_calls: bool,
+    /// Maximum number of `newHeads` notifications queued for a subscriber before the
+    /// subscription is dropped.
+    pub max_buffered_new_heads: usize,
+    /// Maximum number of `logs` notifications queued for a subscriber before the subscription is
+    /// dropped.
+    pub max_buffered_logs: usize,
+    /// Maximum number of `newPendingTransactions` notifications queued for a subscriber before
+    /// the subscription is dropped.
+    pub max_buffered_pending_transactions: usize,
 }
 
 impl EthConfig {
@@ -57,8 +87,38 @@ impl EthConfig {
         EthFilterConfig::default()
             .max_blocks_per_filter(self.max_blocks_per_filter)
             .max_logs_per_response(self.max_logs_per_response)
+            .max_filter_combinations(self.max_filter_combinations)
             .stale_filter_ttl(self.stale_filter_ttl)
     }
+
+    /// Returns the pubsub config for the `eth_subscribe` handler.
+    pub fn pubsub_config(&self) -> EthPubSubConfig {
+        EthPubSubConfig::default()
+            .max_buffered_new_heads(self.max_buffered_new_heads)
+            .max_buffered_logs(self.max_buffered_logs)
+            .max_buffered_pending_transactions(self.max_buffered_pending_transactions)
+    }
+
+    /// Returns the config for the `trace` handler.
+    pub const fn trace_api_config(&self) -> TraceApiConfig {
+        TraceApiConfig {
+            max_trace_depth: self.max_trace_depth,
+            max_trace_filter_count: self.max_trace_filter_count,
+        }
+    }
+
+    /// Returns the config for the `reth` handler.
+    pub const fn reth_api_config(&self) -> RethApiConfig {
+        RethApiConfig { max_account_data_requests: self.max_account_data_requests }
+    }
+
+    /// Returns the config for the `debug` handler.
+    pub const fn debug_api_config(&self) -> DebugApiConfig {
+        DebugApiConfig {
+            max_trace_depth: self.max_trace_depth,
+            disable_js_tracers: self.disable_js_tracers,
+        }
+    }
 }
 
 /// Default value for stale filter ttl
@@ -72,9 +132,18 @@ impl Default for EthConfig {
             max_tracing_requests: default_max_tracing_requests(),
             max_blocks_per_filter: DEFAULT_MAX_BLOCKS_PER_FILTER,
             max_logs_per_response: DEFAULT_MAX_LOGS_PER_RESPONSE,
+            max_filter_combinations: DEFAULT_MAX_FILTER_COMBINATIONS,
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
             stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
             fee_history_cache: FeeHistoryCacheConfig::default(),
+            max_trace_depth: None,
+            max_trace_filter_count: DEFAULT_MAX_TRACE_FILTER_COUNT,
+            max_account_data_requests: DEFAULT_MAX_ACCOUNT_DATA_REQUESTS,
+            disable_js_tracers: false,
+            cancel_dropped_calls: false,
+            max_buffered_new_heads: DEFAULT_MAX_BUFFERED_NEW_HEADS,
+            max_buffered_logs: DEFAULT_MAX_BUFFERED_LOGS,
+            max_buffered_pending_transactions: DEFAULT_MAX_BUFFERED_PENDING_TRANSACTIONS,
         }
     }
 }
@@ -110,9 +179,65 @@ impl EthConfig {
         self
     }
 
+    /// Configures the maximum number of address and topic combinations per filter
+    pub const fn max_filter_combinations(mut self, max_combinations: usize) -> Self {
+        self.max_filter_combinations = max_combinations;
+        self
+    }
+
     /// Configures the maximum gas limit for `eth_call` and call tracing RPC methods
     pub const fn rpc_gas_cap(mut self, rpc_gas_cap: u64) -> Self {
         self.rpc_gas_cap = rpc_gas_cap;
         self
     }
+
+    /// Configures the maximum depth of recorded calls in call traces
+    pub const fn max_trace_depth(mut self, max_trace_depth: Option<usize>) -> Self {
+        self.max_trace_depth = max_trace_depth;
+        self
+    }
+
+    /// Configures the maximum number of traces a `trace_filter` request may page through
+    pub const fn max_trace_filter_count(mut self, max_count: u64) -> Self {
+        self.max_trace_filter_count = max_count;
+        self
+    }
+
+    /// Configures the maximum number of accounts a `reth_getAccountData` request may read
+    pub const fn max_account_data_requests(mut self, max_requests: u64) -> Self {
+        self.max_account_data_requests = max_requests;
+        self
+    }
+
+    /// Configures whether custom JS tracers are disabled
+    pub const fn disable_js_tracers(mut self, disable_js_tracers: bool) -> Self {
+        self.disable_js_tracers = disable_js_tracers;
+        self
+    }
+
+    /// Configures whether `eth_call` and `eth_estimateGas` executions of dropped requests are
+    /// aborted
+    pub const fn cancel_dropped_calls(mut self, cancel_dropped_calls: bool) -> Self {
+        self.cancel_dropped_calls = cancel_dropped_calls;
+        self
+    }
+
+    /// Configures the maximum number of queued `newHeads` notifications per subscription
+    pub const fn max_buffered_new_heads(mut self, max_buffered: usize) -> Self {
+        self.max_buffered_new_heads = max_buffered;
+        self
+    }
+
+    /// Configures the maximum number of queued `logs` notifications per subscription
+    pub const fn max_buffered_logs(mut self, max_buffered: usize) -> Self {
+        self.max_buffered_logs = max_buffered;
+        self
+    }
+
+    /// Configures the maximum number of queued `newPendingTransactions` notifications per
+    /// subscription
+    pub const fn max_buffered_pending_transactions(mut self, max_buffered: usize) -> Self {
+        self.max_buffered_pending_transactions = max_buffered;
+        self
+    }
 }
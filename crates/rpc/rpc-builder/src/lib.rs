@@ -21,7 +21,7 @@
 //! use reth_network_api::{NetworkInfo, Peers};
 //! use reth_provider::{
 //!     AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-//!     ChangeSetReader, EvmEnvProvider, StateProviderFactory,
+//!     ChangeSetReader, EvmEnvProvider, InvalidHeadersReader, StateProviderFactory,
 //! };
 //! use reth_rpc_builder::{
 //!     RethRpcModule, RpcModuleBuilder, RpcServerConfig, ServerBuilder, TransportRpcModuleConfig,
@@ -39,6 +39,7 @@
 //!         + BlockReaderIdExt
 //!         + ChainSpecProvider
 //!         + ChangeSetReader
+//!         + InvalidHeadersReader
 //!         + StateProviderFactory
 //!         + EvmEnvProvider
 //!         + Clone
@@ -82,7 +83,7 @@
 //! use reth_network_api::{NetworkInfo, Peers};
 //! use reth_provider::{
 //!     AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-//!     ChangeSetReader, EvmEnvProvider, StateProviderFactory,
+//!     ChangeSetReader, EvmEnvProvider, InvalidHeadersReader, StateProviderFactory,
 //! };
 //! use reth_rpc_api::EngineApiServer;
 //! use reth_rpc_builder::{
@@ -105,6 +106,7 @@
 //!         + BlockReaderIdExt
 //!         + ChainSpecProvider
 //!         + ChangeSetReader
+//!         + InvalidHeadersReader
 //!         + StateProviderFactory
 //!         + EvmEnvProvider
 //!         + Clone
@@ -172,7 +174,7 @@ use reth_ipc::server::IpcServer;
 use reth_network_api::{noop::NoopNetwork, NetworkInfo, Peers};
 use reth_provider::{
     AccountReader, BlockReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-    ChangeSetReader, EvmEnvProvider, StateProviderFactory,
+    ChangeSetReader, EvmEnvProvider, InvalidHeadersReader, StateProviderFactory,
 };
 use reth_rpc::{
     eth::{
@@ -183,7 +185,7 @@ use reth_rpc::{
         EthBundle, FeeHistoryCache,
     },
     AdminApi, DebugApi, EngineEthApi, EthApi, EthFilter, EthPubSub, EthSubscriptionIdProvider,
-    NetApi, OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi, Web3Api,
+    MevApi, NetApi, OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi, Web3Api,
 };
 use reth_rpc_api::servers::*;
 use reth_rpc_layer::{AuthLayer, Claims, JwtAuthValidator, JwtSecret};
@@ -230,6 +232,12 @@ pub use eth::{EthConfig, EthHandlers};
 // Rpc server metrics
 mod metrics;
 
+/// Per-method call timeouts
+mod timeout;
+pub use timeout::{
+    RpcMethodTimeouts, RpcTimeoutService, TimeoutRequestFuture, RPC_TIMEOUT_ERROR_CODE,
+};
+
 /// Convenience function for starting a server in one step.
 #[allow(clippy::too_many_arguments)]
 pub async fn launch<Provider, Pool, Network, Tasks, Events, EvmConfig>(
@@ -249,6 +257,7 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + InvalidHeadersReader
         + Clone
         + Unpin
         + 'static,
@@ -440,6 +449,7 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + InvalidHeadersReader
         + Clone
         + Unpin
         + 'static,
@@ -765,6 +775,7 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + InvalidHeadersReader
         + Clone
         + Unpin
         + 'static,
@@ -939,6 +950,7 @@ where
                             self.provider.clone(),
                             eth_api.clone(),
                             self.blocking_pool_guard.clone(),
+                            self.config.eth.debug_api_config(),
                         )
                         .into_rpc()
                         .into(),
@@ -957,6 +969,7 @@ where
                             self.provider.clone(),
                             eth_api.clone(),
                             self.blocking_pool_guard.clone(),
+                            self.config.eth.trace_api_config(),
                         )
                         .into_rpc()
                         .into(),
@@ -972,17 +985,30 @@ where
                         )
                         .into_rpc()
                         .into(),
-                        RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
-                        RethRpcModule::Reth => {
-                            RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+                        RethRpcModule::Ots => {
+                            OtterscanApi::new(self.provider.clone(), eth_api.clone())
                                 .into_rpc()
                                 .into()
                         }
+                        RethRpcModule::Reth => RethApi::new(
+                            self.provider.clone(),
+                            self.pool.clone(),
+                            self.network.clone(),
+                            Box::new(self.executor.clone()),
+                            self.config.eth.reth_api_config(),
+                        )
+                        .into_rpc()
+                        .into(),
                         RethRpcModule::EthCallBundle => {
                             EthBundle::new(eth_api.clone(), self.blocking_pool_guard.clone())
                                 .into_rpc()
                                 .into()
                         }
+                        RethRpcModule::Mev => {
+                            MevApi::new(eth_api.clone(), self.blocking_pool_guard.clone())
+                                .into_rpc()
+                                .into()
+                        }
                     })
                     .clone()
             })
@@ -1060,6 +1086,7 @@ where
             fee_history_cache,
             self.evm_config.clone(),
             self.eth_raw_transaction_forwarder.clone(),
+            self.config.eth.cancel_dropped_calls,
         );
         let filter = EthFilter::new(
             self.provider.clone(),
@@ -1075,7 +1102,8 @@ where
             self.events.clone(),
             self.network.clone(),
             executor,
-        );
+        )
+        .with_config(self.config.eth.pubsub_config());
 
         EthHandlers { api, cache, filter, pubsub, blocking_task_pool }
     }
@@ -1107,7 +1135,12 @@ where
     /// If called outside of the tokio runtime. See also [`Self::eth_api`]
     pub fn trace_api(&mut self) -> TraceApi<Provider, EthApi<Provider, Pool, Network, EvmConfig>> {
         let eth = self.eth_handlers();
-        TraceApi::new(self.provider.clone(), eth.api, self.blocking_pool_guard.clone())
+        TraceApi::new(
+            self.provider.clone(),
+            eth.api,
+            self.blocking_pool_guard.clone(),
+            self.config.eth.trace_api_config(),
+        )
     }
 
     /// Instantiates [`EthBundle`] Api
@@ -1125,9 +1158,11 @@ where
     /// # Panics
     ///
     /// If called outside of the tokio runtime. See also [`Self::eth_api`]
-    pub fn otterscan_api(&mut self) -> OtterscanApi<EthApi<Provider, Pool, Network, EvmConfig>> {
+    pub fn otterscan_api(
+        &mut self,
+    ) -> OtterscanApi<Provider, EthApi<Provider, Pool, Network, EvmConfig>> {
         let eth_api = self.eth_api();
-        OtterscanApi::new(eth_api)
+        OtterscanApi::new(self.provider.clone(), eth_api)
     }
 
     /// Instantiates `DebugApi`
@@ -1137,7 +1172,12 @@ where
     /// If called outside of the tokio runtime. See also [`Self::eth_api`]
     pub fn debug_api(&mut self) -> DebugApi<Provider, EthApi<Provider, Pool, Network, EvmConfig>> {
         let eth_api = self.eth_api();
-        DebugApi::new(self.provider.clone(), eth_api, self.blocking_pool_guard.clone())
+        DebugApi::new(
+            self.provider.clone(),
+            eth_api,
+            self.blocking_pool_guard.clone(),
+            self.config.eth.debug_api_config(),
+        )
     }
 
     /// Instantiates `NetApi`
@@ -1151,8 +1191,14 @@ where
     }
 
     /// Instantiates `RethApi`
-    pub fn reth_api(&self) -> RethApi<Provider> {
-        RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+    pub fn reth_api(&self) -> RethApi<Provider, Pool, Network> {
+        RethApi::new(
+            self.provider.clone(),
+            self.pool.clone(),
+            self.network.clone(),
+            Box::new(self.executor.clone()),
+            self.config.eth.reth_api_config(),
+        )
     }
 }
 
@@ -1187,6 +1233,8 @@ pub struct RpcServerConfig {
     ipc_endpoint: Option<String>,
     /// JWT secret for authentication
     jwt_secret: Option<JwtSecret>,
+    /// Timeouts for RPC calls
+    method_timeouts: RpcMethodTimeouts,
 }
 
 // === impl RpcServerConfig ===
@@ -1304,6 +1352,15 @@ impl RpcServerConfig {
         self
     }
 
+    /// Configures the timeouts for RPC calls.
+    ///
+    /// Calls that exceed their timeout are answered with an error and abandoned, see
+    /// [`RpcMethodTimeouts`].
+    pub fn with_method_timeouts(mut self, timeouts: RpcMethodTimeouts) -> Self {
+        self.method_timeouts = timeouts;
+        self
+    }
+
     /// Returns true if any server is configured.
     ///
     /// If no server is configured, no server will be be launched on [`RpcServerConfig::start`].
@@ -1393,14 +1450,16 @@ impl RpcServerConfig {
                         .option_layer(self.maybe_jwt_layer()),
                 )
                 .set_rpc_middleware(
-                    RpcServiceBuilder::new().layer(
-                        modules
-                            .http
-                            .as_ref()
-                            .or(modules.ws.as_ref())
-                            .map(RpcRequestMetrics::same_port)
-                            .unwrap_or_default(),
-                    ),
+                    RpcServiceBuilder::new()
+                        .layer(
+                            modules
+                                .http
+                                .as_ref()
+                                .or(modules.ws.as_ref())
+                                .map(RpcRequestMetrics::same_port)
+                                .unwrap_or_default(),
+                        )
+                        .layer(self.method_timeouts.clone()),
                 )
                 .build(http_socket_addr)
                 .await
@@ -1431,7 +1490,8 @@ impl RpcServerConfig {
                 )
                 .set_rpc_middleware(
                     RpcServiceBuilder::new()
-                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default()),
+                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default())
+                        .layer(self.method_timeouts.clone()),
                 )
                 .build(ws_socket_addr)
                 .await
@@ -1453,9 +1513,11 @@ impl RpcServerConfig {
                         .option_layer(self.maybe_jwt_layer()),
                 )
                 .set_rpc_middleware(
-                    RpcServiceBuilder::new().layer(
-                        modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
-                    ),
+                    RpcServiceBuilder::new()
+                        .layer(
+                            modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
+                        )
+                        .layer(self.method_timeouts.clone()),
                 )
                 .build(http_socket_addr)
                 .await
@@ -1490,7 +1552,9 @@ impl RpcServerConfig {
             let ipc_path =
                 self.ipc_endpoint.unwrap_or_else(|| constants::DEFAULT_IPC_ENDPOINT.into());
             let ipc = builder
-                .set_rpc_middleware(IpcRpcServiceBuilder::new().layer(metrics))
+                .set_rpc_middleware(
+                    IpcRpcServiceBuilder::new().layer(metrics).layer(self.method_timeouts),
+                )
                 .build(ipc_path);
             server.ipc = Some(ipc);
         }
@@ -1729,7 +1793,7 @@ type WsHttpServerKind = Server<
         tower::util::Either<AuthLayer<JwtAuthValidator>, Identity>,
         Stack<tower::util::Either<CorsLayer, Identity>, Identity>,
     >,
-    Stack<RpcRequestMetrics, Identity>,
+    Stack<RpcMethodTimeouts, Stack<RpcRequestMetrics, Identity>>,
 >;
 
 /// Enum for holding the http and ws servers in all possible combinations.
@@ -1793,7 +1857,7 @@ pub struct RpcServer {
     /// Configured ws,http servers
     ws_http: WsHttpServer,
     /// ipc server
-    ipc: Option<IpcServer<Identity, Stack<RpcRequestMetrics, Identity>>>,
+    ipc: Option<IpcServer<Identity, Stack<RpcMethodTimeouts, Stack<RpcRequestMetrics, Identity>>>>,
 }
 
 // === impl RpcServer ===
@@ -2077,6 +2141,7 @@ mod tests {
                 "rpc" => RethRpcModule::Rpc,
                 "ots" => RethRpcModule::Ots,
                 "reth" => RethRpcModule::Reth,
+                "mev" => RethRpcModule::Mev,
             );
     }
 
@@ -0,0 +1,220 @@
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Id, Request},
+    MethodResponse,
+};
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::Sleep;
+use tower::Layer;
+
+/// The error code returned if a call exceeds its timeout.
+pub const RPC_TIMEOUT_ERROR_CODE: i32 = -32002;
+
+/// Per-method timeouts for RPC calls.
+///
+/// Methods without an explicitly configured timeout use the default timeout, if any. Calls that
+/// exceed their timeout are answered with a [`RPC_TIMEOUT_ERROR_CODE`] error and the future of the
+/// call is dropped. Work the call already handed off to other tasks is only stopped if the call
+/// cancels it when dropped, like the EVM executions of the `eth`, `debug` and `trace` handlers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RpcMethodTimeouts {
+    inner: Arc<RpcMethodTimeoutsInner>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct RpcMethodTimeoutsInner {
+    /// The timeout for methods without a configured timeout.
+    default: Option<Duration>,
+    /// The timeouts of individual methods.
+    methods: HashMap<String, Duration>,
+}
+
+impl RpcMethodTimeouts {
+    /// Sets the timeout for all methods without an explicitly configured timeout.
+    pub fn with_default(mut self, timeout: Duration) -> Self {
+        Arc::make_mut(&mut self.inner).default = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout of the given method, e.g. `debug_traceBlock`.
+    pub fn with_method(mut self, method: impl Into<String>, timeout: Duration) -> Self {
+        Arc::make_mut(&mut self.inner).methods.insert(method.into(), timeout);
+        self
+    }
+
+    /// Returns the timeout that applies to the given method, if any.
+    pub fn timeout(&self, method: &str) -> Option<Duration> {
+        self.inner.methods.get(method).copied().or(self.inner.default)
+    }
+
+    /// Returns `true` if no timeouts are configured.
+    pub fn is_empty(&self) -> bool {
+        self.inner.default.is_none() && self.inner.methods.is_empty()
+    }
+}
+
+impl<S> Layer<S> for RpcMethodTimeouts {
+    type Service = RpcTimeoutService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcTimeoutService { inner, timeouts: self.clone() }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that enforces the configured [`RpcMethodTimeouts`].
+#[derive(Clone, Debug)]
+pub struct RpcTimeoutService<S> {
+    inner: S,
+    timeouts: RpcMethodTimeouts,
+}
+
+impl<'a, S> RpcServiceT<'a> for RpcTimeoutService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = TimeoutRequestFuture<S::Future>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let timeout = self.timeouts.timeout(req.method_name());
+        let id = req.id().into_owned();
+        TimeoutRequestFuture {
+            fut: Some(self.inner.call(req)),
+            sleep: timeout.map(tokio::time::sleep),
+            id,
+        }
+    }
+}
+
+/// Response future that answers with a timeout error if the call does not finish in time.
+///
+/// The future of the call is dropped as soon as the timeout elapsed.
+#[pin_project::pin_project]
+pub struct TimeoutRequestFuture<F> {
+    /// The call, `None` once it timed out.
+    #[pin]
+    fut: Option<F>,
+    /// Fires once the timeout of the call elapsed, `None` if the call has no timeout.
+    #[pin]
+    sleep: Option<Sleep>,
+    /// The id of the request.
+    id: Id<'static>,
+}
+
+impl<F> std::fmt::Debug for TimeoutRequestFuture<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TimeoutRequestFuture")
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for TimeoutRequestFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        let fut = this.fut.as_mut().as_pin_mut().expect("polled after completion");
+        if let Poll::Ready(resp) = fut.poll(cx) {
+            return Poll::Ready(resp)
+        }
+
+        if let Some(sleep) = this.sleep.as_pin_mut() {
+            if sleep.poll(cx).is_ready() {
+                // abandon the call right away
+                this.fut.set(None);
+                let err =
+                    ErrorObject::owned(RPC_TIMEOUT_ERROR_CODE, "request timed out", None::<()>);
+                return Poll::Ready(MethodResponse::error(this.id.clone(), err))
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{
+        core::client::{ClientT, Error},
+        http_client::HttpClientBuilder,
+        rpc_params,
+        server::{RpcModule, RpcServiceBuilder, ServerBuilder},
+    };
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Sets the flag once the call is dropped.
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::Relaxed);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn slow_method_times_out() {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let mut module = RpcModule::new(());
+        let flag = dropped.clone();
+        module
+            .register_async_method("test_slow", move |_, _, _| {
+                let flag = DropFlag(flag.clone());
+                async move {
+                    let _flag = flag;
+                    tokio::time::sleep(Duration::from_secs(10)).await;
+                    "slow"
+                }
+            })
+            .unwrap();
+        module.register_method("test_fast", |_, _, _| "fast").unwrap();
+
+        let timeouts = RpcMethodTimeouts::default()
+            .with_default(Duration::from_secs(5))
+            .with_method("test_slow", Duration::from_millis(10));
+        let server = ServerBuilder::default()
+            .set_rpc_middleware(RpcServiceBuilder::new().layer(timeouts))
+            .build("127.0.0.1:0")
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let handle = server.start(module);
+
+        let client = HttpClientBuilder::default().build(format!("http://{addr}")).unwrap();
+        let fast: String = client.request("test_fast", rpc_params![]).await.unwrap();
+        assert_eq!(fast, "fast");
+
+        let err = client.request::<String, _>("test_slow", rpc_params![]).await.unwrap_err();
+        match err {
+            Error::Call(err) => {
+                assert_eq!(err.code(), RPC_TIMEOUT_ERROR_CODE);
+                assert_eq!(err.message(), "request timed out");
+            }
+            err => panic!("expected a call error, got {err:?}"),
+        }
+        // the call was abandoned
+        assert!(dropped.load(Ordering::Relaxed));
+
+        handle.stop().unwrap();
+    }
+
+    #[test]
+    fn method_timeout_overrides_default() {
+        let timeouts = RpcMethodTimeouts::default();
+        assert!(timeouts.is_empty());
+        assert_eq!(timeouts.timeout("eth_blockNumber"), None);
+
+        let timeouts = timeouts
+            .with_default(Duration::from_secs(1))
+            .with_method("debug_traceBlock", Duration::from_secs(60));
+        assert!(!timeouts.is_empty());
+        assert_eq!(timeouts.timeout("eth_blockNumber"), Some(Duration::from_secs(1)));
+        assert_eq!(timeouts.timeout("debug_traceBlock"), Some(Duration::from_secs(60)));
+    }
+}
@@ -143,6 +143,7 @@ where
     AdminApiClient::remove_peer(client, node.into()).await.unwrap();
     AdminApiClient::add_trusted_peer(client, node.into()).await.unwrap();
     AdminApiClient::remove_trusted_peer(client, node.into()).await.unwrap();
+    AdminApiClient::peer_reputation(client, node.id).await.unwrap();
     AdminApiClient::node_info(client).await.unwrap();
 }
 
@@ -192,7 +193,7 @@ where
     EthApiClient::estimate_gas(client, call_request.clone(), Some(block_number.into()), None)
         .await
         .unwrap();
-    EthApiClient::call(client, call_request.clone(), Some(block_number.into()), None, None)
+    EthApiClient::call(client, call_request.clone(), Some(block_number.into()), None, None, None)
         .await
         .unwrap();
     EthApiClient::syncing(client).await.unwrap();
@@ -237,6 +238,7 @@ where
 {
     NetApiClient::version(client).await.unwrap();
     NetApiClient::peer_count(client).await.unwrap();
+    NetApiClient::peer_count_by_direction(client).await.unwrap();
     NetApiClient::is_listening(client).await.unwrap();
 }
 
@@ -331,9 +333,7 @@ where
             .err()
             .unwrap()
     ));
-    assert!(is_unimplemented(
-        OtterscanClient::get_contract_creator(client, address).await.err().unwrap()
-    ));
+    OtterscanClient::get_contract_creator(client, address).await.unwrap();
 }
 
 #[tokio::test(flavor = "multi_thread")]
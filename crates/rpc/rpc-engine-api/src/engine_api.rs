@@ -10,12 +10,11 @@ use reth_payload_primitives::{
     PayloadBuilderAttributes, PayloadOrAttributes,
 };
 use reth_primitives::{BlockHash, BlockHashOrNumber, BlockNumber, ChainSpec, Hardfork, B256, U64};
-use reth_rpc_api::EngineApiServer;
+use reth_rpc_api::{EngineApiServer, ENGINE_CAPABILITIES};
 use reth_rpc_types::engine::{
     CancunPayloadFields, ClientVersionV1, ExecutionPayload, ExecutionPayloadBodiesV1,
     ExecutionPayloadInputV2, ExecutionPayloadV1, ExecutionPayloadV3, ExecutionPayloadV4,
     ForkchoiceState, ForkchoiceUpdated, PayloadId, PayloadStatus, TransitionConfiguration,
-    CAPABILITIES,
 };
 use reth_rpc_types_compat::engine::payload::{
     convert_payload_input_v2_to_payload, convert_to_payload_body_v1,
@@ -819,9 +818,12 @@ where
     }
 
     /// Handler for `engine_exchangeCapabilitiesV1`
+    ///
+    /// Returns [`ENGINE_CAPABILITIES`], the `engine_` methods served by this API.
+    ///
     /// See also <https://github.com/ethereum/execution-apis/blob/6452a6b194d7db269bf1dbd087a267251d3cc7f8/src/engine/common.md#capabilities>
     async fn exchange_capabilities(&self, _capabilities: Vec<String>) -> RpcResult<Vec<String>> {
-        Ok(CAPABILITIES.iter().cloned().map(str::to_owned).collect())
+        Ok(ENGINE_CAPABILITIES.iter().copied().map(str::to_owned).collect())
     }
 }
 
@@ -891,6 +893,24 @@ mod tests {
         assert_eq!(res.unwrap(), vec![client]);
     }
 
+    #[tokio::test]
+    async fn capabilities_match_served_methods() {
+        let (_, api) = setup_engine_api();
+        let served = api.into_rpc().method_names().collect::<Vec<_>>();
+
+        for capability in ENGINE_CAPABILITIES {
+            assert!(served.contains(capability), "{capability} is advertised but not served");
+        }
+        for method in served {
+            if method != "engine_exchangeCapabilities" {
+                assert!(
+                    ENGINE_CAPABILITIES.contains(&method),
+                    "{method} is served but not advertised"
+                );
+            }
+        }
+    }
+
     struct EngineApiTestHandle {
         chain_spec: Arc<ChainSpec>,
         provider: Arc<MockEthProvider>,
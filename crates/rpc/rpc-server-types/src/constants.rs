@@ -15,6 +15,25 @@ pub const DEFAULT_MAX_BLOCKS_PER_FILTER: u64 = 100_000;
 /// The default maximum of logs in a single response.
 pub const DEFAULT_MAX_LOGS_PER_RESPONSE: usize = 20_000;
 
+/// The default maximum number of address and topic combinations a single log filter may specify.
+pub const DEFAULT_MAX_FILTER_COMBINATIONS: usize = 10_000;
+
+/// The default maximum number of traces a single `trace_filter` request may page through.
+pub const DEFAULT_MAX_TRACE_FILTER_COUNT: u64 = 10_000;
+
+/// The default maximum number of accounts a single `reth_getAccountData` request may read.
+pub const DEFAULT_MAX_ACCOUNT_DATA_REQUESTS: u64 = 1_000;
+
+/// The default maximum number of `newHeads` notifications queued for a single subscriber.
+pub const DEFAULT_MAX_BUFFERED_NEW_HEADS: usize = 128;
+
+/// The default maximum number of `logs` notifications queued for a single subscriber.
+pub const DEFAULT_MAX_BUFFERED_LOGS: usize = 20_000;
+
+/// The default maximum number of `newPendingTransactions` notifications queued for a single
+/// subscriber.
+pub const DEFAULT_MAX_BUFFERED_PENDING_TRANSACTIONS: usize = 10_000;
+
 /// The default maximum number tracing requests we're allowing concurrently.
 /// Tracing is mostly CPU bound so we're limiting the number of concurrent requests to something
 /// lower that the number of cores, in order to minimize the impact on the rest of the system.
@@ -14,6 +14,7 @@ mod eth;
 mod mev;
 mod net;
 mod peer;
+mod reth;
 mod rpc;
 
 // re-export for convenience
@@ -46,4 +47,5 @@ pub use eth::{
 pub use mev::*;
 pub use net::*;
 pub use peer::*;
+pub use reth::*;
 pub use rpc::*;
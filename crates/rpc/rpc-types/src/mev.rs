@@ -384,6 +384,28 @@ pub struct SimBundleResponse {
     /// Logs returned by `mev_simBundle`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub logs: Option<Vec<SimBundleLogs>>,
+    /// The results of the simulated transactions in execution order.
+    ///
+    /// If the simulation failed, the last result belongs to the transaction that failed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tx_results: Vec<SimBundleTxResult>,
+}
+
+/// The result of a single transaction simulated by `mev_simBundle`.
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SimBundleTxResult {
+    /// The hash of the transaction.
+    pub tx_hash: TxHash,
+    /// The gas used by the transaction.
+    #[serde(with = "alloy_rpc_types::serde_helpers::num::u64_via_ruint")]
+    pub gas_used: u64,
+    /// The change of the coinbase balance caused by the transaction.
+    pub coinbase_diff: U256,
+    /// Whether the transaction reverted.
+    pub reverted: bool,
+    /// Whether the transaction is allowed to revert without failing the bundle.
+    pub can_revert: bool,
 }
 
 /// Logs returned by `mev_simBundle`.
@@ -677,6 +699,16 @@ pub struct EthCallBundle {
         skip_serializing_if = "Option::is_none"
     )]
     pub timestamp: Option<u64>,
+    /// the gas limit of the simulated block
+    ///
+    /// If set, transactions are executed in order until the next transaction no longer fits into
+    /// the remaining gas of the block. That transaction and all following ones are skipped.
+    #[serde(
+        default,
+        with = "alloy_rpc_types::serde_helpers::num::u64_opt_via_ruint",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub gas_limit: Option<u64>,
 }
 
 /// Response for `eth_callBundle`
@@ -705,6 +737,10 @@ pub struct EthCallBundleResponse {
     /// The total gas used by all transactions in the bundle
     #[serde(with = "alloy_rpc_types::serde_helpers::num::u64_via_ruint")]
     pub total_gas_used: u64,
+    /// Hashes of the transactions that were not executed because they exceeded the gas limit of
+    /// the simulated block
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_txs: Vec<B256>,
 }
 
 /// Result of a single transaction in a bundle for `eth_callBundle`
@@ -11,3 +11,52 @@ pub struct NetworkStatus {
     /// Information about the Ethereum Wire Protocol.
     pub eth_protocol_info: EthProtocolInfo,
 }
+
+/// The number of peers connected to the local node, split by the direction of the connection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerCountByDirection {
+    /// Number of peers that connected to the local node.
+    pub inbound: u64,
+    /// Number of peers the local node connected to.
+    pub outbound: u64,
+    /// Total number of connected peers.
+    pub total: u64,
+}
+
+/// The reputation of a peer, as returned by `admin_peerReputation`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReputationInfo {
+    /// The current reputation score of the peer.
+    pub reputation: i32,
+    /// Whether the peer is currently banned.
+    pub banned: bool,
+    /// The penalties the peer received since its reputation was last reset.
+    pub penalties: ReputationPenalties,
+}
+
+/// The number of reputation penalties a peer received, by kind.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReputationPenalties {
+    /// Unspecific bad messages.
+    pub bad_message: u32,
+    /// Bad blocks.
+    pub bad_block: u32,
+    /// Bad transaction messages.
+    pub bad_transactions: u32,
+    /// Bad announcement messages.
+    pub bad_announcement: u32,
+    /// Transactions or hashes that were already received from the peer.
+    pub already_seen_transaction: u32,
+    /// Requests the peer failed to respond to in time.
+    pub timeout: u32,
+    /// Violations of the network protocol rules.
+    pub bad_protocol: u32,
+    /// Failed attempts to connect to the peer.
+    pub failed_to_connect: u32,
+    /// Connections dropped by the peer.
+    pub dropped: u32,
+    /// Penalties applied by value.
+    pub other: u32,
+}
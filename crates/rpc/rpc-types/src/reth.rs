@@ -0,0 +1,224 @@
+use alloy_primitives::{Address, Bytes, B256, I256, U256};
+use alloy_rpc_types::{serde_helpers::JsonStorageKey, BlockId};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Overrides for re-tracing a mined transaction, as accepted by `debug_traceTransaction` and
+/// `trace_transaction`.
+///
+/// The overrides are applied to the transaction on top of the state right before it in its block.
+/// The resulting trace diverges from the actual on-chain execution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTraceOverrides {
+    /// Executes the transaction as if it was sent by this account.
+    ///
+    /// The nonce of the account is not checked, but it must be able to pay for the gas.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<Address>,
+    /// Executes the transaction with this gas limit instead of its own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas: Option<u64>,
+}
+
+/// Where a pooled transaction currently stands with respect to inclusion in a block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InclusionStatus {
+    /// The transaction is pending and can be included once the transactions ahead of it are.
+    Pending,
+    /// The transaction is parked because its fee cap is below the current base or blob fee.
+    BlockedOnFee,
+    /// The transaction is parked because of a nonce gap.
+    BlockedOnNonceGap,
+}
+
+/// Estimate of when a pooled transaction will be included, as returned by
+/// `reth_estimateInclusion`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionEstimate {
+    /// The current status of the transaction.
+    pub status: InclusionStatus,
+    /// Estimated number of blocks until the transaction is included.
+    ///
+    /// This is `None` if the transaction is blocked.
+    pub blocks: Option<u64>,
+    /// Number of pending transactions from the same sender that are ordered ahead.
+    pub same_sender_ahead: u64,
+    /// Number of pending transactions from other senders that are ordered ahead.
+    pub other_senders_ahead: u64,
+    /// The base fee of the next block, as tracked by the pool.
+    pub base_fee: u64,
+    /// The blob fee of the next block, as tracked by the pool.
+    pub blob_fee: Option<u128>,
+}
+
+/// Cumulative ETH issuance and burn since genesis up to a block, as returned by
+/// `reth_getTotalIssuance`.
+///
+/// The genesis allocation is not included.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotalIssuance {
+    /// The number of the block the totals were computed for.
+    pub block_number: u64,
+    /// The hash of the block the totals were computed for.
+    pub block_hash: B256,
+    /// ETH issued through block and ommer rewards before the merge and withdrawals after it.
+    pub issued: U256,
+    /// ETH burnt through the base fee and the blob fee.
+    pub burnt: U256,
+    /// The net change of the supply since genesis, `issued - burnt`.
+    pub net_supply_change: I256,
+}
+
+/// The distribution of the effective gas prices paid by the transactions of a block, as returned
+/// by `reth_getGasPriceDistribution`.
+///
+/// The statistics are `None` if the block has no transactions.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPriceDistribution {
+    /// The number of the block.
+    pub block_number: u64,
+    /// The hash of the block.
+    pub block_hash: B256,
+    /// The base fee of the block, `None` before London.
+    pub base_fee: Option<u64>,
+    /// The lowest effective gas price paid in the block.
+    pub min: Option<u128>,
+    /// The highest effective gas price paid in the block.
+    pub max: Option<u128>,
+    /// The median effective gas price paid in the block.
+    pub median: Option<u128>,
+    /// The effective gas prices at the requested percentiles, in the order of the request.
+    pub percentiles: Option<Vec<u128>>,
+}
+
+/// A request for the data of an account at a block, as accepted by `reth_getAccountData`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDataRequest {
+    /// The address of the account.
+    pub address: Address,
+    /// The storage slots to read.
+    #[serde(default)]
+    pub slots: Vec<JsonStorageKey>,
+    /// The block to read the account at, defaults to the latest block.
+    #[serde(default)]
+    pub block: BlockId,
+}
+
+/// The data of an account at a block, as returned by `reth_getAccountData`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountData {
+    /// The address of the account.
+    pub address: Address,
+    /// The balance of the account.
+    pub balance: U256,
+    /// The nonce of the account.
+    pub nonce: u64,
+    /// The hash of the code of the account.
+    pub code_hash: B256,
+    /// The values of the requested storage slots.
+    pub storage: BTreeMap<B256, B256>,
+}
+
+/// A payload that was rejected as invalid by the consensus engine, as returned by
+/// `reth_getInvalidPayloads`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidPayload {
+    /// The number of the invalid block.
+    pub number: u64,
+    /// The hash of the invalid block.
+    pub hash: B256,
+    /// The hash of the parent of the invalid block.
+    pub parent_hash: B256,
+}
+
+/// A hardfork of the chain spec, as returned by `reth_getHardforkSchedule`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HardforkInfo {
+    /// The name of the hardfork.
+    pub name: String,
+    /// The condition under which the hardfork activates.
+    pub activation: HardforkActivation,
+    /// Whether the hardfork is active at the current head.
+    pub active: bool,
+}
+
+/// The activation condition of a hardfork.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum HardforkActivation {
+    /// The hardfork activates at the given block.
+    Block {
+        /// The activation block number.
+        block: u64,
+    },
+    /// The hardfork activates once the given total difficulty is reached.
+    #[serde(rename_all = "camelCase")]
+    Ttd {
+        /// The total difficulty after which the hardfork activates.
+        total_difficulty: U256,
+        /// The block number at which the total difficulty is reached, if it is known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fork_block: Option<u64>,
+    },
+    /// The hardfork activates at the given timestamp.
+    Timestamp {
+        /// The activation timestamp.
+        timestamp: u64,
+    },
+    /// The hardfork is never activated.
+    Never,
+}
+
+/// A page of accounts of the state at a block, as returned by `debug_accountRange`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRangeResult {
+    /// The state root of the block the accounts were read at.
+    pub root: B256,
+    /// The accounts of this page, keyed by hashed address.
+    pub accounts: BTreeMap<B256, DumpAccount>,
+    /// The hashed address to start the next page at, `None` if this is the last page.
+    pub next: Option<B256>,
+}
+
+/// A single account of a state dump.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpAccount {
+    /// The balance of the account.
+    pub balance: U256,
+    /// The nonce of the account.
+    pub nonce: u64,
+    /// The storage root of the account.
+    pub root: B256,
+    /// The hash of the account's bytecode.
+    pub code_hash: B256,
+    /// The bytecode of the account, if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+}
+
+/// The receipt field that holds the [`InternalTransfer`]s of a transaction.
+pub const INTERNAL_TRANSFERS_FIELD: &str = "internalTransfers";
+
+/// A value transfer made by an internal call of a transaction, as included in the receipts
+/// returned by `trace_blockReceipts`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransfer {
+    /// The address the value was sent from.
+    pub from: Address,
+    /// The address the value was sent to.
+    pub to: Address,
+    /// The transferred value.
+    pub value: U256,
+}
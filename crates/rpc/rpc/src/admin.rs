@@ -3,12 +3,13 @@ use alloy_primitives::B256;
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_network_api::{NetworkInfo, PeerKind, Peers};
-use reth_network_peers::AnyNode;
+use reth_network_peers::{AnyNode, PeerId};
 use reth_primitives::{ChainSpec, NodeRecord};
 use reth_rpc_api::AdminApiServer;
 use reth_rpc_types::{
     admin::{EthProtocolInfo, NodeInfo, Ports, ProtocolInfo},
-    PeerEthProtocolInfo, PeerInfo, PeerNetworkInfo, PeerProtocolsInfo,
+    PeerEthProtocolInfo, PeerInfo, PeerNetworkInfo, PeerProtocolsInfo, ReputationInfo,
+    ReputationPenalties,
 };
 use std::sync::Arc;
 
@@ -47,17 +48,23 @@ where
     }
 
     /// Handler for `admin_addTrustedPeer`
-    fn add_trusted_peer(&self, record: AnyNode) -> RpcResult<bool> {
+    async fn add_trusted_peer(&self, record: AnyNode) -> RpcResult<bool> {
+        let was_trusted = self.network.is_trusted_peer(record.peer_id()).await.to_rpc_result()?;
+        // the address is updated even if the peer is already trusted
         if let Some(record) = record.node_record() {
             self.network.add_trusted_peer(record.id, record.tcp_addr())
         }
         self.network.add_trusted_peer_id(record.peer_id());
-        Ok(true)
+        Ok(!was_trusted)
     }
 
     /// Handler for `admin_removeTrustedPeer`
-    fn remove_trusted_peer(&self, record: AnyNode) -> RpcResult<bool> {
-        self.network.remove_peer(record.peer_id(), PeerKind::Trusted);
+    async fn remove_trusted_peer(&self, record: AnyNode) -> RpcResult<bool> {
+        let peer_id = record.peer_id();
+        if !self.network.is_trusted_peer(peer_id).await.to_rpc_result()? {
+            return Ok(false)
+        }
+        self.network.remove_peer(peer_id, PeerKind::Trusted);
         Ok(true)
     }
 
@@ -90,6 +97,30 @@ where
         Ok(peers)
     }
 
+    /// Handler for `admin_peerReputation`
+    async fn peer_reputation(&self, peer_id: PeerId) -> RpcResult<Option<ReputationInfo>> {
+        let info = self.network.reputation_info_by_id(peer_id).await.to_rpc_result()?;
+        Ok(info.map(|info| {
+            let penalties = info.penalties;
+            ReputationInfo {
+                reputation: info.reputation,
+                banned: info.banned,
+                penalties: ReputationPenalties {
+                    bad_message: penalties.bad_message,
+                    bad_block: penalties.bad_block,
+                    bad_transactions: penalties.bad_transactions,
+                    bad_announcement: penalties.bad_announcement,
+                    already_seen_transaction: penalties.already_seen_transaction,
+                    timeout: penalties.timeout,
+                    bad_protocol: penalties.bad_protocol,
+                    failed_to_connect: penalties.failed_to_connect,
+                    dropped: penalties.dropped,
+                    other: penalties.other,
+                },
+            }
+        }))
+    }
+
     /// Handler for `admin_nodeInfo`
     async fn node_info(&self) -> RpcResult<NodeInfo> {
         let enode = self.network.local_node_record();
@@ -1,10 +1,11 @@
 use crate::{
     eth::{
         error::{EthApiError, EthResult},
-        revm_utils::{prepare_call_env, EvmOverrides},
+        revm_utils::{apply_transaction_trace_overrides, prepare_call_env, EvmOverrides},
         EthTransactions,
     },
     result::{internal_rpc_err, ToRpcResult},
+    trace_depth::DepthLimitedInspector,
     EthApiSpec,
 };
 use alloy_rlp::{Decodable, Encodable};
@@ -15,29 +16,42 @@ use reth_primitives::{
     TransactionSignedEcRecovered, Withdrawals, B256, U256,
 };
 use reth_provider::{
-    BlockReaderIdExt, ChainSpecProvider, HeaderProvider, StateProviderBox, TransactionVariant,
+    BlockReaderIdExt, ChainSpecProvider, HeaderProvider, ProviderError, StateProvider,
+    StateProviderBox, TransactionVariant,
 };
 use reth_revm::database::StateProviderDatabase;
 use reth_rpc_api::DebugApiServer;
 use reth_rpc_types::{
-    trace::geth::{
-        BlockTraceResult, FourByteFrame, GethDebugBuiltInTracerType, GethDebugTracerType,
-        GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, NoopFrame, TraceResult,
+    trace::{
+        geth::{
+            BlockTraceResult, FourByteFrame, GethDebugBuiltInTracerType, GethDebugTracerType,
+            GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, NoopFrame,
+            TraceResult,
+        },
+        parity::StateDiff,
     },
-    BlockError, Bundle, RichBlock, StateContext, TransactionRequest,
+    AccountRangeResult, BlockError, Bundle, DumpAccount, RichBlock, StateContext,
+    TransactionRequest, TransactionTraceOverrides,
 };
 use reth_tasks::pool::BlockingTaskGuard;
 use revm::{
     db::CacheDB,
-    primitives::{db::DatabaseCommit, BlockEnv, CfgEnvWithHandlerCfg, Env, EnvWithHandlerCfg},
+    primitives::{
+        db::{Database, DatabaseCommit, DatabaseRef},
+        BlockEnv, CfgEnvWithHandlerCfg, Env, EnvWithHandlerCfg, ResultAndState,
+    },
 };
 use revm_inspectors::tracing::{
     js::{JsInspector, TransactionContext},
+    parity::populate_state_diff,
     FourByteInspector, MuxInspector, TracingInspector, TracingInspectorConfig,
 };
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
+/// The maximum number of accounts returned by a single `debug_accountRange` call.
+const MAX_ACCOUNT_RANGE_RESULTS: usize = 256;
+
 /// `debug` API implementation.
 ///
 /// This type provides the functionality for handling `debug` related requests.
@@ -49,8 +63,15 @@ pub struct DebugApi<Provider, Eth> {
 
 impl<Provider, Eth> DebugApi<Provider, Eth> {
     /// Create a new instance of the [`DebugApi`]
-    pub fn new(provider: Provider, eth: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
-        let inner = Arc::new(DebugApiInner { provider, eth_api: eth, blocking_task_guard });
+    ///
+    /// See also [`DebugApiConfig`].
+    pub fn new(
+        provider: Provider,
+        eth: Eth,
+        blocking_task_guard: BlockingTaskGuard,
+        config: DebugApiConfig,
+    ) -> Self {
+        let inner = Arc::new(DebugApiInner { provider, eth_api: eth, blocking_task_guard, config });
         Self { inner }
     }
 
@@ -58,6 +79,19 @@ impl<Provider, Eth> DebugApi<Provider, Eth> {
     pub fn eth_api(&self) -> &Eth {
         &self.inner.eth_api
     }
+
+    /// Returns an error if custom JS tracers are disabled.
+    fn ensure_js_tracers_enabled(&self) -> EthResult<()> {
+        if self.inner.config.disable_js_tracers {
+            return Err(EthApiError::JsTracersDisabled)
+        }
+        Ok(())
+    }
+
+    /// Wraps the inspector, so it only records calls up to the configured maximum trace depth.
+    fn depth_limited<I>(&self, inspector: I) -> DepthLimitedInspector<I> {
+        DepthLimitedInspector::new(inspector, self.inner.config.max_trace_depth)
+    }
 }
 
 // === impl DebugApi ===
@@ -89,7 +123,7 @@ where
         // replay all transactions of the block
         let this = self.clone();
         self.eth_api()
-            .spawn_with_state_at_block(at, move |state| {
+            .spawn_with_state_at_block(at, move |eth, state| {
                 let block_hash = at.as_block_hash();
                 let mut results = Vec::with_capacity(transactions.len());
                 let mut db = CacheDB::new(StateProviderDatabase::new(state));
@@ -102,6 +136,7 @@ where
                         handler_cfg: cfg.handler_cfg,
                     };
                     let (result, state_changes) = this.trace_transaction(
+                        eth,
                         opts.clone(),
                         env,
                         &mut db,
@@ -202,10 +237,14 @@ where
     /// Trace the transaction according to the provided options.
     ///
     /// Ref: <https://geth.ethereum.org/docs/developers/evm-tracing/built-in-tracers>
+    ///
+    /// The overrides are applied to the transaction before it is traced, see
+    /// [`TransactionTraceOverrides`].
     pub async fn debug_trace_transaction(
         &self,
         tx_hash: B256,
         opts: GethDebugTracingOptions,
+        overrides: TransactionTraceOverrides,
     ) -> EthResult<GethTrace> {
         let (transaction, block) = match self.inner.eth_api.transaction_and_block(tx_hash).await? {
             None => return Err(EthApiError::TransactionNotFound),
@@ -222,13 +261,13 @@ where
         let this = self.clone();
         self.inner
             .eth_api
-            .spawn_with_state_at_block(state_at, move |state| {
+            .spawn_with_state_at_block(state_at, move |eth, state| {
                 // configure env for the target transaction
                 let tx = transaction.into_recovered();
 
                 let mut db = CacheDB::new(StateProviderDatabase::new(state));
                 // replay all transactions prior to the targeted transaction
-                let index = this.eth_api().replay_transactions_until(
+                let index = eth.replay_transactions_until(
                     &mut db,
                     cfg.clone(),
                     block_env.clone(),
@@ -236,12 +275,15 @@ where
                     tx.hash,
                 )?;
 
+                let mut tx_env = tx_env_with_recovered(&tx);
+                apply_transaction_trace_overrides(overrides, &mut tx_env);
                 let env = EnvWithHandlerCfg {
-                    env: Env::boxed(cfg.cfg_env.clone(), block_env, tx_env_with_recovered(&tx)),
+                    env: Env::boxed(cfg.cfg_env.clone(), block_env, tx_env),
                     handler_cfg: cfg.handler_cfg,
                 };
 
                 this.trace_transaction(
+                    eth,
                     opts,
                     env,
                     &mut db,
@@ -256,9 +298,73 @@ where
             .await
     }
 
+    /// Re-executes the transaction and returns the state diff it produced.
+    ///
+    /// All transactions prior to the target transaction in its block are replayed first, so the
+    /// diff is computed against the state right before the transaction. The first transaction of
+    /// a block is executed on top of the parent block's state.
+    pub async fn debug_replay_transaction(&self, tx_hash: B256) -> EthResult<StateDiff> {
+        self.inner
+            .eth_api
+            .spawn_replay_transaction(tx_hash, move |_, res, db| transaction_state_diff(&res, &db))
+            .await
+            .transpose()
+            .ok_or(EthApiError::TransactionNotFound)?
+    }
+
+    /// Returns a page of at most `max_results` accounts of the state at the given block, starting
+    /// at the `start` hashed address.
+    ///
+    /// The bytecode of each account is included unless `nocode` is set.
+    pub async fn debug_account_range(
+        &self,
+        block_id: BlockId,
+        start: B256,
+        max_results: usize,
+        nocode: bool,
+    ) -> EthResult<AccountRangeResult> {
+        let header = self
+            .inner
+            .provider
+            .sealed_header_by_id(block_id)?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        let root = header.state_root;
+        let limit = max_results.min(MAX_ACCOUNT_RANGE_RESULTS);
+
+        self.inner
+            .eth_api
+            .spawn_with_state_at_block(header.hash().into(), move |_, state| {
+                let range = state.account_range(start, limit)?;
+                let mut accounts = BTreeMap::new();
+                for entry in range.accounts {
+                    let code_hash = entry.account.get_bytecode_hash();
+                    let code = if nocode || !entry.account.has_bytecode() {
+                        None
+                    } else {
+                        state.bytecode_by_hash(code_hash)?.map(|code| code.original_bytes())
+                    };
+                    accounts.insert(
+                        entry.hashed_address,
+                        DumpAccount {
+                            balance: entry.account.balance,
+                            nonce: entry.account.nonce,
+                            root: entry.storage_root,
+                            code_hash,
+                            code,
+                        },
+                    );
+                }
+                Ok(AccountRangeResult { root, accounts, next: range.next })
+            })
+            .await
+    }
+
     /// The `debug_traceCall` method lets you run an `eth_call` within the context of the given
     /// block execution using the final state of parent block as the base.
     ///
+    /// If a `transaction_index` is provided, the call is executed on top of the state after the
+    /// first `transaction_index` transactions of the block instead.
+    ///
     /// Differences compare to `eth_call`:
     ///  - `debug_traceCall` executes with __enabled__ basefee check, `eth_call` does not: <https://github.com/paradigmxyz/reth/issues/6240>
     pub async fn debug_trace_call(
@@ -266,6 +372,7 @@ where
         call: TransactionRequest,
         block_id: Option<BlockId>,
         opts: GethDebugTracingCallOptions,
+        transaction_index: Option<usize>,
     ) -> EthResult<GethTrace> {
         let at = block_id.unwrap_or_default();
         let GethDebugTracingCallOptions { tracing_options, state_overrides, block_overrides } =
@@ -273,7 +380,6 @@ where
         let overrides = EvmOverrides::new(state_overrides, block_overrides.map(Box::new));
         let GethDebugTracingOptions { config, tracer, tracer_config, .. } = tracing_options;
 
-        let this = self.clone();
         if let Some(tracer) = tracer {
             return match tracer {
                 GethDebugTracerType::BuiltInTracer(tracer) => match tracer {
@@ -282,10 +388,16 @@ where
                         let inspector = self
                             .inner
                             .eth_api
-                            .spawn_with_call_at(call, at, overrides, move |db, env| {
-                                this.eth_api().inspect(db, env, &mut inspector)?;
-                                Ok(inspector)
-                            })
+                            .spawn_with_call_at_transaction_index(
+                                call,
+                                at,
+                                transaction_index,
+                                overrides,
+                                move |eth, db, env| {
+                                    eth.inspect(db, env, &mut inspector)?;
+                                    Ok(inspector)
+                                },
+                            )
                             .await?;
                         return Ok(FourByteFrame::from(inspector).into())
                     }
@@ -294,20 +406,27 @@ where
                             .into_call_config()
                             .map_err(|_| EthApiError::InvalidTracerConfig)?;
 
-                        let mut inspector = TracingInspector::new(
+                        let mut inspector = self.depth_limited(TracingInspector::new(
                             TracingInspectorConfig::from_geth_call_config(&call_config),
-                        );
+                        ));
 
                         let frame = self
                             .inner
                             .eth_api
-                            .spawn_with_call_at(call, at, overrides, move |db, env| {
-                                let (res, _) = this.eth_api().inspect(db, env, &mut inspector)?;
-                                let frame = inspector
-                                    .into_geth_builder()
-                                    .geth_call_traces(call_config, res.result.gas_used());
-                                Ok(frame.into())
-                            })
+                            .spawn_with_call_at_transaction_index(
+                                call,
+                                at,
+                                transaction_index,
+                                overrides,
+                                move |eth, db, env| {
+                                    let (res, _) = eth.inspect(db, env, &mut inspector)?;
+                                    let (inspector, truncated) = inspector.into_parts();
+                                    let frame = inspector
+                                        .into_geth_builder()
+                                        .geth_call_traces(call_config, res.result.gas_used());
+                                    truncated.call_trace(frame)
+                                },
+                            )
                             .await?;
                         return Ok(frame)
                     }
@@ -319,18 +438,23 @@ where
                             TracingInspectorConfig::from_geth_prestate_config(&prestate_config),
                         );
 
-                        let frame =
-                            self.inner
-                                .eth_api
-                                .spawn_with_call_at(call, at, overrides, move |db, env| {
-                                    let (res, _) =
-                                        this.eth_api().inspect(&mut *db, env, &mut inspector)?;
+                        let frame = self
+                            .inner
+                            .eth_api
+                            .spawn_with_call_at_transaction_index(
+                                call,
+                                at,
+                                transaction_index,
+                                overrides,
+                                move |eth, db, env| {
+                                    let (res, _) = eth.inspect(&mut *db, env, &mut inspector)?;
                                     let frame = inspector
                                         .into_geth_builder()
                                         .geth_prestate_traces(&res, prestate_config, db)?;
                                     Ok(frame)
-                                })
-                                .await?;
+                                },
+                            )
+                            .await?;
                         return Ok(frame.into())
                     }
                     GethDebugBuiltInTracerType::NoopTracer => Ok(NoopFrame::default().into()),
@@ -339,22 +463,27 @@ where
                             .into_mux_config()
                             .map_err(|_| EthApiError::InvalidTracerConfig)?;
 
-                        let mut inspector = MuxInspector::try_from_config(mux_config)?;
+                        let inspector =
+                            self.depth_limited(MuxInspector::try_from_config(mux_config)?);
 
                         let frame = self
                             .inner
                             .eth_api
-                            .spawn_with_call_at(call, at, overrides, move |db, env| {
-                                let (res, _) =
-                                    this.eth_api().inspect(&mut *db, env, &mut inspector)?;
-                                let frame = inspector.try_into_mux_frame(&res, db)?;
-                                Ok(frame.into())
-                            })
+                            .spawn_with_call_at_transaction_index(
+                                call,
+                                at,
+                                transaction_index,
+                                overrides,
+                                move |eth, db, env| {
+                                    Self::mux_trace(eth, inspector, env, db).map(|(trace, _)| trace)
+                                },
+                            )
                             .await?;
                         return Ok(frame)
                     }
                 },
                 GethDebugTracerType::JsTracer(code) => {
+                    self.ensure_js_tracers_enabled()?;
                     let config = tracer_config.into_json();
 
                     let (_, _, at) = self.inner.eth_api.evm_env_at(at).await?;
@@ -362,12 +491,18 @@ where
                     let res = self
                         .inner
                         .eth_api
-                        .spawn_with_call_at(call, at, overrides, move |db, env| {
-                            let mut inspector = JsInspector::new(code, config)?;
-                            let (res, _) =
-                                this.eth_api().inspect(&mut *db, env.clone(), &mut inspector)?;
-                            Ok(inspector.json_result(res, &env, db)?)
-                        })
+                        .spawn_with_call_at_transaction_index(
+                            call,
+                            at,
+                            transaction_index,
+                            overrides,
+                            move |eth, db, env| {
+                                let mut inspector = JsInspector::new(code, config)?;
+                                let (res, _) =
+                                    eth.inspect(&mut *db, env.clone(), &mut inspector)?;
+                                Ok(inspector.json_result(res, &env, db)?)
+                            },
+                        )
                         .await?;
 
                     Ok(GethTrace::JS(res))
@@ -383,10 +518,16 @@ where
         let (res, inspector) = self
             .inner
             .eth_api
-            .spawn_with_call_at(call, at, overrides, move |db, env| {
-                let (res, _) = this.eth_api().inspect(db, env, &mut inspector)?;
-                Ok((res, inspector))
-            })
+            .spawn_with_call_at_transaction_index(
+                call,
+                at,
+                transaction_index,
+                overrides,
+                move |eth, db, env| {
+                    let (res, _) = eth.inspect(db, env, &mut inspector)?;
+                    Ok((res, inspector))
+                },
+            )
             .await?;
         let gas_used = res.result.gas_used();
         let return_value = res.result.into_output().unwrap_or_default();
@@ -440,7 +581,7 @@ where
         let this = self.clone();
         self.inner
             .eth_api
-            .spawn_with_state_at_block(at.into(), move |state| {
+            .spawn_with_state_at_block(at.into(), move |eth, state| {
                 // the outer vec for the bundles
                 let mut all_bundles = Vec::with_capacity(bundles.len());
                 let mut db = CacheDB::new(StateProviderDatabase::new(state));
@@ -457,7 +598,7 @@ where
                             env: Env::boxed(cfg.cfg_env.clone(), block_env.clone(), tx),
                             handler_cfg: cfg.handler_cfg,
                         };
-                        let (res, _) = this.inner.eth_api.transact(&mut db, env)?;
+                        let (res, _) = eth.transact(&mut db, env)?;
                         db.commit(res.state);
                     }
                 }
@@ -485,8 +626,13 @@ where
                             overrides,
                         )?;
 
-                        let (trace, state) =
-                            this.trace_transaction(tracing_options.clone(), env, &mut db, None)?;
+                        let (trace, state) = this.trace_transaction(
+                            eth,
+                            tracing_options.clone(),
+                            env,
+                            &mut db,
+                            None,
+                        )?;
 
                         // If there is more transactions, commit the database
                         // If there is no transactions, but more bundles, commit to the database too
@@ -506,6 +652,23 @@ where
             .await
     }
 
+    /// Executes the transaction once with the inspector of the `muxTracer`, so that all of its
+    /// tracers record the same execution.
+    fn mux_trace<DB>(
+        eth: &Eth,
+        mut inspector: DepthLimitedInspector<MuxInspector>,
+        env: EnvWithHandlerCfg,
+        db: &mut DB,
+    ) -> EthResult<(GethTrace, revm_primitives::EvmState)>
+    where
+        DB: Database<Error = ProviderError> + DatabaseRef<Error = ProviderError>,
+    {
+        let (res, _) = eth.inspect(&mut *db, env, &mut inspector)?;
+        let (inspector, truncated) = inspector.into_parts();
+        let frame = inspector.try_into_mux_frame(&res, db)?;
+        Ok((truncated.mux_trace(frame)?, res.state))
+    }
+
     /// Executes the configured transaction with the environment on the given database.
     ///
     /// Returns the trace frame and the state that got updated after executing the transaction.
@@ -515,6 +678,7 @@ where
     /// Caution: this is blocking and should be performed on a blocking task.
     fn trace_transaction(
         &self,
+        eth: &Eth,
         opts: GethDebugTracingOptions,
         env: EnvWithHandlerCfg,
         db: &mut CacheDB<StateProviderDatabase<StateProviderBox>>,
@@ -527,7 +691,7 @@ where
                 GethDebugTracerType::BuiltInTracer(tracer) => match tracer {
                     GethDebugBuiltInTracerType::FourByteTracer => {
                         let mut inspector = FourByteInspector::default();
-                        let (res, _) = self.eth_api().inspect(db, env, &mut inspector)?;
+                        let (res, _) = eth.inspect(db, env, &mut inspector)?;
                         return Ok((FourByteFrame::from(inspector).into(), res.state))
                     }
                     GethDebugBuiltInTracerType::CallTracer => {
@@ -535,17 +699,18 @@ where
                             .into_call_config()
                             .map_err(|_| EthApiError::InvalidTracerConfig)?;
 
-                        let mut inspector = TracingInspector::new(
+                        let mut inspector = self.depth_limited(TracingInspector::new(
                             TracingInspectorConfig::from_geth_call_config(&call_config),
-                        );
+                        ));
 
-                        let (res, _) = self.eth_api().inspect(db, env, &mut inspector)?;
+                        let (res, _) = eth.inspect(db, env, &mut inspector)?;
 
+                        let (inspector, truncated) = inspector.into_parts();
                         let frame = inspector
                             .into_geth_builder()
                             .geth_call_traces(call_config, res.result.gas_used());
 
-                        return Ok((frame.into(), res.state))
+                        return Ok((truncated.call_trace(frame)?, res.state))
                     }
                     GethDebugBuiltInTracerType::PreStateTracer => {
                         let prestate_config = tracer_config
@@ -555,7 +720,7 @@ where
                         let mut inspector = TracingInspector::new(
                             TracingInspectorConfig::from_geth_prestate_config(&prestate_config),
                         );
-                        let (res, _) = self.eth_api().inspect(&mut *db, env, &mut inspector)?;
+                        let (res, _) = eth.inspect(&mut *db, env, &mut inspector)?;
 
                         let frame = inspector.into_geth_builder().geth_prestate_traces(
                             &res,
@@ -573,21 +738,20 @@ where
                             .into_mux_config()
                             .map_err(|_| EthApiError::InvalidTracerConfig)?;
 
-                        let mut inspector = MuxInspector::try_from_config(mux_config)?;
-
-                        let (res, _) = self.eth_api().inspect(&mut *db, env, &mut inspector)?;
-                        let frame = inspector.try_into_mux_frame(&res, db)?;
-                        return Ok((frame.into(), res.state))
+                        let inspector =
+                            self.depth_limited(MuxInspector::try_from_config(mux_config)?);
+                        return Self::mux_trace(eth, inspector, env, db)
                     }
                 },
                 GethDebugTracerType::JsTracer(code) => {
+                    self.ensure_js_tracers_enabled()?;
                     let config = tracer_config.into_json();
                     let mut inspector = JsInspector::with_transaction_context(
                         code,
                         config,
                         transaction_context.unwrap_or_default(),
                     )?;
-                    let (res, env) = self.eth_api().inspect(&mut *db, env, &mut inspector)?;
+                    let (res, env) = eth.inspect(&mut *db, env, &mut inspector)?;
 
                     let state = res.state.clone();
                     let result = inspector.json_result(res, &env, db)?;
@@ -601,7 +765,7 @@ where
 
         let mut inspector = TracingInspector::new(inspector_config);
 
-        let (res, _) = self.eth_api().inspect(db, env, &mut inspector)?;
+        let (res, _) = eth.inspect(db, env, &mut inspector)?;
         let gas_used = res.result.gas_used();
         let return_value = res.result.into_output().unwrap_or_default();
         let frame = inspector.into_geth_builder().geth_traces(gas_used, return_value, config);
@@ -677,13 +841,16 @@ where
     }
 
     /// Handler for `debug_getRawReceipts`
+    ///
+    /// Returns the EIP-2718 encoded receipts in transaction order, or an error if the block is
+    /// unknown.
     async fn raw_receipts(&self, block_id: BlockId) -> RpcResult<Vec<Bytes>> {
         Ok(self
             .inner
             .provider
             .receipts_by_block_id(block_id)
             .to_rpc_result()?
-            .unwrap_or_default()
+            .ok_or(EthApiError::UnknownBlockNumber)?
             .into_iter()
             .map(|receipt| receipt.with_bloom().envelope_encoded())
             .collect())
@@ -738,9 +905,22 @@ where
         &self,
         tx_hash: B256,
         opts: Option<GethDebugTracingOptions>,
+        overrides: Option<TransactionTraceOverrides>,
     ) -> RpcResult<GethTrace> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::debug_trace_transaction(self, tx_hash, opts.unwrap_or_default()).await?)
+        Ok(Self::debug_trace_transaction(
+            self,
+            tx_hash,
+            opts.unwrap_or_default(),
+            overrides.unwrap_or_default(),
+        )
+        .await?)
+    }
+
+    /// Handler for `debug_replayTransaction`
+    async fn debug_replay_transaction(&self, tx_hash: B256) -> RpcResult<StateDiff> {
+        let _permit = self.acquire_trace_permit().await;
+        Ok(Self::debug_replay_transaction(self, tx_hash).await?)
     }
 
     /// Handler for `debug_traceCall`
@@ -749,9 +929,17 @@ where
         request: TransactionRequest,
         block_number: Option<BlockId>,
         opts: Option<GethDebugTracingCallOptions>,
+        transaction_index: Option<usize>,
     ) -> RpcResult<GethTrace> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::debug_trace_call(self, request, block_number, opts.unwrap_or_default()).await?)
+        Ok(Self::debug_trace_call(
+            self,
+            request,
+            block_number,
+            opts.unwrap_or_default(),
+            transaction_index,
+        )
+        .await?)
     }
 
     async fn debug_trace_call_many(
@@ -768,16 +956,28 @@ where
         Ok(())
     }
 
+    /// Handler for `debug_accountRange`
     async fn debug_account_range(
         &self,
-        _block_number: BlockNumberOrTag,
-        _start: Bytes,
-        _max_results: u64,
-        _nocode: bool,
+        block_id: BlockId,
+        start: Bytes,
+        max_results: u64,
+        nocode: bool,
         _nostorage: bool,
         _incompletes: bool,
-    ) -> RpcResult<()> {
-        Ok(())
+    ) -> RpcResult<AccountRangeResult> {
+        if start.len() > 32 {
+            return Err(EthApiError::InvalidParams(
+                "start key must not be longer than 32 bytes".to_string(),
+            )
+            .into())
+        }
+        // shorter keys are right padded, same as geth
+        let mut key = B256::ZERO;
+        key[..start.len()].copy_from_slice(&start);
+
+        let max_results = usize::try_from(max_results).unwrap_or(usize::MAX);
+        Ok(Self::debug_account_range(self, block_id, key, max_results, nocode).await?)
     }
 
     async fn debug_block_profile(&self, _file: String, _seconds: u64) -> RpcResult<()> {
@@ -995,4 +1195,421 @@ struct DebugApiInner<Provider, Eth> {
     eth_api: Eth,
     // restrict the number of concurrent calls to blocking calls
     blocking_task_guard: BlockingTaskGuard,
+    /// Limits of the `debug` namespace.
+    config: DebugApiConfig,
+}
+
+/// Settings for the [`DebugApi`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DebugApiConfig {
+    /// The maximum depth of calls recorded by the `callTracer` and `muxTracer`.
+    ///
+    /// Calls nested deeper than this are executed, but not recorded. If `None` then no limit is
+    /// enforced.
+    pub max_trace_depth: Option<usize>,
+    /// Whether custom JS tracers are rejected, while the native tracers remain available.
+    pub disable_js_tracers: bool,
+}
+
+impl DebugApiConfig {
+    /// Sets the maximum depth of calls recorded by the `callTracer` and `muxTracer`.
+    pub const fn max_trace_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_trace_depth = max_depth;
+        self
+    }
+
+    /// Sets whether custom JS tracers are rejected.
+    pub const fn disable_js_tracers(mut self, disable_js_tracers: bool) -> Self {
+        self.disable_js_tracers = disable_js_tracers;
+        self
+    }
+}
+
+/// Computes the [`StateDiff`] of an executed transaction against the state it was executed on.
+fn transaction_state_diff<DB>(res: &ResultAndState, db: &DB) -> EthResult<StateDiff>
+where
+    DB: DatabaseRef,
+    EthApiError: From<DB::Error>,
+{
+    let mut state_diff = StateDiff::default();
+    populate_state_diff(&mut state_diff, db, res.state.iter())?;
+    Ok(state_diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{
+        cache::EthStateCache, gas_oracle::GasPriceOracle, EthApi, FeeHistoryCache,
+        FeeHistoryCacheConfig,
+    };
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_primitives::{
+        bytes,
+        constants::{ETHEREUM_BLOCK_GAS_LIMIT, ETH_TO_WEI},
+        Block, Header, Receipt, Transaction, TxKind, TxLegacy, TxType, U64,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_rpc_types::trace::{
+        geth::{GethDebugTracerConfig, MuxFrame},
+        parity::{ChangedType, Delta},
+    };
+    use reth_tasks::pool::BlockingTaskPool;
+    use reth_testing_utils::generators::{self, generate_keys, sign_tx_with_key_pair};
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+    use revm::primitives::{AccountInfo, Bytecode, CfgEnv, SpecId, TxEnv};
+    use std::collections::HashMap;
+
+    #[test]
+    fn replayed_transfer_state_diff() {
+        let sender = Address::random();
+        let recipient = Address::random();
+        let balance = U256::from(ETH_TO_WEI);
+        let provider = MockEthProvider::default();
+        provider.add_account(sender, ExtendedAccount::new(0, balance));
+
+        let value = U256::from(1_000);
+        let gas_price = U256::from(10);
+        let env = EnvWithHandlerCfg::new_with_cfg_env(
+            CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::CANCUN),
+            BlockEnv::default(),
+            TxEnv {
+                caller: sender,
+                transact_to: TxKind::Call(recipient),
+                value,
+                gas_limit: 21_000,
+                gas_price,
+                ..Default::default()
+            },
+        );
+
+        let mut db = CacheDB::new(StateProviderDatabase::new(provider));
+        let res = revm::Evm::builder()
+            .with_db(&mut db)
+            .with_env_with_handler_cfg(env)
+            .build()
+            .transact()
+            .unwrap();
+        assert!(res.result.is_success());
+        assert_eq!(res.result.gas_used(), 21_000);
+
+        let state_diff = transaction_state_diff(&res, &db).unwrap();
+
+        // the sender pays the value and 21000 gas at a price of 10, the recipient is created with
+        // the value and the coinbase receives the fee, since there's no base fee
+        assert_eq!(
+            state_diff.0[&sender].balance,
+            Delta::Changed(ChangedType {
+                from: balance,
+                to: U256::from(ETH_TO_WEI - 1_000 - 210_000)
+            })
+        );
+        assert_eq!(
+            state_diff.0[&sender].nonce,
+            Delta::Changed(ChangedType { from: U64::ZERO, to: U64::from(1) })
+        );
+        assert_eq!(state_diff.0[&recipient].balance, Delta::Added(value));
+        assert_eq!(state_diff.0[&Address::ZERO].balance, Delta::Added(U256::from(210_000)));
+    }
+
+    type TestDebugApi =
+        DebugApi<MockEthProvider, EthApi<MockEthProvider, TestPool, (), EthEvmConfig>>;
+
+    /// Returns a [`DebugApi`] over the given provider and the hash of a block to trace at.
+    fn debug_api(provider: MockEthProvider, disable_js_tracers: bool) -> (TestDebugApi, B256) {
+        let header =
+            Header { number: 1, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+        let block_hash = header.hash_slow();
+        provider.add_block(block_hash, Block { header, ..Default::default() });
+
+        let evm_config = EthEvmConfig::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default(), evm_config);
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache.clone()),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            FeeHistoryCache::new(cache, FeeHistoryCacheConfig::default()),
+            evm_config,
+            None,
+        );
+        let config = DebugApiConfig::default().disable_js_tracers(disable_js_tracers);
+        let api = DebugApi::new(provider, eth_api, BlockingTaskGuard::new(1), config);
+        (api, block_hash)
+    }
+
+    #[tokio::test]
+    async fn raw_receipts_in_transaction_order() {
+        let provider = MockEthProvider::default();
+        let (api, block_hash) = debug_api(provider.clone(), false);
+
+        let receipts = vec![
+            Receipt {
+                tx_type: TxType::Legacy,
+                success: true,
+                cumulative_gas_used: 21_000,
+                ..Default::default()
+            },
+            Receipt {
+                tx_type: TxType::Eip1559,
+                success: false,
+                cumulative_gas_used: 42_000,
+                ..Default::default()
+            },
+        ];
+        provider.add_receipts(block_hash, receipts.clone());
+
+        let raw = api.raw_receipts(block_hash.into()).await.unwrap();
+        let expected = receipts
+            .into_iter()
+            .map(|receipt| receipt.with_bloom().envelope_encoded())
+            .collect::<Vec<_>>();
+        assert_eq!(raw, expected);
+        // typed receipts are prefixed with the transaction type
+        assert_eq!(raw[1][0], TxType::Eip1559 as u8);
+
+        assert!(api.raw_receipts(B256::random().into()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn retraces_out_of_gas_transaction_with_overrides() {
+        let contract = Address::random();
+        let other = Address::random();
+        let provider = MockEthProvider::default();
+        // PUSH1 1, PUSH1 0, SSTORE, STOP
+        provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("600160005500")),
+        );
+        provider.add_account(other, ExtendedAccount::new(5, U256::from(ETH_TO_WEI)));
+        let (api, parent_hash) = debug_api(provider.clone(), false);
+
+        // the SSTORE does not fit into the gas limit of the transaction
+        let key_pair = generate_keys(&mut generators::rng(), 1)[0];
+        let transaction = sign_tx_with_key_pair(
+            key_pair,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                gas_price: 1,
+                gas_limit: 30_000,
+                to: TxKind::Call(contract),
+                ..Default::default()
+            }),
+        );
+        let sender = transaction.recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+        let tx_hash = transaction.hash();
+        let header = Header {
+            number: 2,
+            parent_hash,
+            gas_limit: ETHEREUM_BLOCK_GAS_LIMIT,
+            ..Default::default()
+        };
+        provider.add_block(
+            header.hash_slow(),
+            Block { header, body: vec![transaction], ..Default::default() },
+        );
+
+        let trace =
+            |overrides| DebugApiServer::debug_trace_transaction(&api, tx_hash, None, overrides);
+
+        let GethTrace::Default(frame) = trace(None).await.unwrap() else {
+            panic!("expected struct logs")
+        };
+        assert!(frame.failed);
+        assert_eq!(frame.gas, 30_000);
+
+        let overrides = TransactionTraceOverrides { gas: Some(100_000), ..Default::default() };
+        let GethTrace::Default(frame) = trace(Some(overrides)).await.unwrap() else {
+            panic!("expected struct logs")
+        };
+        assert!(!frame.failed);
+        assert!(frame.gas > 30_000 && frame.gas < 100_000);
+
+        // the nonce of the original sender does not apply to the overridden sender
+        let overrides = TransactionTraceOverrides { from: Some(other), gas: Some(100_000) };
+        let GethTrace::Default(frame) = trace(Some(overrides)).await.unwrap() else {
+            panic!("expected struct logs")
+        };
+        assert!(!frame.failed);
+    }
+
+    #[tokio::test]
+    async fn rejects_js_tracers_if_disabled() {
+        let (api, block_hash) = debug_api(MockEthProvider::default(), true);
+
+        let trace_call = |tracer| {
+            let opts = GethDebugTracingCallOptions {
+                tracing_options: GethDebugTracingOptions {
+                    tracer: Some(tracer),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            api.debug_trace_call(
+                TransactionRequest { to: Some(Address::random().into()), ..Default::default() },
+                Some(block_hash.into()),
+                opts,
+                None,
+            )
+        };
+
+        let js_tracer = "{data: [], fault: function(log) {}, result: function() { return 1; }}";
+        let err = trace_call(GethDebugTracerType::JsTracer(js_tracer.to_string())).await;
+        assert!(matches!(err, Err(EthApiError::JsTracersDisabled)));
+
+        let trace =
+            trace_call(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer))
+                .await
+                .unwrap();
+        assert!(matches!(trace, GethTrace::CallTracer(_)));
+    }
+
+    #[tokio::test]
+    async fn mux_tracer_combines_native_tracers() {
+        let contract = Address::random();
+        let provider = MockEthProvider::default();
+        // PUSH1 1, PUSH1 0, SSTORE, STOP
+        provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("600160005500")),
+        );
+        let (api, block_hash) = debug_api(provider, false);
+
+        let trace_call = |tracer, tracer_config| {
+            let opts = GethDebugTracingCallOptions {
+                tracing_options: GethDebugTracingOptions {
+                    tracer: Some(GethDebugTracerType::BuiltInTracer(tracer)),
+                    tracer_config,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            api.debug_trace_call(
+                TransactionRequest { to: Some(contract.into()), ..Default::default() },
+                Some(block_hash.into()),
+                opts,
+                None,
+            )
+        };
+
+        let call_trace =
+            trace_call(GethDebugBuiltInTracerType::CallTracer, Default::default()).await.unwrap();
+        let prestate_trace =
+            trace_call(GethDebugBuiltInTracerType::PreStateTracer, Default::default())
+                .await
+                .unwrap();
+
+        // both tracers are driven by the same execution of the transaction
+        let mux_config =
+            GethDebugTracerConfig(serde_json::json!({ "callTracer": {}, "prestateTracer": {} }));
+        let trace = trace_call(GethDebugBuiltInTracerType::MuxTracer, mux_config).await.unwrap();
+        let GethTrace::MuxTracer(MuxFrame(mut frames)) = trace else {
+            panic!("expected mux frame, got {trace:?}")
+        };
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames.remove(&GethDebugBuiltInTracerType::CallTracer), Some(call_trace));
+        assert_eq!(
+            frames.remove(&GethDebugBuiltInTracerType::PreStateTracer),
+            Some(prestate_trace)
+        );
+    }
+
+    /// A database that counts how often each account is loaded by the EVM.
+    struct CountingDb {
+        inner: CacheDB<StateProviderDatabase<MockEthProvider>>,
+        loaded: HashMap<Address, usize>,
+    }
+
+    impl Database for CountingDb {
+        type Error = ProviderError;
+
+        fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+            *self.loaded.entry(address).or_default() += 1;
+            self.inner.basic(address)
+        }
+
+        fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+            self.inner.code_by_hash(code_hash)
+        }
+
+        fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+            self.inner.storage(address, index)
+        }
+
+        fn block_hash(&mut self, number: U256) -> Result<B256, Self::Error> {
+            self.inner.block_hash(number)
+        }
+    }
+
+    impl DatabaseRef for CountingDb {
+        type Error = ProviderError;
+
+        fn basic_ref(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+            self.inner.basic_ref(address)
+        }
+
+        fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+            self.inner.code_by_hash_ref(code_hash)
+        }
+
+        fn storage_ref(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+            self.inner.storage_ref(address, index)
+        }
+
+        fn block_hash_ref(&self, number: U256) -> Result<B256, Self::Error> {
+            self.inner.block_hash_ref(number)
+        }
+    }
+
+    #[tokio::test]
+    async fn mux_tracer_executes_transaction_once() {
+        let sender = Address::random();
+        let contract = Address::random();
+        let provider = MockEthProvider::default();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+        // PUSH1 1, PUSH1 0, SSTORE, STOP
+        provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("600160005500")),
+        );
+        let (api, _) = debug_api(provider.clone(), false);
+        let eth = &api.inner.eth_api;
+
+        let env = EnvWithHandlerCfg::new_with_cfg_env(
+            CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::CANCUN),
+            BlockEnv::default(),
+            TxEnv {
+                caller: sender,
+                transact_to: TxKind::Call(contract),
+                gas_limit: 100_000,
+                ..Default::default()
+            },
+        );
+        let db = || CountingDb {
+            inner: CacheDB::new(StateProviderDatabase::new(provider.clone())),
+            loaded: HashMap::new(),
+        };
+
+        // the sender is loaded once per execution
+        let mut plain = db();
+        eth.transact(&mut plain, env.clone()).unwrap();
+        assert_eq!(plain.loaded[&sender], 1);
+
+        let mux_config =
+            GethDebugTracerConfig(serde_json::json!({ "callTracer": {}, "prestateTracer": {} }))
+                .into_mux_config()
+                .unwrap();
+        let inspector = api.depth_limited(MuxInspector::try_from_config(mux_config).unwrap());
+        let mut mux = db();
+        let (trace, _) = TestDebugApi::mux_trace(eth, inspector, env, &mut mux).unwrap();
+        let GethTrace::MuxTracer(MuxFrame(frames)) = trace else {
+            panic!("expected mux frame, got {trace:?}")
+        };
+        assert_eq!(frames.len(), 2);
+        assert_eq!(mux.loaded, plain.loaded);
+    }
 }
@@ -63,9 +63,10 @@ where
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
+        transaction_index: Option<usize>,
     ) -> Result<Bytes> {
         self.eth
-            .call(request, block_number, state_overrides, block_overrides)
+            .call(request, block_number, state_overrides, block_overrides, transaction_index)
             .instrument(engine_span!())
             .await
     }
@@ -72,8 +72,27 @@ where
         block_number: Option<BlockId>,
         overrides: EvmOverrides,
     ) -> EthResult<Bytes> {
-        let (res, _env) =
-            self.transact_call_at(request, block_number.unwrap_or_default(), overrides).await?;
+        self.call_at_transaction_index(request, block_number, None, overrides).await
+    }
+
+    /// Same as [`Self::call`], but if a `transaction_index` is provided, the call is executed on
+    /// top of the state after the first `transaction_index` transactions of the block.
+    pub async fn call_at_transaction_index(
+        &self,
+        request: TransactionRequest,
+        block_number: Option<BlockId>,
+        transaction_index: Option<usize>,
+        overrides: EvmOverrides,
+    ) -> EthResult<Bytes> {
+        let (res, _env) = self
+            .spawn_with_call_at_transaction_index(
+                request,
+                block_number.unwrap_or_default(),
+                transaction_index,
+                overrides,
+                move |this, db, env| this.transact(db, env),
+            )
+            .await?;
 
         ensure_success(res.result)
     }
@@ -119,8 +138,7 @@ where
             replay_block_txs = false;
         }
 
-        let this = self.clone();
-        self.spawn_with_state_at_block(at.into(), move |state| {
+        self.spawn_with_state_at_block(at.into(), move |this, state| {
             let mut results = Vec::with_capacity(transactions.len());
             let mut db = CacheDB::new(StateProviderDatabase::new(state));
 
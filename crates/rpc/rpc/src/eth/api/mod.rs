@@ -7,6 +7,7 @@ use crate::eth::{
         pending_block::{PendingBlock, PendingBlockEnv, PendingBlockEnvOrigin},
     },
     cache::EthStateCache,
+    cancellation::RequestCancellation,
     error::{EthApiError, EthResult},
     gas_oracle::GasPriceOracle,
     signer::EthSigner,
@@ -18,8 +19,7 @@ use reth_evm::ConfigureEvm;
 use reth_network_api::NetworkInfo;
 use reth_primitives::{
     revm_primitives::{BlockEnv, CfgEnvWithHandlerCfg},
-    Address, BlockId, BlockNumberOrTag, ChainInfo, SealedBlockWithSenders, SealedHeader, B256,
-    U256, U64,
+    Address, BlockId, BlockNumberOrTag, ChainInfo, SealedBlockWithSenders, B256, U256, U64,
 };
 use reth_provider::{
     BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, StateProviderBox, StateProviderFactory,
@@ -86,9 +86,20 @@ pub trait EthApiSpec: EthTransactions + Send + Sync {
 pub struct EthApi<Provider, Pool, Network, EvmConfig> {
     /// All nested fields bundled together.
     inner: Arc<EthApiInner<Provider, Pool, Network, EvmConfig>>,
+    /// The cancellation of the request this instance executes, if any.
+    ///
+    /// If set, inspected EVM executions of this instance are aborted once the request is
+    /// cancelled. Executions without an inspector are only aborted if the instance was created
+    /// with `cancel_dropped_calls`.
+    cancellation: Option<RequestCancellation>,
 }
 
 impl<Provider, Pool, Network, EvmConfig> EthApi<Provider, Pool, Network, EvmConfig> {
+    /// Returns an instance that aborts its EVM executions once the given request is cancelled.
+    pub(crate) fn with_cancellation(&self, cancellation: RequestCancellation) -> Self {
+        Self { inner: Arc::clone(&self.inner), cancellation: Some(cancellation) }
+    }
+
     /// Sets a forwarder for `eth_sendRawTransaction`
     ///
     /// Note: this might be removed in the future in favor of a more generic approach.
@@ -127,10 +138,14 @@ where
             fee_history_cache,
             evm_config,
             raw_transaction_forwarder,
+            false,
         )
     }
 
     /// Creates a new, shareable instance.
+    ///
+    /// If `cancel_dropped_calls` is set, executions without an inspector, like `eth_call`, are
+    /// inspected as well, so they can be aborted once their request is dropped.
     #[allow(clippy::too_many_arguments)]
     pub fn with_spawner(
         provider: Provider,
@@ -144,6 +159,7 @@ where
         fee_history_cache: FeeHistoryCache,
         evm_config: EvmConfig,
         raw_transaction_forwarder: Option<Arc<dyn RawTransactionForwarder>>,
+        cancel_dropped_calls: bool,
     ) -> Self {
         // get the block number of the latest block
         let latest_block = provider
@@ -168,9 +184,10 @@ where
             fee_history_cache,
             evm_config,
             raw_transaction_forwarder: parking_lot::RwLock::new(raw_transaction_forwarder),
+            cancel_dropped_calls,
         };
 
-        Self { inner: Arc::new(inner) }
+        Self { inner: Arc::new(inner), cancellation: None }
     }
 
     /// Executes the future on a new blocking task.
@@ -179,6 +196,10 @@ where
     /// future onto a new task that is allowed to block.
     ///
     /// Note: This is expected for futures that are dominated by blocking IO operations.
+    ///
+    /// The closure is called with an instance that carries the cancellation of the request: if
+    /// the returned future is dropped before the task completed, EVM executions of that instance
+    /// are aborted.
     pub(crate) async fn on_blocking_task<C, F, R>(&self, c: C) -> EthResult<R>
     where
         C: FnOnce(Self) -> F,
@@ -186,13 +207,16 @@ where
         R: Send + 'static,
     {
         let (tx, rx) = oneshot::channel();
-        let this = self.clone();
-        let f = c(this);
+        let cancellation = RequestCancellation::default();
+        let cancel_on_drop = cancellation.cancel_on_drop();
+        let f = c(self.with_cancellation(cancellation));
         self.inner.task_spawner.spawn_blocking(Box::pin(async move {
             let res = f.await;
             let _ = tx.send(res);
         }));
-        rx.await.map_err(|_| EthApiError::InternalEthError)?
+        let res = rx.await;
+        cancel_on_drop.disarm();
+        res.map_err(|_| EthApiError::InternalEthError)?
     }
 
     /// Returns the state cache frontend
@@ -282,49 +306,30 @@ where
     ///
     /// If no pending block is available, this will derive it from the `latest` block
     pub(crate) fn pending_block_env_and_cfg(&self) -> EthResult<PendingBlockEnv> {
-        let origin: PendingBlockEnvOrigin = if let Some(pending) =
-            self.provider().pending_block_with_senders()?
-        {
+        let mut cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
+        let mut block_env = BlockEnv::default();
+
+        let origin = if let Some(pending) = self.provider().pending_block_with_senders()? {
+            // Note: for the PENDING block we assume it is past the known merge block and thus this
+            // will not fail when looking up the total difficulty value for the blockenv.
+            self.provider().fill_env_with_header(
+                &mut cfg,
+                &mut block_env,
+                &pending.header,
+                self.inner.evm_config.clone(),
+            )?;
             PendingBlockEnvOrigin::ActualPending(pending)
         } else {
-            // no pending block from the CL yet, so we use the latest block and modify the env
-            // values that we can
-            let latest =
-                self.provider().latest_header()?.ok_or_else(|| EthApiError::UnknownBlockNumber)?;
-
-            let (mut latest_header, block_hash) = latest.split();
-            // child block
-            latest_header.number += 1;
-            // assumed child block is in the next slot: 12s
-            latest_header.timestamp += 12;
-            // base fee of the child block
-            let chain_spec = self.provider().chain_spec();
-
-            latest_header.base_fee_per_gas = latest_header.next_block_base_fee(
-                chain_spec.base_fee_params_at_timestamp(latest_header.timestamp),
-            );
-
-            // update excess blob gas consumed above target
-            latest_header.excess_blob_gas = latest_header.next_block_excess_blob_gas();
-
-            // we're reusing the same block hash because we need this to lookup the block's state
-            let latest = SealedHeader::new(latest_header, block_hash);
-
+            // no pending block from the CL yet, so we derive the env of the pending block from the
+            // latest block
+            let latest = self.provider().fill_env_for_pending(
+                &mut cfg,
+                &mut block_env,
+                self.inner.evm_config.clone(),
+            )?;
             PendingBlockEnvOrigin::DerivedFromLatest(latest)
         };
 
-        let mut cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
-
-        let mut block_env = BlockEnv::default();
-        // Note: for the PENDING block we assume it is past the known merge block and thus this will
-        // not fail when looking up the total difficulty value for the blockenv.
-        self.provider().fill_env_with_header(
-            &mut cfg,
-            &mut block_env,
-            origin.header(),
-            self.inner.evm_config.clone(),
-        )?;
-
         Ok(PendingBlockEnv { cfg, block_env, origin })
     }
 
@@ -344,7 +349,7 @@ where
             if let Some(pending_block) = lock.as_ref() {
                 // this is guaranteed to be the `latest` header
                 if pending.block_env.number.to::<u64>() == pending_block.block.number &&
-                    pending.origin.header().hash() == pending_block.block.parent_hash &&
+                    pending.origin.build_target_hash() == pending_block.block.parent_hash &&
                     now <= pending_block.expires_at
                 {
                     return Ok(Some(pending_block.block.clone()))
@@ -382,7 +387,7 @@ impl<Provider, Pool, Events, EvmConfig> std::fmt::Debug
 
 impl<Provider, Pool, Events, EvmConfig> Clone for EthApi<Provider, Pool, Events, EvmConfig> {
     fn clone(&self) -> Self {
-        Self { inner: Arc::clone(&self.inner) }
+        Self { inner: Arc::clone(&self.inner), cancellation: self.cancellation.clone() }
     }
 }
 
@@ -500,4 +505,7 @@ struct EthApiInner<Provider, Pool, Network, EvmConfig> {
     evm_config: EvmConfig,
     /// Allows forwarding received raw transactions
     raw_transaction_forwarder: parking_lot::RwLock<Option<Arc<dyn RawTransactionForwarder>>>,
+    /// Whether executions without an inspector are inspected, so they can be aborted once their
+    /// request is dropped.
+    cancel_dropped_calls: bool,
 }
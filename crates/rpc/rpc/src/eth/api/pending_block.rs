@@ -363,9 +363,10 @@ where
 pub(crate) enum PendingBlockEnvOrigin {
     /// The pending block as received from the CL.
     ActualPending(SealedBlockWithSenders),
-    /// The _modified_ header of the latest block.
+    /// The header of the pending block, derived from the latest block.
     ///
     /// This derives the pending state based on the latest header by modifying:
+    ///  - the parent hash
     ///  - the timestamp
     ///  - the block number
     ///  - fees
@@ -389,11 +390,11 @@ impl PendingBlockEnvOrigin {
     /// Returns the [`BlockId`] that represents the state of the block.
     ///
     /// If this is the actual pending block, the state is the "Pending" tag, otherwise we can safely
-    /// identify the block by its hash (latest block).
+    /// identify the block by its parent hash (latest block).
     pub(crate) fn state_block_id(&self) -> BlockId {
         match self {
             Self::ActualPending(_) => BlockNumberOrTag::Pending.into(),
-            Self::DerivedFromLatest(header) => BlockId::Hash(header.hash().into()),
+            Self::DerivedFromLatest(header) => BlockId::Hash(header.parent_hash.into()),
         }
     }
 
@@ -401,11 +402,11 @@ impl PendingBlockEnvOrigin {
     ///
     /// For the [`PendingBlockEnvOrigin::ActualPending`] this is the parent hash of the block.
     /// For the [`PendingBlockEnvOrigin::DerivedFromLatest`] this is the hash of the _latest_
-    /// header.
-    fn build_target_hash(&self) -> B256 {
+    /// header, which is the parent of the derived header.
+    pub(crate) fn build_target_hash(&self) -> B256 {
         match self {
             Self::ActualPending(block) => block.parent_hash,
-            Self::DerivedFromLatest(header) => header.hash(),
+            Self::DerivedFromLatest(header) => header.parent_hash,
         }
     }
 
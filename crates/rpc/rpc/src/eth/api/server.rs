@@ -273,10 +273,16 @@ where
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
+        transaction_index: Option<usize>,
     ) -> Result<Bytes> {
-        trace!(target: "rpc::eth", ?request, ?block_number, ?state_overrides, ?block_overrides, "Serving eth_call");
+        trace!(target: "rpc::eth", ?request, ?block_number, ?state_overrides, ?block_overrides, ?transaction_index, "Serving eth_call");
         Ok(self
-            .call(request, block_number, EvmOverrides::new(state_overrides, block_overrides))
+            .call_at_transaction_index(
+                request,
+                block_number,
+                transaction_index,
+                EvmOverrides::new(state_overrides, block_overrides),
+            )
             .await?)
     }
 
@@ -437,17 +443,22 @@ mod tests {
     use reth_evm_ethereum::EthEvmConfig;
     use reth_network_api::noop::NoopNetwork;
     use reth_primitives::{
-        constants::ETHEREUM_BLOCK_GAS_LIMIT, BaseFeeParams, Block, BlockNumberOrTag, Header,
-        TransactionSigned, B256, U64,
+        bytes,
+        constants::{eip4844::TARGET_DATA_GAS_PER_BLOCK, ETHEREUM_BLOCK_GAS_LIMIT, ETH_TO_WEI},
+        Address, BaseFeeParams, Block, BlockNumberOrTag, Header, Signature, Transaction,
+        TransactionSigned, TxKind, TxLegacy, B256, U256, U64,
     };
     use reth_provider::{
-        test_utils::{MockEthProvider, NoopProvider},
+        test_utils::{ExtendedAccount, MockEthProvider, NoopProvider},
         BlockReader, BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, StateProviderFactory,
     };
     use reth_rpc_api::EthApiServer;
     use reth_rpc_types::FeeHistory;
     use reth_tasks::pool::BlockingTaskPool;
-    use reth_testing_utils::{generators, generators::Rng};
+    use reth_testing_utils::{
+        generators,
+        generators::{generate_keys, sign_tx_with_key_pair, Rng},
+    };
     use reth_transaction_pool::test_utils::{testing_pool, TestPool};
 
     fn build_test_eth_api<
@@ -720,4 +731,164 @@ mod tests {
             "all: no percentiles were requested, so there should be no rewards result"
         );
     }
+
+    #[tokio::test]
+    async fn test_blob_base_fee() {
+        let mock_provider = MockEthProvider::default();
+        // the parent used exactly the target blob gas, so the excess blob gas carries over
+        let header = Header {
+            excess_blob_gas: Some(10_000_000),
+            blob_gas_used: Some(TARGET_DATA_GAS_PER_BLOCK),
+            ..Default::default()
+        };
+        mock_provider.add_block(B256::random(), Block { header, ..Default::default() });
+        let eth_api = build_test_eth_api(mock_provider);
+
+        // fake_exponential(MIN_BLOB_GASPRICE, 10_000_000, BLOB_GASPRICE_UPDATE_FRACTION)
+        let fee = <EthApi<_, _, _, _> as EthApiServer>::blob_base_fee(&eth_api).await.unwrap();
+        assert_eq!(fee, U256::from(19));
+    }
+
+    #[tokio::test]
+    async fn test_blob_base_fee_pre_cancun() {
+        let mock_provider = MockEthProvider::default();
+        mock_provider.add_block(B256::random(), Block::default());
+        let eth_api = build_test_eth_api(mock_provider);
+
+        let response = <EthApi<_, _, _, _> as EthApiServer>::blob_base_fee(&eth_api).await;
+        assert!(response.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_call_at_transaction_index() {
+        let mock_provider = MockEthProvider::default();
+
+        // a contract that stores the first calldata word in slot 0, or returns slot 0 if called
+        // without calldata
+        let contract = Address::random();
+        let code = bytes!("36600f5760005460005260206000f35b60003560005500");
+        mock_provider
+            .add_account(contract, ExtendedAccount::new(0, U256::ZERO).with_bytecode(code));
+
+        let mut rng = generators::rng();
+        let key_pair = generate_keys(&mut rng, 1)[0];
+        let store = |nonce, value| {
+            sign_tx_with_key_pair(
+                key_pair,
+                Transaction::Legacy(TxLegacy {
+                    chain_id: Some(1),
+                    nonce,
+                    gas_price: 1,
+                    gas_limit: 100_000,
+                    to: TxKind::Call(contract),
+                    input: Bytes::copy_from_slice(B256::with_last_byte(value).as_slice()),
+                    ..Default::default()
+                }),
+            )
+        };
+        let body = vec![store(0, 1), store(1, 2)];
+        let sender = body[0].recover_signer().unwrap();
+        mock_provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+
+        let header =
+            Header { number: 1, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+        let block_hash = header.hash_slow();
+        mock_provider.add_block(block_hash, Block { header, body, ..Default::default() });
+        let eth_api = build_test_eth_api(mock_provider);
+
+        let call = |transaction_index| {
+            <EthApi<_, _, _, _> as EthApiServer>::call(
+                &eth_api,
+                TransactionRequest { to: Some(contract.into()), ..Default::default() },
+                Some(block_hash.into()),
+                None,
+                None,
+                transaction_index,
+            )
+        };
+
+        // the call only sees the changes of the transactions before the index
+        for (transaction_index, expected) in [(0, 0), (1, 1), (2, 2)] {
+            let output = call(Some(transaction_index)).await.unwrap();
+            assert_eq!(B256::from_slice(&output), B256::with_last_byte(expected));
+        }
+
+        // the index can't exceed the number of transactions in the block
+        let error = call(Some(3)).await.unwrap_err();
+        assert_eq!(error.code(), INVALID_PARAMS_CODE);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_by_block_and_index() {
+        let mock_provider = MockEthProvider::default();
+
+        let mut rng = generators::rng();
+        let key_pair = generate_keys(&mut rng, 1)[0];
+        let transaction = |nonce| {
+            sign_tx_with_key_pair(
+                key_pair,
+                Transaction::Legacy(TxLegacy {
+                    chain_id: Some(1),
+                    nonce,
+                    gas_price: 1,
+                    gas_limit: 21_000,
+                    to: TxKind::Call(Address::random()),
+                    ..Default::default()
+                }),
+            )
+        };
+        // the sender of the last transaction can't be recovered, so the block can't be loaded
+        // with senders and only the requested transactions may be read
+        let invalid = TransactionSigned::from_transaction_and_signature(
+            transaction(2).transaction,
+            Signature::default(),
+        );
+        let body = vec![transaction(0), transaction(1), invalid];
+        let sender = body[0].recover_signer().unwrap();
+
+        let header = Header { number: 1, base_fee_per_gas: Some(7), ..Default::default() };
+        let block_hash = header.hash_slow();
+        mock_provider
+            .add_block(block_hash, Block { header, body: body.clone(), ..Default::default() });
+        let eth_api = build_test_eth_api(mock_provider);
+
+        for (index, expected) in body.iter().enumerate().take(2) {
+            let by_hash =
+                <EthApi<_, _, _, _> as EthApiServer>::transaction_by_block_hash_and_index(
+                    &eth_api,
+                    block_hash,
+                    Index::from(index),
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            let by_number =
+                <EthApi<_, _, _, _> as EthApiServer>::transaction_by_block_number_and_index(
+                    &eth_api,
+                    BlockNumberOrTag::Number(1),
+                    Index::from(index),
+                )
+                .await
+                .unwrap()
+                .unwrap();
+
+            for tx in [by_hash, by_number] {
+                assert_eq!(tx.hash, expected.hash());
+                assert_eq!(tx.from, sender);
+                assert_eq!(tx.block_hash, Some(block_hash));
+                assert_eq!(tx.block_number, Some(1));
+                assert_eq!(tx.transaction_index, Some(index as u64));
+            }
+        }
+
+        // out of range index
+        let tx = <EthApi<_, _, _, _> as EthApiServer>::transaction_by_block_hash_and_index(
+            &eth_api,
+            block_hash,
+            Index::from(3),
+        )
+        .await
+        .unwrap();
+        assert!(tx.is_none());
+    }
 }
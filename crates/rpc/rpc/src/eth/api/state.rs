@@ -12,6 +12,7 @@ use reth_provider::{
 use reth_rpc_types::{serde_helpers::JsonStorageKey, EIP1186AccountProofResponse};
 use reth_rpc_types_compat::proof::from_primitive_account_proof;
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
+use std::collections::HashSet;
 
 impl<Provider, Pool, Network, EvmConfig> EthApi<Provider, Pool, Network, EvmConfig>
 where
@@ -38,23 +39,29 @@ where
 
     /// Returns the number of transactions sent from an address at the given block identifier.
     ///
-    /// If this is [`BlockNumberOrTag::Pending`] then this will look up the highest transaction in
-    /// pool and return the next nonce (highest + 1).
+    /// If this is [`BlockNumberOrTag::Pending`] then this starts at the on-chain nonce and advances
+    /// it past every transaction of the sender in the pool with a consecutive nonce, so the
+    /// returned nonce stops at the first nonce gap.
     pub(crate) fn get_transaction_count(
         &self,
         address: Address,
         block_id: Option<BlockId>,
     ) -> EthResult<U256> {
         if block_id == Some(BlockId::pending()) {
-            let address_txs = self.pool().get_transactions_by_sender(address);
-            if let Some(highest_nonce) =
-                address_txs.iter().map(|item| item.transaction.nonce()).max()
-            {
-                let tx_count = highest_nonce
-                    .checked_add(1)
-                    .ok_or(RpcInvalidTransactionError::NonceMaxValue)?;
-                return Ok(U256::from(tx_count))
+            let on_chain_nonce = self.latest_state()?.account_nonce(address)?.unwrap_or_default();
+            let pool_nonces = self
+                .pool()
+                .get_transactions_by_sender(address)
+                .iter()
+                .map(|item| item.transaction.nonce())
+                .collect::<HashSet<_>>();
+
+            let mut next_nonce = on_chain_nonce;
+            while pool_nonces.contains(&next_nonce) {
+                next_nonce =
+                    next_nonce.checked_add(1).ok_or(RpcInvalidTransactionError::NonceMaxValue)?;
             }
+            return Ok(U256::from(next_nonce))
         }
 
         let state = self.state_at_block_id_or_latest(block_id)?;
@@ -123,7 +130,10 @@ mod tests {
     use reth_primitives::{constants::ETHEREUM_BLOCK_GAS_LIMIT, StorageKey, StorageValue};
     use reth_provider::test_utils::{ExtendedAccount, MockEthProvider, NoopProvider};
     use reth_tasks::pool::BlockingTaskPool;
-    use reth_transaction_pool::test_utils::testing_pool;
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction},
+        TransactionOrigin,
+    };
     use std::collections::HashMap;
 
     #[tokio::test]
@@ -175,4 +185,47 @@ mod tests {
         let storage = eth_api.storage_at(address, storage_key.into(), None).unwrap();
         assert_eq!(storage, storage_value.to_be_bytes());
     }
+
+    #[tokio::test]
+    async fn test_pending_transaction_count_stops_at_nonce_gap() {
+        let pool = testing_pool();
+        let evm_config = EthEvmConfig::default();
+
+        let address = Address::random();
+        let mock_provider = MockEthProvider::default();
+        mock_provider.add_account(address, ExtendedAccount::new(5, U256::MAX));
+
+        let cache = EthStateCache::spawn(mock_provider.clone(), Default::default(), evm_config);
+        let eth_api = EthApi::new(
+            mock_provider.clone(),
+            pool.clone(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(mock_provider, Default::default(), cache.clone()),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            FeeHistoryCache::new(cache, FeeHistoryCacheConfig::default()),
+            evm_config,
+            None,
+        );
+
+        // no pooled transactions, the pending count is the on-chain nonce
+        let count = eth_api.get_transaction_count(address, Some(BlockId::pending())).unwrap();
+        assert_eq!(count, U256::from(5));
+
+        // pooled transactions with nonces 5, 6 and 8
+        let tx = MockTransaction::eip1559().with_sender(address).with_nonce(5);
+        let next = tx.next();
+        let after_gap = next.skip(1);
+        for tx in [tx, next, after_gap] {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let count = eth_api.get_transaction_count(address, Some(BlockId::pending())).unwrap();
+        assert_eq!(count, U256::from(7));
+
+        // the latest count is not affected by the pool
+        let count = eth_api.get_transaction_count(address, None).unwrap();
+        assert_eq!(count, U256::from(5));
+    }
 }
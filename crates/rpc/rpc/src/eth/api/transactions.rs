@@ -2,8 +2,9 @@
 use crate::{
     eth::{
         api::pending_block::PendingBlockEnv,
+        cancellation::{CancellableInspector, RequestCancellation},
         error::{EthApiError, EthResult, RpcInvalidTransactionError, SignError},
-        revm_utils::{prepare_call_env, EvmOverrides},
+        revm_utils::{apply_transaction_trace_overrides, prepare_call_env, EvmOverrides},
         utils::recover_raw_transaction,
     },
     EthApi, EthApiSpec,
@@ -31,13 +32,14 @@ use reth_rpc_types::{
         LegacyTransactionRequest,
     },
     AnyReceiptEnvelope, AnyTransactionReceipt, Index, Log, ReceiptWithBloom, Transaction,
-    TransactionInfo, TransactionReceipt, TransactionRequest, TypedTransactionRequest,
-    WithOtherFields,
+    TransactionInfo, TransactionReceipt, TransactionRequest, TransactionTraceOverrides,
+    TypedTransactionRequest, WithOtherFields,
 };
 use reth_rpc_types_compat::transaction::from_recovered_with_block_context;
 use reth_transaction_pool::{TransactionOrigin, TransactionPool};
 use revm::{
     db::CacheDB,
+    inspectors::NoOpInspector,
     primitives::{
         db::DatabaseCommit, BlockEnv, CfgEnvWithHandlerCfg, EnvWithHandlerCfg, EvmState,
         ExecutionResult, ResultAndState, SpecId,
@@ -170,9 +172,12 @@ pub trait EthTransactions: Send + Sync {
         F: FnOnce(StateProviderBox) -> EthResult<T>;
 
     /// Executes the closure with the state that corresponds to the given [BlockId] on a new task
+    ///
+    /// The closure is called with an instance that aborts its EVM executions once the returned
+    /// future is dropped.
     async fn spawn_with_state_at_block<F, T>(&self, at: BlockId, f: F) -> EthResult<T>
     where
-        F: FnOnce(StateProviderBox) -> EthResult<T> + Send + 'static,
+        F: FnOnce(&Self, StateProviderBox) -> EthResult<T> + Send + 'static,
         T: Send + 'static;
 
     /// Returns the revm evm env for the requested [BlockId]
@@ -269,6 +274,9 @@ pub trait EthTransactions: Send + Sync {
     ///
     /// This returns the configured [EnvWithHandlerCfg] for the given [TransactionRequest] at the
     /// given [BlockId] and with configured call settings: `prepare_call_env`.
+    ///
+    /// The closure is called with an instance that aborts its EVM executions once the returned
+    /// future is dropped.
     async fn spawn_with_call_at<F, R>(
         &self,
         request: TransactionRequest,
@@ -277,7 +285,26 @@ pub trait EthTransactions: Send + Sync {
         f: F,
     ) -> EthResult<R>
     where
-        F: FnOnce(&mut StateCacheDB, EnvWithHandlerCfg) -> EthResult<R> + Send + 'static,
+        F: FnOnce(&Self, &mut StateCacheDB, EnvWithHandlerCfg) -> EthResult<R> + Send + 'static,
+        R: Send + 'static;
+
+    /// Same as [Self::spawn_with_call_at], but if a `transaction_index` is provided, the call is
+    /// executed on top of the state after the first `transaction_index` transactions of the block
+    /// were executed.
+    ///
+    /// A `transaction_index` of `0` is the state at the start of the block, an index equal to the
+    /// number of transactions is the state at the end of the block. This matches the semantics of
+    /// the transaction index of `debug_storageRangeAt`.
+    async fn spawn_with_call_at_transaction_index<F, R>(
+        &self,
+        request: TransactionRequest,
+        at: BlockId,
+        transaction_index: Option<usize>,
+        overrides: EvmOverrides,
+        f: F,
+    ) -> EthResult<R>
+    where
+        F: FnOnce(&Self, &mut StateCacheDB, EnvWithHandlerCfg) -> EthResult<R> + Send + 'static,
         R: Send + 'static;
 
     /// Executes the call request at the given [BlockId].
@@ -395,6 +422,33 @@ pub trait EthTransactions: Send + Sync {
         inspector: Insp,
         f: F,
     ) -> EthResult<Option<R>>
+    where
+        F: FnOnce(TransactionInfo, Insp, ResultAndState, StateCacheDB) -> EthResult<R>
+            + Send
+            + 'static,
+        Insp: for<'a> Inspector<&'a mut StateCacheDB> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.spawn_trace_transaction_in_block_with_overrides(
+            hash,
+            TransactionTraceOverrides::default(),
+            inspector,
+            f,
+        )
+        .await
+    }
+
+    /// Same as [`Self::spawn_trace_transaction_in_block_with_inspector`], but applies the given
+    /// overrides to the transaction before it is traced.
+    ///
+    /// With overrides, the trace diverges from the actual on-chain execution of the transaction.
+    async fn spawn_trace_transaction_in_block_with_overrides<Insp, F, R>(
+        &self,
+        hash: B256,
+        overrides: TransactionTraceOverrides,
+        inspector: Insp,
+        f: F,
+    ) -> EthResult<Option<R>>
     where
         F: FnOnce(TransactionInfo, Insp, ResultAndState, StateCacheDB) -> EthResult<R>
             + Send
@@ -558,6 +612,11 @@ where
         DB: Database,
         <DB as Database>::Error: Into<EthApiError>,
     {
+        if self.inner.cancel_dropped_calls && self.cancellation.is_some() {
+            // the execution must be inspected to abort it once the request is cancelled
+            return self.inspect(db, env, NoOpInspector)
+        }
+
         let mut evm = self.inner.evm_config.evm_with_env(db, env);
         let res = evm.transact()?;
         let (_, env) = evm.into_db_and_env_with_handler_cfg();
@@ -589,8 +648,12 @@ where
         <DB as Database>::Error: Into<EthApiError>,
         I: GetInspector<DB>,
     {
+        let inspector = CancellableInspector::new(inspector, self.cancellation.clone());
         let mut evm = self.inner.evm_config.evm_with_env_and_inspector(db, env, inspector);
         let res = evm.transact()?;
+        if let Some(cancellation) = &self.cancellation {
+            cancellation.ensure_not_cancelled()?;
+        }
         let (db, env) = evm.into_db_and_env_with_handler_cfg();
         Ok((res, env, db))
     }
@@ -618,6 +681,9 @@ where
                 // reached the target transaction
                 break
             }
+            if let Some(cancellation) = &self.cancellation {
+                cancellation.ensure_not_cancelled()?;
+            }
 
             tx.try_fill_tx_env(evm.tx_mut())?;
             evm.transact_commit()?;
@@ -660,12 +726,12 @@ where
 
     async fn spawn_with_state_at_block<F, T>(&self, at: BlockId, f: F) -> EthResult<T>
     where
-        F: FnOnce(StateProviderBox) -> EthResult<T> + Send + 'static,
+        F: FnOnce(&Self, StateProviderBox) -> EthResult<T> + Send + 'static,
         T: Send + 'static,
     {
         self.spawn_tracing_task_with(move |this| {
             let state = this.state_at(at)?;
-            f(state)
+            f(&this, state)
         })
         .await
     }
@@ -1067,29 +1133,78 @@ where
         f: F,
     ) -> EthResult<R>
     where
-        F: FnOnce(&mut StateCacheDB, EnvWithHandlerCfg) -> EthResult<R> + Send + 'static,
+        F: FnOnce(&Self, &mut StateCacheDB, EnvWithHandlerCfg) -> EthResult<R> + Send + 'static,
         R: Send + 'static,
     {
         let (cfg, block_env, at) = self.evm_env_at(at).await?;
-        let this = self.clone();
-        self.inner
-            .blocking_task_pool
-            .spawn(move || {
-                let state = this.state_at(at)?;
-                let mut db = CacheDB::new(StateProviderDatabase::new(state));
-
-                let env = prepare_call_env(
-                    cfg,
-                    block_env,
-                    request,
-                    this.call_gas_limit(),
-                    &mut db,
-                    overrides,
-                )?;
-                f(&mut db, env)
-            })
-            .await
-            .map_err(|_| EthApiError::InternalBlockingTaskError)?
+        self.spawn_tracing_task_with(move |this| {
+            let state = this.state_at(at)?;
+            let mut db = CacheDB::new(StateProviderDatabase::new(state));
+
+            let env = prepare_call_env(
+                cfg,
+                block_env,
+                request,
+                this.call_gas_limit(),
+                &mut db,
+                overrides,
+            )?;
+            f(&this, &mut db, env)
+        })
+        .await
+    }
+
+    async fn spawn_with_call_at_transaction_index<F, R>(
+        &self,
+        request: TransactionRequest,
+        at: BlockId,
+        transaction_index: Option<usize>,
+        overrides: EvmOverrides,
+        f: F,
+    ) -> EthResult<R>
+    where
+        F: FnOnce(&Self, &mut StateCacheDB, EnvWithHandlerCfg) -> EthResult<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let Some(transaction_index) = transaction_index else {
+            return self.spawn_with_call_at(request, at, overrides, f).await
+        };
+
+        let ((cfg, block_env, _), block) =
+            futures::try_join!(self.evm_env_at(at), self.block_by_id_with_senders(at))?;
+        let block = block.ok_or(EthApiError::UnknownBlockNumber)?;
+        if transaction_index > block.body.len() {
+            return Err(EthApiError::InvalidParams(format!(
+                "transaction index {transaction_index} out of range for block with {} transactions",
+                block.body.len()
+            )))
+        }
+
+        // the transactions are replayed on top of the state at the start of the block, which is
+        // the state of the parent block
+        self.spawn_with_state_at_block(block.parent_hash.into(), move |this, state| {
+            let mut db = CacheDB::new(StateProviderDatabase::new(state));
+            for tx in block.into_transactions_ecrecovered().take(transaction_index) {
+                let env = EnvWithHandlerCfg::new_with_cfg_env(
+                    cfg.clone(),
+                    block_env.clone(),
+                    tx_env_with_recovered(&tx),
+                );
+                let (res, _) = this.transact(&mut db, env)?;
+                db.commit(res.state);
+            }
+
+            let env = prepare_call_env(
+                cfg,
+                block_env,
+                request,
+                this.call_gas_limit(),
+                &mut db,
+                overrides,
+            )?;
+            f(this, &mut db, env)
+        })
+        .await
     }
 
     async fn transact_call_at(
@@ -1098,8 +1213,8 @@ where
         at: BlockId,
         overrides: EvmOverrides,
     ) -> EthResult<(ResultAndState, EnvWithHandlerCfg)> {
-        let this = self.clone();
-        self.spawn_with_call_at(request, at, overrides, move |db, env| this.transact(db, env)).await
+        self.spawn_with_call_at(request, at, overrides, move |this, db, env| this.transact(db, env))
+            .await
     }
 
     async fn spawn_inspect_call_at<I>(
@@ -1112,8 +1227,7 @@ where
     where
         I: for<'a> Inspector<&'a mut StateCacheDB> + Send + 'static,
     {
-        let this = self.clone();
-        self.spawn_with_call_at(request, at, overrides, move |db, env| {
+        self.spawn_with_call_at(request, at, overrides, move |this, db, env| {
             this.inspect(db, env, inspector)
         })
         .await
@@ -1149,8 +1263,7 @@ where
         F: FnOnce(TracingInspector, ResultAndState, StateCacheDB) -> EthResult<R> + Send + 'static,
         R: Send + 'static,
     {
-        let this = self.clone();
-        self.spawn_with_state_at_block(at, move |state| {
+        self.spawn_with_state_at_block(at, move |this, state| {
             let mut db = CacheDB::new(StateProviderDatabase::new(state));
             let mut inspector = TracingInspector::new(config);
             let (res, _) = this.inspect(&mut db, env, &mut inspector)?;
@@ -1195,8 +1308,7 @@ where
         let parent_block = block.parent_hash;
         let block_txs = block.into_transactions_ecrecovered();
 
-        let this = self.clone();
-        self.spawn_with_state_at_block(parent_block.into(), move |state| {
+        self.spawn_with_state_at_block(parent_block.into(), move |this, state| {
             let mut db = CacheDB::new(StateProviderDatabase::new(state));
 
             // replay all transactions prior to the targeted transaction
@@ -1218,9 +1330,10 @@ where
         .map(Some)
     }
 
-    async fn spawn_trace_transaction_in_block_with_inspector<Insp, F, R>(
+    async fn spawn_trace_transaction_in_block_with_overrides<Insp, F, R>(
         &self,
         hash: B256,
+        overrides: TransactionTraceOverrides,
         mut inspector: Insp,
         f: F,
     ) -> EthResult<Option<R>>
@@ -1244,8 +1357,7 @@ where
         let parent_block = block.parent_hash;
         let block_txs = block.into_transactions_ecrecovered();
 
-        let this = self.clone();
-        self.spawn_with_state_at_block(parent_block.into(), move |state| {
+        self.spawn_with_state_at_block(parent_block.into(), move |this, state| {
             let mut db = CacheDB::new(StateProviderDatabase::new(state));
 
             // replay all transactions prior to the targeted transaction
@@ -1257,8 +1369,9 @@ where
                 tx.hash,
             )?;
 
-            let env =
-                EnvWithHandlerCfg::new_with_cfg_env(cfg, block_env, tx_env_with_recovered(&tx));
+            let mut tx_env = tx_env_with_recovered(&tx);
+            apply_transaction_trace_overrides(overrides, &mut tx_env);
+            let env = EnvWithHandlerCfg::new_with_cfg_env(cfg, block_env, tx_env);
 
             let (res, _) = this.inspect(&mut db, env, &mut inspector)?;
             f(tx_info, inspector, res, db)
@@ -1366,17 +1479,21 @@ where
     Self: Send + Sync + 'static,
 {
     /// Spawns the given closure on a new blocking tracing task
+    ///
+    /// The closure is called with an instance that carries the cancellation of the request: if
+    /// the returned future is dropped before the task completed, EVM executions of that instance
+    /// are aborted.
     async fn spawn_tracing_task_with<F, T>(&self, f: F) -> EthResult<T>
     where
         F: FnOnce(Self) -> EthResult<T> + Send + 'static,
         T: Send + 'static,
     {
-        let this = self.clone();
-        self.inner
-            .blocking_task_pool
-            .spawn(move || f(this))
-            .await
-            .map_err(|_| EthApiError::InternalBlockingTaskError)?
+        let cancellation = RequestCancellation::default();
+        let cancel_on_drop = cancellation.cancel_on_drop();
+        let this = self.with_cancellation(cancellation);
+        let res = self.inner.blocking_task_pool.spawn(move || f(this)).await;
+        cancel_on_drop.disarm();
+        res.map_err(|_| EthApiError::InternalBlockingTaskError)?
     }
 }
 
@@ -1461,12 +1578,58 @@ where
     /// Get Transaction by [`BlockId`] and the index of the transaction within that Block.
     ///
     /// Returns `Ok(None)` if the block does not exist, or the block as fewer transactions
+    ///
+    /// For stored blocks this only reads the transaction at the index and its sender on a blocking
+    /// task, the block body is not loaded.
     pub(crate) async fn transaction_by_block_and_tx_index(
         &self,
         block_id: impl Into<BlockId>,
         index: Index,
     ) -> EthResult<Option<Transaction>> {
-        if let Some(block) = self.block_with_senders(block_id.into()).await? {
+        let block_id = block_id.into();
+        if block_id.is_pending() {
+            // the pending block is not stored
+            return self.transaction_by_block_and_tx_index_from_block(block_id, index).await
+        }
+
+        self.on_blocking_task(|this| async move {
+            let provider = this.provider();
+            let Some(block_number) = provider.block_number_for_id(block_id)? else {
+                return Ok(None)
+            };
+            let Some(header) = provider.sealed_header(block_number)? else { return Ok(None) };
+            let Some(body) = provider.block_body_indices(block_number)? else { return Ok(None) };
+
+            let index: usize = index.into();
+            if index as u64 >= body.tx_count {
+                return Ok(None)
+            }
+            let tx_num = body.first_tx_num + index as u64;
+            let Some(tx) = provider.transaction_by_id(tx_num)? else { return Ok(None) };
+            let signer = match provider.transaction_sender(tx_num)? {
+                Some(signer) => signer,
+                None => tx.recover_signer().ok_or(EthApiError::InvalidTransactionSignature)?,
+            };
+
+            Ok(Some(from_recovered_with_block_context(
+                tx.with_signer(signer),
+                header.hash(),
+                header.number,
+                header.base_fee_per_gas,
+                index,
+            )))
+        })
+        .await
+    }
+
+    /// Same as [`Self::transaction_by_block_and_tx_index`], but looks up the transaction in the
+    /// full block with senders, which is required for the pending block.
+    async fn transaction_by_block_and_tx_index_from_block(
+        &self,
+        block_id: BlockId,
+        index: Index,
+    ) -> EthResult<Option<Transaction>> {
+        if let Some(block) = self.block_with_senders(block_id).await? {
             let block_hash = block.hash();
             let block_number = block.number;
             let base_fee_per_gas = block.base_fee_per_gas;
@@ -46,7 +46,7 @@ where
     /// state, or it can be used to simulate a past block. The sender is responsible for signing the
     /// transactions and using the correct nonce and ensuring validity
     pub async fn call_bundle(&self, bundle: EthCallBundle) -> EthResult<EthCallBundleResponse> {
-        let EthCallBundle { txs, block_number, state_block_number, timestamp } = bundle;
+        let EthCallBundle { txs, block_number, state_block_number, timestamp, gas_limit } = bundle;
         if txs.is_empty() {
             return Err(EthApiError::InvalidParams(
                 EthBundleError::EmptyBundleTransactions.to_string(),
@@ -99,9 +99,13 @@ where
         // use the block number of the request
         block_env.number = U256::from(block_number);
 
+        if let Some(gas_limit) = gas_limit {
+            block_env.gas_limit = U256::from(gas_limit);
+        }
+
         self.inner
             .eth_api
-            .spawn_with_state_at_block(at, move |state| {
+            .spawn_with_state_at_block(at, move |_, state| {
                 let coinbase = block_env.coinbase;
                 let basefee = Some(block_env.basefee.to::<u64>());
                 let env = EnvWithHandlerCfg::new_with_cfg_env(cfg, block_env, TxEnv::default());
@@ -120,6 +124,7 @@ where
                     revm::Evm::builder().with_db(db).with_env_with_handler_cfg(env).build();
 
                 let mut results = Vec::with_capacity(transactions.len());
+                let mut skipped_txs = Vec::new();
                 let mut transactions = transactions.into_iter().peekable();
 
                 while let Some((tx, signer)) = transactions.next() {
@@ -133,6 +138,18 @@ where
                     let tx = tx.into_ecrecovered_transaction(signer);
 
                     hash_bytes.extend_from_slice(tx.hash().as_slice());
+
+                    // like a block builder, stop once the transaction no longer fits into the
+                    // remaining gas of the block, the remaining transactions are skipped
+                    if gas_limit.is_some_and(|limit| total_gas_used + tx.gas_limit() > limit) {
+                        skipped_txs.push(tx.hash());
+                        for (tx, _) in transactions.by_ref() {
+                            hash_bytes.extend_from_slice(tx.hash().as_slice());
+                            skipped_txs.push(*tx.hash());
+                        }
+                        break
+                    }
+
                     let gas_price = tx
                         .effective_tip_per_gas(basefee)
                         .ok_or_else(|| RpcInvalidTransactionError::FeeCapTooLow)?;
@@ -202,6 +219,7 @@ where
                     results,
                     state_block_number: state_block_number.to(),
                     total_gas_used,
+                    skipped_txs,
                 };
 
                 Ok(res)
@@ -256,3 +274,88 @@ pub enum EthBundleError {
     #[error("blob gas usage exceeds the limit of {MAX_BLOB_GAS_PER_BLOCK} gas per block.")]
     Eip4844BlobGasExceeded,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{
+        cache::EthStateCache, gas_oracle::GasPriceOracle, EthApi, FeeHistoryCache,
+        FeeHistoryCacheConfig,
+    };
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_primitives::{
+        constants::{ETHEREUM_BLOCK_GAS_LIMIT, ETH_TO_WEI},
+        Address, Block, BlockNumberOrTag, Header, Transaction, TxKind, TxLegacy,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_tasks::pool::BlockingTaskPool;
+    use reth_testing_utils::generators::{self, generate_keys, sign_tx_with_key_pair};
+    use reth_transaction_pool::test_utils::testing_pool;
+
+    #[tokio::test]
+    async fn call_bundle_stops_at_gas_limit() {
+        let provider = MockEthProvider::default();
+        let evm_config = EthEvmConfig::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default(), evm_config);
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache.clone()),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            FeeHistoryCache::new(cache, FeeHistoryCacheConfig::default()),
+            evm_config,
+            None,
+        );
+        let bundle = EthBundle::new(eth_api, BlockingTaskGuard::new(1));
+
+        // four transfers of 21000 gas each, only the first two fit into the block
+        let mut rng = generators::rng();
+        let key_pair = generate_keys(&mut rng, 1)[0];
+        let transactions = (0..4)
+            .map(|nonce| {
+                sign_tx_with_key_pair(
+                    key_pair,
+                    Transaction::Legacy(TxLegacy {
+                        chain_id: Some(1),
+                        nonce,
+                        gas_price: 1,
+                        gas_limit: 21_000,
+                        to: TxKind::Call(Address::random()),
+                        value: U256::from(1_000),
+                        ..Default::default()
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+        let sender = transactions[0].recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+
+        let header =
+            Header { number: 1, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+        provider.add_block(header.hash_slow(), Block { header, ..Default::default() });
+
+        let request = EthCallBundle {
+            txs: transactions.iter().map(|tx| tx.envelope_encoded()).collect(),
+            block_number: 2,
+            state_block_number: BlockNumberOrTag::Number(1),
+            gas_limit: Some(50_000),
+            ..Default::default()
+        };
+        let response = bundle.call_bundle(request.clone()).await.unwrap();
+
+        let executed = response.results.iter().map(|res| res.tx_hash).collect::<Vec<_>>();
+        let expected = transactions.iter().map(|tx| tx.hash()).collect::<Vec<_>>();
+        assert_eq!(executed, expected[..2]);
+        assert_eq!(response.skipped_txs, expected[2..]);
+        assert_eq!(response.total_gas_used, 2 * 21_000);
+
+        // without a limit all transactions are executed
+        let response =
+            bundle.call_bundle(EthCallBundle { gas_limit: None, ..request }).await.unwrap();
+        assert_eq!(response.results.len(), 4);
+        assert!(response.skipped_txs.is_empty());
+    }
+}
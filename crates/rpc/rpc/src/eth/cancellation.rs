@@ -0,0 +1,242 @@
+//! Cancellation of the EVM executions of dropped requests.
+//!
+//! Requests that execute transactions, like `eth_call`, `eth_estimateGas` or the `debug_trace*`
+//! endpoints, run on a blocking task. The task is handed an `EthApi` that carries the
+//! [`RequestCancellation`] of the request. If the request is dropped before the task completed,
+//! e.g. because the client disconnected, the cancellation is triggered and the
+//! [`CancellableInspector`] halts the executions of that `EthApi` at the next instruction.
+//!
+//! Executions that aren't inspected otherwise, like those of `eth_call` and `eth_estimateGas`, are
+//! only wrapped if the `EthApi` was created with `cancel_dropped_calls`, since running them with an
+//! inspector is slower than the plain execution.
+
+use crate::eth::error::{EthApiError, EthResult};
+use revm::{
+    interpreter::{
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, InstructionResult, Interpreter,
+    },
+    primitives::{Address, Log, U256},
+    Database, EvmContext, GetInspector, Inspector,
+};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Tracks whether the request that is executed on a blocking task was dropped.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RequestCancellation(Arc<AtomicBool>);
+
+impl RequestCancellation {
+    /// Cancels the request.
+    pub(crate) fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the request was cancelled.
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns an error if the request was cancelled.
+    pub(crate) fn ensure_not_cancelled(&self) -> EthResult<()> {
+        if self.is_cancelled() {
+            return Err(EthApiError::RequestCancelled)
+        }
+        Ok(())
+    }
+
+    /// Returns a guard that cancels the request when it's dropped, unless it was disarmed.
+    pub(crate) fn cancel_on_drop(&self) -> CancelOnDrop {
+        CancelOnDrop(Some(self.clone()))
+    }
+}
+
+/// Cancels the request when dropped, see [`RequestCancellation::cancel_on_drop`].
+#[derive(Debug)]
+pub(crate) struct CancelOnDrop(Option<RequestCancellation>);
+
+impl CancelOnDrop {
+    /// Disarms the guard, so the request is not cancelled.
+    pub(crate) fn disarm(mut self) {
+        self.0.take();
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(cancellation) = self.0.take() {
+            cancellation.cancel();
+        }
+    }
+}
+
+/// An [`Inspector`] that halts the execution once the request is cancelled and otherwise forwards
+/// to the wrapped inspector.
+///
+/// The frame that is currently executed is halted at its next instruction, all frames it returns
+/// to are halted at their next instruction as well.
+#[derive(Debug)]
+pub(crate) struct CancellableInspector<I> {
+    inner: I,
+    cancellation: Option<RequestCancellation>,
+}
+
+impl<I> CancellableInspector<I> {
+    /// Wraps the inspector, halting the execution once the given request is cancelled.
+    pub(crate) const fn new(inner: I, cancellation: Option<RequestCancellation>) -> Self {
+        Self { inner, cancellation }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancellation.as_ref().is_some_and(RequestCancellation::is_cancelled)
+    }
+}
+
+impl<DB, I> Inspector<DB> for CancellableInspector<I>
+where
+    DB: Database,
+    I: GetInspector<DB>,
+{
+    fn initialize_interp(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        self.inner.get_inspector().initialize_interp(interp, context)
+    }
+
+    fn step(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        if self.is_cancelled() {
+            interp.instruction_result = InstructionResult::OutOfGas;
+            return
+        }
+        self.inner.get_inspector().step(interp, context)
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        self.inner.get_inspector().step_end(interp, context)
+    }
+
+    fn log(&mut self, context: &mut EvmContext<DB>, log: &Log) {
+        self.inner.get_inspector().log(context, log)
+    }
+
+    fn call(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        self.inner.get_inspector().call(context, inputs)
+    }
+
+    fn call_end(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &CallInputs,
+        outcome: CallOutcome,
+    ) -> CallOutcome {
+        self.inner.get_inspector().call_end(context, inputs, outcome)
+    }
+
+    fn create(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &mut CreateInputs,
+    ) -> Option<CreateOutcome> {
+        self.inner.get_inspector().create(context, inputs)
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &CreateInputs,
+        outcome: CreateOutcome,
+    ) -> CreateOutcome {
+        self.inner.get_inspector().create_end(context, inputs, outcome)
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        self.inner.get_inspector().selfdestruct(contract, target, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{
+        cache::EthStateCache, gas_oracle::GasPriceOracle, revm_utils::EvmOverrides, EthApi,
+        FeeHistoryCache, FeeHistoryCacheConfig,
+    };
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_primitives::{bytes, Block, Header};
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_rpc_types::TransactionRequest;
+    use reth_tasks::{pool::BlockingTaskPool, TokioTaskExecutor};
+    use reth_transaction_pool::test_utils::testing_pool;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn dropped_call_is_cancelled() {
+        // large enough for the loop to run for hours
+        let gas_limit = 1 << 50;
+        let looping = Address::random();
+        let stopping = Address::random();
+        let provider = MockEthProvider::default();
+        // JUMPDEST, PUSH1 0, JUMP
+        provider.add_account(
+            looping,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("5b600056")),
+        );
+        // STOP
+        provider
+            .add_account(stopping, ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("00")));
+        let header = Header { number: 1, gas_limit, ..Default::default() };
+        let block_hash = header.hash_slow();
+        provider.add_block(block_hash, Block { header, ..Default::default() });
+
+        let evm_config = EthEvmConfig::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default(), evm_config);
+        // a single thread, so the looping call would block all following calls
+        let blocking_task_pool = BlockingTaskPool::new(
+            BlockingTaskPool::builder().num_threads(1).build().expect("failed to build pool"),
+        );
+        let api = EthApi::with_spawner(
+            provider.clone(),
+            testing_pool(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache.clone()),
+            gas_limit,
+            Box::<TokioTaskExecutor>::default(),
+            blocking_task_pool,
+            FeeHistoryCache::new(cache, FeeHistoryCacheConfig::default()),
+            evm_config,
+            None,
+            true,
+        );
+
+        let call = |to: Address| {
+            api.call(
+                TransactionRequest { to: Some(to.into()), ..Default::default() },
+                Some(block_hash.into()),
+                EvmOverrides::default(),
+            )
+        };
+
+        // the timeout drops the call
+        let res = tokio::time::timeout(Duration::from_millis(200), call(looping)).await;
+        assert!(res.is_err());
+
+        let res = tokio::time::timeout(Duration::from_secs(10), call(stopping)).await;
+        assert!(res.expect("looping call was not cancelled").is_ok());
+    }
+
+    #[test]
+    fn cancels_on_drop_unless_disarmed() {
+        let cancellation = RequestCancellation::default();
+        assert!(cancellation.ensure_not_cancelled().is_ok());
+        drop(cancellation.cancel_on_drop());
+        assert!(matches!(cancellation.ensure_not_cancelled(), Err(EthApiError::RequestCancelled)));
+
+        let cancellation = RequestCancellation::default();
+        cancellation.cancel_on_drop().disarm();
+        assert!(!cancellation.is_cancelled());
+    }
+}
@@ -52,6 +52,11 @@ pub enum EthApiError {
     /// When an invalid block range is provided
     #[error("invalid block range")]
     InvalidBlockRange,
+    /// Thrown when the canonical chain changed while a range of blocks was being processed.
+    ///
+    /// The request can be retried against the new canonical chain.
+    #[error("canonical chain changed while processing block range, retry the request")]
+    CanonicalChainChanged,
     /// An internal error where prevrandao is not set in the evm's environment
     #[error("prevrandao not in the EVM's environment after merge")]
     PrevrandaoNotSet,
@@ -101,12 +106,18 @@ pub enum EthApiError {
     /// Error thrown when a spawned blocking task failed to deliver an anticipated response
     #[error("internal eth error")]
     InternalEthError,
+    /// Error thrown when the execution of a call was aborted because the request was dropped
+    #[error("request cancelled")]
+    RequestCancelled,
     /// Error thrown when a (tracing) call exceeds the configured timeout
     #[error("execution aborted (timeout = {0:?})")]
     ExecutionTimedOut(Duration),
     /// Internal Error thrown by the javascript tracer
     #[error("{0}")]
     InternalJsTracerError(String),
+    /// Thrown when a custom javascript tracer is requested but javascript tracers are disabled
+    #[error("custom JS tracers are disabled")]
+    JsTracersDisabled,
     #[error(transparent)]
     /// Call Input error when both `data` and `input` fields are set and not equal.
     TransactionInputError(#[from] TransactionInputError),
@@ -142,6 +153,7 @@ impl From<EthApiError> for ErrorObject<'static> {
             EthApiError::Signing(_) |
             EthApiError::BothStateAndStateDiffInOverride(_) |
             EthApiError::InvalidTracerConfig |
+            EthApiError::JsTracersDisabled |
             EthApiError::TransactionConversionError => invalid_params_rpc_err(error.to_string()),
             EthApiError::InvalidTransaction(err) => err.into(),
             EthApiError::PoolError(err) => err.into(),
@@ -150,6 +162,7 @@ impl From<EthApiError> for ErrorObject<'static> {
             EthApiError::InvalidBlockData(_) |
             EthApiError::Internal(_) |
             EthApiError::TransactionNotFound |
+            EthApiError::CanonicalChainChanged |
             EthApiError::EvmCustom(_) |
             EthApiError::InvalidRewardPercentiles => internal_rpc_err(error.to_string()),
             EthApiError::UnknownBlockNumber | EthApiError::UnknownBlockOrTxIndex => {
@@ -164,9 +177,9 @@ impl From<EthApiError> for ErrorObject<'static> {
             err @ EthApiError::ExecutionTimedOut(_) => {
                 rpc_error_with_code(CALL_EXECUTION_FAILED_CODE, err.to_string())
             }
-            err @ EthApiError::InternalBlockingTaskError | err @ EthApiError::InternalEthError => {
-                internal_rpc_err(err.to_string())
-            }
+            err @ EthApiError::InternalBlockingTaskError |
+            err @ EthApiError::InternalEthError |
+            err @ EthApiError::RequestCancelled => internal_rpc_err(err.to_string()),
             err @ EthApiError::TransactionInputError(_) => invalid_params_rpc_err(err.to_string()),
             EthApiError::Other(err) => err.to_rpc_error(),
             EthApiError::MuxTracerError(msg) => internal_rpc_err(msg.to_string()),
@@ -595,6 +608,9 @@ impl From<PoolError> for RpcPoolError {
             PoolErrorKind::InvalidTransaction(err) => err.into(),
             PoolErrorKind::Other(err) => Self::Other(err),
             PoolErrorKind::AlreadyImported => Self::AlreadyKnown,
+            PoolErrorKind::FilteredOnArrival => {
+                Self::Other("transaction rejected by gossip filter".into())
+            }
             PoolErrorKind::ExistingConflictingTransactionType(_, _) => Self::AddressAlreadyReserved,
         }
     }
@@ -618,6 +634,9 @@ impl From<InvalidPoolTransactionError> for RpcPoolError {
             InvalidPoolTransactionError::Overdraft => {
                 Self::Invalid(RpcInvalidTransactionError::InsufficientFunds)
             }
+            InvalidPoolTransactionError::NonceGap => {
+                Self::Invalid(RpcInvalidTransactionError::NonceTooHigh)
+            }
         }
     }
 }
@@ -63,8 +63,12 @@ where
         config: EthFilterConfig,
         task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
-        let EthFilterConfig { max_blocks_per_filter, max_logs_per_response, stale_filter_ttl } =
-            config;
+        let EthFilterConfig {
+            max_blocks_per_filter,
+            max_logs_per_response,
+            max_filter_combinations,
+            stale_filter_ttl,
+        } = config;
         let inner = EthFilterInner {
             provider,
             active_filters: Default::default(),
@@ -77,6 +81,7 @@ where
             // if not set, use the max value, which is effectively no limit
             max_blocks_per_filter: max_blocks_per_filter.unwrap_or(u64::MAX),
             max_logs_per_response: max_logs_per_response.unwrap_or(usize::MAX),
+            max_filter_combinations: max_filter_combinations.unwrap_or(usize::MAX),
         };
 
         let eth_filter = Self { inner: Arc::new(inner) };
@@ -330,6 +335,8 @@ struct EthFilterInner<Provider, Pool> {
     max_blocks_per_filter: u64,
     /// Maximum number of logs that can be returned in a response
     max_logs_per_response: usize,
+    /// Maximum number of address and topic combinations a filter can specify
+    max_filter_combinations: usize,
     /// The async cache frontend for eth related data
     eth_cache: EthStateCache,
     /// maximum number of headers to read at once for range filter
@@ -347,6 +354,11 @@ where
 {
     /// Returns logs matching given filter object.
     async fn logs_for_filter(&self, filter: Filter) -> Result<Vec<Log>, FilterError> {
+        // reject overly complex filters before scanning any blocks
+        if filter_combinations(&filter) > self.max_filter_combinations {
+            return Err(FilterError::QueryExceedsMaxCombinations(self.max_filter_combinations))
+        }
+
         match filter.block_option {
             FilterBlockOption::AtBlockHash(block_hash) => {
                 // for all matching logs in the block
@@ -528,6 +540,10 @@ pub struct EthFilterConfig {
     ///
     /// If `None` then no limit is enforced.
     pub max_logs_per_response: Option<usize>,
+    /// Maximum number of address and topic combinations a single filter can specify.
+    ///
+    /// If `None` then no limit is enforced.
+    pub max_filter_combinations: Option<usize>,
     /// How long a filter remains valid after the last poll.
     ///
     /// A filter is considered stale if it has not been polled for longer than this duration and
@@ -549,6 +565,12 @@ impl EthFilterConfig {
         self
     }
 
+    /// Sets the maximum number of address and topic combinations a single filter can specify.
+    pub const fn max_filter_combinations(mut self, num: usize) -> Self {
+        self.max_filter_combinations = Some(num);
+        self
+    }
+
     /// Sets how long a filter remains valid after the last poll before it will be removed.
     pub const fn stale_filter_ttl(mut self, duration: Duration) -> Self {
         self.stale_filter_ttl = duration;
@@ -561,6 +583,7 @@ impl Default for EthFilterConfig {
         Self {
             max_blocks_per_filter: None,
             max_logs_per_response: None,
+            max_filter_combinations: None,
             // 5min
             stale_filter_ttl: Duration::from_secs(5 * 60),
         }
@@ -692,6 +715,8 @@ pub enum FilterError {
     QueryExceedsMaxBlocks(u64),
     #[error("query exceeds max results {0}")]
     QueryExceedsMaxResults(usize),
+    #[error("query exceeds max address and topic combinations {0}")]
+    QueryExceedsMaxCombinations(usize),
     #[error(transparent)]
     EthAPIError(#[from] EthApiError),
     /// Error thrown when a spawned task failed to deliver a response.
@@ -713,7 +738,8 @@ impl From<FilterError> for jsonrpsee::types::error::ErrorObject<'static> {
             FilterError::EthAPIError(err) => err.into(),
             err @ FilterError::InvalidBlockRangeParams |
             err @ FilterError::QueryExceedsMaxBlocks(_) |
-            err @ FilterError::QueryExceedsMaxResults(_) => {
+            err @ FilterError::QueryExceedsMaxResults(_) |
+            err @ FilterError::QueryExceedsMaxCombinations(_) => {
                 rpc_error_with_code(jsonrpsee::types::error::INVALID_PARAMS_CODE, err.to_string())
             }
         }
@@ -726,6 +752,19 @@ impl From<ProviderError> for FilterError {
     }
 }
 
+/// Returns the number of address and topic combinations the filter matches against.
+///
+/// Every address has to be checked against every combination of topic alternatives, so this is the
+/// product of the number of addresses and the number of alternatives per topic position.
+fn filter_combinations(filter: &Filter) -> usize {
+    filter
+        .topics
+        .iter()
+        .map(|topic| topic.iter().count())
+        .chain(std::iter::once(filter.address.iter().count()))
+        .fold(1usize, |combinations, alternatives| combinations.saturating_mul(alternatives.max(1)))
+}
+
 /// An iterator that yields _inclusive_ block ranges of a given step size
 #[derive(Debug)]
 struct BlockRangeInclusiveIter {
@@ -756,7 +795,14 @@ impl Iterator for BlockRangeInclusiveIter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use jsonrpsee::rpc_params;
     use rand::{thread_rng, Rng};
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_primitives::{Address, Block, Header, Receipt, TransactionSigned, TxType, B256};
+    use reth_provider::test_utils::{MockEthProvider, NoopProvider};
+    use reth_rpc_api::EthFilterApiServer;
+    use reth_tasks::TokioTaskExecutor;
+    use reth_transaction_pool::test_utils::testing_pool;
 
     #[test]
     fn test_block_range_iter() {
@@ -780,4 +826,111 @@ mod tests {
             assert_eq!(end, *range.end());
         }
     }
+
+    #[tokio::test]
+    async fn rejects_filters_exceeding_max_combinations() {
+        let provider = NoopProvider::default();
+        let cache = EthStateCache::spawn(provider, Default::default(), EthEvmConfig::default());
+        let eth_filter = EthFilter::new(
+            provider,
+            testing_pool(),
+            cache,
+            EthFilterConfig::default().max_filter_combinations(4),
+            Box::<TokioTaskExecutor>::default(),
+        );
+
+        // 2 addresses x 2 event signatures
+        let filter = Filter::new()
+            .from_block(0)
+            .to_block(0)
+            .address(vec![Address::random(), Address::random()])
+            .event_signature(vec![B256::random(), B256::random()]);
+        assert_eq!(filter_combinations(&filter), 4);
+        assert!(eth_filter.inner.logs_for_filter(filter.clone()).await.unwrap().is_empty());
+
+        // 2 addresses x 2 event signatures x 2 alternatives for the first indexed topic
+        let filter = filter.topic1(vec![B256::random(), B256::random()]);
+        assert_eq!(filter_combinations(&filter), 8);
+        assert!(matches!(
+            eth_filter.inner.logs_for_filter(filter).await,
+            Err(FilterError::QueryExceedsMaxCombinations(4))
+        ));
+    }
+
+    fn receipt_with_logs(addresses: &[Address]) -> Receipt {
+        Receipt {
+            tx_type: TxType::Legacy,
+            success: true,
+            logs: addresses
+                .iter()
+                .map(|address| {
+                    reth_primitives::Log::new_unchecked(*address, vec![], Default::default())
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn get_logs_by_block_hash() {
+        let provider = MockEthProvider::default();
+        let address = Address::random();
+        let other = Address::random();
+
+        // two blocks with logs of the same address, only the requested one must be returned
+        let mut blocks = Vec::new();
+        for number in 1..=2 {
+            let header = Header { number, ..Default::default() };
+            let hash = header.hash_slow();
+            let tx = TransactionSigned { hash: B256::random(), ..Default::default() };
+            blocks.push((hash, tx.hash()));
+            provider.add_block(hash, Block { header, body: vec![tx], ..Default::default() });
+            provider.add_receipts(hash, vec![receipt_with_logs(&[other, address])]);
+        }
+        let (block_hash, tx_hash) = blocks[0];
+
+        let cache =
+            EthStateCache::spawn(provider.clone(), Default::default(), EthEvmConfig::default());
+        let eth_filter = EthFilter::new(
+            provider,
+            testing_pool(),
+            cache,
+            EthFilterConfig::default(),
+            Box::<TokioTaskExecutor>::default(),
+        )
+        .into_rpc();
+
+        let logs: Vec<Log> = eth_filter
+            .call(
+                "eth_getLogs",
+                rpc_params![serde_json::json!({ "blockHash": block_hash, "address": address })],
+            )
+            .await
+            .unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].inner.address, address);
+        assert_eq!(logs[0].block_hash, Some(block_hash));
+        assert_eq!(logs[0].block_number, Some(1));
+        assert_eq!(logs[0].transaction_hash, Some(tx_hash));
+        assert_eq!(logs[0].log_index, Some(1));
+    }
+
+    #[tokio::test]
+    async fn get_logs_rejects_block_hash_with_range() {
+        let provider = NoopProvider::default();
+        let cache = EthStateCache::spawn(provider, Default::default(), EthEvmConfig::default());
+        let eth_filter = EthFilter::new(
+            provider,
+            testing_pool(),
+            cache,
+            EthFilterConfig::default(),
+            Box::<TokioTaskExecutor>::default(),
+        )
+        .into_rpc();
+
+        // block hash and range are mutually exclusive
+        let filter = serde_json::json!({ "blockHash": B256::random(), "fromBlock": "0x1" });
+        assert!(serde_json::from_value::<Filter>(filter.clone()).is_err());
+        assert!(eth_filter.call::<_, Vec<Log>>("eth_getLogs", rpc_params![filter]).await.is_err());
+    }
 }
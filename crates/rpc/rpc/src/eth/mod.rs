@@ -3,6 +3,7 @@
 mod api;
 pub mod bundle;
 pub mod cache;
+pub(crate) mod cancellation;
 pub mod error;
 mod filter;
 pub mod gas_oracle;
@@ -25,4 +26,4 @@ pub use api::{
 pub use bundle::EthBundle;
 pub use filter::{EthFilter, EthFilterConfig};
 pub use id_provider::EthSubscriptionIdProvider;
-pub use pubsub::EthPubSub;
+pub use pubsub::{EthPubSub, EthPubSubConfig};
@@ -4,14 +4,22 @@ use crate::{
     eth::logs_utils,
     result::{internal_rpc_err, invalid_params_rpc_err},
 };
-use futures::StreamExt;
+use futures::{
+    future::{Fuse, FusedFuture},
+    FutureExt, StreamExt,
+};
 use jsonrpsee::{
     server::SubscriptionMessage, types::ErrorObject, PendingSubscriptionSink, SubscriptionSink,
 };
+use reth_metrics::{metrics::Counter, Metrics};
 use reth_network_api::NetworkInfo;
 use reth_primitives::{IntoRecoveredTransaction, TxHash};
 use reth_provider::{BlockReader, CanonStateSubscriptions, EvmEnvProvider};
 use reth_rpc_api::EthPubSubApiServer;
+use reth_rpc_server_types::constants::{
+    DEFAULT_MAX_BUFFERED_LOGS, DEFAULT_MAX_BUFFERED_NEW_HEADS,
+    DEFAULT_MAX_BUFFERED_PENDING_TRANSACTIONS,
+};
 use reth_rpc_types::{
     pubsub::{
         Params, PubSubSyncStatus, SubscriptionKind, SubscriptionResult as EthSubscriptionResult,
@@ -22,7 +30,8 @@ use reth_rpc_types::{
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::{NewTransactionEvent, TransactionPool};
 use serde::Serialize;
-use std::sync::Arc;
+use std::{collections::VecDeque, pin::pin, sync::Arc};
+use tokio::sync::oneshot;
 use tokio_stream::{
     wrappers::{BroadcastStream, ReceiverStream},
     Stream,
@@ -37,6 +46,8 @@ pub struct EthPubSub<Provider, Pool, Events, Network> {
     inner: Arc<EthPubSubInner<Provider, Pool, Events, Network>>,
     /// The type that's used to spawn subscription tasks.
     subscription_task_spawner: Box<dyn TaskSpawner>,
+    /// Limits for the notifications buffered per subscription.
+    config: EthPubSubConfig,
 }
 
 // === impl EthPubSub ===
@@ -63,8 +74,24 @@ impl<Provider, Pool, Events, Network> EthPubSub<Provider, Pool, Events, Network>
         network: Network,
         subscription_task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
-        let inner = EthPubSubInner { provider, pool, chain_events, network };
-        Self { inner: Arc::new(inner), subscription_task_spawner }
+        let inner = EthPubSubInner {
+            provider,
+            pool,
+            chain_events,
+            network,
+            metrics: PubSubMetrics::default(),
+        };
+        Self {
+            inner: Arc::new(inner),
+            subscription_task_spawner,
+            config: EthPubSubConfig::default(),
+        }
+    }
+
+    /// Sets the limits for the notifications buffered per subscription.
+    pub const fn with_config(mut self, config: EthPubSubConfig) -> Self {
+        self.config = config;
+        self
     }
 }
 
@@ -86,11 +113,15 @@ where
     ) -> jsonrpsee::core::SubscriptionResult {
         let sink = pending.accept().await?;
         let pubsub = self.inner.clone();
+        let config = self.config;
+        let (tx, rx) = oneshot::channel();
         self.subscription_task_spawner.spawn(Box::pin(async move {
-            let _ = handle_accepted(pubsub, sink, kind, params).await;
+            let _ = tx.send(handle_accepted(pubsub, sink, kind, params, config).await);
         }));
 
-        Ok(())
+        // if the subscription task failed, e.g. because the subscriber couldn't keep up, the
+        // subscription is closed with an error notification
+        rx.await.unwrap_or(Ok(())).map_err(|err| err.message().into())
     }
 }
 
@@ -100,6 +131,7 @@ async fn handle_accepted<Provider, Pool, Events, Network>(
     accepted_sink: SubscriptionSink,
     kind: SubscriptionKind,
     params: Option<Params>,
+    config: EthPubSubConfig,
 ) -> Result<(), ErrorObject<'static>>
 where
     Provider: BlockReader + EvmEnvProvider + Clone + 'static,
@@ -112,7 +144,8 @@ where
             let stream = pubsub
                 .new_headers_stream()
                 .map(|block| EthSubscriptionResult::Header(Box::new(block.into())));
-            pipe_from_stream(accepted_sink, stream).await
+            pipe_from_stream(accepted_sink, stream, config.max_buffered_new_heads, &pubsub.metrics)
+                .await
         }
         SubscriptionKind::Logs => {
             // if no params are provided, used default filter params
@@ -125,7 +158,7 @@ where
             };
             let stream =
                 pubsub.log_stream(filter).map(|log| EthSubscriptionResult::Log(Box::new(log)));
-            pipe_from_stream(accepted_sink, stream).await
+            pipe_from_stream(accepted_sink, stream, config.max_buffered_logs, &pubsub.metrics).await
         }
         SubscriptionKind::NewPendingTransactions => {
             if let Some(params) = params {
@@ -139,7 +172,13 @@ where
                                 ),
                             ))
                         });
-                        return pipe_from_stream(accepted_sink, stream).await
+                        return pipe_from_stream(
+                            accepted_sink,
+                            stream,
+                            config.max_buffered_pending_transactions,
+                            &pubsub.metrics,
+                        )
+                        .await
                     }
                     Params::Bool(false) | Params::None => {
                         // only hashes requested
@@ -155,7 +194,13 @@ where
             let stream = pubsub
                 .pending_transaction_hashes_stream()
                 .map(EthSubscriptionResult::TransactionHash);
-            pipe_from_stream(accepted_sink, stream).await
+            pipe_from_stream(
+                accepted_sink,
+                stream,
+                config.max_buffered_pending_transactions,
+                &pubsub.metrics,
+            )
+            .await
         }
         SubscriptionKind::Syncing => {
             // get new block subscription
@@ -211,38 +256,136 @@ impl From<SubscriptionSerializeError> for ErrorObject<'static> {
     }
 }
 
+/// Error returned if a subscriber doesn't keep up with the notifications of its subscription.
+#[derive(Debug, thiserror::Error)]
+#[error("Subscription dropped: more than {0} notifications queued for a slow subscriber")]
+pub(crate) struct SubscriptionBufferFullError(usize);
+
+impl SubscriptionBufferFullError {
+    pub(crate) const fn new(max_buffered: usize) -> Self {
+        Self(max_buffered)
+    }
+}
+
+impl From<SubscriptionBufferFullError> for ErrorObject<'static> {
+    fn from(value: SubscriptionBufferFullError) -> Self {
+        internal_rpc_err(value.to_string())
+    }
+}
+
 /// Pipes all stream items to the subscription sink.
+///
+/// Items are queued while the subscriber is still receiving earlier notifications. If more than
+/// `max_buffered` items are queued, the subscriber can't keep up and the subscription is dropped
+/// with a [`SubscriptionBufferFullError`].
 async fn pipe_from_stream<T, St>(
     sink: SubscriptionSink,
     mut stream: St,
+    max_buffered: usize,
+    metrics: &PubSubMetrics,
 ) -> Result<(), ErrorObject<'static>>
 where
     St: Stream<Item = T> + Unpin,
     T: Serialize,
 {
+    let mut buffered = VecDeque::new();
+    let mut stream_ended = false;
+    let mut sending = pin!(Fuse::terminated());
+
     loop {
+        if sending.is_terminated() {
+            match buffered.pop_front() {
+                Some(msg) => sending.set(sink.send(msg).fuse()),
+                None if stream_ended => {
+                    // stream ended and all items were sent
+                    break Ok(())
+                }
+                None => {}
+            }
+        }
+
+        if buffered.len() > max_buffered {
+            metrics.dropped_subscriptions_total.increment(1);
+            break Err(SubscriptionBufferFullError::new(max_buffered).into())
+        }
+
         tokio::select! {
             _ = sink.closed() => {
                 // connection dropped
                 break Ok(())
             },
-            maybe_item = stream.next() => {
-                let item = match maybe_item {
-                    Some(item) => item,
-                    None => {
-                        // stream ended
-                        break  Ok(())
-                    },
-                };
-                let msg = SubscriptionMessage::from_json(&item).map_err(SubscriptionSerializeError::new)?;
-                if sink.send(msg).await.is_err() {
-                    break Ok(());
+            res = &mut sending, if !sending.is_terminated() => {
+                if res.is_err() {
+                    break Ok(())
+                }
+            },
+            maybe_item = stream.next(), if !stream_ended => {
+                match maybe_item {
+                    Some(item) => {
+                        let msg = SubscriptionMessage::from_json(&item)
+                            .map_err(SubscriptionSerializeError::new)?;
+                        buffered.push_back(msg);
+                    }
+                    None => stream_ended = true,
                 }
             }
         }
     }
 }
 
+/// Settings for the `eth_subscribe` handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EthPubSubConfig {
+    /// Maximum number of `newHeads` notifications queued for a subscriber before the
+    /// subscription is dropped.
+    pub max_buffered_new_heads: usize,
+    /// Maximum number of `logs` notifications queued for a subscriber before the subscription is
+    /// dropped.
+    pub max_buffered_logs: usize,
+    /// Maximum number of `newPendingTransactions` notifications queued for a subscriber before
+    /// the subscription is dropped.
+    pub max_buffered_pending_transactions: usize,
+}
+
+impl EthPubSubConfig {
+    /// Sets the maximum number of queued `newHeads` notifications per subscription.
+    pub const fn max_buffered_new_heads(mut self, num: usize) -> Self {
+        self.max_buffered_new_heads = num;
+        self
+    }
+
+    /// Sets the maximum number of queued `logs` notifications per subscription.
+    pub const fn max_buffered_logs(mut self, num: usize) -> Self {
+        self.max_buffered_logs = num;
+        self
+    }
+
+    /// Sets the maximum number of queued `newPendingTransactions` notifications per
+    /// subscription.
+    pub const fn max_buffered_pending_transactions(mut self, num: usize) -> Self {
+        self.max_buffered_pending_transactions = num;
+        self
+    }
+}
+
+impl Default for EthPubSubConfig {
+    fn default() -> Self {
+        Self {
+            max_buffered_new_heads: DEFAULT_MAX_BUFFERED_NEW_HEADS,
+            max_buffered_logs: DEFAULT_MAX_BUFFERED_LOGS,
+            max_buffered_pending_transactions: DEFAULT_MAX_BUFFERED_PENDING_TRANSACTIONS,
+        }
+    }
+}
+
+/// Metrics for the `eth_subscribe` handler.
+#[derive(Metrics, Clone)]
+#[metrics(scope = "rpc.eth_pubsub")]
+struct PubSubMetrics {
+    /// The number of subscriptions dropped because the subscriber couldn't keep up.
+    dropped_subscriptions_total: Counter,
+}
+
 impl<Provider, Pool, Events, Network> std::fmt::Debug
     for EthPubSub<Provider, Pool, Events, Network>
 {
@@ -262,6 +405,8 @@ struct EthPubSubInner<Provider, Pool, Events, Network> {
     chain_events: Events,
     /// The network.
     network: Network,
+    /// Metrics for all subscriptions.
+    metrics: PubSubMetrics,
 }
 
 // == impl EthPubSubInner ===
@@ -339,3 +484,93 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{core::SubscriptionResult, rpc_params, RpcModule};
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use tokio::sync::mpsc;
+
+    /// Pipes the items to the subscriber, the error the pipe ended with is sent to `result`.
+    async fn pipe_items(
+        pending: PendingSubscriptionSink,
+        items: usize,
+        max_buffered: usize,
+        metrics: PubSubMetrics,
+        result: mpsc::UnboundedSender<Option<String>>,
+    ) -> SubscriptionResult {
+        let sink = pending.accept().await?;
+        let stream = futures::stream::iter(0..items);
+        let res = pipe_from_stream(sink, stream, max_buffered, &metrics)
+            .await
+            .map_err(|err| err.message().to_string());
+        let _ = result.send(res.clone().err());
+        res?;
+        Ok(())
+    }
+
+    fn pipe_module(
+        items: usize,
+        max_buffered: usize,
+        metrics: PubSubMetrics,
+    ) -> (RpcModule<()>, mpsc::UnboundedReceiver<Option<String>>) {
+        let (result_tx, result_rx) = mpsc::unbounded_channel();
+        let mut module = RpcModule::new(());
+        module
+            .register_subscription(
+                "subscribe_items",
+                "s_items",
+                "unsubscribe_items",
+                move |_, pending, _, _| {
+                    pipe_items(pending, items, max_buffered, metrics.clone(), result_tx.clone())
+                },
+            )
+            .unwrap();
+        (module, result_rx)
+    }
+
+    #[tokio::test]
+    async fn pipe_delivers_all_items_to_subscriber() {
+        let (module, mut results) = pipe_module(64, 64, PubSubMetrics::default());
+        let mut sub = module.subscribe("subscribe_items", rpc_params![], 1).await.unwrap();
+
+        for expected in 0..64 {
+            let (item, _) = sub.next::<usize>().await.unwrap().unwrap();
+            assert_eq!(item, expected);
+        }
+        assert_eq!(results.recv().await, Some(None));
+    }
+
+    #[tokio::test]
+    async fn pipe_drops_slow_subscriber() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let metrics = metrics::with_local_recorder(&recorder, PubSubMetrics::default);
+        let (module, mut results) = pipe_module(1024, 16, metrics);
+        let mut sub = module.subscribe("subscribe_items", rpc_params![], 1).await.unwrap();
+
+        // nothing is read before the queue exceeds its limit, so only the items that already
+        // made it into the subscription channel are delivered
+        let mut received = 0;
+        while let Some(Ok(_)) = sub.next::<usize>().await {
+            received += 1;
+        }
+        assert!(received < 1024);
+
+        let err = results.recv().await.unwrap();
+        assert_eq!(err, Some(SubscriptionBufferFullError::new(16).to_string()));
+
+        let dropped = snapshotter.snapshot().into_vec().into_iter().find_map(
+            |(key, _, _, value)| match value {
+                DebugValue::Counter(count)
+                    if key.key().name() == "rpc.eth_pubsub.dropped_subscriptions_total" =>
+                {
+                    Some(count)
+                }
+                _ => None,
+            },
+        );
+        assert_eq!(dropped, Some(1));
+    }
+}
@@ -11,7 +11,7 @@ use reth_primitives::{
 };
 use reth_rpc_types::{
     state::{AccountOverride, StateOverride},
-    BlockOverrides, TransactionRequest,
+    BlockOverrides, TransactionRequest, TransactionTraceOverrides,
 };
 #[cfg(feature = "optimism")]
 use revm::primitives::{Bytes, OptimismFields};
@@ -483,6 +483,23 @@ fn apply_block_overrides(overrides: BlockOverrides, env: &mut BlockEnv) {
     }
 }
 
+/// Applies the given overrides to the env of a transaction that is re-traced.
+pub(crate) fn apply_transaction_trace_overrides(
+    overrides: TransactionTraceOverrides,
+    env: &mut TxEnv,
+) {
+    let TransactionTraceOverrides { from, gas } = overrides;
+
+    if let Some(from) = from {
+        env.caller = from;
+        // the nonce of the transaction belongs to the original sender
+        env.nonce = None;
+    }
+    if let Some(gas) = gas {
+        env.gas_limit = gas;
+    }
+}
+
 /// Applies the given state overrides (a set of [`AccountOverride`]) to the [`CacheDB`].
 pub(crate) fn apply_state_overrides<DB>(
     overrides: StateOverride,
@@ -36,22 +36,26 @@ mod admin;
 mod debug;
 mod engine;
 pub mod eth;
+mod mev;
 mod net;
 mod otterscan;
 mod reth;
 mod rpc;
 mod trace;
+mod trace_depth;
 mod txpool;
 mod web3;
 pub use admin::AdminApi;
-pub use debug::DebugApi;
+pub use debug::{DebugApi, DebugApiConfig};
 pub use engine::{EngineApi, EngineEthApi};
 pub use eth::{EthApi, EthApiSpec, EthFilter, EthPubSub, EthSubscriptionIdProvider};
+pub use mev::{MevApi, MevSimBundleError};
 pub use net::NetApi;
 pub use otterscan::OtterscanApi;
-pub use reth::RethApi;
+pub use reth::{RethApi, RethApiConfig};
 pub use rpc::RPCApi;
-pub use trace::TraceApi;
+pub use trace::{TraceApi, TraceApiConfig};
+pub use trace_depth::TRACE_TRUNCATED;
 pub use txpool::TxPoolApi;
 pub use web3::Web3Api;
 pub mod result;
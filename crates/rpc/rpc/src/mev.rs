@@ -0,0 +1,469 @@
+//! `mev` namespace handler implementation.
+
+use crate::eth::{
+    bundle::EthBundleError,
+    error::{EthApiError, EthResult},
+    revm_utils::FillableTransaction,
+    utils::recover_raw_transaction,
+    EthTransactions,
+};
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_primitives::{
+    constants::{eip4844::MAINNET_KZG_TRUSTED_SETUP, SLOT_DURATION},
+    revm_primitives::db::{DatabaseCommit, DatabaseRef},
+    BlockId, BlockNumberOrTag, PooledTransactionsElement, U256,
+};
+use reth_revm::database::StateProviderDatabase;
+use reth_rpc_api::MevApiServer;
+use reth_rpc_types::{
+    BundleItem, SendBundleRequest, SendBundleResponse, SimBundleOverrides, SimBundleResponse,
+    SimBundleTxResult,
+};
+use reth_tasks::pool::BlockingTaskGuard;
+use revm::{
+    db::CacheDB,
+    primitives::{ResultAndState, TxEnv},
+};
+use revm_primitives::EnvWithHandlerCfg;
+use std::{sync::Arc, time::Duration};
+
+/// The default timeout of a `mev_simBundle` simulation.
+const DEFAULT_SIM_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The maximum timeout of a `mev_simBundle` simulation, larger timeouts are capped.
+const MAX_SIM_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `mev` API implementation.
+///
+/// Only `mev_simBundle` is supported, there is no relay to forward bundles to.
+pub struct MevApi<Eth> {
+    /// All nested fields bundled together.
+    inner: Arc<MevApiInner<Eth>>,
+}
+
+impl<Eth> MevApi<Eth> {
+    /// Create a new `MevApi` instance.
+    pub fn new(eth_api: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
+        Self { inner: Arc::new(MevApiInner { eth_api, blocking_task_guard }) }
+    }
+}
+
+impl<Eth> MevApi<Eth>
+where
+    Eth: EthTransactions + 'static,
+{
+    /// Simulates a fully matched bundle on top of the parent block given in the overrides.
+    ///
+    /// The transactions are executed in order with the same EVM setup as `eth_callBundle`. The
+    /// simulation stops at the first transaction that reverts without being allowed to, the
+    /// response is then unsuccessful and the error names the failing transaction.
+    ///
+    /// The simulation is aborted once the timeout elapsed. Timeouts are capped at 30 seconds.
+    pub async fn sim_bundle(
+        &self,
+        bundle: SendBundleRequest,
+        overrides: SimBundleOverrides,
+    ) -> EthResult<SimBundleResponse> {
+        let SendBundleRequest { inclusion, bundle_body, .. } = bundle;
+        let SimBundleOverrides {
+            parent_block,
+            block_number,
+            coinbase,
+            timestamp,
+            gas_limit,
+            base_fee,
+            timeout,
+        } = overrides;
+
+        if bundle_body.is_empty() {
+            return Err(EthApiError::InvalidParams(
+                EthBundleError::EmptyBundleTransactions.to_string(),
+            ))
+        }
+
+        let transactions = bundle_body
+            .into_iter()
+            .map(|item| match item {
+                BundleItem::Tx { tx, can_revert } => Ok((recover_raw_transaction(tx)?, can_revert)),
+                BundleItem::Hash { .. } => {
+                    Err(EthApiError::InvalidParams(MevSimBundleError::UnmatchedBundle.to_string()))
+                }
+            })
+            .collect::<EthResult<Vec<_>>>()?;
+
+        let parent_block = parent_block.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest));
+        let (cfg, mut block_env, at) = self.inner.eth_api.evm_env_at(parent_block).await?;
+        let state_block = block_env.number.to::<u64>();
+
+        // the simulated block is the child of the parent block, unless overridden
+        let block_number = block_number.unwrap_or(state_block + 1);
+        if block_number < inclusion.block_number() ||
+            inclusion.max_block_number().is_some_and(|max_block| block_number > max_block)
+        {
+            return Err(EthApiError::InvalidParams(
+                MevSimBundleError::BlockOutsideInclusion(block_number).to_string(),
+            ))
+        }
+        block_env.number = U256::from(block_number);
+
+        // need to adjust the timestamp for the next block
+        if let Some(timestamp) = timestamp {
+            block_env.timestamp = U256::from(timestamp);
+        } else {
+            block_env.timestamp += U256::from(SLOT_DURATION.as_secs());
+        }
+        if let Some(coinbase) = coinbase {
+            block_env.coinbase = coinbase;
+        }
+        if let Some(gas_limit) = gas_limit {
+            block_env.gas_limit = U256::from(gas_limit);
+        }
+        if let Some(base_fee) = base_fee {
+            block_env.basefee = U256::from(base_fee);
+        }
+
+        let _permit = self.inner.blocking_task_guard.clone().acquire_owned().await;
+        // the execution is halted once the simulation is dropped on timeout
+        let simulation = self.inner.eth_api.spawn_with_state_at_block(at, move |eth, state| {
+            let coinbase = block_env.coinbase;
+            let mut env = EnvWithHandlerCfg::new_with_cfg_env(cfg, block_env, TxEnv::default());
+            let mut db = CacheDB::new(StateProviderDatabase::new(state));
+
+            let initial_coinbase =
+                DatabaseRef::basic_ref(&db, coinbase)?.map(|acc| acc.balance).unwrap_or_default();
+            let mut coinbase_balance = initial_coinbase;
+            let mut total_gas_used = 0u64;
+            let mut tx_results = Vec::with_capacity(transactions.len());
+            let mut error = None;
+
+            for (idx, (tx, can_revert)) in transactions.into_iter().enumerate() {
+                let (tx, signer) = tx.into_components();
+
+                // Verify that the given blob data, commitments, and proofs are all valid for
+                // this transaction.
+                if let PooledTransactionsElement::BlobTransaction(ref tx) = tx {
+                    tx.validate(MAINNET_KZG_TRUSTED_SETUP.as_ref())
+                        .map_err(|e| EthApiError::InvalidParams(e.to_string()))?;
+                }
+
+                let tx = tx.into_ecrecovered_transaction(signer);
+                tx.try_fill_tx_env(&mut env.tx)?;
+                let (ResultAndState { result, state }, next_env) = match eth.transact(&mut db, env)
+                {
+                    Ok(res) => res,
+                    Err(err) => {
+                        error = Some(format!("tx {idx} ({}) is invalid: {err}", tx.hash()));
+                        break
+                    }
+                };
+                env = next_env;
+
+                let gas_used = result.gas_used();
+                total_gas_used += gas_used;
+
+                // coinbase is always present in the result state
+                let coinbase_balance_after_tx =
+                    state.get(&coinbase).map(|acc| acc.info.balance).unwrap_or_default();
+                let coinbase_diff = coinbase_balance_after_tx.saturating_sub(coinbase_balance);
+                coinbase_balance = coinbase_balance_after_tx;
+
+                let reverted = !result.is_success();
+                tx_results.push(SimBundleTxResult {
+                    tx_hash: tx.hash(),
+                    gas_used,
+                    coinbase_diff,
+                    reverted,
+                    can_revert,
+                });
+
+                if reverted && !can_revert {
+                    error = Some(format!("tx {idx} ({}) reverted", tx.hash()));
+                    break
+                }
+
+                // need to apply the state changes of this transaction before executing the next
+                // one
+                db.commit(state)
+            }
+
+            let profit = coinbase_balance.saturating_sub(initial_coinbase);
+            let mev_gas_price = profit.checked_div(U256::from(total_gas_used)).unwrap_or_default();
+
+            Ok(SimBundleResponse {
+                success: error.is_none(),
+                error,
+                state_block,
+                mev_gas_price: mev_gas_price.saturating_to(),
+                profit: profit.saturating_to(),
+                // all transactions are part of the bundle, so all of the profit is refundable
+                refundable_value: profit.saturating_to(),
+                gas_used: total_gas_used,
+                logs: None,
+                tx_results,
+            })
+        });
+
+        let timeout =
+            timeout.map(Duration::from_secs).unwrap_or(DEFAULT_SIM_TIMEOUT).min(MAX_SIM_TIMEOUT);
+        tokio::time::timeout(timeout, simulation)
+            .await
+            .map_err(|_| EthApiError::ExecutionTimedOut(timeout))?
+    }
+}
+
+#[async_trait]
+impl<Eth> MevApiServer for MevApi<Eth>
+where
+    Eth: EthTransactions + 'static,
+{
+    /// Handler for `mev_sendBundle`
+    async fn send_bundle(&self, _request: SendBundleRequest) -> RpcResult<SendBundleResponse> {
+        Err(EthApiError::Unsupported("mev_sendBundle is not supported").into())
+    }
+
+    /// Handler for `mev_simBundle`
+    async fn sim_bundle(
+        &self,
+        bundle: SendBundleRequest,
+        sim_overrides: SimBundleOverrides,
+    ) -> RpcResult<SimBundleResponse> {
+        Ok(Self::sim_bundle(self, bundle, sim_overrides).await?)
+    }
+}
+
+/// Container type for `MevApi` internals
+#[derive(Debug)]
+struct MevApiInner<Eth> {
+    /// Access to commonly used code of the `eth` namespace
+    eth_api: Eth,
+    // restrict the number of concurrent simulations.
+    blocking_task_guard: BlockingTaskGuard,
+}
+
+impl<Eth> std::fmt::Debug for MevApi<Eth> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MevApi").finish_non_exhaustive()
+    }
+}
+
+impl<Eth> Clone for MevApi<Eth> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+/// [`MevApi`] specific errors.
+#[derive(Debug, thiserror::Error)]
+pub enum MevSimBundleError {
+    /// Thrown if the bundle references transactions by hash, which can't be simulated.
+    #[error("only fully matched bundles can be simulated")]
+    UnmatchedBundle,
+    /// Thrown if the simulated block is outside of the bundle's inclusion range.
+    #[error("block {0} is outside of the bundle's inclusion range")]
+    BlockOutsideInclusion(u64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{
+        cache::EthStateCache, gas_oracle::GasPriceOracle, EthApi, FeeHistoryCache,
+        FeeHistoryCacheConfig,
+    };
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_primitives::{
+        constants::{ETHEREUM_BLOCK_GAS_LIMIT, ETH_TO_WEI},
+        Address, Block, Bytes, Header, Transaction, TxKind, TxLegacy, B256,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_rpc_types::ProtocolVersion;
+    use reth_tasks::pool::BlockingTaskPool;
+    use reth_testing_utils::generators::{self, generate_keys, sign_tx_with_key_pair};
+    use reth_transaction_pool::test_utils::testing_pool;
+
+    #[tokio::test]
+    async fn sim_bundle_reports_failing_tx() {
+        let provider = MockEthProvider::default();
+        let evm_config = EthEvmConfig::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default(), evm_config);
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache.clone()),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            FeeHistoryCache::new(cache, FeeHistoryCacheConfig::default()),
+            evm_config,
+            None,
+        );
+        let mev = MevApi::new(eth_api, BlockingTaskGuard::new(1));
+
+        // a contract that always reverts: PUSH1 0 PUSH1 0 REVERT
+        let reverter = Address::random();
+        provider.add_account(
+            reverter,
+            ExtendedAccount::new(0, U256::ZERO)
+                .with_bytecode(Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xfd])),
+        );
+
+        // a plain transfer followed by a call to the reverting contract
+        let mut rng = generators::rng();
+        let key_pair = generate_keys(&mut rng, 1)[0];
+        let transactions = [Address::random(), reverter]
+            .into_iter()
+            .enumerate()
+            .map(|(nonce, to)| {
+                sign_tx_with_key_pair(
+                    key_pair,
+                    Transaction::Legacy(TxLegacy {
+                        chain_id: Some(1),
+                        nonce: nonce as u64,
+                        gas_price: 1,
+                        gas_limit: 50_000,
+                        to: TxKind::Call(to),
+                        value: U256::from(1_000),
+                        ..Default::default()
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+        let sender = transactions[0].recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+
+        let header =
+            Header { number: 1, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+        provider.add_block(header.hash_slow(), Block { header, ..Default::default() });
+
+        let bundle = |can_revert| {
+            SendBundleRequest::new(
+                2,
+                None,
+                ProtocolVersion::V0_1,
+                transactions
+                    .iter()
+                    .map(|tx| BundleItem::Tx { tx: tx.envelope_encoded(), can_revert })
+                    .collect(),
+            )
+        };
+        let overrides = SimBundleOverrides {
+            parent_block: Some(BlockNumberOrTag::Number(1).into()),
+            ..Default::default()
+        };
+
+        let response = mev.sim_bundle(bundle(false), overrides.clone()).await.unwrap();
+        assert!(!response.success);
+        assert_eq!(response.state_block, 1);
+        assert!(response.error.unwrap().starts_with(&format!("tx 1 ({})", transactions[1].hash())));
+        assert_eq!(response.tx_results.len(), 2);
+        assert!(!response.tx_results[0].reverted);
+        assert!(response.tx_results[1].reverted);
+
+        // the same bundle succeeds if the transactions are allowed to revert
+        let response = mev.sim_bundle(bundle(true), overrides.clone()).await.unwrap();
+        assert!(response.success);
+        assert!(response.error.is_none());
+        assert_eq!(
+            response.gas_used,
+            response.tx_results.iter().map(|res| res.gas_used).sum::<u64>()
+        );
+
+        // bundles that reference other transactions by hash can't be simulated
+        let unmatched = SendBundleRequest::new(
+            2,
+            None,
+            ProtocolVersion::V0_1,
+            vec![BundleItem::Hash { hash: B256::random() }],
+        );
+        assert!(matches!(
+            mev.sim_bundle(unmatched, overrides.clone()).await,
+            Err(EthApiError::InvalidParams(_))
+        ));
+
+        // the simulated block must be within the inclusion range
+        let outside = SimBundleOverrides { block_number: Some(5), ..overrides };
+        let mut late = bundle(true);
+        late.inclusion.max_block = Some(4);
+        assert!(matches!(mev.sim_bundle(late, outside).await, Err(EthApiError::InvalidParams(_))));
+    }
+
+    #[tokio::test]
+    async fn sim_bundle_is_aborted_on_timeout() {
+        // large enough for the loop to run for hours
+        let gas_limit = 1 << 50;
+        let provider = MockEthProvider::default();
+        let evm_config = EthEvmConfig::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default(), evm_config);
+        // a single thread, so the looping simulation would block all following simulations
+        let blocking_task_pool = BlockingTaskPool::new(
+            BlockingTaskPool::builder().num_threads(1).build().expect("failed to build pool"),
+        );
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache.clone()),
+            gas_limit,
+            blocking_task_pool,
+            FeeHistoryCache::new(cache, FeeHistoryCacheConfig::default()),
+            evm_config,
+            None,
+        );
+        let mev = MevApi::new(eth_api, BlockingTaskGuard::new(1));
+
+        // JUMPDEST, PUSH1 0, JUMP
+        let looping = Address::random();
+        provider.add_account(
+            looping,
+            ExtendedAccount::new(0, U256::ZERO)
+                .with_bytecode(Bytes::from_static(&[0x5b, 0x60, 0x00, 0x56])),
+        );
+
+        let mut rng = generators::rng();
+        let key_pair = generate_keys(&mut rng, 1)[0];
+        let transaction = |to: Address, gas_limit: u64| {
+            sign_tx_with_key_pair(
+                key_pair,
+                Transaction::Legacy(TxLegacy {
+                    chain_id: Some(1),
+                    gas_price: 1,
+                    gas_limit,
+                    to: TxKind::Call(to),
+                    ..Default::default()
+                }),
+            )
+        };
+        let sender = transaction(looping, gas_limit).recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+
+        let header = Header { number: 1, gas_limit, ..Default::default() };
+        provider.add_block(header.hash_slow(), Block { header, ..Default::default() });
+
+        let bundle = |to: Address, gas_limit: u64| {
+            SendBundleRequest::new(
+                2,
+                None,
+                ProtocolVersion::V0_1,
+                vec![BundleItem::Tx {
+                    tx: transaction(to, gas_limit).envelope_encoded(),
+                    can_revert: false,
+                }],
+            )
+        };
+        let overrides = |timeout| SimBundleOverrides {
+            parent_block: Some(BlockNumberOrTag::Number(1).into()),
+            timeout: Some(timeout),
+            ..Default::default()
+        };
+
+        let res = mev.sim_bundle(bundle(looping, gas_limit), overrides(1)).await;
+        assert!(matches!(res, Err(EthApiError::ExecutionTimedOut(_))));
+
+        // the looping simulation no longer occupies the only thread
+        let res = mev.sim_bundle(bundle(Address::random(), 21_000), overrides(10)).await;
+        assert!(res.expect("looping simulation was not aborted").success);
+    }
+}
@@ -3,7 +3,7 @@ use jsonrpsee::core::RpcResult as Result;
 use reth_network_api::PeersInfo;
 use reth_primitives::U64;
 use reth_rpc_api::NetApiServer;
-use reth_rpc_types::PeerCount;
+use reth_rpc_types::{PeerCount, PeerCountByDirection};
 
 /// `Net` API implementation.
 ///
@@ -41,6 +41,13 @@ where
         Ok(PeerCount::Hex(U64::from(self.network.num_connected_peers())))
     }
 
+    /// Handler for `net_peerCountByDirection`
+    fn peer_count_by_direction(&self) -> Result<PeerCountByDirection> {
+        let inbound = self.network.num_inbound_peers() as u64;
+        let outbound = self.network.num_outbound_peers() as u64;
+        Ok(PeerCountByDirection { inbound, outbound, total: inbound + outbound })
+    }
+
     /// Handler for `net_listening`
     fn is_listening(&self) -> Result<bool> {
         Ok(true)
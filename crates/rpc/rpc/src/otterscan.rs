@@ -1,15 +1,22 @@
 use alloy_primitives::Bytes;
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
-use revm_inspectors::transfer::{TransferInspector, TransferKind};
+use revm_inspectors::{
+    tracing::TracingInspectorConfig,
+    transfer::{TransferInspector, TransferKind},
+};
 use revm_primitives::ExecutionResult;
 
-use reth_primitives::{Address, BlockId, BlockNumberOrTag, TxHash, B256};
+use reth_primitives::{Address, BlockId, BlockNumber, BlockNumberOrTag, TxHash, B256};
+use reth_provider::{BlockNumReader, ChangeSetReader, ProviderResult};
 use reth_rpc_api::{EthApiServer, OtterscanServer};
 use reth_rpc_types::{
-    trace::otterscan::{
-        BlockDetails, ContractCreator, InternalOperation, OperationType, OtsBlockTransactions,
-        OtsReceipt, OtsTransactionReceipt, TraceEntry, TransactionsWithReceipts,
+    trace::{
+        otterscan::{
+            BlockDetails, ContractCreator, InternalOperation, OperationType, OtsBlockTransactions,
+            OtsReceipt, OtsTransactionReceipt, TraceEntry, TransactionsWithReceipts,
+        },
+        parity::{Action, CreateAction, CreateOutput, TraceOutput},
     },
     BlockTransactions, Transaction,
 };
@@ -20,20 +27,22 @@ const API_LEVEL: u64 = 8;
 
 /// Otterscan API.
 #[derive(Debug)]
-pub struct OtterscanApi<Eth> {
+pub struct OtterscanApi<Provider, Eth> {
+    provider: Provider,
     eth: Eth,
 }
 
-impl<Eth> OtterscanApi<Eth> {
+impl<Provider, Eth> OtterscanApi<Provider, Eth> {
     /// Creates a new instance of `Otterscan`.
-    pub const fn new(eth: Eth) -> Self {
-        Self { eth }
+    pub const fn new(provider: Provider, eth: Eth) -> Self {
+        Self { provider, eth }
     }
 }
 
 #[async_trait]
-impl<Eth> OtterscanServer for OtterscanApi<Eth>
+impl<Provider, Eth> OtterscanServer for OtterscanApi<Provider, Eth>
 where
+    Provider: BlockNumReader + ChangeSetReader + Clone + 'static,
     Eth: EthApiServer + EthTransactions,
 {
     /// Handler for `ots_hasCode`
@@ -206,7 +215,201 @@ where
     }
 
     /// Handler for `getContractCreator`
-    async fn get_contract_creator(&self, _address: Address) -> RpcResult<Option<ContractCreator>> {
-        Err(internal_rpc_err("unimplemented"))
+    async fn get_contract_creator(&self, address: Address) -> RpcResult<Option<ContractCreator>> {
+        if !self.has_code(address, None).await? {
+            return Ok(None)
+        }
+
+        let provider = self.provider.clone();
+        let block = self
+            .eth
+            .spawn_blocking(move || {
+                let tip = provider.best_block_number()?;
+                Ok(creation_block(&provider, address, tip)?)
+            })
+            .await?;
+
+        // the contract is part of the genesis state or was created before the available history
+        let Some(block) = block.filter(|block| *block != 0) else { return Ok(None) };
+
+        let creators = self
+            .eth
+            .trace_block_with(
+                block.into(),
+                TracingInspectorConfig::default_parity(),
+                move |tx_info, inspector, _, _, _| {
+                    let Some(hash) = tx_info.hash else { return Ok(None) };
+                    let creator = inspector
+                        .into_parity_builder()
+                        .into_transaction_traces()
+                        .into_iter()
+                        .filter_map(|trace| match (trace.action, trace.result) {
+                            (
+                                Action::Create(CreateAction { from, .. }),
+                                Some(TraceOutput::Create(CreateOutput {
+                                    address: created, ..
+                                })),
+                            ) if created == address && trace.error.is_none() => Some(from),
+                            _ => None,
+                        })
+                        .last();
+                    Ok(creator.map(|creator| ContractCreator { hash, creator }))
+                },
+            )
+            .await?
+            .unwrap_or_default();
+
+        // the contract can be destroyed and created again within the block, the last creation of
+        // the block is the one of its current code
+        Ok(creators.into_iter().flatten().last())
+    }
+}
+
+/// Returns the block that created the current code of the account, which has code at the `tip`.
+///
+/// The account history is searched backwards from the tip with exponentially growing steps, until
+/// a block is found before which the account had no code. The creation block is then
+/// binary-searched between that block and the last probed block before which the account had
+/// code. Each probe reads one history shard and one changeset entry, so only `O(log n)` blocks of
+/// the history are read.
+///
+/// If the contract was destroyed and created again, the search finds the most recent creation,
+/// unless several creations fall between two probes. Returns `None` if the account already had
+/// code before the oldest available block of its history.
+fn creation_block<Provider>(
+    provider: &Provider,
+    address: Address,
+    tip: BlockNumber,
+) -> ProviderResult<Option<BlockNumber>>
+where
+    Provider: ChangeSetReader,
+{
+    // Returns whether the account had no code before the block, `None` if the changeset of the
+    // block that next changed the account was pruned.
+    let had_no_code_before = |block| -> ProviderResult<Option<bool>> {
+        let Some(change) = provider.account_next_change_block(address, block)? else {
+            // the account wasn't changed since, so it has its current code
+            return Ok(Some(false))
+        };
+        Ok(provider
+            .account_before_block(change, address)?
+            .map(|before| !before.info.is_some_and(|account| account.has_bytecode())))
+    };
+
+    // the account has code before `high` and no code before `low`
+    let mut high = tip + 1;
+    let mut step = 1;
+    let mut low = loop {
+        let block = high.saturating_sub(step);
+        match had_no_code_before(block)? {
+            Some(true) => break block,
+            Some(false) if block > 0 => {
+                high = block;
+                step *= 2;
+            }
+            _ => return Ok(None),
+        }
+    };
+
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        match had_no_code_before(mid)? {
+            Some(true) => low = mid,
+            Some(false) => high = mid,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(low))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_db_api::models::AccountBeforeTx;
+    use reth_primitives::{Account, U256};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const CONTRACT: Address = Address::with_last_byte(0x42);
+
+    /// The changes of the contract, with its state from before each change. Blocks without a state
+    /// have a pruned changeset.
+    struct History {
+        changes: Vec<(BlockNumber, Option<Option<Account>>)>,
+        /// The number of history index lookups.
+        lookups: AtomicUsize,
+    }
+
+    impl History {
+        fn new(changes: Vec<(BlockNumber, Option<Option<Account>>)>) -> Self {
+            Self { changes, lookups: AtomicUsize::new(0) }
+        }
+    }
+
+    impl ChangeSetReader for History {
+        fn account_block_changeset(
+            &self,
+            block_number: BlockNumber,
+        ) -> ProviderResult<Vec<AccountBeforeTx>> {
+            Ok(self
+                .changes
+                .iter()
+                .filter(|(block, _)| *block == block_number)
+                .filter_map(|(_, info)| *info)
+                .map(|info| AccountBeforeTx { address: CONTRACT, info })
+                .collect())
+        }
+
+        fn account_next_change_block(
+            &self,
+            address: Address,
+            block_number: BlockNumber,
+        ) -> ProviderResult<Option<BlockNumber>> {
+            assert_eq!(address, CONTRACT);
+            self.lookups.fetch_add(1, Ordering::Relaxed);
+            Ok(self.changes.iter().map(|(block, _)| *block).find(|block| *block >= block_number))
+        }
+    }
+
+    fn contract() -> Option<Account> {
+        Some(Account { nonce: 0, balance: U256::ZERO, bytecode_hash: Some(B256::repeat_byte(1)) })
+    }
+
+    #[test]
+    fn creation_block_of_recreated_contract() {
+        let history = History::new(vec![
+            // created
+            (2, Some(None)),
+            // balance changed
+            (4, Some(contract())),
+            // self destructed
+            (6, Some(contract())),
+            // created again
+            (9, Some(None)),
+            // balance changed
+            (11, Some(contract())),
+        ]);
+        assert_eq!(creation_block(&history, CONTRACT, 11).unwrap(), Some(9));
+
+        // part of the genesis state
+        let history = History::new(vec![(0, Some(None)), (3, Some(contract()))]);
+        assert_eq!(creation_block(&history, CONTRACT, 20).unwrap(), Some(0));
+
+        // created before the available history
+        let history = History::new(vec![(2, None), (6, Some(contract())), (9, Some(contract()))]);
+        assert_eq!(creation_block(&history, CONTRACT, 20).unwrap(), None);
+    }
+
+    #[test]
+    fn creation_block_of_long_lived_contract() {
+        // created in block 37 and changed in every block after it
+        let history = History::new(
+            std::iter::once((37, Some(None)))
+                .chain((38..=1_000).map(|block| (block, Some(contract()))))
+                .collect(),
+        );
+        assert_eq!(creation_block(&history, CONTRACT, 1_000).unwrap(), Some(37));
+
+        // the search probes a logarithmic number of blocks of the ~1000 changes
+        assert!(history.lookups.load(Ordering::Relaxed) <= 2 * 11);
     }
 }
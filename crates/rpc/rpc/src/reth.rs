@@ -1,39 +1,96 @@
-use crate::eth::error::{EthApiError, EthResult};
+use crate::{
+    eth::error::{EthApiError, EthResult},
+    result::invalid_params_rpc_err,
+};
+use alloy_primitives::I256;
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
+use parking_lot::Mutex;
+use reth_consensus_common::calc::{base_block_reward, block_reward, ommer_reward};
 use reth_errors::RethResult;
-use reth_primitives::{Address, BlockId, U256};
-use reth_provider::{BlockReaderIdExt, ChangeSetReader, StateProviderFactory};
+use reth_network_api::Peers;
+use reth_primitives::{
+    constants::ETHEREUM_BLOCK_GAS_LIMIT, Address, BlockId, BlockNumber, Bytes, ForkCondition, Head,
+    TxHash, B256, KECCAK_EMPTY, U256,
+};
+use reth_provider::{
+    AccountReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, InvalidHeadersReader,
+    StateProviderBox, StateProviderFactory,
+};
 use reth_rpc_api::RethApiServer;
+use reth_rpc_server_types::constants::DEFAULT_MAX_ACCOUNT_DATA_REQUESTS;
+use reth_rpc_types::{
+    AccountData, AccountDataRequest, GasPriceDistribution, HardforkActivation, HardforkInfo,
+    InclusionEstimate, InclusionStatus, InvalidPayload, TotalIssuance,
+};
 use reth_tasks::TaskSpawner;
-use std::{collections::HashMap, future::Future, sync::Arc};
+use reth_transaction_pool::TransactionPool;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    future::Future,
+    sync::Arc,
+};
 use tokio::sync::oneshot;
 
+/// The number of blocks that are loaded at once when accumulating issuance, and the distance of
+/// the cached issuance checkpoints.
+const ISSUANCE_BATCH_SIZE: u64 = 1_000;
+
 /// `reth` API implementation.
 ///
 /// This type provides the functionality for handling `reth` prototype RPC requests.
-pub struct RethApi<Provider> {
-    inner: Arc<RethApiInner<Provider>>,
+pub struct RethApi<Provider, Pool, Network> {
+    inner: Arc<RethApiInner<Provider, Pool, Network>>,
 }
 
 // === impl RethApi ===
 
-impl<Provider> RethApi<Provider> {
+impl<Provider, Pool, Network> RethApi<Provider, Pool, Network> {
     /// The provider that can interact with the chain.
     pub fn provider(&self) -> &Provider {
         &self.inner.provider
     }
 
+    /// The transaction pool.
+    pub fn pool(&self) -> &Pool {
+        &self.inner.pool
+    }
+
+    /// The network that can interact with the peers of the node.
+    pub fn network(&self) -> &Network {
+        &self.inner.network
+    }
+
     /// Create a new instance of the [`RethApi`]
-    pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
-        let inner = Arc::new(RethApiInner { provider, task_spawner });
+    pub fn new(
+        provider: Provider,
+        pool: Pool,
+        network: Network,
+        task_spawner: Box<dyn TaskSpawner>,
+        config: RethApiConfig,
+    ) -> Self {
+        let inner = Arc::new(RethApiInner {
+            provider,
+            pool,
+            network,
+            task_spawner,
+            config,
+            issuance_cache: Default::default(),
+        });
         Self { inner }
     }
 }
 
-impl<Provider> RethApi<Provider>
+impl<Provider, Pool, Network> RethApi<Provider, Pool, Network>
 where
-    Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Provider: BlockReaderIdExt
+        + ChainSpecProvider
+        + ChangeSetReader
+        + InvalidHeadersReader
+        + StateProviderFactory
+        + 'static,
+    Pool: TransactionPool + 'static,
+    Network: 'static,
 {
     /// Executes the future on a new blocking task.
     async fn on_blocking_task<C, F, R>(&self, c: C) -> EthResult<R>
@@ -81,12 +138,343 @@ where
         )?;
         Ok(hash_map)
     }
+
+    /// Returns the payloads that were rejected as invalid by the consensus engine and persisted
+    /// in the database.
+    pub async fn invalid_payloads(&self) -> EthResult<Vec<InvalidPayload>> {
+        self.on_blocking_task(|this| async move {
+            let invalid_headers = this.provider().invalid_headers()?;
+            Ok(invalid_headers
+                .into_iter()
+                .map(|header| InvalidPayload {
+                    number: header.number,
+                    hash: header.hash(),
+                    parent_hash: header.parent_hash,
+                })
+                .collect())
+        })
+        .await
+    }
+
+    /// Returns the hardforks of the chain spec and whether they are active at the current head.
+    pub async fn hardfork_schedule(&self) -> EthResult<Vec<HardforkInfo>> {
+        self.on_blocking_task(|this| async move { this.try_hardfork_schedule() }).await
+    }
+
+    fn try_hardfork_schedule(&self) -> EthResult<Vec<HardforkInfo>> {
+        let header = self.provider().latest_header()?.ok_or(EthApiError::UnknownBlockNumber)?;
+        let total_difficulty =
+            self.provider().header_td_by_number(header.number)?.unwrap_or_default();
+        let head = Head {
+            number: header.number,
+            hash: header.hash(),
+            difficulty: header.difficulty,
+            total_difficulty,
+            timestamp: header.timestamp,
+        };
+
+        let schedule = self
+            .provider()
+            .chain_spec()
+            .forks_iter()
+            .map(|(fork, condition)| HardforkInfo {
+                name: fork.to_string(),
+                activation: match condition {
+                    ForkCondition::Block(block) => HardforkActivation::Block { block },
+                    ForkCondition::TTD { fork_block, total_difficulty } => {
+                        HardforkActivation::Ttd { total_difficulty, fork_block }
+                    }
+                    ForkCondition::Timestamp(timestamp) => {
+                        HardforkActivation::Timestamp { timestamp }
+                    }
+                    ForkCondition::Never => HardforkActivation::Never,
+                },
+                active: condition.active_at_head(&head),
+            })
+            .collect();
+        Ok(schedule)
+    }
+
+    /// Returns the balance, nonce, code hash and the requested storage slots of the given accounts.
+    pub async fn account_data(
+        &self,
+        requests: Vec<AccountDataRequest>,
+    ) -> EthResult<Vec<AccountData>> {
+        self.on_blocking_task(|this| async move { this.try_account_data(requests) }).await
+    }
+
+    /// Reads all requested accounts, opening the state only once per distinct block.
+    fn try_account_data(&self, requests: Vec<AccountDataRequest>) -> EthResult<Vec<AccountData>> {
+        // every request may target another block, so this also bounds the opened states
+        if requests.len() as u64 > self.inner.config.max_account_data_requests {
+            return Err(EthApiError::InvalidParams(format!(
+                "{} accounts exceed the maximum of {}",
+                requests.len(),
+                self.inner.config.max_account_data_requests
+            )))
+        }
+        let mut states: Vec<(BlockId, StateProviderBox)> = Vec::new();
+        let mut results = Vec::with_capacity(requests.len());
+        for request in requests {
+            let state = match states.iter().position(|(block, _)| *block == request.block) {
+                Some(idx) => &states[idx].1,
+                None => {
+                    let state = self.provider().state_by_block_id(request.block)?;
+                    states.push((request.block, state));
+                    &states[states.len() - 1].1
+                }
+            };
+
+            let account = state.basic_account(request.address)?.unwrap_or_default();
+            let mut storage = BTreeMap::new();
+            for slot in request.slots {
+                let value = state.storage(request.address, slot.0)?.unwrap_or_default();
+                storage.insert(slot.0, B256::new(value.to_be_bytes()));
+            }
+
+            results.push(AccountData {
+                address: request.address,
+                balance: account.balance,
+                nonce: account.nonce,
+                code_hash: account.bytecode_hash.unwrap_or(KECCAK_EMPTY),
+                storage,
+            });
+        }
+        Ok(results)
+    }
+
+    /// Returns an estimate of when the pooled transaction with the given hash will be included.
+    pub async fn estimate_inclusion(
+        &self,
+        tx_hash: TxHash,
+    ) -> EthResult<Option<InclusionEstimate>> {
+        self.on_blocking_task(|this| async move { this.try_estimate_inclusion(tx_hash) }).await
+    }
+
+    /// Estimates the inclusion of a pooled transaction by walking the pool's pending transactions
+    /// in the order a block builder would include them, and accumulating the gas ahead of it.
+    fn try_estimate_inclusion(&self, tx_hash: TxHash) -> EthResult<Option<InclusionEstimate>> {
+        let Some(transaction) = self.pool().get(&tx_hash) else { return Ok(None) };
+        let block_info = self.pool().block_info();
+
+        let mut estimate = InclusionEstimate {
+            status: InclusionStatus::Pending,
+            blocks: None,
+            same_sender_ahead: 0,
+            other_senders_ahead: 0,
+            base_fee: block_info.pending_basefee,
+            blob_fee: block_info.pending_blob_fee,
+        };
+
+        // a transaction can only become pending once all lower nonces of the sender are known
+        let sender = transaction.sender();
+        let pooled_nonces = self
+            .pool()
+            .get_transactions_by_sender(sender)
+            .iter()
+            .map(|tx| tx.nonce())
+            .collect::<BTreeSet<_>>();
+        let mut next_nonce = self.provider().latest()?.account_nonce(sender)?.unwrap_or_default();
+        while next_nonce < transaction.nonce() && pooled_nonces.contains(&next_nonce) {
+            next_nonce += 1;
+        }
+        if next_nonce < transaction.nonce() {
+            estimate.status = InclusionStatus::BlockedOnNonceGap;
+            return Ok(Some(estimate))
+        }
+
+        let block_gas_limit = self
+            .provider()
+            .latest_header()?
+            .map(|header| header.gas_limit)
+            .unwrap_or(ETHEREUM_BLOCK_GAS_LIMIT)
+            .max(1);
+
+        let mut gas_ahead = 0u64;
+        for best in self.pool().best_transactions() {
+            if best.hash() == &tx_hash {
+                estimate.blocks = Some(gas_ahead / block_gas_limit + 1);
+                return Ok(Some(estimate))
+            }
+            gas_ahead = gas_ahead.saturating_add(best.gas_limit());
+            if best.sender() == sender {
+                estimate.same_sender_ahead += 1;
+            } else {
+                estimate.other_senders_ahead += 1;
+            }
+        }
+
+        // not part of the pending set, so the transaction is parked because of its fee cap
+        estimate.status = InclusionStatus::BlockedOnFee;
+        estimate.same_sender_ahead = 0;
+        estimate.other_senders_ahead = 0;
+        Ok(Some(estimate))
+    }
+
+    /// Returns the distribution of the effective gas prices paid by the transactions of the given
+    /// block.
+    pub async fn gas_price_distribution(
+        &self,
+        block_id: BlockId,
+        percentiles: Vec<f64>,
+    ) -> EthResult<GasPriceDistribution> {
+        self.on_blocking_task(|this| async move {
+            this.try_gas_price_distribution(block_id, percentiles)
+        })
+        .await
+    }
+
+    fn try_gas_price_distribution(
+        &self,
+        block_id: BlockId,
+        percentiles: Vec<f64>,
+    ) -> EthResult<GasPriceDistribution> {
+        if percentiles.iter().any(|percentile| !(0.0..=100.0).contains(percentile)) {
+            return Err(EthApiError::InvalidRewardPercentiles)
+        }
+
+        let block =
+            self.provider().block_by_id(block_id)?.ok_or(EthApiError::UnknownBlockNumber)?;
+        let base_fee = block.base_fee_per_gas;
+        let mut gas_prices =
+            block.body.iter().map(|tx| tx.effective_gas_price(base_fee)).collect::<Vec<_>>();
+        gas_prices.sort_unstable();
+
+        Ok(GasPriceDistribution {
+            block_number: block.number,
+            block_hash: block.header.hash_slow(),
+            base_fee,
+            min: gas_prices.first().copied(),
+            max: gas_prices.last().copied(),
+            median: nearest_rank(&gas_prices, 50.0),
+            percentiles: (!gas_prices.is_empty()).then(|| {
+                percentiles
+                    .iter()
+                    .filter_map(|percentile| nearest_rank(&gas_prices, *percentile))
+                    .collect()
+            }),
+        })
+    }
+
+    /// Returns the cumulative issuance and burn since genesis up to the given block.
+    pub async fn total_issuance(&self, block_id: BlockId) -> EthResult<TotalIssuance> {
+        self.on_blocking_task(|this| async move { this.try_total_issuance(block_id) }).await
+    }
+
+    /// Computes the totals for the given block.
+    fn try_total_issuance(&self, block_id: BlockId) -> EthResult<TotalIssuance> {
+        let Some(block_number) = self.provider().block_number_for_id(block_id)? else {
+            return Err(EthApiError::UnknownBlockNumber)
+        };
+        let block_hash =
+            self.provider().block_hash(block_number)?.ok_or(EthApiError::UnknownBlockNumber)?;
+        let finalized = self.provider().finalized_block_number()?.unwrap_or_default();
+
+        let totals = self.issuance_totals(block_number, finalized)?;
+
+        Ok(TotalIssuance {
+            block_number,
+            block_hash,
+            issued: totals.issued,
+            burnt: totals.burnt,
+            net_supply_change: I256::from_raw(totals.issued)
+                .wrapping_sub(I256::from_raw(totals.burnt)),
+        })
+    }
+
+    /// Computes the totals up to and including the given block, on top of the nearest cached
+    /// totals at or below it.
+    ///
+    /// The totals of finalized blocks are cached every [`ISSUANCE_BATCH_SIZE`] blocks and at the
+    /// finalized block, so at most one batch of blocks below the finalized block is scanned.
+    fn issuance_totals(
+        &self,
+        block_number: BlockNumber,
+        finalized: BlockNumber,
+    ) -> EthResult<IssuanceTotals> {
+        let mut totals = self.inner.issuance_cache.lock().nearest(block_number);
+        self.accumulate_issuance(&mut totals, block_number, finalized)?;
+        Ok(totals)
+    }
+
+    /// Adds the issuance and burn of all blocks after the block of the given totals, up to and
+    /// including `to`.
+    ///
+    /// The totals of blocks up to the `finalized` block can't be reorged and are cached.
+    fn accumulate_issuance(
+        &self,
+        totals: &mut IssuanceTotals,
+        to: BlockNumber,
+        finalized: BlockNumber,
+    ) -> EthResult<()> {
+        let chain_spec = self.provider().chain_spec();
+        let mut total_difficulty =
+            self.provider().header_td_by_number(totals.block_number)?.unwrap_or_default();
+
+        while totals.block_number < to {
+            let start = totals.block_number + 1;
+            // batches end at checkpoints and at the finalized block, so their totals can be cached
+            let mut end =
+                to.min((totals.block_number / ISSUANCE_BATCH_SIZE + 1) * ISSUANCE_BATCH_SIZE);
+            if totals.block_number < finalized {
+                end = end.min(finalized);
+            }
+            let blocks = self.provider().block_range(start..=end)?;
+            if blocks.len() as u64 != end - start + 1 {
+                return Err(EthApiError::UnknownBlockNumber)
+            }
+
+            for block in blocks {
+                total_difficulty += block.difficulty;
+
+                // block and ommer rewards, which are no longer paid after the merge
+                if let Some(base_reward) =
+                    base_block_reward(&chain_spec, block.number, block.difficulty, total_difficulty)
+                {
+                    totals.issued += U256::from(block_reward(base_reward, block.ommers.len()));
+                    for ommer in &block.ommers {
+                        totals.issued +=
+                            U256::from(ommer_reward(base_reward, block.number, ommer.number));
+                    }
+                }
+
+                if let Some(withdrawals) = &block.withdrawals {
+                    for withdrawal in withdrawals.iter() {
+                        totals.issued += withdrawal.amount_wei();
+                    }
+                }
+
+                if let Some(base_fee) = block.base_fee_per_gas {
+                    totals.burnt += U256::from(base_fee) * U256::from(block.gas_used);
+                }
+                if let (Some(blob_fee), Some(blob_gas_used)) =
+                    (block.blob_fee(), block.blob_gas_used)
+                {
+                    totals.burnt += U256::from(blob_fee) * U256::from(blob_gas_used);
+                }
+            }
+
+            totals.block_number = end;
+            if end <= finalized {
+                self.inner.issuance_cache.lock().insert(*totals);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
-impl<Provider> RethApiServer for RethApi<Provider>
+impl<Provider, Pool, Network> RethApiServer for RethApi<Provider, Pool, Network>
 where
-    Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Provider: BlockReaderIdExt
+        + ChainSpecProvider
+        + ChangeSetReader
+        + InvalidHeadersReader
+        + StateProviderFactory
+        + 'static,
+    Pool: TransactionPool + 'static,
+    Network: Peers + 'static,
 {
     /// Handler for `reth_getBalanceChangesInBlock`
     async fn reth_get_balance_changes_in_block(
@@ -95,23 +483,612 @@ where
     ) -> RpcResult<HashMap<Address, U256>> {
         Ok(Self::balance_changes_in_block(self, block_id).await?)
     }
+
+    /// Handler for `reth_getAccountData`
+    async fn reth_get_account_data(
+        &self,
+        requests: Vec<AccountDataRequest>,
+    ) -> RpcResult<Vec<AccountData>> {
+        Ok(Self::account_data(self, requests).await?)
+    }
+
+    /// Handler for `reth_estimateInclusion`
+    async fn reth_estimate_inclusion(
+        &self,
+        tx_hash: TxHash,
+    ) -> RpcResult<Option<InclusionEstimate>> {
+        Ok(Self::estimate_inclusion(self, tx_hash).await?)
+    }
+
+    /// Handler for `reth_getTotalIssuance`
+    async fn reth_get_total_issuance(&self, block_id: BlockId) -> RpcResult<TotalIssuance> {
+        Ok(Self::total_issuance(self, block_id).await?)
+    }
+
+    /// Handler for `reth_getGasPriceDistribution`
+    async fn reth_get_gas_price_distribution(
+        &self,
+        block_id: BlockId,
+        percentiles: Option<Vec<f64>>,
+    ) -> RpcResult<GasPriceDistribution> {
+        Ok(Self::gas_price_distribution(self, block_id, percentiles.unwrap_or_default()).await?)
+    }
+
+    /// Handler for `reth_getInvalidPayloads`
+    async fn reth_get_invalid_payloads(&self) -> RpcResult<Vec<InvalidPayload>> {
+        Ok(Self::invalid_payloads(self).await?)
+    }
+
+    /// Handler for `reth_getHardforkSchedule`
+    async fn reth_get_hardfork_schedule(&self) -> RpcResult<Vec<HardforkInfo>> {
+        Ok(Self::hardfork_schedule(self).await?)
+    }
+
+    /// Handler for `reth_updateEnr`
+    async fn reth_update_enr(&self, key: String, value: Bytes) -> RpcResult<()> {
+        self.network()
+            .update_enr(key, value)
+            .await
+            .map_err(|err| invalid_params_rpc_err(err.to_string()))
+    }
 }
 
-impl<Provider> std::fmt::Debug for RethApi<Provider> {
+impl<Provider, Pool, Network> std::fmt::Debug for RethApi<Provider, Pool, Network> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RethApi").finish_non_exhaustive()
     }
 }
 
-impl<Provider> Clone for RethApi<Provider> {
+impl<Provider, Pool, Network> Clone for RethApi<Provider, Pool, Network> {
     fn clone(&self) -> Self {
         Self { inner: Arc::clone(&self.inner) }
     }
 }
 
-struct RethApiInner<Provider> {
+struct RethApiInner<Provider, Pool, Network> {
     /// The provider that can interact with the chain.
     provider: Provider,
+    /// The transaction pool.
+    pool: Pool,
+    /// The network that can interact with the peers of the node.
+    network: Network,
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
+    /// Settings of the handler.
+    config: RethApiConfig,
+    /// Issuance totals of finalized blocks.
+    issuance_cache: Mutex<IssuanceCache>,
+}
+
+/// Settings for the [`RethApi`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RethApiConfig {
+    /// The maximum number of accounts a `reth_getAccountData` request may read.
+    pub max_account_data_requests: u64,
+}
+
+impl RethApiConfig {
+    /// Sets the maximum number of accounts a `reth_getAccountData` request may read.
+    pub const fn max_account_data_requests(mut self, max_requests: u64) -> Self {
+        self.max_account_data_requests = max_requests;
+        self
+    }
+}
+
+impl Default for RethApiConfig {
+    fn default() -> Self {
+        Self { max_account_data_requests: DEFAULT_MAX_ACCOUNT_DATA_REQUESTS }
+    }
+}
+
+/// Issuance totals of finalized blocks, see [`RethApi::total_issuance`].
+#[derive(Debug, Default)]
+struct IssuanceCache {
+    /// The totals of every [`ISSUANCE_BATCH_SIZE`]th block.
+    checkpoints: BTreeMap<BlockNumber, IssuanceTotals>,
+    /// The totals of the highest finalized block that was scanned.
+    latest: IssuanceTotals,
+}
+
+impl IssuanceCache {
+    /// Returns the cached totals of the highest block at or below the given block.
+    fn nearest(&self, block_number: BlockNumber) -> IssuanceTotals {
+        let checkpoint = self
+            .checkpoints
+            .range(..=block_number)
+            .next_back()
+            .map(|(_, totals)| *totals)
+            .unwrap_or_default();
+        if checkpoint.block_number < self.latest.block_number &&
+            self.latest.block_number <= block_number
+        {
+            self.latest
+        } else {
+            checkpoint
+        }
+    }
+
+    /// Caches the totals of a finalized block.
+    fn insert(&mut self, totals: IssuanceTotals) {
+        if totals.block_number % ISSUANCE_BATCH_SIZE == 0 {
+            self.checkpoints.insert(totals.block_number, totals);
+        }
+        if self.latest.block_number < totals.block_number {
+            self.latest = totals;
+        }
+    }
+}
+
+/// Cumulative issuance and burn up to and including a block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct IssuanceTotals {
+    /// The last block included in the totals.
+    block_number: BlockNumber,
+    /// Total ETH issued.
+    issued: U256,
+    /// Total ETH burnt.
+    burnt: U256,
+}
+
+/// Returns the value at the given percentile of the sorted values, using the nearest-rank method.
+///
+/// Returns `None` if there are no values.
+fn nearest_rank(sorted: &[u128], percentile: f64) -> Option<u128> {
+    let rank = (percentile / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted.get(rank.saturating_sub(1)).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_network_api::noop::NoopNetwork;
+    use reth_primitives::{
+        constants::{EIP1559_INITIAL_BASE_FEE, ETH_TO_WEI},
+        Block, BlockNumberOrTag, Chain, ChainSpecBuilder, Genesis, Hardfork, Header, Signature,
+        Transaction, TransactionSigned, TxEip1559, TxEip2930, TxLegacy, Withdrawal, Withdrawals,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_rpc_types::serde_helpers::JsonStorageKey;
+    use reth_tasks::TokioTaskExecutor;
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction},
+        TransactionOrigin,
+    };
+
+    #[tokio::test]
+    async fn estimate_inclusion_reflects_queue_position() {
+        let pool = testing_pool();
+        let api = RethApi::new(
+            MockEthProvider::default(),
+            pool.clone(),
+            NoopNetwork::default(),
+            Box::new(TokioTaskExecutor::default()),
+            RethApiConfig::default(),
+        );
+
+        // three transactions fill a block
+        let gas_limit = ETHEREUM_BLOCK_GAS_LIMIT / 3;
+        let tx = |tip| {
+            MockTransaction::eip1559()
+                .with_gas_limit(gas_limit)
+                .with_max_fee(100)
+                .with_priority_fee(tip)
+        };
+        let high = tx(30);
+        let mid = tx(20);
+        let low = tx(10);
+        let low_next = low.next();
+        let gapped = tx(50).with_nonce(2);
+
+        for tx in [&high, &mid, &low, &low_next, &gapped] {
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        }
+
+        let estimate = api.try_estimate_inclusion(*high.get_hash()).unwrap().unwrap();
+        assert_eq!(estimate.status, InclusionStatus::Pending);
+        assert_eq!(estimate.blocks, Some(1));
+        assert_eq!((estimate.same_sender_ahead, estimate.other_senders_ahead), (0, 0));
+
+        let estimate = api.try_estimate_inclusion(*low_next.get_hash()).unwrap().unwrap();
+        assert_eq!(estimate.status, InclusionStatus::Pending);
+        assert_eq!(estimate.blocks, Some(2));
+        assert_eq!((estimate.same_sender_ahead, estimate.other_senders_ahead), (1, 2));
+
+        let estimate = api.try_estimate_inclusion(*gapped.get_hash()).unwrap().unwrap();
+        assert_eq!(estimate.status, InclusionStatus::BlockedOnNonceGap);
+        assert_eq!(estimate.blocks, None);
+
+        assert!(api.try_estimate_inclusion(TxHash::random()).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn total_issuance_across_london_and_merge() {
+        // London at block 2, the merge once the total difficulty reaches 3 and Shanghai at
+        // timestamp 4
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .with_fork(Hardfork::London, ForkCondition::Block(2))
+            .with_fork(
+                Hardfork::Paris,
+                ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(3) },
+            )
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(4))
+            .build();
+        let provider = MockEthProvider { chain_spec: Arc::new(chain_spec), ..Default::default() };
+        let api = RethApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            Box::new(TokioTaskExecutor::default()),
+            RethApiConfig::default(),
+        );
+
+        let ommer = Header { number: 0, difficulty: U256::from(1), ..Default::default() };
+        let withdrawal = Withdrawal { amount: 1, ..Default::default() };
+        let withdrawn = withdrawal.amount_wei() * U256::from(2);
+        let blocks = [
+            // genesis
+            Block {
+                header: Header { number: 0, difficulty: U256::from(1), ..Default::default() },
+                ..Default::default()
+            },
+            // pre-London with an ommer
+            Block {
+                header: Header { number: 1, difficulty: U256::from(1), ..Default::default() },
+                ommers: vec![ommer],
+                ..Default::default()
+            },
+            // London, still proof of work
+            Block {
+                header: Header {
+                    number: 2,
+                    timestamp: 2,
+                    difficulty: U256::from(1),
+                    base_fee_per_gas: Some(EIP1559_INITIAL_BASE_FEE),
+                    gas_used: 100,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            // first proof of stake block
+            Block {
+                header: Header {
+                    number: 3,
+                    timestamp: 3,
+                    base_fee_per_gas: Some(EIP1559_INITIAL_BASE_FEE),
+                    gas_used: 50,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            // Shanghai
+            Block {
+                header: Header {
+                    number: 4,
+                    timestamp: 4,
+                    base_fee_per_gas: Some(EIP1559_INITIAL_BASE_FEE),
+                    ..Default::default()
+                },
+                withdrawals: Some(Withdrawals::new(vec![withdrawal; 2])),
+                ..Default::default()
+            },
+        ];
+        for block in blocks {
+            provider.add_block(block.header.hash_slow(), block);
+        }
+
+        let base_reward = 5 * ETH_TO_WEI;
+        let pow_rewards = U256::from(
+            block_reward(base_reward, 1) + ommer_reward(base_reward, 1, 0) + base_reward,
+        );
+        let burnt = U256::from(EIP1559_INITIAL_BASE_FEE * 150);
+
+        let totals = api.try_total_issuance(2.into()).unwrap();
+        assert_eq!(totals.issued, pow_rewards);
+        assert_eq!(totals.burnt, U256::from(EIP1559_INITIAL_BASE_FEE * 100));
+
+        let totals = api.try_total_issuance(4.into()).unwrap();
+        assert_eq!(totals.block_number, 4);
+        assert_eq!(totals.issued, pow_rewards + withdrawn);
+        assert_eq!(totals.burnt, burnt);
+        assert_eq!(totals.net_supply_change, I256::from_raw(pow_rewards + withdrawn - burnt));
+    }
+
+    #[tokio::test]
+    async fn total_issuance_resumes_from_checkpoints() {
+        let provider = MockEthProvider::default();
+        let new_api = || {
+            RethApi::new(
+                provider.clone(),
+                testing_pool(),
+                NoopNetwork::default(),
+                Box::new(TokioTaskExecutor::default()),
+                RethApiConfig::default(),
+            )
+        };
+        provider.extend_blocks((0..=2_500).map(|number| {
+            let header = Header {
+                number,
+                base_fee_per_gas: Some(EIP1559_INITIAL_BASE_FEE),
+                gas_used: 1,
+                ..Default::default()
+            };
+            (header.hash_slow(), Block { header, ..Default::default() })
+        }));
+
+        // nothing is cached without a finalized block
+        let expected = new_api().issuance_totals(2_100, 0).unwrap();
+        assert_eq!(expected.burnt, U256::from(EIP1559_INITIAL_BASE_FEE * 2_100));
+
+        let api = new_api();
+        api.issuance_totals(2_300, 2_300).unwrap();
+        {
+            let cache = api.inner.issuance_cache.lock();
+            assert_eq!(cache.checkpoints.keys().copied().collect::<Vec<_>>(), [1_000, 2_000]);
+            assert_eq!(cache.latest.block_number, 2_300);
+        }
+
+        // blocks below the checkpoints are not scanned again
+        provider.blocks.lock().retain(|_, block| block.number > 2_000);
+        assert_eq!(api.issuance_totals(2_100, 2_300).unwrap(), expected);
+        assert_eq!(api.issuance_totals(2_500, 2_300).unwrap().block_number, 2_500);
+        assert!(api.issuance_totals(1_500, 2_300).is_err());
+    }
+
+    #[tokio::test]
+    async fn account_data_matches_individual_calls() {
+        let provider = MockEthProvider::default();
+        let api = RethApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            Box::new(TokioTaskExecutor::default()),
+            RethApiConfig::default(),
+        );
+
+        let slot = |n: u64| B256::new(U256::from(n).to_be_bytes());
+        let first = Address::random();
+        let second = Address::random();
+        provider.add_account(
+            first,
+            ExtendedAccount::new(1, U256::from(100))
+                .extend_storage([(slot(0), U256::from(1)), (slot(1), U256::from(2))]),
+        );
+        provider.add_account(
+            second,
+            ExtendedAccount::new(7, U256::from(200))
+                .with_bytecode(Bytes::from_static(&[0x60, 0x00]))
+                .extend_storage([(slot(3), U256::from(3))]),
+        );
+
+        let requests = vec![
+            AccountDataRequest {
+                address: first,
+                slots: [0, 1, 2].map(|n| JsonStorageKey(slot(n))).to_vec(),
+                block: BlockId::default(),
+            },
+            AccountDataRequest {
+                address: second,
+                slots: [3, 4].map(|n| JsonStorageKey(slot(n))).to_vec(),
+                block: 1.into(),
+            },
+        ];
+        let results = api.try_account_data(requests.clone()).unwrap();
+        assert_eq!(results.len(), requests.len());
+
+        for (request, result) in requests.into_iter().zip(results) {
+            let state = provider.state_by_block_id(request.block).unwrap();
+            let account = state.basic_account(request.address).unwrap().unwrap();
+            assert_eq!(result.address, request.address);
+            assert_eq!(result.balance, account.balance);
+            assert_eq!(result.nonce, account.nonce);
+            assert_eq!(result.code_hash, account.bytecode_hash.unwrap_or(KECCAK_EMPTY));
+            assert_eq!(result.storage.len(), request.slots.len());
+            for key in request.slots {
+                let value = state.storage(request.address, key.0).unwrap().unwrap_or_default();
+                assert_eq!(result.storage[&key.0], B256::new(value.to_be_bytes()));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn account_data_rejects_too_many_accounts() {
+        let api = RethApi::new(
+            MockEthProvider::default(),
+            testing_pool(),
+            NoopNetwork::default(),
+            Box::new(TokioTaskExecutor::default()),
+            RethApiConfig::default().max_account_data_requests(2),
+        );
+        let request = |block: u64| AccountDataRequest {
+            address: Address::random(),
+            slots: Vec::new(),
+            block: block.into(),
+        };
+
+        assert_eq!(api.try_account_data(vec![request(0), request(1)]).unwrap().len(), 2);
+        assert!(matches!(
+            api.try_account_data(vec![request(0), request(1), request(2)]),
+            Err(EthApiError::InvalidParams(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn hardfork_schedule_reflects_head() {
+        let chain_spec = ChainSpecBuilder::default()
+            .chain(Chain::mainnet())
+            .genesis(Genesis::default())
+            .with_fork(Hardfork::Frontier, ForkCondition::Block(0))
+            .with_fork(Hardfork::London, ForkCondition::Block(2))
+            .with_fork(Hardfork::Berlin, ForkCondition::Block(1))
+            .with_fork(
+                Hardfork::Paris,
+                ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(10) },
+            )
+            .with_fork(Hardfork::Cancun, ForkCondition::Timestamp(200))
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(100))
+            .with_fork(Hardfork::Prague, ForkCondition::Never)
+            .build();
+        let provider = MockEthProvider { chain_spec: Arc::new(chain_spec), ..Default::default() };
+        let api = RethApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            Box::new(TokioTaskExecutor::default()),
+            RethApiConfig::default(),
+        );
+
+        // the head is block 2 at timestamp 150 with a total difficulty of 15
+        for number in 0..=2 {
+            let header = Header {
+                number,
+                timestamp: number * 75,
+                difficulty: U256::from(5),
+                ..Default::default()
+            };
+            provider.add_block(header.hash_slow(), Block { header, ..Default::default() });
+        }
+
+        let schedule = api.hardfork_schedule().await.unwrap();
+        let expected = vec![
+            HardforkInfo {
+                name: "Frontier".to_string(),
+                activation: HardforkActivation::Block { block: 0 },
+                active: true,
+            },
+            HardforkInfo {
+                name: "Berlin".to_string(),
+                activation: HardforkActivation::Block { block: 1 },
+                active: true,
+            },
+            HardforkInfo {
+                name: "London".to_string(),
+                activation: HardforkActivation::Block { block: 2 },
+                active: true,
+            },
+            HardforkInfo {
+                name: "Paris".to_string(),
+                activation: HardforkActivation::Ttd {
+                    total_difficulty: U256::from(10),
+                    fork_block: None,
+                },
+                active: true,
+            },
+            HardforkInfo {
+                name: "Shanghai".to_string(),
+                activation: HardforkActivation::Timestamp { timestamp: 100 },
+                active: true,
+            },
+            HardforkInfo {
+                name: "Cancun".to_string(),
+                activation: HardforkActivation::Timestamp { timestamp: 200 },
+                active: false,
+            },
+            HardforkInfo {
+                name: "Prague".to_string(),
+                activation: HardforkActivation::Never,
+                active: false,
+            },
+        ];
+        assert_eq!(schedule, expected);
+    }
+
+    #[tokio::test]
+    async fn gas_price_distribution_of_block() {
+        let provider = MockEthProvider::default();
+        let api = RethApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            Box::new(TokioTaskExecutor::default()),
+            RethApiConfig::default(),
+        );
+
+        let base_fee = 100;
+        let eip1559 = |max_fee_per_gas, max_priority_fee_per_gas| {
+            Transaction::Eip1559(TxEip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                ..Default::default()
+            })
+        };
+        let transactions = [
+            Transaction::Legacy(TxLegacy { gas_price: 200, ..Default::default() }),
+            eip1559(1_000, 300),
+            eip1559(500, 10),
+            Transaction::Eip2930(TxEip2930 { gas_price: 130, ..Default::default() }),
+            eip1559(120, 50),
+            Transaction::Legacy(TxLegacy { gas_price: 150, ..Default::default() }),
+        ];
+        let header = Header { number: 1, base_fee_per_gas: Some(base_fee), ..Default::default() };
+        let block_hash = header.hash_slow();
+        provider.add_block(
+            block_hash,
+            Block {
+                header,
+                body: transactions
+                    .iter()
+                    .cloned()
+                    .map(|tx| {
+                        TransactionSigned::from_transaction_and_signature(tx, Signature::default())
+                    })
+                    .collect(),
+                ..Default::default()
+            },
+        );
+        let empty_header =
+            Header { number: 2, base_fee_per_gas: Some(base_fee), ..Default::default() };
+        provider.add_block(
+            empty_header.hash_slow(),
+            Block { header: empty_header, ..Default::default() },
+        );
+
+        let percentiles = vec![0.0, 10.0, 25.0, 50.0, 90.0, 100.0];
+        let distribution =
+            api.gas_price_distribution(block_hash.into(), percentiles.clone()).await.unwrap();
+
+        // the lowest price that at least the given share of the transactions pay at most
+        let mut gas_prices = transactions
+            .iter()
+            .map(|tx| tx.effective_gas_price(Some(base_fee)))
+            .collect::<Vec<_>>();
+        gas_prices.sort_unstable();
+        let at_percentile = |percentile: f64| {
+            *gas_prices
+                .iter()
+                .find(|price| {
+                    let paying_at_most = gas_prices.iter().filter(|other| other <= price).count();
+                    paying_at_most as f64 * 100.0 >= percentile * gas_prices.len() as f64
+                })
+                .unwrap()
+        };
+
+        assert_eq!(gas_prices, vec![110, 120, 130, 150, 200, 400]);
+        assert_eq!(
+            distribution,
+            GasPriceDistribution {
+                block_number: 1,
+                block_hash,
+                base_fee: Some(base_fee),
+                min: Some(110),
+                max: Some(400),
+                median: Some(at_percentile(50.0)),
+                percentiles: Some(percentiles.into_iter().map(at_percentile).collect()),
+            }
+        );
+
+        let distribution = api
+            .gas_price_distribution(BlockNumberOrTag::Number(2).into(), vec![50.0])
+            .await
+            .unwrap();
+        assert_eq!(distribution.base_fee, Some(base_fee));
+        assert_eq!(
+            (distribution.min, distribution.max, distribution.median, distribution.percentiles),
+            (None, None, None, None)
+        );
+
+        assert!(matches!(
+            api.gas_price_distribution(block_hash.into(), vec![101.0]).await,
+            Err(EthApiError::InvalidRewardPercentiles)
+        ));
+    }
 }
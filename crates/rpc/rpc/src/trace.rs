@@ -1,16 +1,24 @@
-use crate::eth::{
-    error::{EthApiError, EthResult},
-    revm_utils::{prepare_call_env, EvmOverrides},
-    utils::recover_raw_transaction,
-    EthTransactions,
+use crate::{
+    eth::{
+        error::{EthApiError, EthResult},
+        revm_utils::{prepare_call_env, EvmOverrides},
+        utils::recover_raw_transaction,
+        EthTransactions,
+    },
+    trace_depth::DepthLimitedInspector,
 };
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult as Result;
 use reth_consensus_common::calc::{base_block_reward, block_reward};
-use reth_primitives::{revm::env::tx_env_with_recovered, BlockId, Bytes, SealedHeader, B256, U256};
-use reth_provider::{BlockReader, ChainSpecProvider, EvmEnvProvider, StateProviderFactory};
+use reth_primitives::{
+    revm::env::tx_env_with_recovered, BlockId, BlockNumber, Bytes, SealedHeader, B256, U256,
+};
+use reth_provider::{
+    BlockHashReader, BlockReader, ChainSpecProvider, EvmEnvProvider, StateProviderFactory,
+};
 use reth_revm::database::StateProviderDatabase;
 use reth_rpc_api::TraceApiServer;
+use reth_rpc_server_types::constants::DEFAULT_MAX_TRACE_FILTER_COUNT;
 use reth_rpc_types::{
     state::StateOverride,
     trace::{
@@ -19,7 +27,8 @@ use reth_rpc_types::{
         parity::*,
         tracerequest::TraceCallRequest,
     },
-    BlockError, BlockOverrides, Index, TransactionRequest,
+    AnyTransactionReceipt, BlockError, BlockOverrides, Index, InternalTransfer, TransactionRequest,
+    TransactionTraceOverrides, INTERNAL_TRANSFERS_FIELD,
 };
 use reth_tasks::pool::BlockingTaskGuard;
 use revm::{
@@ -29,6 +38,7 @@ use revm::{
 use revm_inspectors::{
     opcode::OpcodeGasInspector,
     tracing::{parity::populate_state_diff, TracingInspector, TracingInspectorConfig},
+    transfer::TransferInspector,
 };
 use std::{collections::HashSet, sync::Arc};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
@@ -49,8 +59,15 @@ impl<Provider, Eth> TraceApi<Provider, Eth> {
     }
 
     /// Create a new instance of the [`TraceApi`]
-    pub fn new(provider: Provider, eth_api: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
-        let inner = Arc::new(TraceApiInner { provider, eth_api, blocking_task_guard });
+    ///
+    /// See also [`TraceApiConfig`].
+    pub fn new(
+        provider: Provider,
+        eth_api: Eth,
+        blocking_task_guard: BlockingTaskGuard,
+        config: TraceApiConfig,
+    ) -> Self {
+        let inner = Arc::new(TraceApiInner { provider, eth_api, blocking_task_guard, config });
         Self { inner }
     }
 
@@ -65,6 +82,12 @@ impl<Provider, Eth> TraceApi<Provider, Eth> {
     pub fn eth_api(&self) -> &Eth {
         &self.inner.eth_api
     }
+
+    /// Returns a tracer with the given config that only records calls up to the configured
+    /// maximum trace depth.
+    fn tracer(&self, config: TracingInspectorConfig) -> DepthLimitedInspector<TracingInspector> {
+        DepthLimitedInspector::new(TracingInspector::new(config), self.inner.config.max_trace_depth)
+    }
 }
 
 // === impl TraceApi ===
@@ -80,16 +103,17 @@ where
         let config = TracingInspectorConfig::from_parity_config(&trace_request.trace_types);
         let overrides =
             EvmOverrides::new(trace_request.state_overrides, trace_request.block_overrides);
-        let mut inspector = TracingInspector::new(config);
-        let this = self.clone();
+        let mut inspector = self.tracer(config);
         self.eth_api()
-            .spawn_with_call_at(trace_request.call, at, overrides, move |db, env| {
-                let (res, _) = this.eth_api().inspect(&mut *db, env, &mut inspector)?;
-                let trace_res = inspector.into_parity_builder().into_trace_results_with_state(
+            .spawn_with_call_at(trace_request.call, at, overrides, move |eth, db, env| {
+                let (res, _) = eth.inspect(&mut *db, env, &mut inspector)?;
+                let (inspector, truncated) = inspector.into_parts();
+                let mut trace_res = inspector.into_parity_builder().into_trace_results_with_state(
                     &res,
                     &trace_request.trace_types,
                     &db,
                 )?;
+                truncated.trace_results(&mut trace_res);
                 Ok(trace_res)
             })
             .await
@@ -108,16 +132,21 @@ where
         let tx = tx_env_with_recovered(&tx.into_ecrecovered_transaction());
         let env = EnvWithHandlerCfg::new_with_cfg_env(cfg, block, tx);
 
-        let config = TracingInspectorConfig::from_parity_config(&trace_types);
+        let mut inspector = self.tracer(TracingInspectorConfig::from_parity_config(&trace_types));
 
         self.inner
             .eth_api
-            .spawn_trace_at_with_state(env, config, at, move |inspector, res, db| {
-                Ok(inspector.into_parity_builder().into_trace_results_with_state(
+            .spawn_with_state_at_block(at, move |eth, state| {
+                let mut db = CacheDB::new(StateProviderDatabase::new(state));
+                let (res, _) = eth.inspect(&mut db, env, &mut inspector)?;
+                let (inspector, truncated) = inspector.into_parts();
+                let mut trace_res = inspector.into_parity_builder().into_trace_results_with_state(
                     &res,
                     &trace_types,
                     &db,
-                )?)
+                )?;
+                truncated.trace_results(&mut trace_res);
+                Ok(trace_res)
             })
             .await
     }
@@ -138,7 +167,7 @@ where
         let this = self.clone();
         // execute all transactions on top of each other and record the traces
         self.eth_api()
-            .spawn_with_state_at_block(at, move |state| {
+            .spawn_with_state_at_block(at, move |eth, state| {
                 let mut results = Vec::with_capacity(calls.len());
                 let mut db = CacheDB::new(StateProviderDatabase::new(state));
 
@@ -154,14 +183,14 @@ where
                         Default::default(),
                     )?;
                     let config = TracingInspectorConfig::from_parity_config(&trace_types);
-                    let mut inspector = TracingInspector::new(config);
-                    let (res, _) = this.eth_api().inspect(&mut db, env, &mut inspector)?;
+                    let mut inspector = this.tracer(config);
+                    let (res, _) = eth.inspect(&mut db, env, &mut inspector)?;
 
-                    let trace_res = inspector.into_parity_builder().into_trace_results_with_state(
-                        &res,
-                        &trace_types,
-                        &db,
-                    )?;
+                    let (inspector, truncated) = inspector.into_parts();
+                    let mut trace_res = inspector
+                        .into_parity_builder()
+                        .into_trace_results_with_state(&res, &trace_types, &db)?;
+                    truncated.trace_results(&mut trace_res);
 
                     results.push(trace_res);
 
@@ -185,17 +214,21 @@ where
         hash: B256,
         trace_types: HashSet<TraceType>,
     ) -> EthResult<TraceResults> {
-        let config = TracingInspectorConfig::from_parity_config(&trace_types);
+        let inspector = self.tracer(TracingInspectorConfig::from_parity_config(&trace_types));
         self.inner
             .eth_api
-            .spawn_trace_transaction_in_block(hash, config, move |_, inspector, res, db| {
-                let trace_res = inspector.into_parity_builder().into_trace_results_with_state(
-                    &res,
-                    &trace_types,
-                    &db,
-                )?;
-                Ok(trace_res)
-            })
+            .spawn_trace_transaction_in_block_with_inspector(
+                hash,
+                inspector,
+                move |_, inspector, res, db| {
+                    let (inspector, truncated) = inspector.into_parts();
+                    let mut trace_res = inspector
+                        .into_parity_builder()
+                        .into_trace_results_with_state(&res, &trace_types, &db)?;
+                    truncated.trace_results(&mut trace_res);
+                    Ok(trace_res)
+                },
+            )
             .await
             .transpose()
             .ok_or_else(|| EthApiError::TransactionNotFound)?
@@ -234,12 +267,24 @@ where
     ///
     /// This is similar to [`Self::trace_block`] but only returns traces for transactions that match
     /// the filter.
+    ///
+    /// The traces are ordered by block, transaction index and position in the transaction, so the
+    /// result can be paged with `after`, the number of traces to skip, and `count`, the maximum
+    /// number of traces to return. With a `count`, blocks are only traced until the page is full.
     pub async fn trace_filter(
         &self,
         filter: TraceFilter,
     ) -> EthResult<Vec<LocalizedTransactionTrace>> {
         let matcher = filter.matcher();
-        let TraceFilter { from_block, to_block, after: _after, count: _count, .. } = filter;
+        let TraceFilter { from_block, to_block, after, count, .. } = filter;
+        if let Some(count) = count {
+            if count > self.inner.config.max_trace_filter_count {
+                return Err(EthApiError::InvalidParams(format!(
+                    "count {count} exceeds the maximum of {}",
+                    self.inner.config.max_trace_filter_count
+                )))
+            }
+        }
         let start = from_block.unwrap_or(0);
         let end = if let Some(to_block) = to_block {
             to_block
@@ -255,12 +300,16 @@ where
             ))
         }
 
+        // capture the canonical hashes of the range before scanning, so we can detect a reorg that
+        // happens while the blocks are being traced
+        let canonical_hashes = self.provider().canonical_hashes_range(start, end + 1)?;
+
         // fetch all blocks in that range
         let blocks = self.provider().block_range(start..=end)?;
 
         // find relevant blocks to trace
         let mut target_blocks = Vec::new();
-        for block in blocks {
+        for (block, block_hash) in blocks.into_iter().zip(canonical_hashes.iter().copied()) {
             let mut transaction_indices = HashSet::new();
             let mut highest_matching_index = 0;
             for (tx_idx, tx) in block.body.iter().enumerate() {
@@ -273,17 +322,18 @@ where
                 }
             }
             if !transaction_indices.is_empty() {
-                target_blocks.push((block.number, transaction_indices, highest_matching_index));
+                target_blocks.push((block_hash, transaction_indices, highest_matching_index));
             }
         }
 
         // trace all relevant blocks
         let mut block_traces = Vec::with_capacity(target_blocks.len());
-        for (num, indices, highest_idx) in target_blocks {
-            let traces = self.inner.eth_api.trace_block_until(
-                num.into(),
+        for (block_hash, indices, highest_idx) in target_blocks {
+            let this = self.clone();
+            let traces = self.inner.eth_api.trace_block_until_with_inspector(
+                block_hash.into(),
                 Some(highest_idx),
-                TracingInspectorConfig::default_parity(),
+                move || this.tracer(TracingInspectorConfig::default_parity()),
                 move |tx_info, inspector, res, _, _| {
                     if let Some(idx) = tx_info.index {
                         if !indices.contains(&idx) {
@@ -291,21 +341,45 @@ where
                             return Ok(None)
                         }
                     }
-                    let traces = inspector
+                    let (inspector, truncated) = inspector.into_parts();
+                    let mut traces = inspector
                         .with_transaction_gas_used(res.gas_used())
                         .into_parity_builder()
                         .into_localized_transaction_traces(tx_info);
+                    truncated.localized_traces(&mut traces);
                     Ok(Some(traces))
                 },
             );
             block_traces.push(traces);
         }
 
-        let block_traces = futures::future::try_join_all(block_traces).await?;
+        let block_traces = if let Some(count) = count {
+            // trace the blocks in order until the page is full
+            let needed = after.unwrap_or_default().saturating_add(count);
+            let mut traced = Vec::new();
+            let mut num_traces = 0u64;
+            for traces in block_traces {
+                if num_traces >= needed {
+                    break
+                }
+                let traces = traces.await?;
+                num_traces += traces.iter().flatten().flatten().map(Vec::len).sum::<usize>() as u64;
+                traced.push(traces);
+            }
+            traced
+        } else {
+            futures::future::try_join_all(block_traces).await?
+        };
+
+        // ensure none of the scanned blocks were reorged out while tracing
+        ensure_canonical_range(self.provider(), start, &canonical_hashes)?;
+
         let all_traces = block_traces
             .into_iter()
             .flatten()
             .flat_map(|traces| traces.into_iter().flatten().flat_map(|traces| traces.into_iter()))
+            .skip(after.unwrap_or_default() as usize)
+            .take(count.map_or(usize::MAX, |count| count as usize))
             .collect();
 
         Ok(all_traces)
@@ -315,36 +389,105 @@ where
     pub async fn trace_transaction(
         &self,
         hash: B256,
+    ) -> EthResult<Option<Vec<LocalizedTransactionTrace>>> {
+        self.trace_transaction_with_overrides(hash, TransactionTraceOverrides::default()).await
+    }
+
+    /// Returns all traces for the given transaction hash, with the given overrides applied to the
+    /// transaction.
+    ///
+    /// With overrides, the traces diverge from the actual on-chain execution of the transaction.
+    pub async fn trace_transaction_with_overrides(
+        &self,
+        hash: B256,
+        overrides: TransactionTraceOverrides,
     ) -> EthResult<Option<Vec<LocalizedTransactionTrace>>> {
         self.inner
             .eth_api
-            .spawn_trace_transaction_in_block(
+            .spawn_trace_transaction_in_block_with_overrides(
                 hash,
-                TracingInspectorConfig::default_parity(),
+                overrides,
+                self.tracer(TracingInspectorConfig::default_parity()),
                 move |tx_info, inspector, res, _| {
-                    let traces = inspector
+                    let (inspector, truncated) = inspector.into_parts();
+                    let mut traces = inspector
                         .with_transaction_gas_used(res.result.gas_used())
                         .into_parity_builder()
                         .into_localized_transaction_traces(tx_info);
+                    truncated.localized_traces(&mut traces);
                     Ok(traces)
                 },
             )
             .await
     }
 
+    /// Returns all transaction receipts of the block, each with the value transfers of the
+    /// transaction's internal calls under the [`INTERNAL_TRANSFERS_FIELD`].
+    ///
+    /// The transfers are extracted by re-executing the entire block.
+    ///
+    /// Returns `None` if the block wasn't found.
+    pub async fn block_receipts_with_internal_transfers(
+        &self,
+        block_id: BlockId,
+    ) -> EthResult<Option<Vec<AnyTransactionReceipt>>> {
+        let transfers = self
+            .inner
+            .eth_api
+            .trace_block_with_inspector(
+                block_id,
+                // only record transfers of internal calls, the top-level transfer is part of the
+                // transaction itself
+                || TransferInspector::new(true),
+                |tx_info, inspector, _res, _, _| {
+                    let transfers = inspector
+                        .into_transfers()
+                        .into_iter()
+                        .filter(|transfer| !transfer.value.is_zero())
+                        .map(|transfer| InternalTransfer {
+                            from: transfer.from,
+                            to: transfer.to,
+                            value: transfer.value,
+                        })
+                        .collect::<Vec<_>>();
+                    Ok((tx_info.hash, transfers))
+                },
+            )
+            .await?;
+        let Some(transfers) = transfers else { return Ok(None) };
+
+        let receipts = transfers.into_iter().map(|(hash, transfers)| async move {
+            let hash = hash.ok_or(EthApiError::TransactionNotFound)?;
+            let mut receipt = self
+                .inner
+                .eth_api
+                .transaction_receipt(hash)
+                .await?
+                .ok_or(EthApiError::TransactionNotFound)?;
+            let transfers =
+                serde_json::to_value(transfers).map_err(|_| EthApiError::InternalEthError)?;
+            receipt.other.insert(INTERNAL_TRANSFERS_FIELD.to_string(), transfers);
+            Ok::<_, EthApiError>(receipt)
+        });
+        futures::future::try_join_all(receipts).await.map(Some)
+    }
+
     /// Returns traces created at given block.
     pub async fn trace_block(
         &self,
         block_id: BlockId,
     ) -> EthResult<Option<Vec<LocalizedTransactionTrace>>> {
-        let traces = self.inner.eth_api.trace_block_with(
+        let this = self.clone();
+        let traces = self.inner.eth_api.trace_block_with_inspector(
             block_id,
-            TracingInspectorConfig::default_parity(),
-            |tx_info, inspector, res, _, _| {
-                let traces = inspector
+            move || this.tracer(TracingInspectorConfig::default_parity()),
+            move |tx_info, inspector, res, _, _| {
+                let (inspector, truncated) = inspector.into_parts();
+                let mut traces = inspector
                     .with_transaction_gas_used(res.gas_used())
                     .into_parity_builder()
                     .into_localized_transaction_traces(tx_info);
+                truncated.localized_traces(&mut traces);
                 Ok(traces)
             },
         );
@@ -398,14 +541,18 @@ where
         block_id: BlockId,
         trace_types: HashSet<TraceType>,
     ) -> EthResult<Option<Vec<TraceResultsWithTransactionHash>>> {
+        let this = self.clone();
+        let config = TracingInspectorConfig::from_parity_config(&trace_types);
         self.inner
             .eth_api
-            .trace_block_with(
+            .trace_block_with_inspector(
                 block_id,
-                TracingInspectorConfig::from_parity_config(&trace_types),
+                move || this.tracer(config),
                 move |tx_info, inspector, res, state, db| {
+                    let (inspector, truncated) = inspector.into_parts();
                     let mut full_trace =
                         inspector.into_parity_builder().into_trace_results(&res, &trace_types);
+                    truncated.trace_results(&mut full_trace);
 
                     // If statediffs were requested, populate them with the account balance and
                     // nonce from pre-state
@@ -554,6 +701,15 @@ where
         Ok(Self::trace_block(self, block_id).await?)
     }
 
+    /// Handler for `trace_blockReceipts`
+    async fn trace_block_receipts(
+        &self,
+        block_id: BlockId,
+    ) -> Result<Option<Vec<AnyTransactionReceipt>>> {
+        let _permit = self.acquire_trace_permit().await;
+        Ok(Self::block_receipts_with_internal_transfers(self, block_id).await?)
+    }
+
     /// Handler for `trace_filter`
     ///
     /// This is similar to `eth_getLogs` but for traces.
@@ -579,9 +735,11 @@ where
     async fn trace_transaction(
         &self,
         hash: B256,
+        overrides: Option<TransactionTraceOverrides>,
     ) -> Result<Option<Vec<LocalizedTransactionTrace>>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_transaction(self, hash).await?)
+        Ok(Self::trace_transaction_with_overrides(self, hash, overrides.unwrap_or_default())
+            .await?)
     }
 
     /// Handler for `trace_transactionOpcodeGas`
@@ -618,6 +776,40 @@ struct TraceApiInner<Provider, Eth> {
     eth_api: Eth,
     // restrict the number of concurrent calls to `trace_*`
     blocking_task_guard: BlockingTaskGuard,
+    /// Limits of the `trace` namespace.
+    config: TraceApiConfig,
+}
+
+/// Settings for the [`TraceApi`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceApiConfig {
+    /// The maximum depth of recorded calls.
+    ///
+    /// Calls nested deeper than this are executed, but not recorded. If `None` then no limit is
+    /// enforced.
+    pub max_trace_depth: Option<usize>,
+    /// The maximum number of traces a `trace_filter` request may page through at once.
+    pub max_trace_filter_count: u64,
+}
+
+impl TraceApiConfig {
+    /// Sets the maximum depth of recorded calls.
+    pub const fn max_trace_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_trace_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum number of traces a `trace_filter` request may page through at once.
+    pub const fn max_trace_filter_count(mut self, max_count: u64) -> Self {
+        self.max_trace_filter_count = max_count;
+        self
+    }
+}
+
+impl Default for TraceApiConfig {
+    fn default() -> Self {
+        Self { max_trace_depth: None, max_trace_filter_count: DEFAULT_MAX_TRACE_FILTER_COUNT }
+    }
 }
 
 /// Helper to construct a [`LocalizedTransactionTrace`] that describes a reward to the block
@@ -637,3 +829,369 @@ fn reward_trace(header: &SealedHeader, reward: RewardAction) -> LocalizedTransac
         },
     }
 }
+
+/// Ensures that the canonical hashes starting at `start` still match the `expected` hashes that
+/// were captured before the range was processed.
+///
+/// Returns [`EthApiError::CanonicalChainChanged`] if any of the blocks were reorged out.
+fn ensure_canonical_range<Provider: BlockHashReader>(
+    provider: &Provider,
+    start: BlockNumber,
+    expected: &[B256],
+) -> EthResult<()> {
+    let current = provider.canonical_hashes_range(start, start + expected.len() as u64)?;
+    if current != expected {
+        return Err(EthApiError::CanonicalChainChanged)
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{
+        cache::EthStateCache, gas_oracle::GasPriceOracle, EthApi, FeeHistoryCache,
+        FeeHistoryCacheConfig,
+    };
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_primitives::{
+        bytes,
+        constants::{ETHEREUM_BLOCK_GAS_LIMIT, ETH_TO_WEI},
+        Address, Block, Header, Receipt, Transaction, TransactionSigned, TxKind, TxLegacy, TxType,
+        U64,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_rpc_server_types::constants::DEFAULT_MAX_TRACE_FILTER_COUNT;
+    use reth_tasks::pool::BlockingTaskPool;
+    use reth_testing_utils::generators::{self, generate_keys, sign_tx_with_key_pair};
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+    use std::collections::BTreeMap;
+
+    /// Returns the value after the change, if the delta changed it.
+    fn changed_to<T: Clone + Default>(delta: &Delta<T>) -> Option<T> {
+        match delta {
+            Delta::Unchanged => None,
+            Delta::Added(value) => Some(value.clone()),
+            Delta::Removed(_) => Some(T::default()),
+            Delta::Changed(ChangedType { to, .. }) => Some(to.clone()),
+        }
+    }
+
+    /// Returns the value before the change, if the delta changed it.
+    fn changed_from<T: Clone + Default>(delta: &Delta<T>) -> Option<T> {
+        match delta {
+            Delta::Unchanged => None,
+            Delta::Added(_) => Some(T::default()),
+            Delta::Removed(value) => Some(value.clone()),
+            Delta::Changed(ChangedType { from, .. }) => Some(from.clone()),
+        }
+    }
+
+    #[test]
+    fn detects_reorg_of_scanned_range() {
+        let provider = MockEthProvider::default();
+        for number in 0..4 {
+            let header = Header { number, ..Default::default() };
+            provider.add_block(header.hash_slow(), Block { header, ..Default::default() });
+        }
+
+        let scanned = provider.canonical_hashes_range(0, 4).unwrap();
+        assert!(ensure_canonical_range(&provider, 0, &scanned).is_ok());
+
+        // replace block 2 with a sibling, as if the chain reorged while the range was traced
+        provider.blocks.lock().retain(|_, block| block.number != 2);
+        let header = Header { number: 2, gas_limit: 1, ..Default::default() };
+        provider.add_block(header.hash_slow(), Block { header, ..Default::default() });
+
+        assert!(matches!(
+            ensure_canonical_range(&provider, 0, &scanned),
+            Err(EthApiError::CanonicalChainChanged)
+        ));
+    }
+
+    type TestTraceApi =
+        TraceApi<MockEthProvider, EthApi<MockEthProvider, TestPool, (), EthEvmConfig>>;
+
+    /// Returns a [`TraceApi`] over the given provider.
+    fn trace_api(provider: MockEthProvider, max_trace_filter_count: u64) -> TestTraceApi {
+        let evm_config = EthEvmConfig::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default(), evm_config);
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache.clone()),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            FeeHistoryCache::new(cache, FeeHistoryCacheConfig::default()),
+            evm_config,
+            None,
+        );
+        let config = TraceApiConfig::default().max_trace_filter_count(max_trace_filter_count);
+        TraceApi::new(provider, eth_api, BlockingTaskGuard::new(1), config)
+    }
+
+    /// Returns `count` transfers of the same sender to the receiver.
+    fn transfers(count: u64, receiver: Address, value: U256) -> Vec<TransactionSigned> {
+        let mut rng = generators::rng();
+        let key_pair = generate_keys(&mut rng, 1)[0];
+        (0..count)
+            .map(|nonce| {
+                sign_tx_with_key_pair(
+                    key_pair,
+                    Transaction::Legacy(TxLegacy {
+                        chain_id: Some(1),
+                        nonce,
+                        gas_price: 1,
+                        gas_limit: 21_000,
+                        to: TxKind::Call(receiver),
+                        value,
+                        ..Default::default()
+                    }),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn trace_filter_pages_traces() {
+        let provider = MockEthProvider::default();
+        let transactions = transfers(3, Address::random(), U256::from(1_000));
+        let sender = transactions[0].recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+        for number in 1..=2 {
+            let header =
+                Header { number, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+            let body = if number == 1 { transactions.clone() } else { Vec::new() };
+            provider.add_block(header.hash_slow(), Block { header, body, ..Default::default() });
+        }
+        let api = trace_api(provider, 2);
+
+        let filter = |after, count| TraceFilter {
+            from_block: Some(1),
+            to_block: Some(2),
+            after,
+            count,
+            ..Default::default()
+        };
+
+        let traces = api.trace_filter(filter(None, None)).await.unwrap();
+        let positions = traces.iter().map(|trace| trace.transaction_position).collect::<Vec<_>>();
+        assert_eq!(positions, vec![Some(0), Some(1), Some(2)]);
+
+        let page = api.trace_filter(filter(Some(1), Some(1))).await.unwrap();
+        assert_eq!(page, traces[1..2]);
+
+        let page = api.trace_filter(filter(Some(2), Some(2))).await.unwrap();
+        assert_eq!(page, traces[2..]);
+
+        let page = api.trace_filter(filter(Some(3), Some(2))).await.unwrap();
+        assert!(page.is_empty());
+
+        // the count is limited by the server
+        assert!(matches!(
+            api.trace_filter(filter(None, Some(3))).await,
+            Err(EthApiError::InvalidParams(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn trace_filter_detects_reorg_while_tracing() {
+        let provider = MockEthProvider::default();
+        let transactions = transfers(2, Address::random(), U256::from(1_000));
+        let sender = transactions[0].recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+        for number in 1..=2 {
+            let header =
+                Header { number, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+            let body = if number == 1 { transactions.clone() } else { Vec::new() };
+            provider.add_block(header.hash_slow(), Block { header, body, ..Default::default() });
+        }
+        let api = trace_api(provider.clone(), DEFAULT_MAX_TRACE_FILTER_COUNT);
+
+        let filter = TraceFilter { from_block: Some(1), to_block: Some(2), ..Default::default() };
+        let mut trace_filter = std::pin::pin!(api.trace_filter(filter));
+
+        // the range is scanned and the tracing of block 1 is started, but it can't finish before
+        // the cache task got polled
+        assert!(futures::poll!(&mut trace_filter).is_pending());
+
+        // replace block 2 with a sibling while block 1 is traced
+        provider.blocks.lock().retain(|_, block| block.number != 2);
+        let header = Header { number: 2, gas_limit: 1, ..Default::default() };
+        provider.add_block(header.hash_slow(), Block { header, ..Default::default() });
+
+        assert!(matches!(trace_filter.await, Err(EthApiError::CanonicalChainChanged)));
+    }
+
+    #[tokio::test]
+    async fn trace_transaction_with_overrides() {
+        let contract = Address::random();
+        let provider = MockEthProvider::default();
+        // PUSH1 1, PUSH1 0, SSTORE, STOP
+        provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("600160005500")),
+        );
+        let api = trace_api(provider.clone(), DEFAULT_MAX_TRACE_FILTER_COUNT);
+
+        // the SSTORE does not fit into the gas limit of the transaction
+        let key_pair = generate_keys(&mut generators::rng(), 1)[0];
+        let transaction = sign_tx_with_key_pair(
+            key_pair,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                gas_price: 1,
+                gas_limit: 30_000,
+                to: TxKind::Call(contract),
+                ..Default::default()
+            }),
+        );
+        let sender = transaction.recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+        let tx_hash = transaction.hash();
+        let header =
+            Header { number: 1, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+        provider.add_block(
+            header.hash_slow(),
+            Block { header, body: vec![transaction], ..Default::default() },
+        );
+
+        let traces = TraceApiServer::trace_transaction(&api, tx_hash, None).await.unwrap().unwrap();
+        assert_eq!(traces.len(), 1);
+        assert!(traces[0].trace.error.is_some());
+
+        let overrides = TransactionTraceOverrides { gas: Some(100_000), ..Default::default() };
+        let traces = TraceApiServer::trace_transaction(&api, tx_hash, Some(overrides))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(traces.len(), 1);
+        assert!(traces[0].trace.error.is_none());
+
+        // unknown transactions have no traces
+        let unknown = TraceApiServer::trace_transaction(&api, B256::random(), None);
+        assert!(unknown.await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn replay_block_transactions_state_diffs_aggregate_to_block_changes() {
+        let provider = MockEthProvider::default();
+        let api = trace_api(provider.clone(), DEFAULT_MAX_TRACE_FILTER_COUNT);
+
+        // two transfers of the same sender, the second one relies on the state of the first one
+        let receiver = Address::random();
+        let value = U256::from(1_000);
+        let transactions = transfers(2, receiver, value);
+        let sender = transactions[0].recover_signer().unwrap();
+        let initial_balance = U256::from(ETH_TO_WEI);
+        provider.add_account(sender, ExtendedAccount::new(0, initial_balance));
+
+        let header =
+            Header { number: 1, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+        let block_hash = header.hash_slow();
+        provider.add_block(block_hash, Block { header, body: transactions, ..Default::default() });
+
+        let traces = api
+            .replay_block_transactions(block_hash.into(), HashSet::from([TraceType::StateDiff]))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(traces.len(), 2);
+
+        // aggregate the per-transaction diffs, each transaction must start from the state the
+        // previous one left behind
+        let mut balances = BTreeMap::<Address, (U256, U256)>::new();
+        let mut nonces = BTreeMap::<Address, (U64, U64)>::new();
+        for trace in &traces {
+            let state_diff = trace.full_trace.state_diff.as_ref().unwrap();
+            for (address, diff) in state_diff.iter() {
+                if let (Some(from), Some(to)) =
+                    (changed_from(&diff.balance), changed_to(&diff.balance))
+                {
+                    let entry = balances.entry(*address).or_insert((from, from));
+                    assert_eq!(entry.1, from);
+                    entry.1 = to;
+                }
+                if let (Some(from), Some(to)) = (changed_from(&diff.nonce), changed_to(&diff.nonce))
+                {
+                    let entry = nonces.entry(*address).or_insert((from, from));
+                    assert_eq!(entry.1, from);
+                    entry.1 = to;
+                }
+            }
+        }
+
+        let fees = U256::from(2 * 21_000);
+        let transferred = value * U256::from(2);
+        assert_eq!(balances[&sender], (initial_balance, initial_balance - transferred - fees));
+        assert_eq!(balances[&receiver], (U256::ZERO, transferred));
+        assert_eq!(balances[&Address::ZERO], (U256::ZERO, fees));
+        assert_eq!(nonces[&sender], (U64::ZERO, U64::from(2)));
+    }
+
+    #[tokio::test]
+    async fn block_receipts_include_internal_transfers() {
+        let provider = MockEthProvider::default();
+        let api = trace_api(provider.clone(), DEFAULT_MAX_TRACE_FILTER_COUNT);
+
+        // a contract that forwards the call value: CALL(gas, recipient, callvalue, 0, 0, 0, 0)
+        let recipient = Address::random();
+        let forwarder = Address::random();
+        let mut code = vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x34, 0x73];
+        code.extend_from_slice(recipient.as_slice());
+        code.extend_from_slice(&[0x5a, 0xf1, 0x00]);
+        provider
+            .add_account(forwarder, ExtendedAccount::new(0, U256::ZERO).with_bytecode(code.into()));
+
+        // a plain transfer to an account without code, followed by a call to the forwarder
+        let value = U256::from(1_000);
+        let mut transactions = transfers(1, Address::random(), value);
+        let key_pair = generate_keys(&mut generators::rng(), 1)[0];
+        transactions.push(sign_tx_with_key_pair(
+            key_pair,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 1,
+                gas_limit: 100_000,
+                to: TxKind::Call(forwarder),
+                value,
+                ..Default::default()
+            }),
+        ));
+        for transaction in &transactions {
+            let sender = transaction.recover_signer().unwrap();
+            provider.add_account(sender, ExtendedAccount::new(0, U256::from(ETH_TO_WEI)));
+        }
+
+        let header =
+            Header { number: 1, gas_limit: ETHEREUM_BLOCK_GAS_LIMIT, ..Default::default() };
+        let block_hash = header.hash_slow();
+        provider.add_block(block_hash, Block { header, body: transactions, ..Default::default() });
+        let receipt = Receipt { tx_type: TxType::Legacy, success: true, ..Default::default() };
+        provider.add_receipts(block_hash, vec![receipt.clone(), receipt]);
+
+        let receipts =
+            TraceApiServer::trace_block_receipts(&api, block_hash.into()).await.unwrap().unwrap();
+        assert_eq!(receipts.len(), 2);
+        let transfers = receipts
+            .iter()
+            .map(|receipt| {
+                serde_json::from_value::<Vec<InternalTransfer>>(
+                    receipt.other.get(INTERNAL_TRANSFERS_FIELD).unwrap().clone(),
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            transfers,
+            vec![vec![], vec![InternalTransfer { from: forwarder, to: recipient, value }]]
+        );
+
+        // unknown blocks have no receipts
+        let unknown = TraceApiServer::trace_block_receipts(&api, BlockId::Number(2.into()));
+        assert!(unknown.await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,337 @@
+//! Helpers to limit the depth of call traces.
+//!
+//! Recursive calls can produce call trees of arbitrary size. If a maximum trace depth is
+//! configured, the tracer is wrapped in a [`DepthLimitedInspector`], so calls nested deeper than
+//! the limit are still executed, but never recorded.
+//!
+//! The frames whose nested calls were not recorded are flagged in the traces: `callTracer` frames
+//! get a [`TRACE_TRUNCATED`] field set to `true`, parity traces keep the number of calls that
+//! were made as `subtraces`, even though the nested traces are omitted.
+
+use crate::eth::error::{EthApiError, EthResult};
+use reth_rpc_types::trace::{
+    geth::{CallFrame, GethDebugBuiltInTracerType, GethTrace, MuxFrame},
+    parity::{LocalizedTransactionTrace, TraceResults, TransactionTrace},
+};
+use revm::{
+    interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter},
+    primitives::{Address, Log, U256},
+    Database, EvmContext, Inspector,
+};
+
+/// The field of a `callTracer` frame that is set if the frame's nested calls were not recorded.
+pub const TRACE_TRUNCATED: &str = "truncated";
+
+/// An [`Inspector`] that only forwards the calls up to a maximum depth to the wrapped inspector.
+///
+/// Calls nested deeper than the limit are executed as usual, but the wrapped inspector doesn't see
+/// any of their events. The top level call has depth 0.
+#[derive(Debug)]
+pub(crate) struct DepthLimitedInspector<I> {
+    inner: I,
+    max_depth: usize,
+    /// The number of frames that are currently executed, including the ones that are not
+    /// recorded.
+    open_frames: usize,
+    /// The position of the currently recorded frame in the call tree.
+    path: Vec<usize>,
+    /// The number of recorded calls of each recorded frame that is currently executed.
+    calls: Vec<usize>,
+    /// The positions of the frames whose calls were not recorded, with the number of these calls.
+    truncated: Vec<(Vec<usize>, usize)>,
+}
+
+impl<I> DepthLimitedInspector<I> {
+    /// Wraps the inspector, hiding all calls nested deeper than `max_depth` from it.
+    ///
+    /// If no `max_depth` is set, all calls are forwarded.
+    pub(crate) const fn new(inner: I, max_depth: Option<usize>) -> Self {
+        Self {
+            inner,
+            max_depth: match max_depth {
+                Some(max_depth) => max_depth,
+                None => usize::MAX,
+            },
+            open_frames: 0,
+            path: Vec::new(),
+            calls: Vec::new(),
+            truncated: Vec::new(),
+        }
+    }
+
+    /// Returns the wrapped inspector and the [`TruncatedFrames`].
+    pub(crate) fn into_parts(self) -> (I, TruncatedFrames) {
+        (self.inner, TruncatedFrames(self.truncated))
+    }
+
+    /// Returns `true` if the events of the currently executed frame are recorded.
+    const fn is_recorded(&self) -> bool {
+        self.open_frames == 0 || self.open_frames - 1 <= self.max_depth
+    }
+
+    /// Enters a new frame and returns whether it is recorded.
+    fn enter_frame(&mut self) -> bool {
+        let depth = self.open_frames;
+        self.open_frames += 1;
+        if depth > self.max_depth {
+            if depth - 1 == self.max_depth {
+                match self.truncated.last_mut() {
+                    Some((path, calls)) if *path == self.path => *calls += 1,
+                    _ => self.truncated.push((self.path.clone(), 1)),
+                }
+            }
+            return false
+        }
+        if let Some(calls) = self.calls.last_mut() {
+            self.path.push(*calls);
+            *calls += 1;
+        }
+        self.calls.push(0);
+        true
+    }
+
+    /// Exits the current frame and returns whether it was recorded.
+    fn exit_frame(&mut self) -> bool {
+        self.open_frames = self.open_frames.saturating_sub(1);
+        if self.open_frames > self.max_depth {
+            return false
+        }
+        self.calls.pop();
+        self.path.pop();
+        true
+    }
+}
+
+impl<DB, I> Inspector<DB> for DepthLimitedInspector<I>
+where
+    DB: Database,
+    I: Inspector<DB>,
+{
+    fn initialize_interp(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        if self.is_recorded() {
+            self.inner.initialize_interp(interp, context)
+        }
+    }
+
+    fn step(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        if self.is_recorded() {
+            self.inner.step(interp, context)
+        }
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        if self.is_recorded() {
+            self.inner.step_end(interp, context)
+        }
+    }
+
+    fn log(&mut self, context: &mut EvmContext<DB>, log: &Log) {
+        if self.is_recorded() {
+            self.inner.log(context, log)
+        }
+    }
+
+    fn call(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        if self.enter_frame() {
+            return self.inner.call(context, inputs)
+        }
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &CallInputs,
+        outcome: CallOutcome,
+    ) -> CallOutcome {
+        if self.exit_frame() {
+            return self.inner.call_end(context, inputs, outcome)
+        }
+        outcome
+    }
+
+    fn create(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &mut CreateInputs,
+    ) -> Option<CreateOutcome> {
+        if self.enter_frame() {
+            return self.inner.create(context, inputs)
+        }
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &CreateInputs,
+        outcome: CreateOutcome,
+    ) -> CreateOutcome {
+        if self.exit_frame() {
+            return self.inner.create_end(context, inputs, outcome)
+        }
+        outcome
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        if self.is_recorded() {
+            self.inner.selfdestruct(contract, target, value)
+        }
+    }
+}
+
+/// The frames of a trace whose nested calls were not recorded by a [`DepthLimitedInspector`].
+///
+/// A frame is identified by the indices of the calls that lead to it, starting at the top level
+/// call, which is the trace address of parity traces.
+#[derive(Debug)]
+pub(crate) struct TruncatedFrames(Vec<(Vec<usize>, usize)>);
+
+impl TruncatedFrames {
+    /// Converts the `callTracer` frame into a [`GethTrace`], setting [`TRACE_TRUNCATED`] on all
+    /// truncated frames.
+    pub(crate) fn call_trace(&self, frame: CallFrame) -> EthResult<GethTrace> {
+        if self.0.is_empty() {
+            return Ok(frame.into())
+        }
+        let mut frame = serde_json::to_value(frame).map_err(|_| EthApiError::InternalEthError)?;
+        for (path, _) in &self.0 {
+            let pointer = path.iter().map(|idx| format!("/calls/{idx}")).collect::<String>();
+            if let Some(serde_json::Value::Object(frame)) = frame.pointer_mut(&pointer) {
+                frame.insert(TRACE_TRUNCATED.to_string(), true.into());
+            }
+        }
+        Ok(GethTrace::JS(frame))
+    }
+
+    /// Flags the truncated frames of the `callTracer` that is part of the `muxTracer` frame.
+    pub(crate) fn mux_trace(&self, mut frame: MuxFrame) -> EthResult<GethTrace> {
+        if let Some(GethTrace::CallTracer(call_frame)) =
+            frame.0.remove(&GethDebugBuiltInTracerType::CallTracer)
+        {
+            frame.0.insert(GethDebugBuiltInTracerType::CallTracer, self.call_trace(call_frame)?);
+        }
+        Ok(frame.into())
+    }
+
+    /// Sets the number of calls made by the truncated frames as their `subtraces`.
+    pub(crate) fn transaction_traces(&self, traces: &mut [TransactionTrace]) {
+        self.traces(traces, |trace| trace)
+    }
+
+    /// Same as [`Self::transaction_traces`], but for the traces of the [`TraceResults`].
+    pub(crate) fn trace_results(&self, results: &mut TraceResults) {
+        self.transaction_traces(&mut results.trace)
+    }
+
+    /// Same as [`Self::transaction_traces`], but for localized traces.
+    pub(crate) fn localized_traces(&self, traces: &mut [LocalizedTransactionTrace]) {
+        self.traces(traces, |trace| &mut trace.trace)
+    }
+
+    fn traces<T>(&self, traces: &mut [T], mut trace: impl FnMut(&mut T) -> &mut TransactionTrace) {
+        if self.0.is_empty() {
+            return
+        }
+        for item in traces {
+            let trace = trace(item);
+            if let Some((_, calls)) = self.0.iter().find(|(path, _)| *path == trace.trace_address) {
+                trace.subtraces = *calls;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{bytes, Bytes};
+    use revm::{
+        db::{CacheDB, EmptyDB},
+        inspector_handle_register,
+        primitives::{AccountInfo, Bytecode, TransactTo},
+        Evm,
+    };
+    use revm_inspectors::tracing::{TracingInspector, TracingInspectorConfig};
+
+    /// Executes a call to a contract that calls itself until it runs out of gas, while recording
+    /// calls up to the given depth.
+    fn trace_recursion(
+        config: TracingInspectorConfig,
+        max_depth: Option<usize>,
+    ) -> (TracingInspector, TruncatedFrames) {
+        let contract = Address::with_last_byte(0x42);
+        // PUSH1 0 (x5), ADDRESS, GAS, CALL, STOP
+        let code: Bytes = bytes!("60006000600060006000305af100");
+
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            contract,
+            AccountInfo { code: Some(Bytecode::new_raw(code)), ..Default::default() },
+        );
+
+        let mut inspector = DepthLimitedInspector::new(TracingInspector::new(config), max_depth);
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .with_external_context(&mut inspector)
+            .modify_tx_env(|tx| {
+                tx.transact_to = TransactTo::Call(contract);
+                tx.gas_limit = 100_000;
+                tx.gas_price = U256::ZERO;
+            })
+            .append_handler_register(inspector_handle_register)
+            .build();
+        evm.transact().unwrap();
+        drop(evm);
+        inspector.into_parts()
+    }
+
+    #[test]
+    fn records_call_frames_up_to_max_depth() {
+        let config = TracingInspectorConfig::default_geth();
+        let (inspector, truncated) = trace_recursion(config, None);
+        let frame = inspector.into_geth_builder().geth_call_traces(Default::default(), 0);
+        let GethTrace::CallTracer(mut frame) = truncated.call_trace(frame).unwrap() else {
+            panic!("untruncated trace is flagged")
+        };
+        let mut depth = 0;
+        while let Some(call) = frame.calls.pop() {
+            frame = call;
+            depth += 1;
+        }
+        assert!(depth > 3);
+
+        let (inspector, truncated) = trace_recursion(config, Some(3));
+        let frame = inspector.into_geth_builder().geth_call_traces(Default::default(), 0);
+        let GethTrace::JS(mut frame) = truncated.call_trace(frame).unwrap() else {
+            panic!("truncated trace is not flagged")
+        };
+        for _ in 0..3 {
+            assert!(frame.get(TRACE_TRUNCATED).is_none());
+            assert!(frame.get("error").is_none());
+            frame = frame["calls"][0].take();
+        }
+        assert!(frame.get("calls").is_none());
+        assert!(frame.get("error").is_none());
+        assert_eq!(frame[TRACE_TRUNCATED], true);
+    }
+
+    #[test]
+    fn records_transaction_traces_up_to_max_depth() {
+        let config = TracingInspectorConfig::default_parity();
+        let (inspector, truncated) = trace_recursion(config, Some(3));
+        let mut traces = inspector.into_parity_builder().into_transaction_traces();
+        truncated.transaction_traces(&mut traces);
+
+        assert_eq!(traces.len(), 4);
+        for (depth, trace) in traces.iter().enumerate() {
+            assert_eq!(trace.trace_address, vec![0; depth]);
+            assert_eq!(trace.subtraces, 1);
+            assert!(trace.error.is_none());
+        }
+    }
+}
@@ -6,8 +6,10 @@ use reth_rpc_types::{
     txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolInspectSummary, TxpoolStatus},
     Transaction,
 };
-use reth_transaction_pool::{AllPoolTransactions, PoolTransaction, TransactionPool};
-use std::collections::BTreeMap;
+use reth_transaction_pool::{
+    AllPoolTransactions, PoolTransaction, TransactionPool, ValidPoolTransaction,
+};
+use std::{collections::BTreeMap, sync::Arc};
 use tracing::trace;
 
 /// `txpool` API implementation.
@@ -120,7 +122,23 @@ where
     /// Handler for `txpool_contentFrom`
     async fn txpool_content_from(&self, from: Address) -> Result<TxpoolContentFrom> {
         trace!(target: "rpc::eth", ?from, "Serving txpool_contentFrom");
-        Ok(self.content().remove_from(&from))
+        let AllPoolTransactions { pending, queued } = self.pool.all_transactions_by_sender(from);
+
+        let by_nonce = |txs: Vec<Arc<ValidPoolTransaction<Pool::Transaction>>>| {
+            txs.into_iter()
+                .map(|tx| {
+                    let tx = &tx.transaction;
+                    (
+                        tx.nonce().to_string(),
+                        reth_rpc_types_compat::transaction::from_recovered(
+                            tx.to_recovered_transaction(),
+                        ),
+                    )
+                })
+                .collect()
+        };
+
+        Ok(TxpoolContentFrom { pending: by_nonce(pending), queued: by_nonce(queued) })
     }
 
     /// Returns the details of all transactions currently pending for inclusion in the next
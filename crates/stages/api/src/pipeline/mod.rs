@@ -97,6 +97,13 @@ where
         self.progress.minimum_block_number
     }
 
+    /// Sets the maximum block number to sync to.
+    ///
+    /// Stages that reached this block are skipped, see also [`PipelineBuilder::with_max_block`].
+    pub fn set_max_block(&mut self, block: BlockNumber) {
+        self.max_block = Some(block);
+    }
+
     /// Set tip for reverse sync.
     #[track_caller]
     pub fn set_tip(&self, tip: B256) {
@@ -254,17 +261,19 @@ where
         // Copies data from database to static files
         let lowest_static_file_height = {
             let provider = self.provider_factory.provider()?;
-            let stages_checkpoints = [StageId::Headers, StageId::Execution, StageId::Bodies]
-                .into_iter()
-                .map(|stage| {
-                    provider.get_stage_checkpoint(stage).map(|c| c.map(|c| c.block_number))
-                })
-                .collect::<Result<Vec<_>, _>>()?;
+            let stages_checkpoints =
+                [StageId::Headers, StageId::Execution, StageId::Bodies, StageId::SenderRecovery]
+                    .into_iter()
+                    .map(|stage| {
+                        provider.get_stage_checkpoint(stage).map(|c| c.map(|c| c.block_number))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
 
             let targets = static_file_producer.get_static_file_targets(HighestStaticFiles {
                 headers: stages_checkpoints[0],
                 receipts: stages_checkpoints[1],
                 transactions: stages_checkpoints[2],
+                senders: stages_checkpoints[3],
             })?;
             static_file_producer.run(targets)?;
             stages_checkpoints.into_iter().min().expect("exists")
@@ -128,6 +128,8 @@ impl<DB: Database, D: BodyDownloader> Stage<DB> for BodyStage<D> {
         // Get id for the next tx_num of zero if there are no transactions.
         let mut next_tx_num = tx_block_cursor.last()?.map(|(id, _)| id + 1).unwrap_or_default();
 
+        let chain_spec = provider.chain_spec();
+
         let static_file_provider = provider.static_file_provider();
         let mut static_file_producer =
             static_file_provider.get_writer(from_block, StaticFileSegment::Transactions)?;
@@ -222,8 +224,10 @@ impl<DB: Database, D: BodyDownloader> Stage<DB> for BodyStage<D> {
                         next_tx_num += 1;
                     }
 
-                    // Write ommers if any
-                    if !block.ommers.is_empty() {
+                    // Write ommers if any, post-merge blocks can't have ommers
+                    if !block.ommers.is_empty() &&
+                        chain_spec.final_paris_total_difficulty(block_number).is_none()
+                    {
                         ommers_cursor
                             .append(block_number, StoredBlockOmmers { ommers: block.ommers })?;
                     }
@@ -1,15 +1,11 @@
 use reth_config::config::SenderRecoveryConfig;
 use reth_consensus::ConsensusError;
 use reth_db::{static_file::TransactionMask, tables, RawValue};
-use reth_db_api::{
-    cursor::DbCursorRW,
-    database::Database,
-    transaction::{DbTx, DbTxMut},
-};
+use reth_db_api::{database::Database, transaction::DbTxMut};
 use reth_primitives::{Address, StaticFileSegment, TransactionSignedNoHash, TxNumber};
 use reth_provider::{
-    BlockReader, DatabaseProviderRW, HeaderProvider, ProviderError, PruneCheckpointReader,
-    StatsReader,
+    providers::StaticFileWriter, BlockReader, DatabaseProviderRW, HeaderProvider, ProviderError,
+    PruneCheckpointReader, StaticFileProviderFactory, StatsReader,
 };
 use reth_prune_types::PruneSegment;
 use reth_stages_api::{
@@ -84,11 +80,6 @@ impl<DB: Database> Stage<DB> for SenderRecoveryStage {
             })
         }
 
-        let tx = provider.tx_ref();
-
-        // Acquire the cursor for inserting elements
-        let mut senders_cursor = tx.cursor_write::<tables::TransactionSenders>()?;
-
         info!(target: "sync::stages::sender_recovery", ?tx_range, "Recovering senders");
 
         // Iterate over transactions in batches, recover the senders and append them
@@ -98,7 +89,7 @@ impl<DB: Database> Stage<DB> for SenderRecoveryStage {
             .collect::<Vec<Range<u64>>>();
 
         for range in batch {
-            recover_range(range, provider, tx, &mut senders_cursor)?;
+            recover_range(range, provider)?;
         }
 
         Ok(ExecOutput {
@@ -123,6 +114,21 @@ impl<DB: Database> Stage<DB> for SenderRecoveryStage {
             .last_tx_num();
         provider.unwind_table_by_num::<tables::TransactionSenders>(latest_tx_id)?;
 
+        // Unwind the senders that were already moved to static files
+        let static_file_provider = provider.static_file_provider();
+        if static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::Senders)
+            .is_some_and(|block| block > unwind_to)
+        {
+            let highest_static_file_tx = static_file_provider
+                .get_highest_static_file_tx(StaticFileSegment::Senders)
+                .unwrap_or_default();
+            let mut static_file_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Senders)?;
+            static_file_writer
+                .prune_senders(highest_static_file_tx.saturating_sub(latest_tx_id), unwind_to)?;
+        }
+
         Ok(UnwindOutput {
             checkpoint: StageCheckpoint::new(unwind_to)
                 .with_entities_stage_checkpoint(stage_checkpoint(provider)?),
@@ -133,10 +139,6 @@ impl<DB: Database> Stage<DB> for SenderRecoveryStage {
 fn recover_range<DB: Database>(
     tx_range: Range<u64>,
     provider: &DatabaseProviderRW<DB>,
-    tx: &<DB as Database>::TXMut,
-    senders_cursor: &mut <<DB as Database>::TXMut as DbTxMut>::CursorMut<
-        tables::TransactionSenders,
-    >,
 ) -> Result<(), StageError> {
     debug!(target: "sync::stages::sender_recovery", ?tx_range, "Recovering senders batch");
 
@@ -184,7 +186,7 @@ fn recover_range<DB: Database>(
         }
     });
 
-    debug!(target: "sync::stages::sender_recovery", ?tx_range, "Appending recovered senders to the database");
+    let mut senders = Vec::with_capacity((tx_range.end - tx_range.start) as usize);
     for channel in receivers {
         while let Ok(recovered) = channel.recv() {
             let (tx_id, sender) = match recovered {
@@ -193,8 +195,8 @@ fn recover_range<DB: Database>(
                     return match *error {
                         SenderRecoveryStageError::FailedRecovery(err) => {
                             // get the block number for the bad transaction
-                            let block_number = tx
-                                .get::<tables::TransactionBlocks>(err.tx)?
+                            let block_number = provider
+                                .block_number_for_tx_number(err.tx)?
                                 .ok_or(ProviderError::BlockNumberForTransactionIndexNotFound)?;
 
                             // fetch the sealed header so we can use it in the sender recovery
@@ -213,9 +215,14 @@ fn recover_range<DB: Database>(
                     }
                 }
             };
-            senders_cursor.append(tx_id, sender)?;
+            senders.push((tx_id, sender));
         }
     }
+
+    // the senders are recovered in ascending order of the transaction numbers, so they are
+    // appended
+    debug!(target: "sync::stages::sender_recovery", ?tx_range, "Appending recovered senders to the database");
+    provider.tx_ref().put_batch::<tables::TransactionSenders>(senders)?;
     debug!(target: "sync::stages::sender_recovery", ?tx_range, "Finished recovering senders batch");
 
     Ok(())
@@ -279,7 +286,7 @@ struct FailedSenderRecoveryError {
 #[cfg(test)]
 mod tests {
     use assert_matches::assert_matches;
-    use reth_db_api::cursor::DbCursorRO;
+    use reth_db_api::{cursor::DbCursorRO, transaction::DbTx};
     use reth_primitives::{BlockNumber, SealedBlock, TransactionSigned, B256};
     use reth_provider::{
         providers::StaticFileWriter, PruneCheckpointWriter, StaticFileProviderFactory,
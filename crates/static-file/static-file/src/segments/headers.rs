@@ -1,13 +1,18 @@
 use crate::segments::{dataset_for_compression, prepare_jar, Segment, SegmentHeader};
+use rayon::prelude::*;
 use reth_db::{static_file::create_static_file_T1_T2_T3, tables, RawKey, RawTable};
 use reth_db_api::{cursor::DbCursorRO, database::Database, transaction::DbTx};
-use reth_primitives::{static_file::SegmentConfig, BlockNumber, StaticFileSegment};
+use reth_primitives::{static_file::SegmentConfig, BlockNumber, GotExpected, StaticFileSegment};
 use reth_provider::{
     providers::{StaticFileProvider, StaticFileWriter},
-    DatabaseProviderRO,
+    DatabaseProviderRO, ProviderError,
 };
 use reth_storage_errors::provider::ProviderResult;
-use std::{ops::RangeInclusive, path::Path};
+use std::{collections::HashMap, ops::RangeInclusive, path::Path};
+
+/// Number of headers that are loaded and verified in parallel at once by
+/// [`verify_canonical_hashes`].
+const HASH_VERIFICATION_BATCH_SIZE: u64 = 10_000;
 
 /// Static File segment responsible for [`StaticFileSegment::Headers`] part of data.
 #[derive(Debug, Default)]
@@ -24,6 +29,8 @@ impl<DB: Database> Segment<DB> for Headers {
         static_file_provider: StaticFileProvider,
         block_range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<()> {
+        verify_canonical_hashes(&provider, block_range.clone())?;
+
         let mut static_file_writer =
             static_file_provider.get_writer(*block_range.start(), StaticFileSegment::Headers)?;
 
@@ -63,6 +70,8 @@ impl<DB: Database> Segment<DB> for Headers {
         config: SegmentConfig,
         block_range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<()> {
+        verify_canonical_hashes(provider, block_range.clone())?;
+
         let range_len = block_range.clone().count();
         let jar = prepare_jar::<DB, 3>(
             provider,
@@ -125,3 +134,43 @@ impl<DB: Database> Segment<DB> for Headers {
         Ok(())
     }
 }
+
+/// Verifies that the recomputed hash of every header in the range matches its
+/// [`tables::CanonicalHeaders`] entry, so that corrupted data is not moved to static files.
+///
+/// Headers are loaded in batches, and the hashes of each batch are recomputed in parallel.
+fn verify_canonical_hashes<DB: Database>(
+    provider: &DatabaseProviderRO<DB>,
+    block_range: RangeInclusive<BlockNumber>,
+) -> ProviderResult<()> {
+    let mut headers_cursor = provider.tx_ref().cursor_read::<tables::Headers>()?;
+    let mut canonical_headers_cursor =
+        provider.tx_ref().cursor_read::<tables::CanonicalHeaders>()?;
+
+    for batch_start in block_range.clone().step_by(HASH_VERIFICATION_BATCH_SIZE as usize) {
+        let batch_end =
+            batch_start.saturating_add(HASH_VERIFICATION_BATCH_SIZE - 1).min(*block_range.end());
+
+        let headers =
+            headers_cursor.walk_range(batch_start..=batch_end)?.collect::<Result<Vec<_>, _>>()?;
+        let canonical_hashes = canonical_headers_cursor
+            .walk_range(batch_start..=batch_end)?
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        let mismatch = headers
+            .par_iter()
+            .map(|(block_number, header)| (*block_number, header.hash_slow()))
+            .find_first(|(block_number, hash)| canonical_hashes.get(block_number) != Some(hash));
+        if let Some((block_number, hash)) = mismatch {
+            return Err(ProviderError::CanonicalHashMismatch {
+                block_number,
+                hash: Box::new(GotExpected {
+                    got: hash,
+                    expected: canonical_hashes.get(&block_number).copied().unwrap_or_default(),
+                }),
+            })
+        }
+    }
+
+    Ok(())
+}
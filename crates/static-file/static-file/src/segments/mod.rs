@@ -9,6 +9,9 @@ pub use headers::Headers;
 mod receipts;
 pub use receipts::Receipts;
 
+mod senders;
+pub use senders::Senders;
+
 use reth_db::{RawKey, RawTable};
 use reth_db_api::{cursor::DbCursorRO, database::Database, table::Table, transaction::DbTx};
 use reth_nippy_jar::NippyJar;
@@ -65,7 +68,9 @@ pub(crate) fn prepare_jar<DB: Database, const COLUMNS: usize>(
 ) -> ProviderResult<NippyJar<SegmentHeader>> {
     let tx_range = match segment {
         StaticFileSegment::Headers => None,
-        StaticFileSegment::Receipts | StaticFileSegment::Transactions => {
+        StaticFileSegment::Receipts |
+        StaticFileSegment::Transactions |
+        StaticFileSegment::Senders => {
             Some(provider.transaction_range_by_block_range(block_range.clone())?.into())
         }
     };
@@ -61,12 +61,16 @@ pub struct StaticFileTargets {
     headers: Option<RangeInclusive<BlockNumber>>,
     receipts: Option<RangeInclusive<BlockNumber>>,
     transactions: Option<RangeInclusive<BlockNumber>>,
+    senders: Option<RangeInclusive<BlockNumber>>,
 }
 
 impl StaticFileTargets {
     /// Returns `true` if any of the targets are [Some].
     pub const fn any(&self) -> bool {
-        self.headers.is_some() || self.receipts.is_some() || self.transactions.is_some()
+        self.headers.is_some() ||
+            self.receipts.is_some() ||
+            self.transactions.is_some() ||
+            self.senders.is_some()
     }
 
     // Returns `true` if all targets are either [`None`] or has beginning of the range equal to the
@@ -76,6 +80,7 @@ impl StaticFileTargets {
             (self.headers.as_ref(), static_files.headers),
             (self.receipts.as_ref(), static_files.receipts),
             (self.transactions.as_ref(), static_files.transactions),
+            (self.senders.as_ref(), static_files.senders),
         ]
         .iter()
         .all(|(target_block_range, highest_static_fileted_block)| {
@@ -134,6 +139,9 @@ impl<DB: Database> StaticFileProducerInner<DB> {
         if let Some(block_range) = targets.receipts.clone() {
             segments.push((Box::new(segments::Receipts), block_range));
         }
+        if let Some(block_range) = targets.senders.clone() {
+            segments.push((Box::new(segments::Senders), block_range));
+        }
 
         segments.par_iter().try_for_each(|(segment, block_range)| -> ProviderResult<()> {
             debug!(target: "static_file", segment = %segment.segment(), ?block_range, "StaticFileProducer segment");
@@ -199,6 +207,17 @@ impl<DB: Database> StaticFileProducerInner<DB> {
                     finalized_block_number,
                 )
             }),
+            // StaticFile senders only if they're not pruned according to the user configuration
+            senders: if self.prune_modes.sender_recovery.is_none() {
+                finalized_block_numbers.senders.and_then(|finalized_block_number| {
+                    self.get_static_file_target(
+                        highest_static_files.senders,
+                        finalized_block_number,
+                    )
+                })
+            } else {
+                None
+            },
         };
 
         trace!(
@@ -229,8 +248,11 @@ mod tests {
         StaticFileProducer, StaticFileProducerInner, StaticFileTargets,
     };
     use assert_matches::assert_matches;
-    use reth_db::{test_utils::TempDatabase, DatabaseEnv};
-    use reth_db_api::{database::Database, transaction::DbTx};
+    use reth_db::{tables, test_utils::TempDatabase, DatabaseEnv};
+    use reth_db_api::{
+        database::Database,
+        transaction::{DbTx, DbTxMut},
+    };
     use reth_primitives::{static_file::HighestStaticFiles, StaticFileSegment, B256, U256};
     use reth_provider::{
         providers::StaticFileWriter, ProviderError, ProviderFactory, StaticFileProviderFactory,
@@ -278,6 +300,13 @@ mod tests {
         }
         db.insert_receipts(receipts).expect("insert receipts");
 
+        let senders = blocks
+            .iter()
+            .flat_map(|block| block.senders().expect("recover senders"))
+            .enumerate()
+            .map(|(tx_num, sender)| (tx_num as u64, sender));
+        db.insert_transaction_senders(senders).expect("insert senders");
+
         let provider_factory = db.factory;
         (provider_factory, db.temp_static_files_dir)
     }
@@ -294,6 +323,7 @@ mod tests {
                 headers: Some(1),
                 receipts: Some(1),
                 transactions: Some(1),
+                senders: Some(1),
             })
             .expect("get static file targets");
         assert_eq!(
@@ -301,13 +331,19 @@ mod tests {
             StaticFileTargets {
                 headers: Some(0..=1),
                 receipts: Some(0..=1),
-                transactions: Some(0..=1)
+                transactions: Some(0..=1),
+                senders: Some(0..=1)
             }
         );
         assert_matches!(static_file_producer.run(targets), Ok(_));
         assert_eq!(
             provider_factory.static_file_provider().get_highest_static_files(),
-            HighestStaticFiles { headers: Some(1), receipts: Some(1), transactions: Some(1) }
+            HighestStaticFiles {
+                headers: Some(1),
+                receipts: Some(1),
+                transactions: Some(1),
+                senders: Some(1)
+            }
         );
 
         let targets = static_file_producer
@@ -315,6 +351,7 @@ mod tests {
                 headers: Some(3),
                 receipts: Some(3),
                 transactions: Some(3),
+                senders: Some(3),
             })
             .expect("get static file targets");
         assert_eq!(
@@ -322,13 +359,19 @@ mod tests {
             StaticFileTargets {
                 headers: Some(2..=3),
                 receipts: Some(2..=3),
-                transactions: Some(2..=3)
+                transactions: Some(2..=3),
+                senders: Some(2..=3)
             }
         );
         assert_matches!(static_file_producer.run(targets), Ok(_));
         assert_eq!(
             provider_factory.static_file_provider().get_highest_static_files(),
-            HighestStaticFiles { headers: Some(3), receipts: Some(3), transactions: Some(3) }
+            HighestStaticFiles {
+                headers: Some(3),
+                receipts: Some(3),
+                transactions: Some(3),
+                senders: Some(3)
+            }
         );
 
         let targets = static_file_producer
@@ -336,6 +379,7 @@ mod tests {
                 headers: Some(4),
                 receipts: Some(4),
                 transactions: Some(4),
+                senders: Some(4),
             })
             .expect("get static file targets");
         assert_eq!(
@@ -343,7 +387,8 @@ mod tests {
             StaticFileTargets {
                 headers: Some(4..=4),
                 receipts: Some(4..=4),
-                transactions: Some(4..=4)
+                transactions: Some(4..=4),
+                senders: Some(4..=4)
             }
         );
         assert_matches!(
@@ -352,7 +397,40 @@ mod tests {
         );
         assert_eq!(
             provider_factory.static_file_provider().get_highest_static_files(),
-            HighestStaticFiles { headers: Some(3), receipts: Some(3), transactions: Some(3) }
+            HighestStaticFiles {
+                headers: Some(3),
+                receipts: Some(3),
+                transactions: Some(3),
+                senders: Some(3)
+            }
+        );
+    }
+
+    #[test]
+    fn run_with_corrupted_canonical_hash() {
+        let (provider_factory, _temp_static_files_dir) = setup();
+
+        // Point the canonical hash of block 1 to a different header.
+        let tx = provider_factory.db_ref().tx_mut().expect("init tx");
+        tx.put::<tables::CanonicalHeaders>(1, B256::random()).expect("corrupt canonical hash");
+        tx.commit().expect("commit tx");
+
+        let static_file_producer =
+            StaticFileProducerInner::new(provider_factory.clone(), PruneModes::default());
+        let targets = StaticFileTargets {
+            headers: Some(0..=1),
+            receipts: None,
+            transactions: None,
+            senders: None,
+        };
+
+        assert_matches!(
+            static_file_producer.run(targets),
+            Err(ProviderError::CanonicalHashMismatch { block_number: 1, .. })
+        );
+        assert_eq!(
+            provider_factory.static_file_provider().get_highest_static_files(),
+            HighestStaticFiles::default()
         );
     }
 
@@ -380,6 +458,7 @@ mod tests {
                         headers: Some(1),
                         receipts: Some(1),
                         transactions: Some(1),
+                        senders: Some(1),
                     })
                     .expect("get static file targets");
                 assert_matches!(locked_producer.run(targets.clone()), Ok(_));
@@ -17,3 +17,6 @@ pub type IterPairResult<T> = Option<Result<KeyValue<T>, DatabaseError>>;
 
 /// A value only result for table `T`.
 pub type ValueOnlyResult<T> = Result<Option<<T as Table>::Value>, DatabaseError>;
+
+/// A key only result for table `T`.
+pub type KeyOnlyResult<T> = Result<Option<<T as Table>::Key>, DatabaseError>;
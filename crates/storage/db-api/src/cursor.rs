@@ -4,7 +4,7 @@ use std::{
 };
 
 use crate::{
-    common::{IterPairResult, PairResult, ValueOnlyResult},
+    common::{IterPairResult, KeyOnlyResult, PairResult, ValueOnlyResult},
     table::{DupSort, Table, TableRow},
     DatabaseError,
 };
@@ -33,6 +33,20 @@ pub trait DbCursorRO<T: Table> {
     /// Get the KV pair at the cursor's current position.
     fn current(&mut self) -> PairResult<T>;
 
+    /// Position the cursor at the next KV pair, returning only its key.
+    ///
+    /// Implementations may skip decoding the value, which is cheaper for tables with large values.
+    fn next_key(&mut self) -> KeyOnlyResult<T> {
+        Ok(self.next()?.map(|(key, _)| key))
+    }
+
+    /// Position the cursor at the previous KV pair, returning only its key.
+    ///
+    /// Implementations may skip decoding the value, see [`DbCursorRO::next_key`].
+    fn prev_key(&mut self) -> KeyOnlyResult<T> {
+        Ok(self.prev()?.map(|(key, _)| key))
+    }
+
     /// Get an iterator that walks through the table.
     ///
     /// If `start_key` is `None`, then the walker will start from the first entry of the table,
@@ -49,6 +63,22 @@ pub trait DbCursorRO<T: Table> {
     where
         Self: Sized;
 
+    /// Get an iterator that walks over a range of keys in the table, returning at most `limit`
+    /// entries.
+    ///
+    /// The walker stops at the limit without advancing the cursor any further, see
+    /// [`LimitedRangeWalker::has_more`] to check if there are entries left in the range.
+    fn walk_range_with_limit(
+        &mut self,
+        range: impl RangeBounds<T::Key>,
+        limit: usize,
+    ) -> Result<LimitedRangeWalker<'_, T, Self>, DatabaseError>
+    where
+        Self: Sized,
+    {
+        Ok(LimitedRangeWalker::new(self.walk_range(range)?, limit))
+    }
+
     /// Get an iterator that walks through the table in reverse order.
     ///
     /// If `start_key` is `None`, then the walker will start from the last entry of the table,
@@ -80,6 +110,14 @@ pub trait DbDupCursorRO<T: DupSort> {
     /// exist.
     fn seek_by_key_subkey(&mut self, key: T::Key, subkey: T::SubKey) -> ValueOnlyResult<T>;
 
+    /// Returns the number of duplicate values of the provided key, or `0` if the key does not
+    /// exist.
+    ///
+    /// # Note
+    ///
+    /// The cursor is positioned at the first value of the key if it exists.
+    fn count_dup(&mut self, key: T::Key) -> Result<usize, DatabaseError>;
+
     /// Get an iterator that walks through the dup table.
     ///
     /// The cursor will start at different points in the table depending on the values of `key` and
@@ -176,6 +214,11 @@ impl<'cursor, T: Table, CURSOR: DbCursorRO<T>> Walker<'cursor, T, CURSOR> {
         let start = self.cursor.current().transpose();
         ReverseWalker::new(self.cursor, start)
     }
+
+    /// convert current [`Walker`] to [`KeysWalker`] which only yields the keys
+    pub fn keys(self) -> KeysWalker<'cursor, T, CURSOR> {
+        KeysWalker::new(self.cursor, self.start, Bound::Unbounded, false)
+    }
 }
 
 impl<'cursor, T: Table, CURSOR: DbCursorRW<T> + DbCursorRO<T>> Walker<'cursor, T, CURSOR> {
@@ -219,6 +262,11 @@ impl<'cursor, T: Table, CURSOR: DbCursorRO<T>> ReverseWalker<'cursor, T, CURSOR>
         let start = self.cursor.current().transpose();
         Walker::new(self.cursor, start)
     }
+
+    /// convert current [`ReverseWalker`] to [`KeysWalker`] which only yields the keys reversely
+    pub fn keys(self) -> KeysWalker<'cursor, T, CURSOR> {
+        KeysWalker::new(self.cursor, self.start, Bound::Unbounded, true)
+    }
 }
 
 impl<'cursor, T: Table, CURSOR: DbCursorRW<T> + DbCursorRO<T>> ReverseWalker<'cursor, T, CURSOR> {
@@ -318,6 +366,13 @@ impl<'cursor, T: Table, CURSOR: DbCursorRO<T>> RangeWalker<'cursor, T, CURSOR> {
         };
         Self { cursor, start, end_key, is_done }
     }
+
+    /// convert current [`RangeWalker`] to [`KeysWalker`] which only yields the keys of the range
+    pub fn keys(self) -> KeysWalker<'cursor, T, CURSOR> {
+        let mut walker = KeysWalker::new(self.cursor, self.start, self.end_key, false);
+        walker.is_done = self.is_done;
+        walker
+    }
 }
 
 impl<'cursor, T: Table, CURSOR: DbCursorRW<T> + DbCursorRO<T>> RangeWalker<'cursor, T, CURSOR> {
@@ -328,6 +383,156 @@ impl<'cursor, T: Table, CURSOR: DbCursorRW<T> + DbCursorRO<T>> RangeWalker<'curs
     }
 }
 
+/// Provides an iterator to `Cursor` that only yields the keys of the entries, without decoding
+/// their values.
+/// Also check [`Walker::keys`], [`ReverseWalker::keys`] and [`RangeWalker::keys`]
+pub struct KeysWalker<'cursor, T: Table, CURSOR: DbCursorRO<T>> {
+    /// Cursor to be used to walk through the table.
+    cursor: &'cursor mut CURSOR,
+    /// `key` where to start the walk.
+    start: Option<Result<T::Key, DatabaseError>>,
+    /// `key` where to stop the walk.
+    end_key: Bound<T::Key>,
+    /// flag whether the walk is reversed
+    reverse: bool,
+    /// flag whether is ended
+    is_done: bool,
+}
+
+impl<T, CURSOR> fmt::Debug for KeysWalker<'_, T, CURSOR>
+where
+    T: Table,
+    CURSOR: DbCursorRO<T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeysWalker")
+            .field("cursor", &self.cursor)
+            .field("start", &self.start)
+            .field("end_key", &self.end_key)
+            .field("reverse", &self.reverse)
+            .field("is_done", &self.is_done)
+            .finish()
+    }
+}
+
+impl<'cursor, T: Table, CURSOR: DbCursorRO<T>> Iterator for KeysWalker<'cursor, T, CURSOR> {
+    type Item = Result<T::Key, DatabaseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None
+        }
+
+        let next_key = self.start.take().or_else(|| {
+            let next_key =
+                if self.reverse { self.cursor.prev_key() } else { self.cursor.next_key() };
+            next_key.transpose()
+        });
+
+        match next_key {
+            Some(Ok(key)) => match &self.end_key {
+                Bound::Included(end_key) if &key <= end_key => Some(Ok(key)),
+                Bound::Excluded(end_key) if &key < end_key => Some(Ok(key)),
+                Bound::Unbounded => Some(Ok(key)),
+                _ => {
+                    self.is_done = true;
+                    None
+                }
+            },
+            res => res,
+        }
+    }
+}
+
+impl<'cursor, T: Table, CURSOR: DbCursorRO<T>> KeysWalker<'cursor, T, CURSOR> {
+    /// construct `KeysWalker`
+    ///
+    /// The `end_key` is only checked when walking forward.
+    pub fn new(
+        cursor: &'cursor mut CURSOR,
+        start: IterPairResult<T>,
+        end_key: Bound<T::Key>,
+        reverse: bool,
+    ) -> Self {
+        let start = start.map(|res| res.map(|(key, _)| key));
+        let end_key = if reverse { Bound::Unbounded } else { end_key };
+        Self { cursor, start, end_key, reverse, is_done: false }
+    }
+}
+
+impl<'cursor, T: Table, CURSOR: DbCursorRW<T> + DbCursorRO<T>> KeysWalker<'cursor, T, CURSOR> {
+    /// Delete current item that walker points to.
+    pub fn delete_current(&mut self) -> Result<(), DatabaseError> {
+        self.start.take();
+        self.cursor.delete_current()
+    }
+}
+
+/// Provides a range iterator to `Cursor` that returns at most `limit` entries.
+/// Also check [`RangeWalker`]
+pub struct LimitedRangeWalker<'cursor, T: Table, CURSOR: DbCursorRO<T>> {
+    /// Walker over the range.
+    walker: RangeWalker<'cursor, T, CURSOR>,
+    /// Number of entries that can still be returned.
+    remaining: usize,
+}
+
+impl<T, CURSOR> fmt::Debug for LimitedRangeWalker<'_, T, CURSOR>
+where
+    T: Table,
+    CURSOR: DbCursorRO<T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LimitedRangeWalker")
+            .field("walker", &self.walker)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<'cursor, T: Table, CURSOR: DbCursorRO<T>> Iterator for LimitedRangeWalker<'cursor, T, CURSOR> {
+    type Item = Result<TableRow<T>, DatabaseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None
+        }
+
+        let next_item = self.walker.next();
+        if next_item.is_some() {
+            self.remaining -= 1;
+        }
+        next_item
+    }
+}
+
+impl<'cursor, T: Table, CURSOR: DbCursorRO<T>> LimitedRangeWalker<'cursor, T, CURSOR> {
+    /// construct `LimitedRangeWalker`
+    pub const fn new(walker: RangeWalker<'cursor, T, CURSOR>, limit: usize) -> Self {
+        Self { walker, remaining: limit }
+    }
+
+    /// Returns `true` if the range has entries left that were not returned by the walker, e.g.
+    /// because the limit was reached.
+    ///
+    /// If the walker is not done, this advances the cursor to the next entry of the range. The
+    /// entry is kept and still returned by the walker if the limit allows it.
+    pub fn has_more(&mut self) -> Result<bool, DatabaseError> {
+        if self.walker.start.is_some() {
+            return Ok(true)
+        }
+
+        match self.walker.next() {
+            Some(Ok(row)) => {
+                self.walker.start = Some(Ok(row));
+                Ok(true)
+            }
+            Some(Err(err)) => Err(err),
+            None => Ok(false),
+        }
+    }
+}
+
 /// Provides an iterator to `Cursor` when handling a `DupSort` table.
 ///
 /// Reason why we have two lifetimes is to distinguish between `'cursor` lifetime
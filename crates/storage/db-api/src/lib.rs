@@ -19,8 +19,10 @@
 //! - **Cursors** ([`DbCursorRO`] / [`DbCursorRW`]) for iterating data in a table. Cursors are
 //!   assumed to resolve data in a sorted manner when iterating from start to finish, and it is safe
 //!   to assume that they are efficient at doing so.
-//! - **Walkers** ([`Walker`] / [`RangeWalker`] / [`ReverseWalker`]) use cursors to walk the entries
-//!   in a table, either fully from a specific point, or over a range.
+//! - **Walkers** ([`Walker`] / [`RangeWalker`] / [`LimitedRangeWalker`] / [`ReverseWalker`]) use
+//!   cursors to walk the entries in a table, either fully from a specific point, or over a range.
+//!   Each of them can be turned into a [`KeysWalker`] that only yields keys and doesn't decode the
+//!   values.
 //!
 //! Dup tables (see below) also have corresponding cursors and walkers (e.g. [`DbDupCursorRO`]).
 //! These **should** be preferred when working with dup tables, as they provide additional methods
@@ -43,7 +45,9 @@
 //! [`DbCursorRW`]: crate::cursor::DbCursorRW
 //! [`Walker`]: crate::cursor::Walker
 //! [`RangeWalker`]: crate::cursor::RangeWalker
+//! [`LimitedRangeWalker`]: crate::cursor::LimitedRangeWalker
 //! [`ReverseWalker`]: crate::cursor::ReverseWalker
+//! [`KeysWalker`]: crate::cursor::KeysWalker
 //! [`DbDupCursorRO`]: crate::cursor::DbDupCursorRO
 //! [`Encode`]: crate::table::Encode
 //! [`Decode`]: crate::table::Decode
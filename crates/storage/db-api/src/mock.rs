@@ -200,6 +200,10 @@ impl<T: DupSort> DbDupCursorRO<T> for CursorMock {
         Ok(None)
     }
 
+    fn count_dup(&mut self, _key: <T as Table>::Key) -> Result<usize, DatabaseError> {
+        Ok(0)
+    }
+
     fn walk_dup(
         &mut self,
         _key: Option<<T>::Key>,
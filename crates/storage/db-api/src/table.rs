@@ -92,6 +92,35 @@ pub trait Table: Send + Sync + Debug + 'static {
     type Value: Value;
 }
 
+/// Statistics of a single table, as reported by the database backend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableStats {
+    /// Number of entries in the table.
+    pub entries: usize,
+    /// Depth of the table's B-tree.
+    pub depth: u32,
+    /// Size of a database page in bytes.
+    pub page_size: u32,
+    /// Number of internal (non-leaf) pages.
+    pub branch_pages: usize,
+    /// Number of leaf pages.
+    pub leaf_pages: usize,
+    /// Number of overflow pages.
+    pub overflow_pages: usize,
+}
+
+impl TableStats {
+    /// Returns the total number of pages used by the table.
+    pub const fn num_pages(&self) -> usize {
+        self.branch_pages + self.leaf_pages + self.overflow_pages
+    }
+
+    /// Returns the total size of the table in bytes.
+    pub const fn total_size(&self) -> usize {
+        self.page_size as usize * self.num_pages()
+    }
+}
+
 /// Tuple with `T::Key` and `T::Value`.
 pub type TableRow<T> = (<T as Table>::Key, <T as Table>::Value);
 
@@ -1,6 +1,6 @@
 use crate::{
     cursor::{DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW},
-    table::{DupSort, Table},
+    table::{DupSort, Table, TableStats},
     DatabaseError,
 };
 
@@ -24,6 +24,11 @@ pub trait DbTx: Send + Sync {
     fn cursor_dup_read<T: DupSort>(&self) -> Result<Self::DupCursor<T>, DatabaseError>;
     /// Returns number of entries in the table.
     fn entries<T: Table>(&self) -> Result<usize, DatabaseError>;
+    /// Returns the statistics of the table, or `None` if the database backend doesn't report
+    /// them.
+    fn table_stats<T: Table>(&self) -> Result<Option<TableStats>, DatabaseError> {
+        Ok(None)
+    }
     /// Disables long-lived read transaction safety guarantees.
     fn disable_long_read_transaction_safety(&mut self);
 }
@@ -42,6 +47,27 @@ pub trait DbTxMut: Send + Sync {
 
     /// Put value to database
     fn put<T: Table>(&self, key: T::Key, value: T::Value) -> Result<(), DatabaseError>;
+    /// Puts all rows to database using a single cursor.
+    ///
+    /// Rows with a key above the last key of the table are appended, which is considerably faster
+    /// than a regular put. A row with a key at or below the last key is upserted instead, so
+    /// callers must pass the keys in ascending order to benefit from the append path.
+    fn put_batch<T: Table>(
+        &self,
+        rows: impl IntoIterator<Item = (T::Key, T::Value)>,
+    ) -> Result<(), DatabaseError> {
+        let mut cursor = self.cursor_write::<T>()?;
+        let mut last_key = cursor.last()?.map(|(key, _)| key);
+        for (key, value) in rows {
+            if last_key.as_ref().map_or(true, |last_key| key > *last_key) {
+                cursor.append(key.clone(), value)?;
+                last_key = Some(key);
+            } else {
+                cursor.upsert(key, value)?;
+            }
+        }
+        Ok(())
+    }
     /// Delete value from database
     fn delete<T: Table>(&self, key: T::Key, value: Option<T::Value>)
         -> Result<bool, DatabaseError>;
@@ -0,0 +1,106 @@
+//! Export of database tables as newline-delimited JSON.
+
+use reth_db_api::{
+    cursor::DbCursorRO,
+    table::{Table, TableRow},
+    transaction::DbTx,
+    DatabaseError,
+};
+use serde::Serialize;
+use std::{
+    io::{self, BufWriter, Write},
+    ops::RangeBounds,
+};
+
+/// Errors that can occur while exporting a table, see [`export_table`].
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// Failed to read from the database.
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+    /// Failed to serialize a row.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    /// Failed to write to the output.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// A single exported row of a table.
+#[derive(Debug, Serialize)]
+struct ExportedRow<'a, T: Table> {
+    key: &'a T::Key,
+    value: &'a T::Value,
+}
+
+/// Writes all rows of the table `T` within `range` to `writer` as newline-delimited JSON, in key
+/// order. Each line is an object with a `key` and a `value` field.
+///
+/// The rows are streamed with a single read cursor, so the table is never loaded into memory as a
+/// whole. Dup sorted tables emit one line per value.
+///
+/// Returns the number of exported rows.
+pub fn export_table<T: Table, TX: DbTx>(
+    tx: &TX,
+    range: impl RangeBounds<T::Key>,
+    writer: impl Write,
+) -> Result<usize, ExportError> {
+    let mut writer = BufWriter::new(writer);
+    let mut cursor = tx.cursor_read::<T>()?;
+    let mut rows = 0;
+    for entry in cursor.walk_range(range)? {
+        let (key, value): TableRow<T> = entry?;
+        serde_json::to_writer(&mut writer, &ExportedRow::<T> { key: &key, value: &value })?;
+        writer.write_all(b"\n")?;
+        rows += 1;
+    }
+    writer.flush()?;
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tables, test_utils::create_test_rw_db};
+    use reth_db_api::{database::Database, transaction::DbTxMut};
+    use reth_primitives::{Address, StorageEntry, B256, U256};
+
+    #[test]
+    fn exports_range_as_json_lines() {
+        let db = create_test_rw_db();
+        let tx = db.tx_mut().unwrap();
+        for number in 0..5 {
+            tx.put::<tables::CanonicalHeaders>(number, B256::with_last_byte(number as u8)).unwrap();
+        }
+
+        let mut out = Vec::new();
+        assert_eq!(export_table::<tables::CanonicalHeaders, _>(&tx, 1..3, &mut out).unwrap(), 2);
+        let lines = String::from_utf8(out).unwrap();
+        let rows = lines
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [1u64, 2].map(|number| serde_json::json!({
+                "key": number,
+                "value": B256::with_last_byte(number as u8),
+            }))
+        );
+    }
+
+    #[test]
+    fn exports_every_dup_value() {
+        let db = create_test_rw_db();
+        let tx = db.tx_mut().unwrap();
+        let address = Address::random();
+        for slot in 0..3 {
+            let entry = StorageEntry { key: B256::with_last_byte(slot), value: U256::from(slot) };
+            tx.put::<tables::PlainStorageState>(address, entry).unwrap();
+        }
+
+        let mut out = Vec::new();
+        assert_eq!(export_table::<tables::PlainStorageState, _>(&tx, .., &mut out).unwrap(), 3);
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 3);
+    }
+}
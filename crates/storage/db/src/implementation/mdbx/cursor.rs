@@ -6,7 +6,7 @@ use crate::{
     DatabaseError,
 };
 use reth_db_api::{
-    common::{PairResult, ValueOnlyResult},
+    common::{KeyOnlyResult, PairResult, ValueOnlyResult},
     cursor::{
         DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, RangeWalker,
         ReverseWalker, Walker,
@@ -117,6 +117,24 @@ impl<K: TransactionKind, T: Table> DbCursorRO<T> for Cursor<K, T> {
         decode::<T>(self.inner.get_current())
     }
 
+    /// Returns the next key without decoding the value.
+    fn next_key(&mut self) -> KeyOnlyResult<T> {
+        self.inner
+            .next::<Cow<'_, [u8]>, ()>()
+            .map_err(|e| DatabaseError::Read(e.into()))?
+            .map(|(key, _)| decode_key::<T>(key))
+            .transpose()
+    }
+
+    /// Returns the previous key without decoding the value.
+    fn prev_key(&mut self) -> KeyOnlyResult<T> {
+        self.inner
+            .prev::<Cow<'_, [u8]>, ()>()
+            .map_err(|e| DatabaseError::Read(e.into()))?
+            .map(|(key, _)| decode_key::<T>(key))
+            .transpose()
+    }
+
     fn walk(&mut self, start_key: Option<T::Key>) -> Result<Walker<'_, T, Self>, DatabaseError> {
         let start = if let Some(start_key) = start_key {
             decode::<T>(self.inner.set_range(start_key.encode().as_ref())).transpose()
@@ -189,6 +207,20 @@ impl<K: TransactionKind, T: DupSort> DbDupCursorRO<T> for Cursor<K, T> {
             .transpose()
     }
 
+    /// Returns the number of values of a duplicate `key`, using the MDBX count of the key instead
+    /// of walking its values.
+    fn count_dup(&mut self, key: <T as Table>::Key) -> Result<usize, DatabaseError> {
+        if self
+            .inner
+            .set::<()>(key.encode().as_ref())
+            .map_err(|e| DatabaseError::Read(e.into()))?
+            .is_none()
+        {
+            return Ok(0)
+        }
+        self.inner.count().map_err(|e| DatabaseError::Read(e.into()))
+    }
+
     /// Depending on its arguments, returns an iterator starting at:
     /// - Some(key), Some(subkey): a `key` item whose data is >= than `subkey`
     /// - Some(key), None: first item of a specified `key`
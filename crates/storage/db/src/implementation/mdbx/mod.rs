@@ -578,6 +578,36 @@ mod tests {
         assert_eq!(walker.next(), None);
     }
 
+    #[test]
+    fn db_dup_cursor_count_dup() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+
+        let mut dup_cursor = tx.cursor_dup_write::<PlainStorageState>().unwrap();
+        for (address, slots) in [(1, 3), (2, 1)] {
+            for slot in 0..slots {
+                let entry =
+                    StorageEntry { key: B256::with_last_byte(slot), value: U256::from(slot) };
+                dup_cursor.upsert(Address::with_last_byte(address), entry).expect(ERROR_UPSERT);
+            }
+        }
+
+        assert_eq!(dup_cursor.count_dup(Address::with_last_byte(1)), Ok(3));
+        assert_eq!(dup_cursor.count_dup(Address::with_last_byte(2)), Ok(1));
+        // missing key
+        assert_eq!(dup_cursor.count_dup(Address::with_last_byte(3)), Ok(0));
+
+        // the cursor is positioned at the first value of the key
+        assert_eq!(dup_cursor.count_dup(Address::with_last_byte(1)), Ok(3));
+        assert_eq!(
+            dup_cursor.current(),
+            Ok(Some((
+                Address::with_last_byte(1),
+                StorageEntry { key: B256::with_last_byte(0), value: U256::ZERO }
+            )))
+        );
+    }
+
     #[test]
     fn db_cursor_walk() {
         let env = create_test_db(DatabaseEnvKind::RW);
@@ -667,6 +697,140 @@ mod tests {
         assert_eq!(walker.next(), None);
     }
 
+    #[test]
+    fn db_table_stats() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        (0..4).try_for_each(|key| tx.put::<CanonicalHeaders>(key, B256::ZERO)).expect(ERROR_PUT);
+        tx.commit().expect(ERROR_COMMIT);
+
+        let tx = db.tx().expect(ERROR_INIT_TX);
+        let stats = tx.table_stats::<CanonicalHeaders>().unwrap().unwrap();
+        assert_eq!(stats.entries, 4);
+        assert_eq!(stats.depth, 1);
+        assert_eq!(stats.leaf_pages, 1);
+        assert_eq!(stats.total_size(), stats.page_size as usize);
+
+        let db_stats = crate::database_stats(&tx).unwrap();
+        assert_eq!(db_stats.tables.len(), Tables::COUNT);
+        assert_eq!(db_stats.total_entries(), 4);
+        assert!(db_stats.tables.contains(&(Tables::CanonicalHeaders, stats)));
+    }
+
+    #[test]
+    fn db_cursor_walk_keys() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+
+        // PUT (0, 0), (1, 0), (2, 0), (3, 0)
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        vec![0, 1, 2, 3]
+            .into_iter()
+            .try_for_each(|key| tx.put::<CanonicalHeaders>(key, B256::ZERO))
+            .expect(ERROR_PUT);
+        tx.commit().expect(ERROR_COMMIT);
+
+        let tx = db.tx().expect(ERROR_INIT_TX);
+        let mut cursor = tx.cursor_read::<CanonicalHeaders>().unwrap();
+
+        let keys = cursor.walk(Some(1)).unwrap().keys().collect::<Result<Vec<_>, _>>();
+        assert_eq!(keys, Ok(vec![1, 2, 3]));
+
+        let keys = cursor.walk_back(Some(2)).unwrap().keys().collect::<Result<Vec<_>, _>>();
+        assert_eq!(keys, Ok(vec![2, 1, 0]));
+
+        // [1, 3)
+        let mut walker = cursor.walk_range(1..3).unwrap().keys();
+        assert_eq!(walker.next(), Some(Ok(1)));
+        assert_eq!(walker.next(), Some(Ok(2)));
+        assert_eq!(walker.next(), None);
+        // next() returns None after walker is done
+        assert_eq!(walker.next(), None);
+
+        // empty range
+        let mut walker = cursor.walk_range(4..).unwrap().keys();
+        assert_eq!(walker.next(), None);
+
+        // key only positioning
+        cursor.first().unwrap();
+        assert_eq!(cursor.next_key(), Ok(Some(1)));
+        assert_eq!(cursor.current(), Ok(Some((1, B256::ZERO))));
+        assert_eq!(cursor.prev_key(), Ok(Some(0)));
+        assert_eq!(cursor.prev_key(), Ok(None));
+    }
+
+    #[test]
+    fn db_cursor_walk_keys_delete() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        vec![0, 1, 2, 3]
+            .into_iter()
+            .try_for_each(|key| tx.put::<CanonicalHeaders>(key, B256::ZERO))
+            .expect(ERROR_PUT);
+
+        // delete [1, 2] in reverse
+        let mut cursor = tx.cursor_write::<CanonicalHeaders>().unwrap();
+        let mut walker = cursor.walk_back(Some(2)).unwrap().keys();
+        while let Some(key) = walker.next().transpose().unwrap() {
+            if key < 1 {
+                break
+            }
+            walker.delete_current().expect(ERROR_DEL);
+        }
+
+        let keys = cursor.walk(None).unwrap().keys().collect::<Result<Vec<_>, _>>();
+        assert_eq!(keys, Ok(vec![0, 3]));
+    }
+
+    #[test]
+    fn db_cursor_walk_range_with_limit() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+
+        // PUT (0, 0), (1, 0), (2, 0), (3, 0)
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        vec![0, 1, 2, 3]
+            .into_iter()
+            .try_for_each(|key| tx.put::<CanonicalHeaders>(key, B256::ZERO))
+            .expect(ERROR_PUT);
+        tx.commit().expect(ERROR_COMMIT);
+
+        let tx = db.tx().expect(ERROR_INIT_TX);
+        let mut cursor = tx.cursor_read::<CanonicalHeaders>().unwrap();
+
+        // [1, ∞) limited to 2 entries
+        let mut walker = cursor.walk_range_with_limit(1.., 2).unwrap();
+        assert_eq!(walker.next(), Some(Ok((1, B256::ZERO))));
+        assert_eq!(walker.next(), Some(Ok((2, B256::ZERO))));
+        assert_eq!(walker.next(), None);
+        // the walker stops at the limit without advancing the cursor
+        assert_eq!(cursor.current(), Ok(Some((2, B256::ZERO))));
+
+        let mut walker = cursor.walk_range_with_limit(1.., 2).unwrap();
+        assert_eq!(walker.next(), Some(Ok((1, B256::ZERO))));
+        assert_eq!(walker.has_more(), Ok(true));
+        assert_eq!(walker.next(), Some(Ok((2, B256::ZERO))));
+        assert_eq!(walker.has_more(), Ok(true));
+        assert_eq!(walker.next(), None);
+
+        // [1, 3) limited to 2 entries
+        let mut walker = cursor.walk_range_with_limit(1..3, 2).unwrap();
+        assert_eq!(walker.next(), Some(Ok((1, B256::ZERO))));
+        assert_eq!(walker.next(), Some(Ok((2, B256::ZERO))));
+        assert_eq!(walker.has_more(), Ok(false));
+        assert_eq!(walker.next(), None);
+
+        // [1, ∞) limited to 5 entries
+        let mut walker = cursor.walk_range_with_limit(1.., 5).unwrap();
+        assert_eq!(walker.by_ref().count(), 3);
+        assert_eq!(walker.has_more(), Ok(false));
+
+        // [1, ∞) limited to 0 entries
+        let mut walker = cursor.walk_range_with_limit(1.., 0).unwrap();
+        assert_eq!(walker.has_more(), Ok(true));
+        assert_eq!(walker.next(), None);
+    }
+
     #[test]
     fn db_cursor_walk_range_on_dup_table() {
         let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
@@ -1011,6 +1175,37 @@ mod tests {
         tx.commit().expect(ERROR_COMMIT);
     }
 
+    #[test]
+    fn db_put_batch() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+
+        // PUT
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        vec![0, 2, 4]
+            .into_iter()
+            .try_for_each(|key| tx.put::<CanonicalHeaders>(key, B256::ZERO))
+            .expect(ERROR_PUT);
+        tx.commit().expect(ERROR_COMMIT);
+
+        // PUT BATCH, with keys below the last key and out of order
+        let value = B256::with_last_byte(1);
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        tx.put_batch::<CanonicalHeaders>([5, 6, 3, 4, 8, 7].map(|key| (key, value)))
+            .expect(ERROR_PUT);
+        tx.commit().expect(ERROR_COMMIT);
+
+        // Confirm the result
+        let tx = db.tx().expect(ERROR_INIT_TX);
+        let mut cursor = tx.cursor_read::<CanonicalHeaders>().unwrap();
+        let res = cursor.walk(None).unwrap().map(|res| res.unwrap()).collect::<Vec<_>>();
+        let expected = [(0, B256::ZERO), (2, B256::ZERO)]
+            .into_iter()
+            .chain((3..=8).map(|key| (key, value)))
+            .collect::<Vec<_>>();
+        assert_eq!(res, expected);
+        tx.commit().expect(ERROR_COMMIT);
+    }
+
     #[test]
     fn db_cursor_append_failure() {
         let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
@@ -7,7 +7,7 @@ use crate::{
     DatabaseError,
 };
 use reth_db_api::{
-    table::{Compress, DupSort, Encode, Table, TableImporter},
+    table::{Compress, DupSort, Encode, Table, TableImporter, TableStats},
     transaction::{DbTx, DbTxMut},
 };
 use reth_libmdbx::{ffi::MDBX_dbi, CommitLatency, Transaction, TransactionKind, WriteFlags, RW};
@@ -307,6 +307,22 @@ impl<K: TransactionKind> DbTx for Tx<K> {
             .entries())
     }
 
+    /// Returns the table statistics reported by `mdbx_dbi_stat`.
+    fn table_stats<T: Table>(&self) -> Result<Option<TableStats>, DatabaseError> {
+        let stat = self
+            .inner
+            .db_stat_with_dbi(self.get_dbi::<T>()?)
+            .map_err(|e| DatabaseError::Stats(e.into()))?;
+        Ok(Some(TableStats {
+            entries: stat.entries(),
+            depth: stat.depth(),
+            page_size: stat.page_size(),
+            branch_pages: stat.branch_pages(),
+            leaf_pages: stat.leaf_pages(),
+            overflow_pages: stat.overflow_pages(),
+        }))
+    }
+
     /// Disables long-lived read transaction safety guarantees, such as backtrace recording and
     /// timeout.
     fn disable_long_read_transaction_safety(&mut self) {
@@ -15,10 +15,12 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
+mod export;
 mod implementation;
 pub mod lockfile;
 mod metrics;
 pub mod static_file;
+mod stats;
 pub mod tables;
 mod utils;
 pub mod version;
@@ -26,7 +28,9 @@ pub mod version;
 #[cfg(feature = "mdbx")]
 pub mod mdbx;
 
+pub use export::{export_table, ExportError};
 pub use reth_storage_errors::db::{DatabaseError, DatabaseWriteOperation};
+pub use stats::{database_stats, DatabaseStats};
 pub use tables::*;
 pub use utils::is_database_empty;
 
@@ -0,0 +1,54 @@
+//! Statistics of the database tables.
+
+use crate::{TableViewer, Tables};
+use reth_db_api::{
+    table::{Table, TableStats},
+    transaction::DbTx,
+    DatabaseError,
+};
+
+/// Statistics of all database tables.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DatabaseStats {
+    /// Statistics of every table, ordered by table name.
+    pub tables: Vec<(Tables, TableStats)>,
+}
+
+impl DatabaseStats {
+    /// Returns the total number of entries in all tables.
+    pub fn total_entries(&self) -> usize {
+        self.tables.iter().map(|(_, stats)| stats.entries).sum()
+    }
+
+    /// Returns the total size of all tables in bytes.
+    pub fn total_size(&self) -> usize {
+        self.tables.iter().map(|(_, stats)| stats.total_size()).sum()
+    }
+}
+
+/// Collects the statistics of all [`Tables`] with the given transaction.
+///
+/// Tables for which the database backend doesn't report statistics are skipped.
+pub fn database_stats<TX: DbTx>(tx: &TX) -> Result<DatabaseStats, DatabaseError> {
+    let viewer = TableStatsViewer(tx);
+    let mut tables = Vec::with_capacity(Tables::COUNT);
+    for table in Tables::ALL {
+        if let Some(stats) = table.view(&viewer)? {
+            tables.push((*table, stats));
+        }
+    }
+    tables.sort_by_key(|(table, _)| table.name());
+
+    Ok(DatabaseStats { tables })
+}
+
+/// Reads the [`TableStats`] of a table.
+struct TableStatsViewer<'a, TX>(&'a TX);
+
+impl<TX: DbTx> TableViewer<Option<TableStats>> for TableStatsViewer<'_, TX> {
+    type Error = DatabaseError;
+
+    fn view<T: Table>(&self) -> Result<Option<TableStats>, Self::Error> {
+        self.0.table_stats::<T>()
+    }
+}
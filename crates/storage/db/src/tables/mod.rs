@@ -410,6 +410,9 @@ tables! {
 
     /// Stores generic chain state info, like the last finalized block.
     table ChainState<Key = ChainStateKey, Value = BlockNumber>;
+
+    /// Stores the headers of blocks that were rejected by the consensus engine as invalid.
+    table InvalidHeaders<Key = BlockHash, Value = Header>;
 }
 
 /// Keys for the `ChainState` table.
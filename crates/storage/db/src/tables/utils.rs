@@ -25,6 +25,17 @@ where
     ))
 }
 
+/// Helper function to decode only a key.
+pub(crate) fn decode_key<T>(key: Cow<'_, [u8]>) -> Result<T::Key, DatabaseError>
+where
+    T: Table,
+{
+    match key {
+        Cow::Borrowed(k) => Decode::decode(k),
+        Cow::Owned(k) => Decode::decode(k),
+    }
+}
+
 /// Helper function to decode only a value from a `(key, value)` pair.
 pub(crate) fn decode_value<'a, T>(
     kv: (Cow<'a, [u8]>, Cow<'a, [u8]>),
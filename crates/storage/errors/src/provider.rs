@@ -92,6 +92,14 @@ pub enum ProviderError {
     /// Unable to compute state root on top of historical block.
     #[error("unable to compute state root on top of historical block")]
     StateRootNotAvailableForHistoricalBlock,
+    /// The recomputed hash of a header does not match its `CanonicalHeaders` entry.
+    #[error("canonical hash mismatch for block #{block_number}: {hash}")]
+    CanonicalHashMismatch {
+        /// The block number of the mismatching header.
+        block_number: BlockNumber,
+        /// The recomputed and the stored canonical hash.
+        hash: Box<GotExpected<B256>>,
+    },
     /// Unable to find the block number for a given transaction index.
     #[error("unable to find the block number for a given transaction index")]
     BlockNumberForTransactionIndexNotFound,
@@ -122,6 +130,9 @@ pub enum ProviderError {
     /// Trying to insert data from an unexpected block number.
     #[error("trying to append data to {0} as block #{1} but expected block #{2}")]
     UnexpectedStaticFileBlockNumber(StaticFileSegment, BlockNumber, BlockNumber),
+    /// Static File entry does not match the database entry with the same key.
+    #[error("{0} static file entry {1} does not match the database")]
+    StaticFileDatabaseMismatch(StaticFileSegment, u64),
     /// Static File Provider was initialized as read-only.
     #[error("cannot get a writer on a read-only environment.")]
     ReadOnlyStaticFileAccess,
@@ -332,6 +332,19 @@ where
         Ok(Some((found, k.unwrap(), v)))
     }
 
+    /// [`DatabaseFlags::DUP_SORT`]-only: Returns the number of duplicate data items of the key
+    /// the cursor is positioned at.
+    ///
+    /// For non DupSort-ed collections this is always 1 if the cursor is positioned.
+    pub fn count(&self) -> Result<usize> {
+        let mut count: usize = 0;
+        mdbx_result(unsafe {
+            self.txn.txn_execute(|_| ffi::mdbx_cursor_count(self.cursor, &mut count))?
+        })?;
+
+        Ok(count)
+    }
+
     /// Returns an iterator over database items.
     ///
     /// The iterator will begin with item next after the cursor, and continue until the end of the
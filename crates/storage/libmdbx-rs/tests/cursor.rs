@@ -61,6 +61,7 @@ fn test_get_dup() {
     assert_eq!(cursor.get_both(b"key1", b"val3").unwrap(), Some(*b"val3"));
     assert_eq!(cursor.get_both_range::<()>(b"key1", b"val4").unwrap(), None);
     assert_eq!(cursor.get_both_range(b"key2", b"val").unwrap(), Some(*b"val1"));
+    assert_eq!(cursor.count().unwrap(), 3);
 
     assert_eq!(cursor.last().unwrap(), Some((*b"key2", *b"val3")));
     cursor.del(WriteFlags::empty()).unwrap();
@@ -1,5 +1,6 @@
 use crate::{
-    AccountReader, BlockHashReader, ExecutionDataProvider, StateProvider, StateRootProvider,
+    AccountRange, AccountReader, BlockHashReader, ExecutionDataProvider, StateProvider,
+    StateRootProvider,
 };
 use reth_primitives::{proofs::AccountProof, Account, Address, BlockNumber, Bytecode, B256};
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
@@ -111,4 +112,8 @@ impl<SP: StateProvider, EDP: ExecutionDataProvider> StateProvider for BundleStat
     fn proof(&self, _address: Address, _keys: &[B256]) -> ProviderResult<AccountProof> {
         Err(ProviderError::StateRootNotAvailableForHistoricalBlock)
     }
+
+    fn account_range(&self, _start: B256, _limit: usize) -> ProviderResult<AccountRange> {
+        Err(ProviderError::StateRootNotAvailableForHistoricalBlock)
+    }
 }
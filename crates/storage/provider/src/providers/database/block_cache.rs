@@ -0,0 +1,145 @@
+//! An opt-in read-through cache for recently read blocks.
+
+use parking_lot::Mutex;
+use reth_db_api::models::StoredBlockBodyIndices;
+use reth_metrics::{
+    metrics::{Counter, Gauge},
+    Metrics,
+};
+use reth_primitives::{Block, BlockHash, Header};
+use reth_storage_errors::provider::ProviderResult;
+use schnellru::{ByLength, LruMap};
+use std::fmt;
+
+/// A bounded LRU cache for headers, blocks and block body indices read from the database.
+///
+/// The cache is shared by all read-only [`DatabaseProvider`](crate::DatabaseProvider)s of a
+/// [`ProviderFactory`](crate::ProviderFactory) that has the cache enabled, see
+/// [`ProviderFactory::with_block_cache`](crate::ProviderFactory::with_block_cache).
+///
+/// All entries are keyed by block hash, because the header, body and body indices of a block never
+/// change for a given hash. Lookups by number always resolve the canonical hash from the database
+/// first, so an unwind or a reorg can't make the cache return stale data. Entries of blocks that
+/// are no longer canonical are dropped on reorg by
+/// [`BlockchainProvider`](crate::providers::BlockchainProvider).
+pub struct BlockCache {
+    headers: MeteredLruMap<Header>,
+    blocks: MeteredLruMap<Block>,
+    body_indices: MeteredLruMap<StoredBlockBodyIndices>,
+}
+
+impl BlockCache {
+    /// Creates a new cache that holds up to `max_blocks` entries of each kind.
+    pub fn new(max_blocks: u32) -> Self {
+        Self {
+            headers: MeteredLruMap::new(max_blocks, "headers"),
+            blocks: MeteredLruMap::new(max_blocks, "blocks"),
+            body_indices: MeteredLruMap::new(max_blocks, "body_indices"),
+        }
+    }
+
+    /// Returns the header with the given hash, reading it with `f` on a cache miss.
+    pub(crate) fn header(
+        &self,
+        hash: BlockHash,
+        f: impl FnOnce() -> ProviderResult<Option<Header>>,
+    ) -> ProviderResult<Option<Header>> {
+        self.headers.get_or_try_insert(hash, f)
+    }
+
+    /// Returns the block with the given hash, reading it with `f` on a cache miss.
+    pub(crate) fn block(
+        &self,
+        hash: BlockHash,
+        f: impl FnOnce() -> ProviderResult<Option<Block>>,
+    ) -> ProviderResult<Option<Block>> {
+        self.blocks.get_or_try_insert(hash, f)
+    }
+
+    /// Returns the body indices of the block with the given hash, reading them with `f` on a cache
+    /// miss.
+    pub(crate) fn body_indices(
+        &self,
+        hash: BlockHash,
+        f: impl FnOnce() -> ProviderResult<Option<StoredBlockBodyIndices>>,
+    ) -> ProviderResult<Option<StoredBlockBodyIndices>> {
+        self.body_indices.get_or_try_insert(hash, f)
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.headers.clear();
+        self.blocks.clear();
+        self.body_indices.clear();
+    }
+}
+
+impl fmt::Debug for BlockCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockCache")
+            .field("headers", &self.headers.len())
+            .field("blocks", &self.blocks.len())
+            .field("body_indices", &self.body_indices.len())
+            .finish()
+    }
+}
+
+/// An LRU map keyed by block hash that records hits and misses.
+struct MeteredLruMap<V> {
+    inner: Mutex<LruMap<BlockHash, V, ByLength>>,
+    metrics: BlockCacheMetrics,
+}
+
+impl<V: Clone> MeteredLruMap<V> {
+    fn new(max_len: u32, cache: &'static str) -> Self {
+        Self {
+            inner: Mutex::new(LruMap::new(ByLength::new(max_len))),
+            metrics: BlockCacheMetrics::new_with_labels(&[("cache", cache)]),
+        }
+    }
+
+    /// Returns the cached value or reads and caches it with `f`.
+    ///
+    /// Values that are not found are not cached, because they may be written later.
+    fn get_or_try_insert(
+        &self,
+        hash: BlockHash,
+        f: impl FnOnce() -> ProviderResult<Option<V>>,
+    ) -> ProviderResult<Option<V>> {
+        if let Some(value) = self.inner.lock().get(&hash).cloned() {
+            self.metrics.hits_total.increment(1);
+            return Ok(Some(value))
+        }
+        self.metrics.misses_total.increment(1);
+
+        // the lock is not held while reading, so concurrent misses for the same hash may read the
+        // value twice, which is fine since the values of a hash never change
+        let value = f()?;
+        if let Some(value) = &value {
+            let mut inner = self.inner.lock();
+            inner.insert(hash, value.clone());
+            self.metrics.cached_count.set(inner.len() as f64);
+        }
+        Ok(value)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.lock().len()
+    }
+
+    fn clear(&self) {
+        self.inner.lock().clear();
+        self.metrics.cached_count.set(0.0);
+    }
+}
+
+#[derive(Metrics)]
+#[metrics(scope = "storage.providers.block_cache")]
+struct BlockCacheMetrics {
+    /// The number of entries in the cache.
+    cached_count: Gauge,
+    /// The number of cache hits.
+    hits_total: Counter,
+    /// The number of cache misses.
+    misses_total: Counter,
+}
@@ -4,9 +4,9 @@ use crate::{
     traits::{BlockSource, ReceiptProvider},
     BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, DatabaseProviderFactory,
     EvmEnvProvider, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider, HeaderSyncMode,
-    ProviderError, PruneCheckpointReader, RequestsProvider, StageCheckpointReader,
-    StateProviderBox, StaticFileProviderFactory, TransactionVariant, TransactionsProvider,
-    WithdrawalsProvider,
+    InvalidHeadersReader, ProviderError, PruneCheckpointReader, RequestsProvider,
+    StageCheckpointReader, StateProviderBox, StaticFileProviderFactory, TransactionVariant,
+    TransactionsProvider, WithdrawalsProvider,
 };
 use reth_db::{init_db, mdbx::DatabaseArguments, DatabaseEnv};
 use reth_db_api::{database::Database, models::StoredBlockBodyIndices};
@@ -29,10 +29,12 @@ use std::{
 };
 use tracing::trace;
 
+mod block_cache;
 mod metrics;
 mod provider;
 
-pub use provider::{DatabaseProvider, DatabaseProviderRO, DatabaseProviderRW};
+pub use block_cache::BlockCache;
+pub use provider::{BaseFeeInfo, DatabaseProvider, DatabaseProviderRO, DatabaseProviderRW};
 
 /// A common provider that fetches data from a database or static file.
 ///
@@ -45,6 +47,8 @@ pub struct ProviderFactory<DB> {
     chain_spec: Arc<ChainSpec>,
     /// Static File Provider
     static_file_provider: StaticFileProvider,
+    /// Optional cache for recently read blocks, shared by all read-only providers.
+    block_cache: Option<Arc<BlockCache>>,
 }
 
 impl<DB> ProviderFactory<DB> {
@@ -54,7 +58,7 @@ impl<DB> ProviderFactory<DB> {
         chain_spec: Arc<ChainSpec>,
         static_file_provider: StaticFileProvider,
     ) -> Self {
-        Self { db: Arc::new(db), chain_spec, static_file_provider }
+        Self { db: Arc::new(db), chain_spec, static_file_provider, block_cache: None }
     }
 
     /// Enables metrics on the static file provider.
@@ -63,6 +67,20 @@ impl<DB> ProviderFactory<DB> {
         self
     }
 
+    /// Enables a [`BlockCache`] that holds up to `max_blocks` recently read headers, blocks and
+    /// block body indices.
+    ///
+    /// The cache is disabled by default.
+    pub fn with_block_cache(mut self, max_blocks: u32) -> Self {
+        self.block_cache = Some(Arc::new(BlockCache::new(max_blocks)));
+        self
+    }
+
+    /// Returns the [`BlockCache`], if enabled.
+    pub fn block_cache(&self) -> Option<&BlockCache> {
+        self.block_cache.as_deref()
+    }
+
     /// Returns reference to the underlying database.
     pub fn db_ref(&self) -> &DB {
         &self.db
@@ -88,6 +106,7 @@ impl ProviderFactory<DatabaseEnv> {
             db: Arc::new(init_db(path, args).map_err(RethError::msg)?),
             chain_spec,
             static_file_provider,
+            block_cache: None,
         })
     }
 }
@@ -98,11 +117,15 @@ impl<DB: Database> ProviderFactory<DB> {
     /// [`BlockHashReader`]. This may fail if the inner read database transaction fails to open.
     #[track_caller]
     pub fn provider(&self) -> ProviderResult<DatabaseProviderRO<DB>> {
-        Ok(DatabaseProvider::new(
+        let provider = DatabaseProvider::new(
             self.db.tx()?,
             self.chain_spec.clone(),
             self.static_file_provider.clone(),
-        ))
+        );
+        Ok(match &self.block_cache {
+            Some(block_cache) => provider.with_block_cache(block_cache.clone()),
+            None => provider,
+        })
     }
 
     /// Returns a provider with a created `DbTxMut` inside, which allows fetching and updating
@@ -178,6 +201,9 @@ impl<DB: Database> HeaderProvider for ProviderFactory<DB> {
     }
 
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
+        if self.block_cache.is_some() {
+            return self.provider()?.header_by_number(num)
+        }
         self.static_file_provider.get_with_static_file_or_database(
             StaticFileSegment::Headers,
             num,
@@ -491,6 +517,12 @@ impl<DB: Database> StageCheckpointReader for ProviderFactory<DB> {
     }
 }
 
+impl<DB: Database> InvalidHeadersReader for ProviderFactory<DB> {
+    fn invalid_headers(&self) -> ProviderResult<Vec<SealedHeader>> {
+        self.provider()?.invalid_headers()
+    }
+}
+
 impl<DB: Database> EvmEnvProvider for ProviderFactory<DB> {
     fn fill_env_at<EvmConfig>(
         &self,
@@ -518,6 +550,18 @@ impl<DB: Database> EvmEnvProvider for ProviderFactory<DB> {
         self.provider()?.fill_env_with_header(cfg, block_env, header, evm_config)
     }
 
+    fn fill_env_for_pending<EvmConfig>(
+        &self,
+        cfg: &mut CfgEnvWithHandlerCfg,
+        block_env: &mut BlockEnv,
+        evm_config: EvmConfig,
+    ) -> ProviderResult<SealedHeader>
+    where
+        EvmConfig: ConfigureEvmEnv,
+    {
+        self.provider()?.fill_env_for_pending(cfg, block_env, evm_config)
+    }
+
     fn fill_block_env_at(
         &self,
         block_env: &mut BlockEnv,
@@ -583,6 +627,7 @@ impl<DB> Clone for ProviderFactory<DB> {
             db: Arc::clone(&self.db),
             chain_spec: self.chain_spec.clone(),
             static_file_provider: self.static_file_provider.clone(),
+            block_cache: self.block_cache.clone(),
         }
     }
 }
@@ -590,9 +635,11 @@ impl<DB> Clone for ProviderFactory<DB> {
 mod tests {
     use super::*;
     use crate::{
+        bundle_state::{BundleStateInit, ExecutionOutcome, OriginalValuesKnown, RevertsInit},
         providers::{StaticFileProvider, StaticFileWriter},
-        test_utils::create_test_provider_factory,
-        BlockHashReader, BlockNumReader, BlockWriter, HeaderSyncGapProvider, HeaderSyncMode,
+        test_utils::{create_test_provider_factory, create_test_provider_factory_with_chain_spec},
+        BlockHashReader, BlockNumReader, BlockWriter, ChangeSetReader, HashingWriter,
+        HeaderSyncGapProvider, HeaderSyncMode, StageCheckpointWriter, StateWriter,
         TransactionsProvider,
     };
     use alloy_rlp::Decodable;
@@ -603,16 +650,32 @@ mod tests {
         tables,
         test_utils::{create_test_static_files_dir, ERROR_TEMPDIR},
     };
+    use reth_db_api::{
+        cursor::DbCursorRO,
+        models::{AccountBeforeTx, BlockNumberAddress, ShardedKey, StoredBlockOmmers},
+        table::Table,
+        transaction::{DbTx, DbTxMut},
+    };
     use reth_primitives::{
-        hex_literal::hex, ChainSpecBuilder, SealedBlock, StaticFileSegment, TxNumber, B256, U256,
+        address,
+        constants::{EMPTY_ROOT_HASH, ETH_TO_WEI},
+        hex_literal::hex,
+        keccak256, Account, Address, Bytecode, Bytes, ChainSpecBuilder, ForkCondition, Hardfork,
+        IntegerList, Receipts, SealedBlock, StaticFileSegment, StorageEntry, TxHash, TxNumber,
+        Withdrawal, Withdrawals, B256, KECCAK_EMPTY, MAINNET, U256,
     };
     use reth_prune_types::{PruneMode, PruneModes};
     use reth_storage_errors::provider::ProviderError;
     use reth_testing_utils::{
         generators,
-        generators::{random_block, random_header},
+        generators::{random_block, random_block_range, random_header, random_receipt},
+    };
+    use reth_trie::{test_utils::state_root, StateRoot};
+    use std::{
+        collections::{HashMap, HashSet},
+        ops::RangeInclusive,
+        sync::Arc,
     };
-    use std::{ops::RangeInclusive, sync::Arc};
     use tokio::sync::watch;
 
     #[test]
@@ -660,6 +723,53 @@ mod tests {
         provider.block_hash(0).unwrap();
     }
 
+    #[test]
+    fn block_cache_never_serves_unwound_blocks() {
+        let factory = create_test_provider_factory().with_block_cache(16);
+
+        let mut rng = generators::rng();
+        let block = random_block(&mut rng, 0, None, Some(1), None);
+        let hash = block.hash();
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap(), None).unwrap();
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.header_by_number(0).unwrap().as_ref(), Some(block.header.header()));
+        let cached = provider.block(hash.into()).unwrap().unwrap();
+        assert_eq!(cached.header, block.header.clone().unseal());
+        let indices = provider.block_body_indices(0).unwrap();
+        assert!(indices.is_some());
+        drop(provider);
+
+        // the header is served from the cache as long as the block is canonical
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.tx_ref().delete::<tables::Headers>(0, None).unwrap();
+            provider.commit().unwrap();
+        }
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.header_by_number(0).unwrap().as_ref(), Some(block.header.header()));
+        assert_eq!(provider.block(BlockHashOrNumber::Number(0)).unwrap(), Some(cached));
+        assert_eq!(provider.block_body_indices(0).unwrap(), indices);
+        drop(provider);
+
+        // unwind the block
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.tx_ref().delete::<tables::CanonicalHeaders>(0, None).unwrap();
+            provider.tx_ref().delete::<tables::HeaderNumbers>(hash, None).unwrap();
+            provider.commit().unwrap();
+        }
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.header_by_number(0).unwrap(), None);
+        assert_eq!(provider.block(BlockHashOrNumber::Number(0)).unwrap(), None);
+        assert_eq!(provider.block(hash.into()).unwrap(), None);
+        assert_eq!(provider.block_body_indices(0).unwrap(), None);
+    }
+
     #[test]
     fn insert_block_with_prune_modes() {
         let factory = create_test_provider_factory();
@@ -698,6 +808,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn insert_block_retains_watched_senders() {
+        let factory = create_test_provider_factory();
+
+        let mut rng = generators::rng();
+        let block = random_block(&mut rng, 0, None, Some(2), None).try_seal_with_senders().unwrap();
+        let (watched, unwatched) = (block.senders[0], block.senders[1]);
+        assert_ne!(watched, unwatched);
+
+        let provider = factory.provider_rw().unwrap();
+        assert_matches!(
+            provider.insert_block(
+                block,
+                Some(&PruneModes {
+                    sender_recovery: Some(PruneMode::Full),
+                    watched_senders: [watched].into(),
+                    ..PruneModes::none()
+                })
+            ),
+            Ok(_)
+        );
+        assert_eq!(provider.table::<tables::TransactionSenders>().unwrap(), vec![(0, watched)]);
+    }
+
     #[test]
     fn get_take_block_transaction_range_recover_senders() {
         let factory = create_test_provider_factory();
@@ -740,6 +874,557 @@ mod tests {
         }
     }
 
+    #[test]
+    fn withdrawals_by_block_range_respects_shanghai() {
+        // Shanghai activates at timestamp 2, so blocks 0 and 1 are pre-Shanghai.
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .paris_activated()
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(2))
+            .build();
+        let factory = create_test_provider_factory_with_chain_spec(Arc::new(chain_spec));
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let mut parent = B256::ZERO;
+        let mut expected = Vec::new();
+        for number in 0..=4 {
+            let mut block = random_block(&mut rng, number, Some(parent), Some(0), Some(0)).unseal();
+            block.header.timestamp = number;
+            if number >= 2 {
+                let withdrawals = (0..number)
+                    .map(|index| Withdrawal {
+                        index,
+                        validator_index: number,
+                        ..Default::default()
+                    })
+                    .collect::<Vec<_>>();
+                expected.push((number, withdrawals.clone()));
+                block.withdrawals = Some(Withdrawals::new(withdrawals));
+            }
+            let block = block.seal_slow();
+            parent = block.hash();
+            provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+        }
+
+        assert_eq!(provider.withdrawals_by_block_range(0..=4).unwrap(), expected);
+        assert_eq!(provider.withdrawals_by_block_range(0..=1).unwrap(), vec![]);
+        assert_eq!(provider.withdrawals_by_block_range(3..=3).unwrap(), expected[1..2].to_vec());
+    }
+
+    #[test]
+    fn genesis_accounts_contains_mainnet_alloc() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider().unwrap();
+
+        let accounts = provider.genesis_accounts().collect::<Vec<_>>();
+        assert_eq!(accounts.len(), MAINNET.genesis.alloc.len());
+
+        let address = address!("000d836201318ec6899a67540690382780743280");
+        let (_, account) = accounts.iter().find(|(a, _)| *a == address).unwrap();
+        assert_eq!(account.balance, U256::from(200) * U256::from(ETH_TO_WEI));
+    }
+
+    #[test]
+    fn has_transaction_checks_hash_index_and_pending() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let block = random_block(&mut rng, 0, None, Some(3), Some(0));
+        let mined = block.body[0].hash();
+        provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+
+        let unknown = B256::random();
+        let pending = B256::random();
+        let pool = HashSet::from([pending]);
+
+        assert!(provider.has_transaction(mined).unwrap());
+        assert!(!provider.has_transaction(unknown).unwrap());
+        assert!(!provider.has_transaction(pending).unwrap());
+
+        let is_pending = |hash: &TxHash| pool.contains(hash);
+        assert!(provider.has_transaction_or_pending(mined, is_pending).unwrap());
+        assert!(!provider.has_transaction_or_pending(unknown, is_pending).unwrap());
+        assert!(provider.has_transaction_or_pending(pending, is_pending).unwrap());
+    }
+
+    #[test]
+    fn insert_hashes_for_bundle_matches_changeset_path() {
+        let address_a = Address::repeat_byte(0xaa);
+        let address_b = Address::repeat_byte(0xbb);
+        let account_a = Account { nonce: 1, balance: U256::from(10), bytecode_hash: None };
+        let account_b = Account { nonce: 2, balance: U256::from(20), bytecode_hash: None };
+        let slot = B256::with_last_byte(1);
+        let value = U256::from(42);
+
+        // both accounts are created in block 1, account B also writes a storage slot
+        let state_init = BundleStateInit::from([
+            (address_a, (None, Some(account_a), HashMap::new())),
+            (address_b, (None, Some(account_b), HashMap::from([(slot, (U256::ZERO, value))]))),
+        ]);
+        let reverts_init = RevertsInit::from([(
+            1,
+            HashMap::from([
+                (address_a, (Some(None), vec![])),
+                (address_b, (Some(None), vec![StorageEntry { key: slot, value: U256::ZERO }])),
+            ]),
+        )]);
+        let outcome = ExecutionOutcome::new_init(
+            state_init,
+            reverts_init,
+            vec![],
+            Receipts::default(),
+            1,
+            vec![],
+        );
+        let expected_root = state_root([
+            (address_a, (account_a, vec![])),
+            (address_b, (account_b, vec![(slot, value)])),
+        ]);
+        let block_hash = B256::random();
+
+        let hashed_tables = |use_bundle: bool| {
+            let factory = create_test_provider_factory();
+            let provider = factory.provider_rw().unwrap();
+            outcome
+                .clone()
+                .write_to_storage(provider.tx_ref(), None, OriginalValuesKnown::Yes)
+                .unwrap();
+
+            if use_bundle {
+                provider
+                    .insert_hashes_for_bundle(&outcome.bundle, 1, block_hash, expected_root)
+                    .unwrap();
+            } else {
+                provider.insert_hashes(1..=1, block_hash, expected_root).unwrap();
+            }
+
+            (
+                table_entries::<tables::HashedAccounts, _>(provider.tx_ref()),
+                table_entries::<tables::HashedStorages, _>(provider.tx_ref()),
+                table_entries::<tables::AccountsTrie, _>(provider.tx_ref()),
+                table_entries::<tables::StoragesTrie, _>(provider.tx_ref()),
+            )
+        };
+
+        let from_changesets = hashed_tables(false);
+        assert_eq!(from_changesets.0.len(), 2);
+        assert_eq!(from_changesets.1.len(), 1);
+        assert_eq!(hashed_tables(true), from_changesets);
+    }
+
+    fn table_entries<T: Table, TX: DbTx>(tx: &TX) -> Vec<(T::Key, T::Value)> {
+        tx.cursor_read::<T>().unwrap().walk(None).unwrap().collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn fully_synced_block_number_is_minimum_checkpoint() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        // no checkpoints at all
+        assert_eq!(provider.fully_synced_block_number().unwrap(), 0);
+
+        for (idx, stage_id) in StageId::ALL.iter().enumerate() {
+            provider
+                .save_stage_checkpoint(*stage_id, StageCheckpoint::new(100 + idx as u64))
+                .unwrap();
+        }
+        assert_eq!(provider.fully_synced_block_number().unwrap(), 100);
+
+        // a single lagging stage determines the fully synced block
+        provider.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(42)).unwrap();
+        assert_eq!(provider.fully_synced_block_number().unwrap(), 42);
+    }
+
+    #[test]
+    fn canonical_chain_between_matches_block_hashes() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let blocks = random_block_range(&mut rng, 0..=4, B256::ZERO, 0..1);
+        for block in blocks {
+            provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+        }
+
+        let segment = provider.canonical_chain_between(1, 3).unwrap();
+        assert_eq!(segment.iter().map(|(number, _)| *number).collect::<Vec<_>>(), vec![1, 2, 3]);
+        for (number, hash) in &segment {
+            assert_eq!(provider.block_hash(*number).unwrap(), Some(*hash));
+        }
+
+        // clamped to the last canonical block
+        let segment = provider.canonical_chain_between(3, 100).unwrap();
+        assert_eq!(segment.iter().map(|(number, _)| *number).collect::<Vec<_>>(), vec![3, 4]);
+
+        // inverted range
+        assert!(provider.canonical_chain_between(3, 2).unwrap().is_empty());
+        // range entirely past the head
+        assert!(provider.canonical_chain_between(10, 20).unwrap().is_empty());
+    }
+
+    #[test]
+    fn latest_sealed_headers_newest_first() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        assert!(provider.latest_sealed_headers(3).unwrap().is_empty());
+
+        let mut rng = generators::rng();
+        let mut parent = None;
+        let mut blocks = Vec::new();
+        for number in 0..5 {
+            let block = random_block(&mut rng, number, parent, Some(0), None);
+            parent = Some(block.hash());
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap(), None).unwrap();
+            blocks.push(block);
+        }
+
+        let expected = blocks.iter().rev().map(|block| block.header.clone()).collect::<Vec<_>>();
+        assert_eq!(provider.latest_sealed_headers(3).unwrap(), expected[..3]);
+        // stops early if there are fewer blocks
+        assert_eq!(provider.latest_sealed_headers(10).unwrap(), expected);
+        assert!(provider.latest_sealed_headers(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn transactions_root_matches_header() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let with_transactions = random_block(&mut rng, 0, None, Some(3), None);
+        let empty = random_block(&mut rng, 1, Some(with_transactions.hash()), Some(0), None);
+        for block in [with_transactions.clone(), empty.clone()] {
+            provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+        }
+
+        assert_eq!(
+            provider.transactions_root(with_transactions.number.into()).unwrap(),
+            Some(with_transactions.header.transactions_root)
+        );
+        assert_eq!(
+            provider.transactions_root(with_transactions.hash().into()).unwrap(),
+            Some(with_transactions.header.transactions_root)
+        );
+        assert_eq!(provider.transactions_root(empty.number.into()).unwrap(), Some(EMPTY_ROOT_HASH));
+        assert_eq!(provider.transactions_root(2u64.into()).unwrap(), None);
+    }
+
+    #[test]
+    fn receipts_by_block_range_groups_by_block() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let mut parent = None;
+        let mut blocks = Vec::new();
+        for (number, tx_count) in [(0, 2), (1, 0), (2, 3)] {
+            let block = random_block(&mut rng, number, parent, Some(tx_count), None);
+            parent = Some(block.hash());
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap(), None).unwrap();
+            blocks.push(block);
+        }
+
+        let mut expected = Vec::new();
+        let mut tx_num = 0;
+        for block in &blocks {
+            let mut receipts = Vec::new();
+            for tx in &block.body {
+                let receipt = random_receipt(&mut rng, tx, Some(1));
+                provider.tx_ref().put::<tables::Receipts>(tx_num, receipt.clone()).unwrap();
+                receipts.push(receipt);
+                tx_num += 1;
+            }
+            expected.push((block.number, receipts));
+        }
+
+        let receipts = provider.receipts_by_block_range(0..=2).unwrap();
+        assert_eq!(receipts, expected);
+        assert!(receipts[1].1.is_empty());
+        for (number, receipts) in &receipts {
+            assert_eq!(
+                provider.receipts_by_block((*number).into()).unwrap().as_ref(),
+                Some(receipts)
+            );
+        }
+
+        assert_eq!(provider.receipts_by_block_range(1..2).unwrap(), vec![(1, Vec::new())]);
+        assert!(provider.receipts_by_block_range(3..).unwrap().is_empty());
+    }
+
+    #[test]
+    fn block_number_for_tx_number_matches_transaction_block() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let mut parent = None;
+        for (number, tx_count) in [(0, 2), (1, 0), (2, 3)] {
+            let block = random_block(&mut rng, number, parent, Some(tx_count), None);
+            parent = Some(block.hash());
+            provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+        }
+
+        for (tx_num, expected) in
+            [(0, Some(0)), (1, Some(0)), (2, Some(2)), (4, Some(2)), (5, None)]
+        {
+            assert_eq!(provider.block_number_for_tx_number(tx_num).unwrap(), expected);
+            assert_eq!(provider.transaction_block(tx_num).unwrap(), expected);
+        }
+        assert_eq!(provider.block_number_for_tx_number(TxNumber::MAX).unwrap(), None);
+    }
+
+    #[test]
+    fn state_root_at_matches_recomputed_root() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+
+        let address = Address::with_last_byte(1);
+        let hashed_address = keccak256(address);
+        let slot = B256::with_last_byte(1);
+
+        // block 1 creates the account with a storage slot, block 2 updates both
+        let mut roots = vec![EMPTY_ROOT_HASH];
+        let mut previous = (None, U256::ZERO);
+        for number in [1, 2] {
+            let account =
+                Account { nonce: number, balance: U256::from(number), bytecode_hash: None };
+            let value = U256::from(number);
+
+            tx.put::<tables::AccountChangeSets>(
+                number,
+                AccountBeforeTx { address, info: previous.0 },
+            )
+            .unwrap();
+            tx.put::<tables::StorageChangeSets>(
+                BlockNumberAddress((number, address)),
+                StorageEntry { key: slot, value: previous.1 },
+            )
+            .unwrap();
+            tx.put::<tables::HashedAccounts>(hashed_address, account).unwrap();
+            tx.delete::<tables::HashedStorages>(hashed_address, None).unwrap();
+            tx.put::<tables::HashedStorages>(
+                hashed_address,
+                StorageEntry { key: keccak256(slot), value },
+            )
+            .unwrap();
+
+            let (root, updates) = StateRoot::from_tx(tx).root_with_updates().unwrap();
+            updates.flush(tx).unwrap();
+            roots.push(root);
+            previous = (Some(account), value);
+        }
+
+        let mut rng = generators::rng();
+        let mut parent = None;
+        for (number, state_root) in roots.iter().enumerate() {
+            let mut block = random_block(&mut rng, number as u64, parent, Some(0), None).unseal();
+            block.header.state_root = *state_root;
+            let block = block.seal_slow();
+            parent = Some(block.hash());
+            provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+        }
+
+        for (number, state_root) in roots.iter().enumerate() {
+            let number = number as u64;
+            assert_eq!(provider.state_root_at(number).unwrap(), Some(*state_root));
+            assert_eq!(provider.recompute_state_root_at(number).unwrap(), Some(*state_root));
+        }
+        assert_eq!(provider.state_root_at(3).unwrap(), None);
+        assert_eq!(provider.recompute_state_root_at(3).unwrap(), None);
+    }
+
+    #[test]
+    fn base_fee_info() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let mut pre_london = random_block(&mut rng, 0, None, Some(0), None).unseal();
+        pre_london.header.base_fee_per_gas = None;
+        pre_london.header.gas_limit = 0;
+        pre_london.header.gas_used = 0;
+        let pre_london = pre_london.seal_slow();
+        let mut london = random_block(&mut rng, 1, Some(pre_london.hash()), Some(0), None).unseal();
+        london.header.base_fee_per_gas = Some(1_000_000_000);
+        london.header.gas_limit = 30_000_000;
+        london.header.gas_used = 20_000_000;
+        let london = london.seal_slow();
+        for block in [pre_london.clone(), london.clone()] {
+            provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+        }
+
+        let info = provider.base_fee_info(pre_london.number.into()).unwrap().unwrap();
+        assert_eq!(info.current_base_fee, None);
+        assert_eq!(info.next_base_fee, None);
+        assert_eq!(info.gas_used_ratio, 0.0);
+
+        // the gas target is 15M, the base fee increases by 1/8 of the relative excess gas used
+        let expected = 1_000_000_000 + 1_000_000_000 * 5_000_000 / 15_000_000 / 8;
+        for block_id in [london.number.into(), london.hash().into()] {
+            let info = provider.base_fee_info(block_id).unwrap().unwrap();
+            assert_eq!(info.current_base_fee, Some(1_000_000_000));
+            assert_eq!(info.next_base_fee, Some(expected));
+            assert_eq!(info.gas_used_ratio, 2.0 / 3.0);
+        }
+
+        assert_eq!(provider.base_fee_info(2u64.into()).unwrap(), None);
+    }
+
+    #[test]
+    fn contract_accounts() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let raw_code = Bytes::from_static(&hex!("6001600055"));
+        let code_hash = keccak256(&raw_code);
+        let code = Bytecode::new_raw(raw_code);
+        provider.tx_ref().put::<tables::Bytecodes>(code_hash, code.clone()).unwrap();
+
+        let eoa = Account { nonce: 1, balance: U256::from(1), bytecode_hash: None };
+        let empty_code = Account { bytecode_hash: Some(KECCAK_EMPTY), ..eoa };
+        let contract = Account { nonce: 1, balance: U256::ZERO, bytecode_hash: Some(code_hash) };
+        let missing_code = Account { bytecode_hash: Some(B256::with_last_byte(1)), ..contract };
+        let accounts = [
+            (Address::with_last_byte(1), eoa),
+            (Address::with_last_byte(2), contract),
+            (Address::with_last_byte(3), empty_code),
+            (Address::with_last_byte(4), missing_code),
+            (Address::with_last_byte(5), eoa),
+        ];
+        for (address, account) in accounts {
+            provider.tx_ref().put::<tables::PlainAccountState>(address, account).unwrap();
+        }
+
+        let contracts =
+            provider.contract_accounts().unwrap().collect::<ProviderResult<Vec<_>>>().unwrap();
+        assert_eq!(
+            contracts,
+            vec![
+                (Address::with_last_byte(2), contract),
+                (Address::with_last_byte(4), missing_code)
+            ]
+        );
+
+        let contracts = provider
+            .contract_accounts_with_bytecode()
+            .unwrap()
+            .collect::<ProviderResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            contracts,
+            vec![
+                (Address::with_last_byte(2), contract, Some(code)),
+                (Address::with_last_byte(4), missing_code, None)
+            ]
+        );
+    }
+
+    #[test]
+    fn cumulative_tx_count() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        assert_eq!(provider.cumulative_tx_count(0).unwrap(), 0);
+
+        let mut rng = generators::rng();
+        let mut parent = None;
+        for (number, tx_count) in [0, 3, 0, 2, 5].into_iter().enumerate() {
+            let block = random_block(&mut rng, number as u64, parent, Some(tx_count), Some(0));
+            parent = Some(block.hash());
+            provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+        }
+
+        for (number, expected) in [0, 3, 3, 5, 10].into_iter().enumerate() {
+            assert_eq!(provider.cumulative_tx_count(number as u64).unwrap(), expected);
+        }
+        assert_matches!(
+            provider.cumulative_tx_count(5),
+            Err(ProviderError::BlockBodyIndicesNotFound(5))
+        );
+    }
+
+    #[test]
+    fn insert_block_skips_post_merge_ommers() {
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .with_fork(
+                Hardfork::Paris,
+                ForkCondition::TTD { fork_block: Some(1), total_difficulty: U256::ZERO },
+            )
+            .build();
+        let factory = create_test_provider_factory_with_chain_spec(Arc::new(chain_spec));
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let pre_merge = random_block(&mut rng, 0, None, Some(0), Some(1));
+        let post_merge = random_block(&mut rng, 1, Some(pre_merge.hash()), Some(0), Some(1));
+        for block in [pre_merge, post_merge] {
+            provider.insert_block(block.try_seal_with_senders().unwrap(), None).unwrap();
+        }
+
+        assert!(provider.tx_ref().get::<tables::BlockOmmers>(0).unwrap().is_some());
+        assert!(provider.tx_ref().get::<tables::BlockOmmers>(1).unwrap().is_none());
+    }
+
+    #[test]
+    fn remove_post_merge_ommers_removes_stray_rows() {
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .with_fork(
+                Hardfork::Paris,
+                ForkCondition::TTD { fork_block: Some(2), total_difficulty: U256::ZERO },
+            )
+            .build();
+        let factory = create_test_provider_factory_with_chain_spec(Arc::new(chain_spec));
+        let provider = factory.provider_rw().unwrap();
+
+        let ommers = StoredBlockOmmers { ommers: vec![Header::default()] };
+        for number in [1, 2, 3] {
+            provider.tx_ref().put::<tables::BlockOmmers>(number, ommers.clone()).unwrap();
+        }
+
+        assert!(provider.has_post_merge_ommers().unwrap());
+        assert_eq!(provider.remove_post_merge_ommers().unwrap(), 2);
+        assert_eq!(provider.tx_ref().get::<tables::BlockOmmers>(1).unwrap(), Some(ommers));
+        assert!(provider.tx_ref().get::<tables::BlockOmmers>(2).unwrap().is_none());
+        assert!(provider.tx_ref().get::<tables::BlockOmmers>(3).unwrap().is_none());
+
+        // nothing left to remove
+        assert!(!provider.has_post_merge_ommers().unwrap());
+        assert_eq!(provider.remove_post_merge_ommers().unwrap(), 0);
+    }
+
+    #[test]
+    fn account_history_lookup() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        let address = Address::with_last_byte(1);
+        let other = Address::with_last_byte(2);
+
+        let shards = [(address, 5, vec![1, 5]), (address, u64::MAX, vec![8]), (other, 3, vec![3])];
+        for (key, highest_block_number, blocks) in shards {
+            provider
+                .tx_ref()
+                .put::<tables::AccountsHistory>(
+                    ShardedKey::new(key, highest_block_number),
+                    IntegerList::new_pre_sorted(blocks),
+                )
+                .unwrap();
+        }
+        let before =
+            AccountBeforeTx { address, info: Some(Account { nonce: 1, ..Default::default() }) };
+        for account in [AccountBeforeTx { address: other, info: None }, before.clone()] {
+            provider.tx_ref().put::<tables::AccountChangeSets>(5, account).unwrap();
+        }
+
+        for (block, next) in [(0, Some(1)), (1, Some(1)), (2, Some(5)), (6, Some(8)), (9, None)] {
+            assert_eq!(provider.account_next_change_block(address, block).unwrap(), next);
+        }
+        assert_eq!(provider.account_next_change_block(Address::ZERO, 0).unwrap(), None);
+        assert_eq!(provider.account_before_block(5, address).unwrap(), Some(before));
+        assert_eq!(provider.account_before_block(8, address).unwrap(), None);
+    }
+
     #[test]
     fn header_sync_gap_lookup() {
         let factory = create_test_provider_factory();
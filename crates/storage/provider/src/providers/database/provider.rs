@@ -1,6 +1,10 @@
 use crate::{
     bundle_state::{BundleStateInit, ExecutionOutcome, HashedStateChanges, RevertsInit},
-    providers::{database::metrics, static_file::StaticFileWriter, StaticFileProvider},
+    providers::{
+        database::{block_cache::BlockCache, metrics},
+        static_file::StaticFileWriter,
+        StaticFileProvider,
+    },
     to_range,
     traits::{
         AccountExtReader, BlockSource, ChangeSetReader, ReceiptProvider, StageCheckpointWriter,
@@ -8,9 +12,10 @@ use crate::{
     AccountReader, BlockExecutionWriter, BlockHashReader, BlockNumReader, BlockReader, BlockWriter,
     Chain, EvmEnvProvider, FinalizedBlockReader, FinalizedBlockWriter, HashingWriter,
     HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider, HeaderSyncMode, HistoricalStateProvider,
-    HistoryWriter, LatestStateProvider, OriginalValuesKnown, ProviderError, PruneCheckpointReader,
-    PruneCheckpointWriter, RequestsProvider, StageCheckpointReader, StateProviderBox, StateWriter,
-    StatsReader, StorageReader, TransactionVariant, TransactionsProvider, TransactionsProviderExt,
+    HistoryWriter, InvalidHeadersReader, InvalidHeadersWriter, LatestStateProvider,
+    OriginalValuesKnown, ProviderError, PruneCheckpointReader, PruneCheckpointWriter,
+    RequestsProvider, StageCheckpointReader, StateProviderBox, StateWriter, StatsReader,
+    StorageReader, TransactionVariant, TransactionsProvider, TransactionsProviderExt,
     WithdrawalsProvider,
 };
 use itertools::{izip, Itertools};
@@ -27,16 +32,18 @@ use reth_db_api::{
     transaction::{DbTx, DbTxMut},
     DatabaseError,
 };
-use reth_evm::ConfigureEvmEnv;
+use reth_evm::{provider::pending_block_header, ConfigureEvmEnv};
 use reth_network_p2p::headers::downloader::SyncTarget;
 use reth_primitives::{
+    constants::SLOT_DURATION,
     keccak256,
+    proofs::calculate_transaction_root,
     revm::{config::revm_spec, env::fill_block_env},
-    Account, Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders,
-    ChainInfo, ChainSpec, GotExpected, Head, Header, Receipt, Requests, SealedBlock,
-    SealedBlockWithSenders, SealedHeader, StaticFileSegment, StorageEntry, TransactionMeta,
-    TransactionSigned, TransactionSignedEcRecovered, TransactionSignedNoHash, TxHash, TxNumber,
-    Withdrawal, Withdrawals, B256, U256,
+    Account, Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders, Bytecode,
+    ChainInfo, ChainSpec, GenesisAccount, GotExpected, Head, Header, Receipt, Requests,
+    SealedBlock, SealedBlockWithSenders, SealedHeader, StaticFileSegment, StorageEntry,
+    TransactionMeta, TransactionSigned, TransactionSignedEcRecovered, TransactionSignedNoHash,
+    TxHash, TxNumber, Withdrawal, Withdrawals, B256, KECCAK_EMPTY, U256,
 };
 use reth_prune_types::{PruneCheckpoint, PruneLimiter, PruneModes, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
@@ -46,7 +53,10 @@ use reth_trie::{
     updates::TrieUpdates,
     HashedPostState, Nibbles, StateRoot,
 };
-use revm::primitives::{BlockEnv, CfgEnvWithHandlerCfg, SpecId};
+use revm::{
+    db::BundleState,
+    primitives::{BlockEnv, CfgEnvWithHandlerCfg, SpecId},
+};
 use std::{
     cmp::Ordering,
     collections::{hash_map, BTreeMap, BTreeSet, HashMap, HashSet},
@@ -93,6 +103,17 @@ impl<DB: Database> DatabaseProviderRW<DB> {
     }
 }
 
+/// The base fee of a block and of the block after it, see [`DatabaseProvider::base_fee_info`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BaseFeeInfo {
+    /// The base fee of the block, `None` before London.
+    pub current_base_fee: Option<u64>,
+    /// The base fee of the next block, `None` if the block has no base fee.
+    pub next_base_fee: Option<u64>,
+    /// The ratio of gas used to the gas limit of the block, `0.0` if the block has no gas limit.
+    pub gas_used_ratio: f64,
+}
+
 /// A provider struct that fetches data from the database.
 /// Wrapper around [`DbTx`] and [`DbTxMut`]. Example: [`HeaderProvider`] [`BlockHashReader`]
 #[derive(Debug)]
@@ -103,6 +124,8 @@ pub struct DatabaseProvider<TX> {
     chain_spec: Arc<ChainSpec>,
     /// Static File provider
     static_file_provider: StaticFileProvider,
+    /// Optional cache for recently read blocks, only set for read-only providers.
+    block_cache: Option<Arc<BlockCache>>,
 }
 
 impl<TX> DatabaseProvider<TX> {
@@ -119,7 +142,7 @@ impl<TX: DbTxMut> DatabaseProvider<TX> {
         chain_spec: Arc<ChainSpec>,
         static_file_provider: StaticFileProvider,
     ) -> Self {
-        Self { tx, chain_spec, static_file_provider }
+        Self { tx, chain_spec, static_file_provider, block_cache: None }
     }
 }
 
@@ -256,7 +279,7 @@ impl<TX: DbTx> DatabaseProvider<TX> {
         chain_spec: Arc<ChainSpec>,
         static_file_provider: StaticFileProvider,
     ) -> Self {
-        Self { tx, chain_spec, static_file_provider }
+        Self { tx, chain_spec, static_file_provider, block_cache: None }
     }
 
     /// Consume `DbTx` or `DbTxMut`.
@@ -279,6 +302,72 @@ impl<TX: DbTx> DatabaseProvider<TX> {
         &self.chain_spec
     }
 
+    /// Serves headers, blocks and block body indices from the given [`BlockCache`].
+    pub(crate) fn with_block_cache(mut self, block_cache: Arc<BlockCache>) -> Self {
+        self.block_cache = Some(block_cache);
+        self
+    }
+
+    /// Reads the header of the given block, bypassing the [`BlockCache`].
+    fn header_by_number_uncached(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
+        self.static_file_provider.get_with_static_file_or_database(
+            StaticFileSegment::Headers,
+            num,
+            |static_file| static_file.header_by_number(num),
+            || Ok(self.tx.get::<tables::Headers>(num)?),
+        )
+    }
+
+    /// Reads the block with the given number, bypassing the [`BlockCache`].
+    ///
+    /// The header is still read through [`HeaderProvider::header_by_number`].
+    fn block_uncached(&self, number: BlockNumber) -> ProviderResult<Option<Block>> {
+        let Some(header) = self.header_by_number(number)? else { return Ok(None) };
+
+        let withdrawals = self.withdrawals_by_block(number.into(), header.timestamp)?;
+        let ommers = self.ommers(number.into())?.unwrap_or_default();
+        let requests = self.requests_by_block(number.into(), header.timestamp)?;
+        // If the body indices are not found, this means that the transactions either do not
+        // exist in the database yet, or they do exit but are not indexed.
+        // If they exist but are not indexed, we don't have enough
+        // information to return the block anyways, so we return `None`.
+        let transactions = match self.transactions_by_block(number.into())? {
+            Some(transactions) => transactions,
+            None => return Ok(None),
+        };
+
+        Ok(Some(Block { header, body: transactions, ommers, withdrawals, requests }))
+    }
+
+    /// Fills the [CfgEnvWithHandlerCfg] and [BlockEnv] fields with values for the pending block on
+    /// top of the given tip and returns the pending block's header.
+    ///
+    /// See also [`EvmEnvProvider::fill_env_for_pending`].
+    pub(crate) fn fill_env_for_pending_on<EvmConfig>(
+        &self,
+        cfg: &mut CfgEnvWithHandlerCfg,
+        block_env: &mut BlockEnv,
+        tip: SealedHeader,
+    ) -> ProviderResult<SealedHeader>
+    where
+        EvmConfig: ConfigureEvmEnv,
+    {
+        // the pending block is not in the database, so its total difficulty is derived from the
+        // tip's
+        let total_difficulty = self
+            .header_td_by_number(tip.number)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(tip.number.into()))?;
+        let header = pending_block_header(tip, &self.chain_spec);
+        EvmConfig::fill_cfg_and_block_env(
+            cfg,
+            block_env,
+            &self.chain_spec,
+            &header,
+            total_difficulty + header.difficulty,
+        );
+        Ok(header)
+    }
+
     /// Disables long-lived read transaction safety guarantees for leaks prevention and
     /// observability improvements.
     ///
@@ -356,6 +445,23 @@ impl<TX: DbTx> DatabaseProvider<TX> {
         )
     }
 
+    fn receipts_by_tx_range_with_cursor<C>(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+        cursor: &mut C,
+    ) -> ProviderResult<Vec<Receipt>>
+    where
+        C: DbCursorRO<tables::Receipts>,
+    {
+        self.static_file_provider.get_range_with_static_file_or_database(
+            StaticFileSegment::Receipts,
+            to_range(range),
+            |static_file, range, _| static_file.receipts_by_tx_range(range),
+            |range, _| self.cursor_collect(cursor, range),
+            |_| true,
+        )
+    }
+
     /// Returns a range of blocks from the database.
     ///
     /// Uses the provided `headers_range` to get the headers for the range, and `assemble_block` to
@@ -487,11 +593,22 @@ impl<TX: DbTx> DatabaseProvider<TX> {
                     .into_iter()
                     .map(Into::into)
                     .collect::<Vec<TransactionSigned>>();
-                // fetch senders from the senders table
-                let known_senders =
-                    senders_cursor
-                        .walk_range(tx_range.clone())?
-                        .collect::<Result<HashMap<_, _>, _>>()?;
+                // fetch senders from the senders static files and table
+                let known_senders = self
+                    .static_file_provider
+                    .get_range_with_static_file_or_database(
+                        StaticFileSegment::Senders,
+                        tx_range.clone(),
+                        |static_file, range, _| {
+                            Ok(range.clone().zip(static_file.senders_by_tx_range(range)?).collect())
+                        },
+                        |range, _| {
+                            Ok(senders_cursor.walk_range(range)?.collect::<Result<Vec<_>, _>>()?)
+                        },
+                        |_| true,
+                    )?
+                    .into_iter()
+                    .collect::<HashMap<_, _>>();
 
                 let mut senders = Vec::with_capacity(body.len());
                 for (tx_num, tx) in tx_range.zip(body.iter()) {
@@ -513,6 +630,309 @@ impl<TX: DbTx> DatabaseProvider<TX> {
             assemble_block(header, body, ommers, withdrawals, requests, senders)
         })
     }
+
+    /// Returns the withdrawals of all blocks in the given range, in block order.
+    ///
+    /// Blocks before the Shanghai activation are skipped entirely, while post-Shanghai blocks
+    /// without withdrawals yield an empty list. A single [`tables::BlockWithdrawals`] cursor is
+    /// used for the whole range.
+    pub fn withdrawals_by_block_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, Vec<Withdrawal>)>> {
+        if range.is_empty() {
+            return Ok(Vec::new())
+        }
+
+        let mut withdrawals_cursor = self.tx.cursor_read::<tables::BlockWithdrawals>()?;
+        let mut withdrawals = Vec::new();
+        for header in self.headers_range(range)? {
+            if !self.chain_spec.is_shanghai_active_at_timestamp(header.timestamp) {
+                continue
+            }
+
+            let block_withdrawals = withdrawals_cursor
+                .seek_exact(header.number)?
+                .map(|(_, w)| w.withdrawals.into_inner())
+                .unwrap_or_default();
+            withdrawals.push((header.number, block_withdrawals));
+        }
+
+        Ok(withdrawals)
+    }
+
+    /// Returns the highest block number up to which every stage has completed, i.e. the minimum
+    /// checkpoint across all [`StageId::ALL`] stages.
+    ///
+    /// Stages without a checkpoint are treated as being at block `0`.
+    pub fn fully_synced_block_number(&self) -> ProviderResult<BlockNumber> {
+        StageId::ALL.iter().try_fold(BlockNumber::MAX, |min, stage_id| {
+            let checkpoint = self.get_stage_checkpoint(*stage_id)?.unwrap_or_default().block_number;
+            Ok(min.min(checkpoint))
+        })
+    }
+
+    /// Returns the ordered `(number, hash)` pairs of the canonical chain in `[from, to]`.
+    ///
+    /// This only reads the canonical hashes, see [`tables::CanonicalHeaders`], and never the full
+    /// headers. The range is clamped to the last canonical block, and an empty list is returned if
+    /// `from > to`.
+    pub fn canonical_chain_between(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> ProviderResult<Vec<(BlockNumber, B256)>> {
+        let to = to.min(self.last_block_number()?);
+        if from > to {
+            return Ok(Vec::new())
+        }
+        let hashes = self.canonical_hashes_range(from, to + 1)?;
+        Ok((from..).zip(hashes).collect())
+    }
+
+    /// Computes the transactions root of the given block from its stored transactions.
+    ///
+    /// The transactions are encoded in their consensus (EIP-2718) form and in block order, so the
+    /// result matches the header's `transactions_root`. A block without transactions yields the
+    /// empty root. Returns `None` if the block is unknown.
+    pub fn transactions_root(&self, id: BlockHashOrNumber) -> ProviderResult<Option<B256>> {
+        Ok(self
+            .transactions_by_block(id)?
+            .map(|transactions| calculate_transaction_root(&transactions)))
+    }
+
+    /// Returns the receipts of all blocks in the given range, grouped by block and in block order.
+    ///
+    /// Unlike calling [`ReceiptProvider::receipts_by_block`] for each block, this reuses a single
+    /// cursor over the block body indices and a single cursor over the receipts for the whole
+    /// range. Blocks without transactions yield an empty list of receipts.
+    pub fn receipts_by_block_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, Vec<Receipt>)>> {
+        let mut receipts_cursor = self.tx.cursor_read::<tables::Receipts>()?;
+        let mut results = Vec::new();
+        let mut body_cursor = self.tx.cursor_read::<tables::BlockBodyIndices>()?;
+        for entry in body_cursor.walk_range(range)? {
+            let (number, body) = entry?;
+            let tx_num_range = body.tx_num_range();
+            if tx_num_range.is_empty() {
+                results.push((number, Vec::new()));
+            } else {
+                results.push((
+                    number,
+                    self.receipts_by_tx_range_with_cursor(tx_num_range, &mut receipts_cursor)?,
+                ));
+            }
+        }
+        Ok(results)
+    }
+
+    /// Returns the `n` most recent canonical headers, newest first.
+    ///
+    /// The headers in the database are read with a single reverse walk from the last entry of
+    /// [`tables::Headers`]. Older headers that were already moved to static files are read from
+    /// there. Returns fewer than `n` headers if there are fewer blocks.
+    pub fn latest_sealed_headers(&self, n: usize) -> ProviderResult<Vec<SealedHeader>> {
+        let mut headers = Vec::with_capacity(n);
+        let mut cursor = self.tx.cursor_read::<tables::Headers>()?;
+        for entry in cursor.walk_back(None)?.take(n) {
+            let (number, header) = entry?;
+            let hash = self
+                .block_hash(number)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(number.into()))?;
+            headers.push(header.seal(hash));
+        }
+
+        // the remaining headers are below the lowest header in the database
+        let remaining = (n - headers.len()) as u64;
+        let end = match headers.last() {
+            Some(header) => header.number,
+            None => match self
+                .static_file_provider
+                .get_highest_static_file_block(StaticFileSegment::Headers)
+            {
+                Some(highest) => highest + 1,
+                None => return Ok(headers),
+            },
+        };
+        if remaining > 0 && end > 0 {
+            let mut older = self.sealed_headers_range(end.saturating_sub(remaining)..end)?;
+            older.reverse();
+            headers.extend(older);
+        }
+        Ok(headers)
+    }
+
+    /// Returns the number of the block that contains the transaction with the given number.
+    ///
+    /// [`tables::TransactionBlocks`] is keyed by the number of the last transaction of each block,
+    /// so this seeks to the first entry at or above the given transaction number. Returns `None`
+    /// if the transaction number is above the last indexed transaction.
+    pub fn block_number_for_tx_number(
+        &self,
+        tx_num: TxNumber,
+    ) -> ProviderResult<Option<BlockNumber>> {
+        let mut cursor = self.tx.cursor_read::<tables::TransactionBlocks>()?;
+        Ok(cursor.seek(tx_num)?.map(|(_, block_number)| block_number))
+    }
+
+    /// Returns the state root stored in the header of the given block.
+    ///
+    /// This is a single header lookup. See [`Self::recompute_state_root_at`] for recomputing the
+    /// root from the historical state. Returns `None` if the block is unknown.
+    pub fn state_root_at(&self, block_number: BlockNumber) -> ProviderResult<Option<B256>> {
+        Ok(self.header_by_number(block_number)?.map(|header| header.state_root))
+    }
+
+    /// Recomputes the state root after the given block from the hashed state and the reverts of
+    /// all later blocks.
+    ///
+    /// This is expensive for blocks far behind the tip, because all changesets up to the tip are
+    /// loaded into memory. It is meant for cross-checking the root returned by
+    /// [`Self::state_root_at`]. Returns `None` if the block is above the last block.
+    pub fn recompute_state_root_at(
+        &self,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Option<B256>> {
+        let tip = self.last_block_number()?;
+        if block_number > tip {
+            return Ok(None)
+        }
+
+        // the reverts of the following blocks are required to reconstruct the state
+        for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+            let pruned = self.get_prune_checkpoint(segment)?.and_then(|cp| cp.block_number);
+            if pruned.is_some_and(|pruned| pruned > block_number) {
+                return Err(ProviderError::StateAtBlockPruned(block_number))
+            }
+        }
+
+        let state_root = HashedPostState::from_revert_range(&self.tx, block_number + 1..=tip)?
+            .state_root(&self.tx)
+            .map_err(Into::<reth_db::DatabaseError>::into)?;
+        Ok(Some(state_root))
+    }
+
+    /// Returns the base fee of the given block and the base fee of the block after it, computed
+    /// per EIP-1559 from the block's base fee, gas used and gas limit.
+    ///
+    /// The next base fee uses the base fee params in effect at the timestamp of the next block. If
+    /// the next block is not known yet, it is assumed to be built in the next slot, see
+    /// [`SLOT_DURATION`].
+    ///
+    /// The base fees are `None` if the block is not an EIP-1559 block. Returns `None` if the
+    /// block is unknown.
+    pub fn base_fee_info(
+        &self,
+        block_id: BlockHashOrNumber,
+    ) -> ProviderResult<Option<BaseFeeInfo>> {
+        let Some(header) = self.header_by_hash_or_number(block_id)? else { return Ok(None) };
+        let child_timestamp = match self.header_by_number(header.number + 1)? {
+            Some(child) if child.parent_hash == header.hash_slow() => child.timestamp,
+            _ => header.timestamp + SLOT_DURATION.as_secs(),
+        };
+        let base_fee_params = self.chain_spec.base_fee_params_at_timestamp(child_timestamp);
+        let gas_used_ratio = if header.gas_limit == 0 {
+            0.0
+        } else {
+            header.gas_used as f64 / header.gas_limit as f64
+        };
+        Ok(Some(BaseFeeInfo {
+            current_base_fee: header.base_fee_per_gas,
+            next_base_fee: header.next_block_base_fee(base_fee_params),
+            gas_used_ratio,
+        }))
+    }
+
+    /// Returns `true` if there are [`tables::BlockOmmers`] entries of post-merge blocks.
+    ///
+    /// See also [`DatabaseProvider::remove_post_merge_ommers`].
+    pub fn has_post_merge_ommers(&self) -> ProviderResult<bool> {
+        let Some((paris_block, _)) = self.chain_spec.paris_block_and_final_difficulty else {
+            return Ok(false)
+        };
+        Ok(self.tx.cursor_read::<tables::BlockOmmers>()?.seek(paris_block)?.is_some())
+    }
+
+    /// Returns `true` if a mined transaction with the given hash is known.
+    ///
+    /// This only checks the [`tables::TransactionHashNumbers`] index and does not read the
+    /// transaction itself. If the transaction lookup index is fully pruned, this always returns
+    /// `false`.
+    pub fn has_transaction(&self, tx_hash: TxHash) -> ProviderResult<bool> {
+        Ok(self.tx.get::<tables::TransactionHashNumbers>(tx_hash)?.is_some())
+    }
+
+    /// Same as [`Self::has_transaction`], but also consults `is_pending` if the transaction is not
+    /// mined, e.g. to check whether the transaction pool contains it.
+    pub fn has_transaction_or_pending(
+        &self,
+        tx_hash: TxHash,
+        is_pending: impl FnOnce(&TxHash) -> bool,
+    ) -> ProviderResult<bool> {
+        Ok(self.has_transaction(tx_hash)? || is_pending(&tx_hash))
+    }
+
+    /// Returns an iterator over the genesis allocation of the chain spec.
+    ///
+    /// These are the accounts that were premined at genesis, ordered by address.
+    pub fn genesis_accounts(&self) -> impl Iterator<Item = (Address, GenesisAccount)> + '_ {
+        self.chain_spec.genesis.alloc.iter().map(|(address, account)| (*address, account.clone()))
+    }
+
+    /// Returns an iterator over all accounts with code, ordered by address.
+    ///
+    /// This walks the entire [`tables::PlainAccountState`] table and yields the accounts whose
+    /// bytecode hash is set and not [`KECCAK_EMPTY`]. The accounts are read lazily from the
+    /// cursor, so this does not load the entire state into memory.
+    pub fn contract_accounts(
+        &self,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(Address, Account)>> + '_> {
+        let mut cursor = self.tx.cursor_read::<tables::PlainAccountState>()?;
+        let mut next = cursor.first().transpose();
+        Ok(std::iter::from_fn(move || loop {
+            match next.take()? {
+                Ok((address, account)) => {
+                    next = cursor.next().transpose();
+                    if account.bytecode_hash.is_some_and(|hash| hash != KECCAK_EMPTY) {
+                        return Some(Ok((address, account)))
+                    }
+                }
+                Err(err) => return Some(Err(err.into())),
+            }
+        }))
+    }
+
+    /// Same as [`Self::contract_accounts`], but also reads the bytecode of each account from
+    /// [`tables::Bytecodes`].
+    ///
+    /// The bytecode is `None` if it is missing from the database.
+    pub fn contract_accounts_with_bytecode(
+        &self,
+    ) -> ProviderResult<
+        impl Iterator<Item = ProviderResult<(Address, Account, Option<Bytecode>)>> + '_,
+    > {
+        Ok(self.contract_accounts()?.map(|res| {
+            let (address, account) = res?;
+            let bytecode = self.tx.get::<tables::Bytecodes>(account.get_bytecode_hash())?;
+            Ok((address, account, bytecode))
+        }))
+    }
+
+    /// Returns the total number of transactions in the chain up to and including the given block.
+    ///
+    /// This is the number of the last transaction of the block plus one, read from
+    /// [`tables::BlockBodyIndices`]. Returns `0` if the chain is empty.
+    pub fn cumulative_tx_count(&self, block_number: BlockNumber) -> ProviderResult<u64> {
+        let Some(indices) = self.block_body_indices(block_number)? else {
+            if self.tx.cursor_read::<tables::BlockBodyIndices>()?.last()?.is_none() {
+                return Ok(0)
+            }
+            return Err(ProviderError::BlockBodyIndicesNotFound(block_number))
+        };
+        Ok(indices.next_tx_num())
+    }
 }
 
 impl<TX: DbTxMut + DbTx> DatabaseProvider<TX> {
@@ -521,6 +941,49 @@ impl<TX: DbTxMut + DbTx> DatabaseProvider<TX> {
         Ok(self.tx.commit()?)
     }
 
+    /// Removes all [`tables::BlockOmmers`] entries of post-merge blocks.
+    ///
+    /// Post-merge blocks can't have ommers, so such entries are never read and only take up space.
+    /// Returns the number of removed entries.
+    pub fn remove_post_merge_ommers(&self) -> ProviderResult<usize> {
+        let Some((paris_block, _)) = self.chain_spec.paris_block_and_final_difficulty else {
+            return Ok(0)
+        };
+
+        let mut cursor = self.tx.cursor_write::<tables::BlockOmmers>()?;
+        let mut walker = cursor.walk(Some(paris_block))?;
+        let mut removed = 0;
+        while walker.next().transpose()?.is_some() {
+            walker.delete_current()?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    /// Calculates the state root from the hashed state using the given prefix sets, compares it
+    /// with `expected_state_root` and writes the intermediate trie nodes.
+    fn insert_merkle_tree(
+        &self,
+        prefix_sets: TriePrefixSets,
+        block_number: BlockNumber,
+        block_hash: B256,
+        expected_state_root: B256,
+    ) -> ProviderResult<()> {
+        let (state_root, trie_updates) = StateRoot::from_tx(&self.tx)
+            .with_prefix_sets(prefix_sets)
+            .root_with_updates()
+            .map_err(Into::<reth_db::DatabaseError>::into)?;
+        if state_root != expected_state_root {
+            return Err(ProviderError::StateRootMismatch(Box::new(RootMismatch {
+                root: GotExpected { got: state_root, expected: expected_state_root },
+                block_number,
+                block_hash,
+            })))
+        }
+        trie_updates.flush(&self.tx)?;
+        Ok(())
+    }
+
     // TODO(joshie) TEMPORARY should be moved to trait providers
     /// Unwind or peek at last N blocks of state recreating the [`ExecutionOutcome`].
     ///
@@ -999,10 +1462,10 @@ impl<TX: DbTxMut + DbTx> DatabaseProvider<TX> {
         F: FnMut(T::Key) -> u64,
     {
         let mut cursor = self.tx.cursor_write::<T>()?;
-        let mut reverse_walker = cursor.walk_back(None)?;
+        let mut reverse_walker = cursor.walk_back(None)?.keys();
         let mut deleted = 0;
 
-        while let Some(Ok((entry_key, _))) = reverse_walker.next() {
+        while let Some(Ok(entry_key)) = reverse_walker.next() {
             if selector(entry_key.clone()) <= key {
                 break
             }
@@ -1267,6 +1730,44 @@ impl<TX: DbTx> ChangeSetReader for DatabaseProvider<TX> {
             })
             .collect()
     }
+
+    fn account_before_block(
+        &self,
+        block_number: BlockNumber,
+        address: Address,
+    ) -> ProviderResult<Option<AccountBeforeTx>> {
+        Ok(self
+            .tx
+            .cursor_dup_read::<tables::AccountChangeSets>()?
+            .seek_by_key_subkey(block_number, address)?
+            .filter(|account| account.address == address))
+    }
+
+    fn account_next_change_block(
+        &self,
+        address: Address,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Option<BlockNumber>> {
+        // the first shard whose highest block is at or after the block contains the change
+        let Some((key, shard)) = self
+            .tx
+            .cursor_read::<tables::AccountsHistory>()?
+            .seek(ShardedKey::new(address, block_number))?
+        else {
+            return Ok(None)
+        };
+        if key.key != address {
+            return Ok(None)
+        }
+
+        // the rank of the first entry after the block, or of the block itself if it changed the
+        // account
+        let mut rank = shard.rank(block_number);
+        if rank.checked_sub(1).and_then(|rank| shard.select(rank)) == Some(block_number) {
+            rank -= 1
+        }
+        Ok(shard.select(rank))
+    }
 }
 
 impl<TX: DbTx> HeaderSyncGapProvider for DatabaseProvider<TX> {
@@ -1326,12 +1827,11 @@ impl<TX: DbTx> HeaderProvider for DatabaseProvider<TX> {
     }
 
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
-        self.static_file_provider.get_with_static_file_or_database(
-            StaticFileSegment::Headers,
-            num,
-            |static_file| static_file.header_by_number(num),
-            || Ok(self.tx.get::<tables::Headers>(num)?),
-        )
+        if let Some(cache) = &self.block_cache {
+            let Some(hash) = self.block_hash(num)? else { return Ok(None) };
+            return cache.header(hash, || self.header_by_number_uncached(num))
+        }
+        self.header_by_number_uncached(num)
     }
 
     fn header_td(&self, block_hash: &BlockHash) -> ProviderResult<Option<U256>> {
@@ -1487,25 +1987,22 @@ impl<TX: DbTx> BlockReader for DatabaseProvider<TX> {
     /// If the header is found, but the transactions either do not exist, or are not indexed, this
     /// will return None.
     fn block(&self, id: BlockHashOrNumber) -> ProviderResult<Option<Block>> {
-        if let Some(number) = self.convert_hash_or_number(id)? {
-            if let Some(header) = self.header_by_number(number)? {
-                let withdrawals = self.withdrawals_by_block(number.into(), header.timestamp)?;
-                let ommers = self.ommers(number.into())?.unwrap_or_default();
-                let requests = self.requests_by_block(number.into(), header.timestamp)?;
-                // If the body indices are not found, this means that the transactions either do not
-                // exist in the database yet, or they do exit but are not indexed.
-                // If they exist but are not indexed, we don't have enough
-                // information to return the block anyways, so we return `None`.
-                let transactions = match self.transactions_by_block(number.into())? {
-                    Some(transactions) => transactions,
+        let Some(number) = self.convert_hash_or_number(id)? else { return Ok(None) };
+
+        if let Some(cache) = &self.block_cache {
+            // the hash is resolved from the database, so blocks that were unwound are never served
+            // from the cache
+            let hash = match id {
+                BlockHashOrNumber::Hash(hash) => hash,
+                BlockHashOrNumber::Number(_) => match self.block_hash(number)? {
+                    Some(hash) => hash,
                     None => return Ok(None),
-                };
-
-                return Ok(Some(Block { header, body: transactions, ommers, withdrawals, requests }))
-            }
+                },
+            };
+            return cache.block(hash, || self.block_uncached(number))
         }
 
-        Ok(None)
+        self.block_uncached(number)
     }
 
     fn pending_block(&self) -> ProviderResult<Option<SealedBlock>> {
@@ -1536,6 +2033,10 @@ impl<TX: DbTx> BlockReader for DatabaseProvider<TX> {
     }
 
     fn block_body_indices(&self, num: u64) -> ProviderResult<Option<StoredBlockBodyIndices>> {
+        if let Some(cache) = &self.block_cache {
+            let Some(hash) = self.block_hash(num)? else { return Ok(None) };
+            return cache.body_indices(hash, || Ok(self.tx.get::<tables::BlockBodyIndices>(num)?))
+        }
         Ok(self.tx.get::<tables::BlockBodyIndices>(num)?)
     }
 
@@ -1759,7 +2260,6 @@ impl<TX: DbTx> TransactionsProvider for DatabaseProvider<TX> {
         &self,
         tx_hash: TxHash,
     ) -> ProviderResult<Option<(TransactionSigned, TransactionMeta)>> {
-        let mut transaction_cursor = self.tx.cursor_read::<tables::TransactionBlocks>()?;
         if let Some(transaction_id) = self.transaction_id(tx_hash)? {
             if let Some(tx) = self.transaction_by_id_no_hash(transaction_id)? {
                 let transaction = TransactionSigned {
@@ -1767,9 +2267,7 @@ impl<TX: DbTx> TransactionsProvider for DatabaseProvider<TX> {
                     signature: tx.signature,
                     transaction: tx.transaction,
                 };
-                if let Some(block_number) =
-                    transaction_cursor.seek(transaction_id).map(|b| b.map(|(_, bn)| bn))?
-                {
+                if let Some(block_number) = self.block_number_for_tx_number(transaction_id)? {
                     if let Some(sealed_header) = self.sealed_header(block_number)? {
                         let (header, block_hash) = sealed_header.split();
                         if let Some(block_body) = self.block_body_indices(block_number)? {
@@ -1800,8 +2298,7 @@ impl<TX: DbTx> TransactionsProvider for DatabaseProvider<TX> {
     }
 
     fn transaction_block(&self, id: TxNumber) -> ProviderResult<Option<BlockNumber>> {
-        let mut cursor = self.tx.cursor_read::<tables::TransactionBlocks>()?;
-        Ok(cursor.seek(id)?.map(|(_, bn)| bn))
+        self.block_number_for_tx_number(id)
     }
 
     fn transactions_by_block(
@@ -1866,11 +2363,22 @@ impl<TX: DbTx> TransactionsProvider for DatabaseProvider<TX> {
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<Address>> {
-        self.cursor_read_collect::<tables::TransactionSenders>(range).map_err(Into::into)
+        self.static_file_provider.get_range_with_static_file_or_database(
+            StaticFileSegment::Senders,
+            to_range(range),
+            |static_file, range, _| static_file.senders_by_tx_range(range),
+            |range, _| self.cursor_read_collect::<tables::TransactionSenders>(range),
+            |_| true,
+        )
     }
 
     fn transaction_sender(&self, id: TxNumber) -> ProviderResult<Option<Address>> {
-        Ok(self.tx.get::<tables::TransactionSenders>(id)?)
+        self.static_file_provider.get_with_static_file_or_database(
+            StaticFileSegment::Senders,
+            id,
+            |static_file| static_file.transaction_sender(id),
+            || Ok(self.tx.get::<tables::TransactionSenders>(id)?),
+        )
     }
 }
 
@@ -1910,12 +2418,9 @@ impl<TX: DbTx> ReceiptProvider for DatabaseProvider<TX> {
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<Receipt>> {
-        self.static_file_provider.get_range_with_static_file_or_database(
-            StaticFileSegment::Receipts,
-            to_range(range),
-            |static_file, range, _| static_file.receipts_by_tx_range(range),
-            |range, _| self.cursor_read_collect::<tables::Receipts>(range).map_err(Into::into),
-            |_| true,
+        self.receipts_by_tx_range_with_cursor(
+            range,
+            &mut self.tx.cursor_read::<tables::Receipts>()?,
         )
     }
 }
@@ -2005,6 +2510,22 @@ impl<TX: DbTx> EvmEnvProvider for DatabaseProvider<TX> {
         Ok(())
     }
 
+    fn fill_env_for_pending<EvmConfig>(
+        &self,
+        cfg: &mut CfgEnvWithHandlerCfg,
+        block_env: &mut BlockEnv,
+        _evm_config: EvmConfig,
+    ) -> ProviderResult<SealedHeader>
+    where
+        EvmConfig: ConfigureEvmEnv,
+    {
+        let best_number = self.best_block_number()?;
+        let tip = self
+            .sealed_header(best_number)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(best_number.into()))?;
+        self.fill_env_for_pending_on::<EvmConfig>(cfg, block_env, tip)
+    }
+
     fn fill_block_env_at(
         &self,
         block_env: &mut BlockEnv,
@@ -2370,18 +2891,12 @@ impl<TX: DbTxMut + DbTx> HashingWriter for DatabaseProvider<TX> {
                     .collect(),
                 destroyed_accounts,
             };
-            let (state_root, trie_updates) = StateRoot::from_tx(&self.tx)
-                .with_prefix_sets(prefix_sets)
-                .root_with_updates()
-                .map_err(Into::<reth_db::DatabaseError>::into)?;
-            if state_root != expected_state_root {
-                return Err(ProviderError::StateRootMismatch(Box::new(RootMismatch {
-                    root: GotExpected { got: state_root, expected: expected_state_root },
-                    block_number: *range.end(),
-                    block_hash: end_block_hash,
-                })))
-            }
-            trie_updates.flush(&self.tx)?;
+            self.insert_merkle_tree(
+                prefix_sets,
+                *range.end(),
+                end_block_hash,
+                expected_state_root,
+            )?;
         }
         durations_recorder.record_relative(metrics::Action::InsertMerkleTree);
 
@@ -2389,6 +2904,30 @@ impl<TX: DbTxMut + DbTx> HashingWriter for DatabaseProvider<TX> {
 
         Ok(())
     }
+
+    fn insert_hashes_for_bundle(
+        &self,
+        bundle: &BundleState,
+        block_number: BlockNumber,
+        block_hash: B256,
+        expected_state_root: B256,
+    ) -> ProviderResult<()> {
+        let mut durations_recorder = metrics::DurationsRecorder::default();
+
+        // account and storage hashing, the changes are already known from the bundle
+        let hashed_state = HashedPostState::from_bundle_state(&bundle.state);
+        let prefix_sets = hashed_state.construct_prefix_sets();
+        HashedStateChanges(hashed_state).write_to_db(&self.tx)?;
+        durations_recorder.record_relative(metrics::Action::InsertHashes);
+
+        // merkle tree
+        self.insert_merkle_tree(prefix_sets, block_number, block_hash, expected_state_root)?;
+        durations_recorder.record_relative(metrics::Action::InsertMerkleTree);
+
+        debug!(target: "providers::db", block_number, actions = ?durations_recorder.actions, "Inserted hashes for bundle");
+
+        Ok(())
+    }
 }
 
 impl<TX: DbTxMut + DbTx> HistoryWriter for DatabaseProvider<TX> {
@@ -2635,8 +3174,11 @@ impl<TX: DbTxMut + DbTx> BlockWriter for DatabaseProvider<TX> {
         self.tx.put::<tables::HeaderTerminalDifficulties>(block_number, ttd.into())?;
         durations_recorder.record_relative(metrics::Action::InsertHeaderTerminalDifficulties);
 
-        // insert body ommers data
-        if !block.ommers.is_empty() {
+        // insert body ommers data, post-merge blocks can't have ommers so nothing is ever stored
+        // for them
+        if !block.ommers.is_empty() &&
+            self.chain_spec.final_paris_total_difficulty(block_number).is_none()
+        {
             self.tx.put::<tables::BlockOmmers>(
                 block_number,
                 StoredBlockOmmers { ommers: block.block.ommers },
@@ -2664,6 +3206,7 @@ impl<TX: DbTxMut + DbTx> BlockWriter for DatabaseProvider<TX> {
             let hash = transaction.hash();
 
             if prune_modes
+                .filter(|modes| !modes.watched_senders.contains(sender))
                 .and_then(|modes| modes.sender_recovery)
                 .filter(|prune_mode| prune_mode.is_full())
                 .is_none()
@@ -2851,6 +3394,40 @@ impl<TX: DbTxMut> FinalizedBlockWriter for DatabaseProvider<TX> {
     }
 }
 
+impl<TX: DbTx> InvalidHeadersReader for DatabaseProvider<TX> {
+    fn invalid_headers(&self) -> ProviderResult<Vec<SealedHeader>> {
+        self.tx
+            .cursor_read::<tables::InvalidHeaders>()?
+            .walk(None)?
+            .map(|entry| entry.map(|(hash, header)| header.seal(hash)).map_err(Into::into))
+            .collect()
+    }
+}
+
+impl<TX: DbTxMut + DbTx> InvalidHeadersWriter for DatabaseProvider<TX> {
+    fn save_invalid_header(&self, header: SealedHeader, max_entries: usize) -> ProviderResult<()> {
+        let hash = header.hash();
+        self.tx.put::<tables::InvalidHeaders>(hash, header.unseal())?;
+
+        let entries = self.tx.entries::<tables::InvalidHeaders>()?;
+        if entries > max_entries {
+            // evict the headers with the lowest block numbers
+            let mut headers = self
+                .tx
+                .cursor_read::<tables::InvalidHeaders>()?
+                .walk(None)?
+                .map(|entry| entry.map(|(hash, header)| (header.number, hash)))
+                .collect::<Result<Vec<_>, _>>()?;
+            headers.sort_unstable();
+            for (_, hash) in headers.into_iter().take(entries - max_entries) {
+                self.tx.delete::<tables::InvalidHeaders>(hash, None)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 fn range_size_hint(range: &impl RangeBounds<TxNumber>) -> Option<usize> {
     let start = match range.start_bound().cloned() {
         Bound::Included(start) => start,
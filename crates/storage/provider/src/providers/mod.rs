@@ -2,7 +2,7 @@ use crate::{
     AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
     BlockSource, BlockchainTreePendingStateProvider, CanonChainTracker, CanonStateNotifications,
     CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader, DatabaseProviderFactory,
-    EvmEnvProvider, FullExecutionDataProvider, HeaderProvider, ProviderError,
+    EvmEnvProvider, FullExecutionDataProvider, HeaderProvider, InvalidHeadersReader, ProviderError,
     PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt, RequestsProvider,
     StageCheckpointReader, StateProviderBox, StateProviderFactory, StaticFileProviderFactory,
     TransactionVariant, TransactionsProvider, TreeViewer, WithdrawalsProvider,
@@ -41,7 +41,7 @@ pub use database::*;
 mod static_file;
 pub use static_file::{
     StaticFileAccess, StaticFileJarProvider, StaticFileProvider, StaticFileProviderRW,
-    StaticFileProviderRWRefMut, StaticFileWriter,
+    StaticFileProviderRWRefMut, StaticFileValidation, StaticFileWriter,
 };
 
 mod state;
@@ -507,6 +507,15 @@ where
     }
 }
 
+impl<DB> InvalidHeadersReader for BlockchainProvider<DB>
+where
+    DB: Database,
+{
+    fn invalid_headers(&self) -> ProviderResult<Vec<SealedHeader>> {
+        self.database.provider()?.invalid_headers()
+    }
+}
+
 impl<DB> EvmEnvProvider for BlockchainProvider<DB>
 where
     DB: Database,
@@ -537,6 +546,20 @@ where
         self.database.provider()?.fill_env_with_header(cfg, block_env, header, evm_config)
     }
 
+    fn fill_env_for_pending<EvmConfig>(
+        &self,
+        cfg: &mut CfgEnvWithHandlerCfg,
+        block_env: &mut BlockEnv,
+        _evm_config: EvmConfig,
+    ) -> ProviderResult<SealedHeader>
+    where
+        EvmConfig: ConfigureEvmEnv,
+    {
+        // the canonical head is tracked in memory and may be ahead of the database checkpoint
+        let tip = self.chain_info.get_canonical_head();
+        self.database.provider()?.fill_env_for_pending_on::<EvmConfig>(cfg, block_env, tip)
+    }
+
     fn fill_block_env_at(
         &self,
         block_env: &mut BlockEnv,
@@ -785,6 +808,13 @@ where
     }
 
     fn set_canonical_head(&self, header: SealedHeader) {
+        if let Some(block_cache) = self.database.block_cache() {
+            // the new head doesn't extend the previous one, so this is a reorg or an unwind and the
+            // cache may hold blocks that are no longer canonical
+            if header.parent_hash != self.chain_info.get_canonical_head().hash() {
+                block_cache.clear();
+            }
+        }
         self.chain_info.set_canonical_head(header);
     }
 
@@ -907,6 +937,22 @@ where
     ) -> ProviderResult<Vec<AccountBeforeTx>> {
         self.database.provider()?.account_block_changeset(block_number)
     }
+
+    fn account_before_block(
+        &self,
+        block_number: BlockNumber,
+        address: Address,
+    ) -> ProviderResult<Option<AccountBeforeTx>> {
+        self.database.provider()?.account_before_block(block_number, address)
+    }
+
+    fn account_next_change_block(
+        &self,
+        address: Address,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Option<BlockNumber>> {
+        self.database.provider()?.account_next_change_block(address, block_number)
+    }
 }
 
 impl<DB> AccountReader for BlockchainProvider<DB>
@@ -1,6 +1,9 @@
 use crate::{
-    providers::{state::macros::delegate_provider_impls, StaticFileProvider},
-    AccountReader, BlockHashReader, ProviderError, StateProvider, StateRootProvider,
+    providers::{
+        state::{hashed_account_range, macros::delegate_provider_impls},
+        StaticFileProvider,
+    },
+    AccountRange, AccountReader, BlockHashReader, ProviderError, StateProvider, StateRootProvider,
 };
 use reth_db::{tables, BlockNumberList};
 use reth_db_api::{
@@ -14,7 +17,9 @@ use reth_primitives::{
     StaticFileSegment, StorageKey, StorageValue, B256,
 };
 use reth_storage_errors::provider::ProviderResult;
-use reth_trie::{updates::TrieUpdates, HashedPostState};
+use reth_trie::{
+    hashed_cursor::HashedPostStateCursorFactory, updates::TrieUpdates, HashedPostState,
+};
 use revm::db::BundleState;
 use std::fmt::Debug;
 
@@ -311,6 +316,20 @@ impl<'b, TX: DbTx> StateProvider for HistoricalStateProviderRef<'b, TX> {
     fn proof(&self, _address: Address, _keys: &[B256]) -> ProviderResult<AccountProof> {
         Err(ProviderError::StateRootNotAvailableForHistoricalBlock)
     }
+
+    /// Get a page of accounts by overlaying the reverted state on top of the hashed state.
+    fn account_range(&self, start: B256, limit: usize) -> ProviderResult<AccountRange> {
+        let revert_state = self.revert_state()?;
+        let prefix_sets = revert_state.construct_prefix_sets();
+        let revert_state = revert_state.into_sorted();
+        Ok(hashed_account_range(
+            self.tx,
+            HashedPostStateCursorFactory::new(self.tx, &revert_state),
+            &prefix_sets.storage_prefix_sets,
+            start,
+            limit,
+        )?)
+    }
 }
 
 /// State provider for a given block number.
@@ -1,6 +1,9 @@
 use crate::{
-    providers::{state::macros::delegate_provider_impls, StaticFileProvider},
-    AccountReader, BlockHashReader, StateProvider, StateRootProvider,
+    providers::{
+        state::{hashed_account_range, macros::delegate_provider_impls},
+        StaticFileProvider,
+    },
+    AccountRange, AccountReader, BlockHashReader, StateProvider, StateRootProvider,
 };
 use reth_db::tables;
 use reth_db_api::{
@@ -117,6 +120,10 @@ impl<'b, TX: DbTx> StateProvider for LatestStateProviderRef<'b, TX> {
             .account_proof(address, slots)
             .map_err(Into::<reth_db::DatabaseError>::into)?)
     }
+
+    fn account_range(&self, start: B256, limit: usize) -> ProviderResult<AccountRange> {
+        Ok(hashed_account_range(self.tx, self.tx, &Default::default(), start, limit)?)
+    }
 }
 
 /// State provider for the latest state.
@@ -147,10 +154,52 @@ delegate_provider_impls!(LatestStateProvider<TX> where [TX: DbTx]);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{test_utils::create_test_provider_factory, StaticFileProviderFactory};
+    use reth_db_api::transaction::DbTxMut;
+    use reth_primitives::{keccak256, U256};
+    use reth_trie::EMPTY_ROOT_HASH;
 
     const fn assert_state_provider<T: StateProvider>() {}
     #[allow(dead_code)]
     const fn assert_latest_state_provider<T: DbTx>() {
         assert_state_provider::<LatestStateProvider<T>>();
     }
+
+    #[test]
+    fn account_range_pages() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let mut expected = (0..5u64)
+            .map(|i| {
+                let account = Account { nonce: i, balance: U256::from(i), bytecode_hash: None };
+                (keccak256(Address::with_last_byte(i as u8)), account)
+            })
+            .collect::<Vec<_>>();
+        for (hashed_address, account) in &expected {
+            tx.put::<tables::HashedAccounts>(*hashed_address, *account).unwrap();
+        }
+        expected.sort_unstable_by_key(|(hashed_address, _)| *hashed_address);
+
+        let provider = LatestStateProviderRef::new(&tx, factory.static_file_provider());
+
+        let first = provider.account_range(B256::ZERO, 3).unwrap();
+        assert_eq!(first.accounts.len(), 3);
+        assert_eq!(first.next, Some(expected[3].0));
+
+        let second = provider.account_range(first.next.unwrap(), 3).unwrap();
+        assert_eq!(second.accounts.len(), 2);
+        assert_eq!(second.next, None);
+
+        let dumped = first
+            .accounts
+            .iter()
+            .chain(&second.accounts)
+            .map(|entry| {
+                assert_eq!(entry.storage_root, EMPTY_ROOT_HASH);
+                (entry.hashed_address, entry.account)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(dumped, expected);
+    }
 }
@@ -2,3 +2,53 @@
 pub(crate) mod historical;
 pub(crate) mod latest;
 pub(crate) mod macros;
+
+use crate::{AccountRange, AccountRangeEntry};
+use reth_db::DatabaseError;
+use reth_db_api::transaction::DbTx;
+use reth_primitives::B256;
+use reth_trie::{
+    hashed_cursor::{HashedCursor, HashedCursorFactory},
+    prefix_set::PrefixSet,
+    StorageRoot,
+};
+use std::collections::HashMap;
+
+/// Walks the hashed accounts of the given hashed cursor factory starting at `start` and returns up
+/// to `limit` accounts alongside their storage roots.
+///
+/// The `storage_prefix_sets` contain the storage slots that differ between the hashed state and
+/// the storage tries in the database, they're required to compute correct storage roots when the
+/// hashed state is overlaid, e.g. for historical state.
+pub(crate) fn hashed_account_range<TX, H>(
+    tx: &TX,
+    hashed_cursor_factory: H,
+    storage_prefix_sets: &HashMap<B256, PrefixSet>,
+    start: B256,
+    limit: usize,
+) -> Result<AccountRange, DatabaseError>
+where
+    TX: DbTx,
+    H: HashedCursorFactory + Clone,
+{
+    let mut cursor = hashed_cursor_factory.hashed_account_cursor()?;
+    let mut range = AccountRange::default();
+
+    let mut entry = cursor.seek(start)?;
+    while let Some((hashed_address, account)) = entry {
+        if range.accounts.len() == limit {
+            range.next = Some(hashed_address);
+            break
+        }
+
+        let storage_root = StorageRoot::from_tx_hashed(tx, hashed_address)
+            .with_hashed_cursor_factory(hashed_cursor_factory.clone())
+            .with_prefix_set(storage_prefix_sets.get(&hashed_address).cloned().unwrap_or_default())
+            .root()?;
+        range.accounts.push(AccountRangeEntry { hashed_address, account, storage_root });
+
+        entry = cursor.next()?;
+    }
+
+    Ok(range)
+}
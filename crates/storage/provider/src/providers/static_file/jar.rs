@@ -6,11 +6,14 @@ use crate::{
     to_range, BlockHashReader, BlockNumReader, HeaderProvider, ReceiptProvider,
     TransactionsProvider,
 };
-use reth_db::static_file::{HeaderMask, ReceiptMask, StaticFileCursor, TransactionMask};
+use reth_db::static_file::{
+    HeaderMask, ReceiptMask, SenderMask, StaticFileCursor, TransactionMask,
+};
 use reth_db_api::models::CompactU256;
 use reth_primitives::{
     Address, BlockHash, BlockHashOrNumber, BlockNumber, ChainInfo, Header, Receipt, SealedHeader,
-    TransactionMeta, TransactionSigned, TransactionSignedNoHash, TxHash, TxNumber, B256, U256,
+    StaticFileSegment, TransactionMeta, TransactionSigned, TransactionSignedNoHash, TxHash,
+    TxNumber, B256, U256,
 };
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
 use std::{
@@ -275,12 +278,31 @@ impl<'a> TransactionsProvider for StaticFileJarProvider<'a> {
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<Address>> {
+        if self.segment().is_senders() {
+            let range = to_range(range);
+            let mut cursor = self.cursor()?;
+            let mut senders = Vec::with_capacity((range.end - range.start) as usize);
+
+            for num in range {
+                let sender = cursor
+                    .get_one::<SenderMask<Address>>(num.into())?
+                    .ok_or(ProviderError::MissingStaticFileTx(StaticFileSegment::Senders, num))?;
+                senders.push(sender)
+            }
+            return Ok(senders)
+        }
+
+        // Senders are not part of this static file, so they're recovered from the transactions.
         let txs = self.transactions_by_tx_range(range)?;
         TransactionSignedNoHash::recover_signers(&txs, txs.len())
             .ok_or(ProviderError::SenderRecoveryError)
     }
 
     fn transaction_sender(&self, num: TxNumber) -> ProviderResult<Option<Address>> {
+        if self.segment().is_senders() {
+            return self.cursor()?.get_one::<SenderMask<Address>>(num.into())
+        }
+
         Ok(self
             .cursor()?
             .get_one::<TransactionMask<TransactionSignedNoHash>>(num.into())?
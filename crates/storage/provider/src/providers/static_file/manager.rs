@@ -11,7 +11,9 @@ use dashmap::{mapref::entry::Entry as DashMapEntry, DashMap};
 use parking_lot::RwLock;
 use reth_db::{
     lockfile::StorageLock,
-    static_file::{iter_static_files, HeaderMask, ReceiptMask, StaticFileCursor, TransactionMask},
+    static_file::{
+        iter_static_files, HeaderMask, ReceiptMask, SenderMask, StaticFileCursor, TransactionMask,
+    },
     tables,
 };
 use reth_db_api::{
@@ -67,6 +69,31 @@ impl StaticFileAccess {
     }
 }
 
+/// The number of entries per static file segment that are checked by
+/// [`StaticFileValidation::Sampled`].
+const VALIDATION_SAMPLES: u64 = 1_000;
+
+/// How thoroughly [`StaticFileProvider::validate_against_database`] compares static files against
+/// the database.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StaticFileValidation {
+    /// Checks a sample of evenly spaced entries, including the first and last overlapping entry.
+    #[default]
+    Sampled,
+    /// Checks every overlapping entry.
+    Full,
+}
+
+impl StaticFileValidation {
+    /// Returns the distance between two checked entries of a range with `len` entries.
+    const fn step(&self, len: u64) -> u64 {
+        match self {
+            Self::Sampled if len > VALIDATION_SAMPLES => len / VALIDATION_SAMPLES,
+            _ => 1,
+        }
+    }
+}
+
 /// [`StaticFileProvider`] manages all existing [`StaticFileJarProvider`].
 #[derive(Debug, Default, Clone)]
 pub struct StaticFileProvider(pub(crate) Arc<StaticFileProviderInner>);
@@ -445,7 +472,9 @@ impl StaticFileProvider {
                     if jar.user_header().expected_block_start() == 0 &&
                         matches!(
                             segment,
-                            StaticFileSegment::Receipts | StaticFileSegment::Transactions
+                            StaticFileSegment::Receipts |
+                                StaticFileSegment::Transactions |
+                                StaticFileSegment::Senders
                         )
                     {
                         tx_index.remove(&segment);
@@ -546,6 +575,12 @@ impl StaticFileProvider {
                 continue
             }
 
+            if segment.is_senders() && self.get_highest_static_file_block(segment).is_none() {
+                // Senders are only moved to static files if they're not pruned, otherwise they're
+                // kept in the database.
+                continue
+            }
+
             let initial_highest_block = self.get_highest_static_file_block(segment);
 
             //  File consistency is broken if:
@@ -574,7 +609,7 @@ impl StaticFileProvider {
                 update_unwind_target(highest_block.unwrap_or_default());
             }
 
-            // Only applies to transaction-based static files. (Receipts & Transactions)
+            // Only applies to transaction-based static files. (Receipts, Transactions & Senders)
             //
             // Make sure the last transaction matches the last block from its indices, since a heal
             // from a pruning interruption might have decreased the number of transactions without
@@ -630,6 +665,13 @@ impl StaticFileProvider {
                     highest_tx,
                     highest_block,
                 )?,
+                StaticFileSegment::Senders => self
+                    .ensure_invariants::<_, tables::TransactionSenders>(
+                        provider,
+                        segment,
+                        highest_tx,
+                        highest_block,
+                    )?,
             } {
                 update_unwind_target(unwind);
             }
@@ -638,6 +680,97 @@ impl StaticFileProvider {
         Ok(unwind_target.map(PipelineTarget::Unwind))
     }
 
+    /// Validates the static files against the database.
+    ///
+    /// For each static file segment, the entries that are also present in the corresponding
+    /// database table are compared. This catches stale or mismatched static files that were placed
+    /// alongside a database, before they are used as the source of truth.
+    ///
+    /// Returns [`ProviderError::StaticFileDatabaseMismatch`] on the first entry that differs.
+    pub fn validate_against_database<TX: DbTx>(
+        &self,
+        provider: &DatabaseProvider<TX>,
+        validation: StaticFileValidation,
+    ) -> ProviderResult<()> {
+        for segment in StaticFileSegment::iter() {
+            match segment {
+                StaticFileSegment::Headers => self.validate_segment::<_, tables::Headers>(
+                    provider,
+                    segment,
+                    self.get_highest_static_file_block(segment),
+                    validation,
+                    |number| self.header_by_number(number),
+                )?,
+                StaticFileSegment::Transactions => self
+                    .validate_segment::<_, tables::Transactions>(
+                        provider,
+                        segment,
+                        self.get_highest_static_file_tx(segment),
+                        validation,
+                        |id| self.transaction_by_id_no_hash(id),
+                    )?,
+                StaticFileSegment::Receipts => self.validate_segment::<_, tables::Receipts>(
+                    provider,
+                    segment,
+                    self.get_highest_static_file_tx(segment),
+                    validation,
+                    |id| self.receipt(id),
+                )?,
+                StaticFileSegment::Senders => self
+                    .validate_segment::<_, tables::TransactionSenders>(
+                        provider,
+                        segment,
+                        self.get_highest_static_file_tx(segment),
+                        validation,
+                        |id| self.static_file_sender(id),
+                    )?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compares the entries of a static file segment with the entries of the corresponding table
+    /// in the overlapping key range.
+    fn validate_segment<TX: DbTx, T: Table<Key = u64>>(
+        &self,
+        provider: &DatabaseProvider<TX>,
+        segment: StaticFileSegment,
+        highest_static_file_entry: Option<u64>,
+        validation: StaticFileValidation,
+        static_file_entry: impl Fn(u64) -> ProviderResult<Option<T::Value>>,
+    ) -> ProviderResult<()>
+    where
+        T::Value: PartialEq,
+    {
+        let Some(highest_static_file_entry) = highest_static_file_entry else { return Ok(()) };
+        let mut db_cursor = provider.tx_ref().cursor_read::<T>()?;
+        let Some((db_first_entry, _)) = db_cursor.first()? else { return Ok(()) };
+        let Some((db_last_entry, _)) = db_cursor.last()? else { return Ok(()) };
+
+        let last_entry = db_last_entry.min(highest_static_file_entry);
+        if db_first_entry > last_entry {
+            return Ok(())
+        }
+
+        let step = validation.step(last_entry - db_first_entry + 1);
+        let keys = (db_first_entry..=last_entry).step_by(step as usize);
+        for key in keys.chain(std::iter::once(last_entry)) {
+            let Some((_, db_value)) = db_cursor.seek_exact(key)? else { continue };
+            if static_file_entry(key)?.as_ref() != Some(&db_value) {
+                warn!(
+                    target: "reth::providers::static_file",
+                    ?segment,
+                    key,
+                    "Static file entry does not match the database."
+                );
+                return Err(ProviderError::StaticFileDatabaseMismatch(segment, key))
+            }
+        }
+
+        Ok(())
+    }
+
     /// Check invariants for each corresponding table and static file segment:
     ///
     /// * the corresponding database table should overlap or have continuity in their keys
@@ -690,6 +823,7 @@ impl StaticFileProvider {
                 StaticFileSegment::Headers => StageId::Headers,
                 StaticFileSegment::Transactions => StageId::Bodies,
                 StaticFileSegment::Receipts => StageId::Execution,
+                StaticFileSegment::Senders => StageId::SenderRecovery,
             })?
             .unwrap_or_default()
             .block_number;
@@ -722,10 +856,14 @@ impl StaticFileProvider {
                 writer.prune_headers(highest_static_file_block - checkpoint_block_number)?;
             } else if let Some(block) = provider.block_body_indices(checkpoint_block_number)? {
                 let number = highest_static_file_entry - block.last_tx_num();
-                if segment.is_receipts() {
-                    writer.prune_receipts(number, checkpoint_block_number)?;
-                } else {
-                    writer.prune_transactions(number, checkpoint_block_number)?;
+                match segment {
+                    StaticFileSegment::Receipts => {
+                        writer.prune_receipts(number, checkpoint_block_number)?
+                    }
+                    StaticFileSegment::Senders => {
+                        writer.prune_senders(number, checkpoint_block_number)?
+                    }
+                    _ => writer.prune_transactions(number, checkpoint_block_number)?,
                 }
             }
             writer.commit()?;
@@ -734,6 +872,22 @@ impl StaticFileProvider {
         Ok(None)
     }
 
+    /// Returns the sender of a transaction from the [`StaticFileSegment::Senders`] static files.
+    ///
+    /// Unlike [`TransactionsProvider::transaction_sender`], this does not fall back to recovering
+    /// the sender from the transaction.
+    pub fn static_file_sender(&self, id: TxNumber) -> ProviderResult<Option<Address>> {
+        self.get_segment_provider_from_transaction(StaticFileSegment::Senders, id, None)
+            .and_then(|provider| provider.cursor()?.get_one::<SenderMask<Address>>(id.into()))
+            .or_else(|err| {
+                if let ProviderError::MissingStaticFileTx(_, _) = err {
+                    Ok(None)
+                } else {
+                    Err(err)
+                }
+            })
+    }
+
     /// Gets the highest static file block if it exists for a static file segment.
     pub fn get_highest_static_file_block(&self, segment: StaticFileSegment) -> Option<BlockNumber> {
         self.static_files_max_block.read().get(&segment).copied()
@@ -753,6 +907,7 @@ impl StaticFileProvider {
             headers: self.get_highest_static_file_block(StaticFileSegment::Headers),
             receipts: self.get_highest_static_file_block(StaticFileSegment::Receipts),
             transactions: self.get_highest_static_file_block(StaticFileSegment::Transactions),
+            senders: self.get_highest_static_file_block(StaticFileSegment::Senders),
         }
     }
 
@@ -799,7 +954,9 @@ impl StaticFileProvider {
             StaticFileSegment::Headers => {
                 self.get_segment_provider_from_block(segment, start, None)
             }
-            StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+            StaticFileSegment::Transactions |
+            StaticFileSegment::Receipts |
+            StaticFileSegment::Senders => {
                 self.get_segment_provider_from_transaction(segment, start, None)
             }
         };
@@ -869,7 +1026,9 @@ impl StaticFileProvider {
             StaticFileSegment::Headers => {
                 self.get_segment_provider_from_block(segment, start, None)
             }
-            StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+            StaticFileSegment::Transactions |
+            StaticFileSegment::Receipts |
+            StaticFileSegment::Senders => {
                 self.get_segment_provider_from_transaction(segment, start, None)
             }
         };
@@ -914,9 +1073,9 @@ impl StaticFileProvider {
         // If there is, check the maximum block or transaction number of the segment.
         let static_file_upper_bound = match segment {
             StaticFileSegment::Headers => self.get_highest_static_file_block(segment),
-            StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
-                self.get_highest_static_file_tx(segment)
-            }
+            StaticFileSegment::Transactions |
+            StaticFileSegment::Receipts |
+            StaticFileSegment::Senders => self.get_highest_static_file_tx(segment),
         };
 
         if static_file_upper_bound
@@ -956,9 +1115,9 @@ impl StaticFileProvider {
         // If there is, check the maximum block or transaction number of the segment.
         if let Some(static_file_upper_bound) = match segment {
             StaticFileSegment::Headers => self.get_highest_static_file_block(segment),
-            StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
-                self.get_highest_static_file_tx(segment)
-            }
+            StaticFileSegment::Transactions |
+            StaticFileSegment::Receipts |
+            StaticFileSegment::Senders => self.get_highest_static_file_tx(segment),
         } {
             if block_or_tx_range.start <= static_file_upper_bound {
                 let end = block_or_tx_range.end.min(static_file_upper_bound + 1);
@@ -1373,13 +1532,35 @@ impl TransactionsProvider for StaticFileProvider {
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<Address>> {
-        let txes = self.transactions_by_tx_range(range)?;
-        TransactionSignedNoHash::recover_signers(&txes, txes.len())
-            .ok_or(ProviderError::SenderRecoveryError)
+        // Senders which are not part of the senders static files are recovered from the
+        // transactions.
+        self.get_range_with_static_file_or_database(
+            StaticFileSegment::Senders,
+            to_range(range),
+            |static_file, range, _| {
+                static_file.fetch_range_with_predicate(
+                    StaticFileSegment::Senders,
+                    range,
+                    |cursor, number| cursor.get_one::<SenderMask<Address>>(number.into()),
+                    |_| true,
+                )
+            },
+            |range, _| {
+                let txes = self.transactions_by_tx_range(range)?;
+                TransactionSignedNoHash::recover_signers(&txes, txes.len())
+                    .ok_or(ProviderError::SenderRecoveryError)
+            },
+            |_| true,
+        )
     }
 
     fn transaction_sender(&self, id: TxNumber) -> ProviderResult<Option<Address>> {
-        Ok(self.transaction_by_id_no_hash(id)?.and_then(|tx| tx.recover_signer()))
+        self.get_with_static_file_or_database(
+            StaticFileSegment::Senders,
+            id,
+            |static_file| static_file.static_file_sender(id),
+            || Ok(self.transaction_by_id_no_hash(id)?.and_then(|tx| tx.recover_signer())),
+        )
     }
 }
 
@@ -1,5 +1,5 @@
 mod manager;
-pub use manager::{StaticFileAccess, StaticFileProvider, StaticFileWriter};
+pub use manager::{StaticFileAccess, StaticFileProvider, StaticFileValidation, StaticFileWriter};
 
 mod jar;
 pub use jar::StaticFileJarProvider;
@@ -57,7 +57,11 @@ impl Deref for LoadedJar {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{test_utils::create_test_provider_factory, HeaderProvider};
+    use crate::{
+        test_utils::create_test_provider_factory, HeaderProvider, StaticFileProviderFactory,
+        TransactionsProvider,
+    };
+    use assert_matches::assert_matches;
     use rand::seq::SliceRandom;
     use reth_db::{
         static_file::create_static_file_T1_T2_T3, CanonicalHeaders, HeaderNumbers,
@@ -67,8 +71,10 @@ mod tests {
         cursor::DbCursorRO,
         transaction::{DbTx, DbTxMut},
     };
-    use reth_primitives::{static_file::find_fixed_range, BlockNumber, B256, U256};
-    use reth_testing_utils::generators::{self, random_header_range};
+    use reth_primitives::{
+        static_file::find_fixed_range, Address, BlockNumber, SealedBlock, B256, U256,
+    };
+    use reth_testing_utils::generators::{self, random_block_range, random_header_range};
 
     #[test]
     fn test_snap() {
@@ -179,4 +185,136 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn validate_against_database() {
+        let factory = create_test_provider_factory();
+        let static_file_provider = factory.static_file_provider();
+
+        let headers = random_header_range(&mut generators::rng(), 0..10, B256::ZERO);
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        for header in &headers {
+            writer.append_header(header.header().clone(), U256::ZERO, header.hash()).unwrap();
+        }
+        writer.commit().unwrap();
+        drop(writer);
+
+        // the database overlaps with the last five headers of the static file
+        let provider_rw = factory.provider_rw().unwrap();
+        for header in &headers[5..] {
+            provider_rw.tx_ref().put::<Headers>(header.number, header.header().clone()).unwrap();
+        }
+        provider_rw.commit().unwrap();
+
+        for validation in [StaticFileValidation::Sampled, StaticFileValidation::Full] {
+            assert!(static_file_provider
+                .validate_against_database(&factory.provider().unwrap(), validation)
+                .is_ok());
+        }
+
+        // a divergent database entry rejects the static file
+        let provider_rw = factory.provider_rw().unwrap();
+        let mut divergent = headers[7].header().clone();
+        divergent.gas_used += 1;
+        provider_rw.tx_ref().put::<Headers>(7, divergent).unwrap();
+        provider_rw.commit().unwrap();
+
+        for validation in [StaticFileValidation::Sampled, StaticFileValidation::Full] {
+            assert_matches!(
+                static_file_provider
+                    .validate_against_database(&factory.provider().unwrap(), validation),
+                Err(ProviderError::StaticFileDatabaseMismatch(StaticFileSegment::Headers, 7))
+            );
+        }
+    }
+
+    /// Writes the transactions of the blocks to static files.
+    fn write_transactions(static_file_provider: &StaticFileProvider, blocks: &[SealedBlock]) {
+        let mut writer =
+            static_file_provider.latest_writer(StaticFileSegment::Transactions).unwrap();
+        let mut tx_num = 0;
+        for block in blocks {
+            writer.increment_block(StaticFileSegment::Transactions, block.number).unwrap();
+            for tx in &block.body {
+                writer.append_transaction(tx_num, tx.clone().into()).unwrap();
+                tx_num += 1;
+            }
+        }
+        writer.commit().unwrap();
+    }
+
+    #[test]
+    fn senders_from_static_files() {
+        let factory = create_test_provider_factory();
+        let static_file_provider = factory.static_file_provider();
+
+        let blocks = random_block_range(&mut generators::rng(), 0..=2, B256::ZERO, 2..3);
+        write_transactions(&static_file_provider, &blocks);
+
+        // only the senders of the first two blocks are moved to static files. They're random, so
+        // they can't be confused with the senders recovered from the transactions.
+        let static_file_senders = (0..4).map(|_| Address::random()).collect::<Vec<_>>();
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Senders).unwrap();
+        let mut tx_num = 0;
+        for (block, senders) in blocks[..2].iter().zip(static_file_senders.chunks(2)) {
+            writer.increment_block(StaticFileSegment::Senders, block.number).unwrap();
+            for sender in senders {
+                writer.append_sender(tx_num, *sender).unwrap();
+                tx_num += 1;
+            }
+        }
+        writer.commit().unwrap();
+        drop(writer);
+
+        assert_eq!(
+            static_file_provider.get_highest_static_file_block(StaticFileSegment::Senders),
+            Some(1)
+        );
+        assert_eq!(
+            static_file_provider.get_highest_static_file_tx(StaticFileSegment::Senders),
+            Some(3)
+        );
+
+        let jar_provider = static_file_provider
+            .get_segment_provider_from_transaction(StaticFileSegment::Senders, 0, None)
+            .unwrap();
+        assert_eq!(jar_provider.senders_by_tx_range(0..4).unwrap(), static_file_senders);
+        assert_eq!(jar_provider.transaction_sender(2).unwrap(), Some(static_file_senders[2]));
+        drop(jar_provider);
+
+        // the senders of the last block are recovered from the transactions
+        let recovered = blocks[2].senders().unwrap();
+        let mut expected = static_file_senders.clone();
+        expected.extend(recovered.iter().copied());
+        assert_eq!(static_file_provider.senders_by_tx_range(0..6).unwrap(), expected);
+        assert_eq!(static_file_provider.senders_by_tx_range(2..5).unwrap(), expected[2..5]);
+        assert_eq!(static_file_provider.transaction_sender(3).unwrap(), Some(expected[3]));
+        assert_eq!(static_file_provider.transaction_sender(5).unwrap(), Some(recovered[1]));
+    }
+
+    #[test]
+    fn senders_recovered_without_senders_static_files() {
+        let factory = create_test_provider_factory();
+        let static_file_provider = factory.static_file_provider();
+
+        let blocks = random_block_range(&mut generators::rng(), 0..=2, B256::ZERO, 2..3);
+        write_transactions(&static_file_provider, &blocks);
+        assert_eq!(
+            static_file_provider.get_highest_static_file_tx(StaticFileSegment::Senders),
+            None
+        );
+
+        let expected = blocks.iter().flat_map(|block| block.senders().unwrap()).collect::<Vec<_>>();
+
+        let jar_provider = static_file_provider
+            .get_segment_provider_from_transaction(StaticFileSegment::Transactions, 0, None)
+            .unwrap();
+        assert_eq!(jar_provider.senders_by_tx_range(0..6).unwrap(), expected);
+        assert_eq!(jar_provider.transaction_sender(4).unwrap(), Some(expected[4]));
+        drop(jar_provider);
+
+        assert_eq!(static_file_provider.senders_by_tx_range(0..6).unwrap(), expected);
+        assert_eq!(static_file_provider.transaction_sender(1).unwrap(), Some(expected[1]));
+        assert_eq!(static_file_provider.static_file_sender(1).unwrap(), None);
+    }
 }
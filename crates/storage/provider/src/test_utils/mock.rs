@@ -1,19 +1,21 @@
 use crate::{
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    ChainSpecProvider, ChangeSetReader, EvmEnvProvider, FullExecutionDataProvider, HeaderProvider,
-    ReceiptProviderIdExt, RequestsProvider, StateProvider, StateProviderBox, StateProviderFactory,
-    StateRootProvider, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    AccountRange, AccountRangeEntry, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
+    FullExecutionDataProvider, HeaderProvider, InvalidHeadersReader, ReceiptProviderIdExt,
+    RequestsProvider, StateProvider, StateProviderBox, StateProviderFactory, StateRootProvider,
+    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use parking_lot::Mutex;
 use reth_db_api::models::{AccountBeforeTx, StoredBlockBodyIndices};
-use reth_evm::ConfigureEvmEnv;
+use reth_evm::{provider::pending_block_header, ConfigureEvmEnv};
 use reth_primitives::{
-    keccak256, proofs::AccountProof, Account, Address, Block, BlockHash, BlockHashOrNumber,
-    BlockId, BlockNumber, BlockWithSenders, Bytecode, Bytes, ChainInfo, ChainSpec, Header, Receipt,
-    SealedBlock, SealedBlockWithSenders, SealedHeader, StorageKey, StorageValue, TransactionMeta,
-    TransactionSigned, TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, Withdrawals, B256,
-    U256,
+    keccak256,
+    proofs::{storage_root_unhashed, AccountProof},
+    Account, Address, Block, BlockHash, BlockHashOrNumber, BlockId, BlockNumber, BlockWithSenders,
+    Bytecode, Bytes, ChainInfo, ChainSpec, Header, Receipt, SealedBlock, SealedBlockWithSenders,
+    SealedHeader, StorageKey, StorageValue, TransactionMeta, TransactionSigned,
+    TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, Withdrawals, B256, U256,
 };
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
 use reth_trie::updates::TrieUpdates;
@@ -36,6 +38,8 @@ pub struct MockEthProvider {
     pub headers: Arc<Mutex<HashMap<B256, Header>>>,
     /// Local account store
     pub accounts: Arc<Mutex<HashMap<Address, ExtendedAccount>>>,
+    /// Local receipt store, keyed by block hash
+    pub receipts: Arc<Mutex<HashMap<B256, Vec<Receipt>>>>,
     /// Local chain spec
     pub chain_spec: Arc<ChainSpec>,
 }
@@ -46,6 +50,7 @@ impl Default for MockEthProvider {
             blocks: Default::default(),
             headers: Default::default(),
             accounts: Default::default(),
+            receipts: Default::default(),
             chain_spec: Arc::new(reth_primitives::ChainSpecBuilder::mainnet().build()),
         }
     }
@@ -115,6 +120,11 @@ impl MockEthProvider {
         }
     }
 
+    /// Add the receipts of a block to local receipt store
+    pub fn add_receipts(&self, block_hash: B256, receipts: Vec<Receipt>) {
+        self.receipts.lock().insert(block_hash, receipts);
+    }
+
     /// Add account to local account store
     pub fn add_account(&self, address: Address, account: ExtendedAccount) {
         self.accounts.lock().insert(address, account);
@@ -342,12 +352,21 @@ impl ReceiptProvider for MockEthProvider {
         Ok(None)
     }
 
-    fn receipt_by_hash(&self, _hash: TxHash) -> ProviderResult<Option<Receipt>> {
-        Ok(None)
+    fn receipt_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Receipt>> {
+        let Some((_, meta)) = self.transaction_by_hash_with_meta(hash)? else { return Ok(None) };
+        let receipts = self.receipts.lock();
+        Ok(receipts
+            .get(&meta.block_hash)
+            .and_then(|receipts| receipts.get(meta.index as usize))
+            .cloned())
     }
 
-    fn receipts_by_block(&self, _block: BlockHashOrNumber) -> ProviderResult<Option<Vec<Receipt>>> {
-        Ok(None)
+    fn receipts_by_block(&self, block: BlockHashOrNumber) -> ProviderResult<Option<Vec<Receipt>>> {
+        let hash = match block {
+            BlockHashOrNumber::Hash(hash) => Some(hash),
+            BlockHashOrNumber::Number(number) => self.block_hash(number)?,
+        };
+        Ok(hash.and_then(|hash| self.receipts.lock().get(&hash).cloned()))
     }
 
     fn receipts_by_tx_range(
@@ -462,16 +481,26 @@ impl BlockReader for MockEthProvider {
         Ok(None)
     }
 
-    fn block_body_indices(&self, _num: u64) -> ProviderResult<Option<StoredBlockBodyIndices>> {
+    fn block_body_indices(&self, num: u64) -> ProviderResult<Option<StoredBlockBodyIndices>> {
+        // transactions are numbered in the iteration order of the blocks, see `transaction_by_id`
+        let lock = self.blocks.lock();
+        let mut first_tx_num = 0;
+        for block in lock.values() {
+            let tx_count = block.body.len() as u64;
+            if block.number == num {
+                return Ok(Some(StoredBlockBodyIndices { first_tx_num, tx_count }))
+            }
+            first_tx_num += tx_count;
+        }
         Ok(None)
     }
 
     fn block_with_senders(
         &self,
-        _id: BlockHashOrNumber,
+        id: BlockHashOrNumber,
         _transaction_kind: TransactionVariant,
     ) -> ProviderResult<Option<BlockWithSenders>> {
-        Ok(None)
+        Ok(self.block(id)?.and_then(|block| block.with_recovered_senders()))
     }
 
     fn block_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<Block>> {
@@ -570,6 +599,26 @@ impl StateProvider for MockEthProvider {
     fn proof(&self, address: Address, _keys: &[B256]) -> ProviderResult<AccountProof> {
         Ok(AccountProof::new(address))
     }
+
+    fn account_range(&self, start: B256, limit: usize) -> ProviderResult<AccountRange> {
+        let lock = self.accounts.lock();
+        let mut accounts = lock
+            .iter()
+            .map(|(address, account)| AccountRangeEntry {
+                hashed_address: keccak256(address),
+                account: account.account,
+                storage_root: storage_root_unhashed(
+                    account.storage.iter().map(|(key, value)| (*key, *value)),
+                ),
+            })
+            .filter(|entry| entry.hashed_address >= start)
+            .collect::<Vec<_>>();
+        accounts.sort_unstable_by_key(|entry| entry.hashed_address);
+
+        let next = accounts.get(limit).map(|entry| entry.hashed_address);
+        accounts.truncate(limit);
+        Ok(AccountRange { accounts, next })
+    }
 }
 
 impl EvmEnvProvider for MockEthProvider {
@@ -599,6 +648,25 @@ impl EvmEnvProvider for MockEthProvider {
         Ok(())
     }
 
+    fn fill_env_for_pending<EvmConfig>(
+        &self,
+        _cfg: &mut CfgEnvWithHandlerCfg,
+        _block_env: &mut BlockEnv,
+        _evm_config: EvmConfig,
+    ) -> ProviderResult<SealedHeader>
+    where
+        EvmConfig: ConfigureEvmEnv,
+    {
+        let tip = self
+            .headers
+            .lock()
+            .iter()
+            .max_by_key(|(_, header)| header.number)
+            .map(|(hash, header)| SealedHeader::new(header.clone(), *hash))
+            .ok_or(ProviderError::BestBlockNotFound)?;
+        Ok(pending_block_header(tip, &self.chain_spec))
+    }
+
     fn fill_block_env_at(
         &self,
         _block_env: &mut BlockEnv,
@@ -703,4 +771,18 @@ impl ChangeSetReader for MockEthProvider {
     ) -> ProviderResult<Vec<AccountBeforeTx>> {
         Ok(Vec::default())
     }
+
+    fn account_next_change_block(
+        &self,
+        _address: Address,
+        _block_number: BlockNumber,
+    ) -> ProviderResult<Option<BlockNumber>> {
+        Ok(None)
+    }
+}
+
+impl InvalidHeadersReader for MockEthProvider {
+    fn invalid_headers(&self) -> ProviderResult<Vec<SealedHeader>> {
+        Ok(Vec::new())
+    }
 }
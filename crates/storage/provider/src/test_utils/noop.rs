@@ -1,10 +1,10 @@
 use crate::{
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider, PruneCheckpointReader,
-    ReceiptProviderIdExt, RequestsProvider, StageCheckpointReader, StateProvider, StateProviderBox,
-    StateProviderFactory, StateRootProvider, TransactionVariant, TransactionsProvider,
-    WithdrawalsProvider,
+    AccountRange, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader,
+    BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider,
+    InvalidHeadersReader, PruneCheckpointReader, ReceiptProviderIdExt, RequestsProvider,
+    StageCheckpointReader, StateProvider, StateProviderBox, StateProviderFactory,
+    StateRootProvider, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use reth_db_api::models::{AccountBeforeTx, StoredBlockBodyIndices};
 use reth_evm::ConfigureEvmEnv;
@@ -298,6 +298,14 @@ impl ChangeSetReader for NoopProvider {
     ) -> ProviderResult<Vec<AccountBeforeTx>> {
         Ok(Vec::default())
     }
+
+    fn account_next_change_block(
+        &self,
+        _address: Address,
+        _block_number: BlockNumber,
+    ) -> ProviderResult<Option<BlockNumber>> {
+        Ok(None)
+    }
 }
 
 impl StateRootProvider for NoopProvider {
@@ -329,6 +337,10 @@ impl StateProvider for NoopProvider {
     fn proof(&self, address: Address, _keys: &[B256]) -> ProviderResult<AccountProof> {
         Ok(AccountProof::new(address))
     }
+
+    fn account_range(&self, _start: B256, _limit: usize) -> ProviderResult<AccountRange> {
+        Ok(AccountRange::default())
+    }
 }
 
 impl EvmEnvProvider for NoopProvider {
@@ -358,6 +370,18 @@ impl EvmEnvProvider for NoopProvider {
         Ok(())
     }
 
+    fn fill_env_for_pending<EvmConfig>(
+        &self,
+        _cfg: &mut CfgEnvWithHandlerCfg,
+        _block_env: &mut BlockEnv,
+        _evm_config: EvmConfig,
+    ) -> ProviderResult<SealedHeader>
+    where
+        EvmConfig: ConfigureEvmEnv,
+    {
+        Ok(SealedHeader::default())
+    }
+
     fn fill_block_env_at(
         &self,
         _block_env: &mut BlockEnv,
@@ -442,6 +466,12 @@ impl StageCheckpointReader for NoopProvider {
     }
 }
 
+impl InvalidHeadersReader for NoopProvider {
+    fn invalid_headers(&self) -> ProviderResult<Vec<SealedHeader>> {
+        Ok(Vec::new())
+    }
+}
+
 impl WithdrawalsProvider for NoopProvider {
     fn withdrawals_by_block(
         &self,
@@ -2,6 +2,7 @@ use auto_impl::auto_impl;
 use reth_db_api::models::BlockNumberAddress;
 use reth_primitives::{Account, Address, BlockNumber, StorageEntry, B256};
 use reth_storage_errors::provider::ProviderResult;
+use revm::db::BundleState;
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap},
     ops::{Range, RangeInclusive},
@@ -62,4 +63,17 @@ pub trait HashingWriter: Send + Sync {
         end_block_hash: B256,
         expected_state_root: B256,
     ) -> ProviderResult<()>;
+
+    /// Same as [`HashingWriter::insert_hashes`] for a single block, but takes the changed accounts
+    /// and storages directly from the block's [`BundleState`] instead of re-deriving them from the
+    /// changeset tables.
+    ///
+    /// The resulting state root is compared with `expected_state_root`.
+    fn insert_hashes_for_bundle(
+        &self,
+        bundle: &BundleState,
+        block_number: BlockNumber,
+        block_hash: B256,
+        expected_state_root: B256,
+    ) -> ProviderResult<()>;
 }
@@ -0,0 +1,18 @@
+use reth_errors::ProviderResult;
+use reth_primitives::SealedHeader;
+
+/// Functionality to read the headers of blocks that were rejected as invalid from the database.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait InvalidHeadersReader: Send + Sync {
+    /// Returns all persisted invalid headers.
+    fn invalid_headers(&self) -> ProviderResult<Vec<SealedHeader>>;
+}
+
+/// Functionality to write the headers of blocks that were rejected as invalid to the database.
+pub trait InvalidHeadersWriter: Send + Sync {
+    /// Saves the given invalid header in the DB.
+    ///
+    /// If more than `max_entries` headers are persisted afterwards, the headers with the lowest
+    /// block numbers are removed.
+    fn save_invalid_header(&self, header: SealedHeader, max_entries: usize) -> ProviderResult<()>;
+}
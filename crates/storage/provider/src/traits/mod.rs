@@ -50,3 +50,6 @@ pub use tree_viewer::TreeViewer;
 
 mod finalized_block;
 pub use finalized_block::{FinalizedBlockReader, FinalizedBlockWriter};
+
+mod invalid_headers;
+pub use invalid_headers::{InvalidHeadersReader, InvalidHeadersWriter};
@@ -52,4 +52,25 @@ pub trait ChangeSetReader: Send + Sync {
         &self,
         block_number: BlockNumber,
     ) -> ProviderResult<Vec<AccountBeforeTx>>;
+
+    /// Returns the account state from before the given block, if the block changed the account.
+    fn account_before_block(
+        &self,
+        block_number: BlockNumber,
+        address: Address,
+    ) -> ProviderResult<Option<AccountBeforeTx>> {
+        Ok(self
+            .account_block_changeset(block_number)?
+            .into_iter()
+            .find(|account| account.address == address))
+    }
+
+    /// Returns the first block at or after `block_number` that changed the account.
+    ///
+    /// Blocks whose history was pruned are not considered.
+    fn account_next_change_block(
+        &self,
+        address: Address,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Option<BlockNumber>>;
 }
@@ -2,8 +2,8 @@ use super::{AccountReader, BlockHashReader, BlockIdReader, StateRootProvider};
 use auto_impl::auto_impl;
 use reth_execution_types::ExecutionOutcome;
 use reth_primitives::{
-    proofs::AccountProof, Address, BlockHash, BlockId, BlockNumHash, BlockNumber, BlockNumberOrTag,
-    Bytecode, StorageKey, StorageValue, B256, KECCAK_EMPTY, U256,
+    proofs::AccountProof, Account, Address, BlockHash, BlockId, BlockNumHash, BlockNumber,
+    BlockNumberOrTag, Bytecode, StorageKey, StorageValue, B256, KECCAK_EMPTY, U256,
 };
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
 
@@ -26,6 +26,10 @@ pub trait StateProvider: BlockHashReader + AccountReader + StateRootProvider + S
     /// Get account and storage proofs.
     fn proof(&self, address: Address, keys: &[B256]) -> ProviderResult<AccountProof>;
 
+    /// Returns up to `limit` accounts ordered by hashed address, starting at the first account
+    /// with a hashed address greater or equal to `start`.
+    fn account_range(&self, start: B256, limit: usize) -> ProviderResult<AccountRange>;
+
     /// Get account code by its address.
     ///
     /// Returns `None` if the account doesn't exist or account is not a contract
@@ -74,6 +78,27 @@ pub trait StateProvider: BlockHashReader + AccountReader + StateRootProvider + S
     }
 }
 
+/// A page of accounts ordered by hashed address, see [`StateProvider::account_range`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountRange {
+    /// The accounts of this page.
+    pub accounts: Vec<AccountRangeEntry>,
+    /// The hashed address of the first account of the next page, `None` if this is the last
+    /// page.
+    pub next: Option<B256>,
+}
+
+/// An account returned by [`StateProvider::account_range`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountRangeEntry {
+    /// The hashed address of the account.
+    pub hashed_address: B256,
+    /// The account info.
+    pub account: Account,
+    /// The storage root of the account.
+    pub storage_root: B256,
+}
+
 /// Light wrapper that returns `StateProvider` implementations that correspond to the given
 /// `BlockNumber`, the latest state, or the pending state.
 ///
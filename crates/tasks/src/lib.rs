@@ -495,6 +495,36 @@ impl TaskExecutor {
         name: &'static str,
         f: impl FnOnce(GracefulShutdown) -> F,
     ) -> JoinHandle<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_critical_with_graceful_shutdown_signal_as(name, f, TaskKind::Default)
+    }
+
+    /// This spawns a critical blocking task onto the runtime.
+    ///
+    /// If this task panics, the [`TaskManager`] is notified.
+    /// The [`TaskManager`] will wait until the given future has completed before shutting down.
+    ///
+    /// See also [`Self::spawn_critical_with_graceful_shutdown_signal`].
+    pub fn spawn_critical_blocking_with_graceful_shutdown_signal<F>(
+        &self,
+        name: &'static str,
+        f: impl FnOnce(GracefulShutdown) -> F,
+    ) -> JoinHandle<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_critical_with_graceful_shutdown_signal_as(name, f, TaskKind::Blocking)
+    }
+
+    /// Spawns a critical task with a graceful shutdown signal depending on the given [`TaskKind`]
+    fn spawn_critical_with_graceful_shutdown_signal_as<F>(
+        &self,
+        name: &'static str,
+        f: impl FnOnce(GracefulShutdown) -> F,
+        task_kind: TaskKind,
+    ) -> JoinHandle<()>
     where
         F: Future<Output = ()> + Send + 'static,
     {
@@ -516,7 +546,7 @@ impl TaskExecutor {
             .map(|_| ())
             .in_current_span();
 
-        self.handle.spawn(task)
+        self.spawn_on_rt(task, task_kind)
     }
 
     /// This spawns a regular task onto the runtime.
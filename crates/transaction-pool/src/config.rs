@@ -36,6 +36,10 @@ pub struct PoolConfig {
     /// How to handle locally received transactions:
     /// [`TransactionOrigin::Local`](crate::TransactionOrigin).
     pub local_transactions_config: LocalTransactionConfig,
+    /// Whether non-local transactions with a nonce gap are rejected instead of being queued.
+    ///
+    /// Local transactions are always queued.
+    pub reject_nonce_gaps: bool,
 }
 
 impl PoolConfig {
@@ -59,6 +63,7 @@ impl Default for PoolConfig {
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
             price_bumps: Default::default(),
             local_transactions_config: Default::default(),
+            reject_nonce_gaps: false,
         }
     }
 }
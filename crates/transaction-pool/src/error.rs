@@ -53,6 +53,10 @@ pub enum PoolErrorKind {
     /// respect the size limits of the pool.
     #[error("transaction discarded outright due to pool size constraints")]
     DiscardedOnInsert,
+    /// Thrown when a gossiped transaction is dropped by the configured
+    /// [`GossipTransactionFilter`](crate::filter::GossipTransactionFilter) before validation.
+    #[error("transaction rejected by gossip filter")]
+    FilteredOnArrival,
     /// Thrown when the transaction is considered invalid.
     #[error(transparent)]
     InvalidTransaction(#[from] InvalidPoolTransactionError),
@@ -120,6 +124,10 @@ impl PoolError {
                 // valid tx but dropped due to size constraints
                 false
             }
+            PoolErrorKind::FilteredOnArrival => {
+                // dropped by a local policy, the tx itself may be perfectly valid
+                false
+            }
             PoolErrorKind::InvalidTransaction(err) => {
                 // transaction rejected because it violates constraints
                 err.is_bad_transaction()
@@ -195,6 +203,10 @@ pub enum InvalidPoolTransactionError {
     /// Thrown if the transaction's would require an account to be overdrawn
     #[error("transaction overdraws from account")]
     Overdraft,
+    /// Thrown if the transaction has a nonce gap and the pool is configured to reject those for
+    /// non-local transactions, see [`PoolConfig::reject_nonce_gaps`](crate::PoolConfig).
+    #[error("transaction nonce gap")]
+    NonceGap,
     /// Eip-4844 related errors
     #[error(transparent)]
     Eip4844(#[from] Eip4844PoolTransactionError),
@@ -261,6 +273,10 @@ impl InvalidPoolTransactionError {
             }
             Self::IntrinsicGasTooLow => true,
             Self::Overdraft => false,
+            Self::NonceGap => {
+                // it is possible that the pool sees `nonce n` before `nonce n-1`
+                false
+            }
             Self::Other(err) => err.is_bad_transaction(),
             Self::Eip4844(eip4844_err) => {
                 match eip4844_err {
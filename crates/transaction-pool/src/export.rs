@@ -0,0 +1,115 @@
+//! Export and import of the transactions of a pool.
+
+use crate::TransactionOrigin;
+use reth_primitives::TransactionSigned;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The pending and queued transactions of a pool, as returned by
+/// [`Pool::export_state`](crate::Pool::export_state).
+///
+/// This can be used to hand the transactions of a retiring node over to the node that replaces it,
+/// see [`Pool::import_state`](crate::Pool::import_state).
+///
+/// Blob transactions are exported without their sidecar, the sidecar is expected to be available
+/// in the blob store of the importing pool.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PoolState {
+    /// The exported transactions, pending transactions first.
+    pub transactions: Vec<ExportedTransaction>,
+}
+
+impl PoolState {
+    /// Returns the number of exported transactions.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if no transactions were exported.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+}
+
+/// A transaction of a [`PoolState`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExportedTransaction {
+    /// Where the transaction originated from.
+    pub origin: TransactionOrigin,
+    /// The signed transaction, without the blob sidecar.
+    pub transaction: TransactionSigned,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blobstore::InMemoryBlobStore, test_utils::TransactionBuilder,
+        validate::EthTransactionValidatorBuilder, CoinbaseTipOrdering, EthPooledTransaction, Pool,
+        TransactionPool,
+    };
+    use reth_primitives::{
+        constants::MIN_PROTOCOL_BASE_FEE, TryFromRecoveredTransaction, B256, MAINNET, U256,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+
+    fn transfer(signer: B256) -> TransactionSigned {
+        TransactionBuilder::default()
+            .signer(signer)
+            .gas_limit(21_000)
+            .max_fee_per_gas(MIN_PROTOCOL_BASE_FEE as u128)
+            .max_priority_fee_per_gas(MIN_PROTOCOL_BASE_FEE as u128)
+            .into_eip1559()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn export_and_import_state() {
+        let provider = MockEthProvider::default();
+        let new_pool = || {
+            let blob_store = InMemoryBlobStore::default();
+            let validator = EthTransactionValidatorBuilder::new(MAINNET.clone())
+                .build(provider.clone(), blob_store.clone());
+            Pool::new(validator, CoinbaseTipOrdering::default(), blob_store, Default::default())
+        };
+
+        let kept = transfer(B256::random());
+        let dropped = transfer(B256::random());
+        for tx in [&kept, &dropped] {
+            provider.add_account(tx.recover_signer().unwrap(), ExtendedAccount::new(0, U256::MAX));
+        }
+
+        let pool = new_pool();
+        for (origin, tx) in
+            [(TransactionOrigin::Local, &kept), (TransactionOrigin::External, &dropped)]
+        {
+            let tx = EthPooledTransaction::try_from_recovered_transaction(
+                tx.clone().into_ecrecovered().unwrap(),
+            )
+            .unwrap();
+            pool.add_transaction(origin, tx).await.unwrap();
+        }
+
+        let state = pool.export_state();
+        assert_eq!(state.len(), 2);
+
+        // the state survives serialization
+        let state: PoolState =
+            serde_json::from_str(&serde_json::to_string(&state).unwrap()).unwrap();
+
+        // the second transaction was included in the meantime, which invalidates it
+        let sender = dropped.recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(1, U256::MAX));
+
+        let pool = new_pool();
+        let results = pool.import_state(state).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.iter().filter(|res| res.is_ok()).count(), 1);
+
+        assert_eq!(pool.len(), 1);
+        assert!(pool.contains(&kept.hash()));
+        assert!(!pool.contains(&dropped.hash()));
+        assert!(pool.get(&kept.hash()).unwrap().origin.is_local());
+    }
+}
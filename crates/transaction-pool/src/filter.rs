@@ -0,0 +1,47 @@
+//! Filters for transactions received over the network.
+
+use crate::traits::{PoolTransaction, TransactionOrigin};
+
+/// A filter that decides whether a transaction received via network gossip should be considered
+/// by the pool at all.
+///
+/// The filter is consulted _before_ the transaction is validated, so it can be used to shed load
+/// cheaply, for example by dropping transactions below a minimum gas price during spam.
+///
+/// Only [`TransactionOrigin::External`] transactions are passed through the filter, locally
+/// submitted transactions always bypass it.
+pub trait GossipTransactionFilter<T>: Send + Sync + 'static {
+    /// Returns `true` if the transaction should be kept and handed to the validator, `false` if it
+    /// should be dropped.
+    fn keep(&self, origin: TransactionOrigin, transaction: &T) -> bool;
+}
+
+impl<T, F> GossipTransactionFilter<T> for F
+where
+    F: Fn(TransactionOrigin, &T) -> bool + Send + Sync + 'static,
+{
+    fn keep(&self, origin: TransactionOrigin, transaction: &T) -> bool {
+        self(origin, transaction)
+    }
+}
+
+/// A [`GossipTransactionFilter`] that drops transactions whose max fee per gas is below the
+/// configured minimum.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MinGasPriceFilter {
+    /// The minimum max fee per gas a gossiped transaction must offer.
+    pub min_gas_price: u128,
+}
+
+impl MinGasPriceFilter {
+    /// Creates a new filter with the given minimum gas price.
+    pub const fn new(min_gas_price: u128) -> Self {
+        Self { min_gas_price }
+    }
+}
+
+impl<T: PoolTransaction> GossipTransactionFilter<T> for MinGasPriceFilter {
+    fn keep(&self, _origin: TransactionOrigin, transaction: &T) -> bool {
+        transaction.max_fee_per_gas() >= self.min_gas_price
+    }
+}
@@ -150,10 +150,18 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![warn(clippy::missing_const_for_fn)]
 
-use crate::{identifier::TransactionId, pool::PoolInner};
+use crate::{
+    error::{PoolError, PoolErrorKind},
+    identifier::TransactionId,
+    pool::PoolInner,
+};
 use aquamarine as _;
 use reth_eth_wire_types::HandleMempoolData;
-use reth_primitives::{Address, BlobTransactionSidecar, PooledTransactionsElement, TxHash, U256};
+use reth_primitives::{
+    Address, BlobTransactionSidecar, FromRecoveredPooledTransaction, IntoRecoveredTransaction,
+    PooledTransactionsElement, PooledTransactionsElementEcRecovered, TransactionSigned,
+    TryFromRecoveredTransaction, TxHash, U256,
+};
 use reth_provider::StateProviderFactory;
 use std::{collections::HashSet, sync::Arc};
 use tokio::sync::mpsc::Receiver;
@@ -167,6 +175,8 @@ pub use crate::{
         TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
     },
     error::PoolResult,
+    export::{ExportedTransaction, PoolState},
+    filter::{GossipTransactionFilter, MinGasPriceFilter},
     ordering::{CoinbaseTipOrdering, Priority, TransactionOrdering},
     pool::{
         blob_tx_priority, fee_delta, state::SubPool, AllTransactionsEvents, FullTransactionEvent,
@@ -180,6 +190,8 @@ pub use crate::{
 };
 
 pub mod error;
+mod export;
+pub mod filter;
 pub mod maintain;
 pub mod metrics;
 pub mod noop;
@@ -233,6 +245,22 @@ where
         self.inner().config()
     }
 
+    /// Sets the [`GossipTransactionFilter`] that is applied to transactions received via network
+    /// gossip before they're validated.
+    ///
+    /// Local transactions always bypass the filter.
+    pub fn set_gossip_filter<F>(&self, filter: F)
+    where
+        F: GossipTransactionFilter<V::Transaction>,
+    {
+        self.pool.set_gossip_filter(Some(Box::new(filter)))
+    }
+
+    /// Removes the configured [`GossipTransactionFilter`], if any.
+    pub fn clear_gossip_filter(&self) {
+        self.pool.set_gossip_filter(None)
+    }
+
     /// Returns future that validates all transaction in the given iterator.
     ///
     /// This returns the validated transactions in the iterator's order.
@@ -272,6 +300,70 @@ where
     pub fn is_exceeded(&self) -> bool {
         self.pool.is_exceeded()
     }
+
+    /// Returns the pending and queued transactions of the pool.
+    ///
+    /// The state can be handed to another pool with [`Pool::import_state`], e.g. to keep the
+    /// mempool across a node upgrade.
+    pub fn export_state(&self) -> PoolState {
+        let AllPoolTransactions { pending, queued } = self.all_transactions();
+        let transactions = pending
+            .into_iter()
+            .chain(queued)
+            .map(|tx| ExportedTransaction {
+                origin: tx.origin,
+                transaction: tx.to_recovered_transaction().into_signed(),
+            })
+            .collect();
+        PoolState { transactions }
+    }
+
+    /// Re-validates the transactions of the given [`PoolState`] against the current state and
+    /// adds the valid ones to the pool, with their original origin.
+    ///
+    /// The sidecars of blob transactions are loaded from the pool's blob store. Blob transactions
+    /// without a stored sidecar and transactions with an invalid signature are dropped.
+    ///
+    /// Returns the outcome of every transaction that was re-validated.
+    pub async fn import_state(&self, state: PoolState) -> Vec<PoolResult<TxHash>> {
+        let mut results = Vec::with_capacity(state.len());
+        let mut remaining = state.transactions;
+        for origin in
+            [TransactionOrigin::Local, TransactionOrigin::Private, TransactionOrigin::External]
+        {
+            let (batch, rest): (Vec<_>, Vec<_>) =
+                remaining.into_iter().partition(|tx| tx.origin == origin);
+            remaining = rest;
+
+            let transactions = batch
+                .into_iter()
+                .filter_map(|tx| self.restore_transaction(tx.transaction))
+                .collect::<Vec<_>>();
+            results.extend(self.add_transactions(origin, transactions).await);
+        }
+        results
+    }
+
+    /// Converts an exported transaction back into a pool transaction.
+    fn restore_transaction(&self, tx: TransactionSigned) -> Option<V::Transaction> {
+        let hash = tx.hash();
+        let Some(tx) = tx.into_ecrecovered() else {
+            trace!(target: "txpool", %hash, "Dropping imported transaction with invalid signature");
+            return None
+        };
+
+        if tx.is_eip4844() {
+            let Some(sidecar) = self.get_blob(hash).ok().flatten() else {
+                trace!(target: "txpool", %hash, "Dropping imported blob transaction, no sidecar");
+                return None
+            };
+            PooledTransactionsElementEcRecovered::try_from_blob_transaction(tx, sidecar)
+                .ok()
+                .map(V::Transaction::from_recovered_pooled_transaction)
+        } else {
+            V::Transaction::try_from_recovered_transaction(tx).ok()
+        }
+    }
 }
 
 impl<Client, S> EthTransactionPool<Client, S>
@@ -338,6 +430,9 @@ where
         origin: TransactionOrigin,
         transaction: Self::Transaction,
     ) -> PoolResult<TransactionEvents> {
+        if self.pool.is_filtered(origin, &transaction) {
+            return Err(PoolError::new(*transaction.hash(), PoolErrorKind::FilteredOnArrival))
+        }
         let (_, tx) = self.validate(origin, transaction).await;
         self.pool.add_transaction_and_subscribe(origin, tx)
     }
@@ -347,6 +442,9 @@ where
         origin: TransactionOrigin,
         transaction: Self::Transaction,
     ) -> PoolResult<TxHash> {
+        if self.pool.is_filtered(origin, &transaction) {
+            return Err(PoolError::new(*transaction.hash(), PoolErrorKind::FilteredOnArrival))
+        }
         let (_, tx) = self.validate(origin, transaction).await;
         let mut results = self.pool.add_transactions(origin, std::iter::once(tx));
         results.pop().expect("result length is the same as the input")
@@ -360,9 +458,32 @@ where
         if transactions.is_empty() {
             return Vec::new()
         }
+
+        // drop filtered transactions before validation, but remember their position so that the
+        // results are still returned in the input order
+        let mut filtered = Vec::new();
+        let transactions = transactions
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, tx)| {
+                if self.pool.is_filtered(origin, &tx) {
+                    filtered.push((idx, *tx.hash()));
+                    None
+                } else {
+                    Some(tx)
+                }
+            })
+            .collect::<Vec<_>>();
+
         let validated = self.validate_all(origin, transactions).await;
+        let mut results =
+            self.pool.add_transactions(origin, validated.into_iter().map(|(_, tx)| tx));
 
-        self.pool.add_transactions(origin, validated.into_iter().map(|(_, tx)| tx))
+        for (idx, hash) in filtered {
+            results.insert(idx, Err(PoolError::new(hash, PoolErrorKind::FilteredOnArrival)));
+        }
+
+        results
     }
 
     fn transaction_event_listener(&self, tx_hash: TxHash) -> Option<TransactionEvents> {
@@ -451,6 +572,13 @@ where
         self.pool.all_transactions()
     }
 
+    fn all_transactions_by_sender(
+        &self,
+        sender: Address,
+    ) -> AllPoolTransactions<Self::Transaction> {
+        self.pool.all_transactions_by_sender(sender)
+    }
+
     fn remove_transactions(
         &self,
         hashes: Vec<TxHash>,
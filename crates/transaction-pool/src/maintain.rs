@@ -5,7 +5,7 @@ use crate::{
     error::PoolError,
     metrics::MaintainPoolMetrics,
     traits::{CanonicalStateUpdate, ChangedAccount, TransactionPool, TransactionPoolExt},
-    BlockInfo,
+    BlockInfo, PoolTransaction, TransactionListenerKind, ValidPoolTransaction,
 };
 use futures_util::{
     future::{BoxFuture, Fuse, FusedFuture},
@@ -15,7 +15,7 @@ use reth_fs_util::FsPathError;
 use reth_primitives::{
     Address, BlockHash, BlockNumber, BlockNumberOrTag, FromRecoveredPooledTransaction,
     IntoRecoveredTransaction, PooledTransactionsElementEcRecovered, TransactionSigned,
-    TryFromRecoveredTransaction,
+    TryFromRecoveredTransaction, TxHash,
 };
 use reth_provider::{
     BlockReaderIdExt, CanonStateNotification, ChainSpecProvider, ExecutionOutcome, ProviderError,
@@ -27,8 +27,11 @@ use std::{
     collections::HashSet,
     hash::{Hash, Hasher},
     path::{Path, PathBuf},
+    pin::pin,
+    sync::Arc,
+    time::Duration,
 };
-use tokio::sync::oneshot;
+use tokio::{sync::oneshot, time::MissedTickBehavior};
 use tracing::{debug, error, info, trace, warn};
 
 /// Additional settings for maintaining the transaction pool
@@ -51,6 +54,10 @@ impl Default for MaintainPoolConfig {
     }
 }
 
+/// The interval at which the local transactions backup file is rewritten, if the local
+/// transactions changed.
+const LOCAL_TXS_BACKUP_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Settings for local transaction backup task
 #[derive(Debug, Clone, Default)]
 pub struct LocalTransactionBackupConfig {
@@ -567,6 +574,8 @@ fn changed_accounts_iter(
 /// Loads transactions from a file, decodes them from the RLP format, and inserts them
 /// into the transaction pool on node boot up.
 /// The file is removed after the transactions have been successfully processed.
+///
+/// Entries that can't be decoded or are no longer valid are skipped.
 async fn load_and_reinsert_transactions<P>(
     pool: P,
     file_path: &Path,
@@ -585,48 +594,119 @@ where
         return Ok(())
     }
 
-    let txs_signed: Vec<TransactionSigned> = alloy_rlp::Decodable::decode(&mut data.as_slice())?;
+    let txs_signed = decode_transactions_backup(&data)?;
 
-    let pool_transactions = txs_signed
-        .into_iter()
-        .filter_map(|tx| tx.try_ecrecovered())
-        .filter_map(|tx| {
-            // Filter out errors
-            <P as TransactionPool>::Transaction::try_from_recovered_transaction(tx).ok()
-        })
-        .collect::<Vec<_>>();
+    let mut pool_transactions = Vec::with_capacity(txs_signed.len());
+    for tx in txs_signed {
+        let hash = tx.hash();
+        let Some(tx) = tx.try_ecrecovered() else {
+            warn!(target: "txpool", %hash, "Skipping saved transaction with invalid signature");
+            continue
+        };
+        match <P as TransactionPool>::Transaction::try_from_recovered_transaction(tx) {
+            Ok(tx) => pool_transactions.push(tx),
+            Err(_) => {
+                warn!(target: "txpool", %hash, "Skipping saved transaction that can't be pooled")
+            }
+        }
+    }
 
     let outcome = pool.add_transactions(crate::TransactionOrigin::Local, pool_transactions).await;
+    let mut num_txs = 0;
+    for res in outcome {
+        match res {
+            Ok(_) => num_txs += 1,
+            Err(err) => warn!(target: "txpool", %err, "Skipping invalid saved transaction"),
+        }
+    }
 
-    info!(target: "txpool", txs_file =?file_path, num_txs=%outcome.len(), "Successfully reinserted local transactions from file");
+    info!(target: "txpool", txs_file =?file_path, %num_txs, "Successfully reinserted local transactions from file");
     reth_fs_util::remove_file(file_path)?;
     Ok(())
 }
 
+/// Decodes the RLP list of transactions of a backup file.
+///
+/// Entries that can't be decoded are skipped, if the file is truncated, all entries that were
+/// written completely are returned.
+fn decode_transactions_backup(mut data: &[u8]) -> Result<Vec<TransactionSigned>, alloy_rlp::Error> {
+    let header = alloy_rlp::Header::decode(&mut data)?;
+    if !header.list {
+        return Err(alloy_rlp::Error::UnexpectedString)
+    }
+
+    let mut payload = &data[..data.len().min(header.payload_length)];
+    let mut txs = Vec::new();
+    while !payload.is_empty() {
+        let mut item = payload;
+        let item_len = match alloy_rlp::Header::decode(&mut item) {
+            Ok(item_header) => payload.len() - item.len() + item_header.payload_length,
+            Err(err) => {
+                warn!(target: "txpool", %err, "Failed to decode saved transactions, skipping the rest");
+                break
+            }
+        };
+        if item_len > payload.len() {
+            warn!(target: "txpool", "Skipping truncated saved transaction");
+            break
+        }
+
+        let (mut raw, rest) = payload.split_at(item_len);
+        payload = rest;
+        match <TransactionSigned as alloy_rlp::Decodable>::decode(&mut raw) {
+            Ok(tx) => txs.push(tx),
+            Err(err) => warn!(target: "txpool", %err, "Skipping corrupt saved transaction"),
+        }
+    }
+
+    Ok(txs)
+}
+
+/// Writes the local transactions of the pool to the file, or removes the file if there are none.
 fn save_local_txs_backup<P>(pool: P, file_path: &Path)
 where
     P: TransactionPool,
 {
     let local_transactions = pool.get_local_transactions();
-    if local_transactions.is_empty() {
+    write_txs_backup(into_signed_transactions(local_transactions), file_path)
+}
+
+/// Converts the pooled transactions into the transactions that are written to the backup file.
+fn into_signed_transactions<T: PoolTransaction>(
+    transactions: Vec<Arc<ValidPoolTransaction<T>>>,
+) -> Vec<TransactionSigned> {
+    transactions.into_iter().map(|tx| tx.to_recovered_transaction().into_signed()).collect()
+}
+
+/// Writes the transactions to the file, or removes the file if there are none.
+///
+/// The file is replaced atomically, so an interrupted write can't corrupt the backup.
+fn write_txs_backup(transactions: Vec<TransactionSigned>, file_path: &Path) {
+    if transactions.is_empty() {
         trace!(target: "txpool", "no local transactions to save");
+        if file_path.exists() {
+            if let Err(err) = reth_fs_util::remove_file(file_path) {
+                warn!(target: "txpool", %err, txs_file=?file_path, "Failed to remove local transactions file");
+            }
+        }
         return
     }
 
-    let local_transactions = local_transactions
-        .into_iter()
-        .map(|tx| tx.to_recovered_transaction().into_signed())
-        .collect::<Vec<_>>();
-
-    let num_txs = local_transactions.len();
+    let num_txs = transactions.len();
     let mut buf = Vec::new();
-    alloy_rlp::encode_list(&local_transactions, &mut buf);
-    info!(target: "txpool", txs_file =?file_path, num_txs=%num_txs, "Saving current local transactions");
-    let parent_dir = file_path.parent().map(std::fs::create_dir_all).transpose();
-
-    match parent_dir.map(|_| reth_fs_util::write(file_path, buf)) {
+    alloy_rlp::encode_list(&transactions, &mut buf);
+    debug!(target: "txpool", txs_file =?file_path, num_txs=%num_txs, "Saving current local transactions");
+
+    let tmp_path = file_path.with_extension("tmp");
+    let res = file_path
+        .parent()
+        .map(reth_fs_util::create_dir_all)
+        .transpose()
+        .and_then(|_| reth_fs_util::write(&tmp_path, buf))
+        .and_then(|_| reth_fs_util::rename(&tmp_path, file_path));
+    match res {
         Ok(_) => {
-            info!(target: "txpool", txs_file=?file_path, "Wrote local transactions to file");
+            debug!(target: "txpool", txs_file=?file_path, "Wrote local transactions to file");
         }
         Err(err) => {
             warn!(target: "txpool", %err, txs_file=?file_path, "Failed to write local transactions to file");
@@ -650,6 +730,10 @@ pub enum TransactionsBackupError {
 
 /// Task which manages saving local transactions to the persistent file in case of shutdown.
 /// Reloads the transactions from the file on the boot up and inserts them into the pool.
+///
+/// While the task is running, the file is rewritten at most once per second if a local
+/// transaction was added to or removed from the pool, so the local transactions also survive an
+/// unclean shutdown. The file is written on a blocking task.
 pub async fn backup_local_transactions_task<P>(
     shutdown: reth_tasks::shutdown::GracefulShutdown,
     pool: P,
@@ -666,9 +750,49 @@ pub async fn backup_local_transactions_task<P>(
         error!(target: "txpool", "{}", err)
     }
 
-    let graceful_guard = shutdown.await;
+    // all local transactions are tracked, even the ones that aren't propagated
+    let mut new_transactions = pool.new_transactions_listener_for(TransactionListenerKind::All);
+    let mut local_transactions =
+        pool.get_local_transactions().iter().map(|tx| *tx.hash()).collect::<HashSet<_>>();
+    let mut changed = true;
+    let mut interval = tokio::time::interval(LOCAL_TXS_BACKUP_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let mut shutdown = pin!(shutdown);
+    let graceful_guard = loop {
+        tokio::select! {
+            graceful_guard = &mut shutdown => break graceful_guard,
+            Some(event) = new_transactions.recv() => {
+                if event.transaction.origin.is_local() {
+                    changed |= local_transactions.insert(*event.transaction.hash());
+                }
+            }
+            _ = interval.tick() => {
+                // local transactions that were mined, replaced or evicted since the last tick
+                let num_local_transactions = local_transactions.len();
+                local_transactions.retain(|hash| pool.contains(hash));
+                if !changed && local_transactions.len() == num_local_transactions {
+                    continue
+                }
+                changed = false;
+
+                let transactions = into_signed_transactions(
+                    pool.get_all(local_transactions.iter().copied().collect()),
+                );
+                let file_path = transactions_path.clone();
+                if let Err(err) = tokio::task::spawn_blocking(move || {
+                    write_txs_backup(transactions, &file_path)
+                })
+                .await
+                {
+                    error!(target: "txpool", %err, "Failed to write local transactions backup");
+                }
+            }
+        }
+    };
 
     // write transactions to disk
+    info!(target: "txpool", txs_file=?transactions_path, "Saving local transactions on shutdown");
     save_local_txs_backup(pool, &transactions_path);
 
     drop(graceful_guard)
@@ -679,7 +803,7 @@ mod tests {
     use super::*;
     use crate::{
         blobstore::InMemoryBlobStore, validate::EthTransactionValidatorBuilder,
-        CoinbaseTipOrdering, EthPooledTransaction, Pool, PoolTransaction, TransactionOrigin,
+        CoinbaseTipOrdering, EthPooledTransaction, Pool, TransactionOrigin,
     };
     use reth_fs_util as fs;
     use reth_primitives::{hex, PooledTransactionsElement, MAINNET, U256};
@@ -696,30 +820,39 @@ mod tests {
 
     const EXTENSION: &str = "rlp";
     const FILENAME: &str = "test_transactions_backup";
-
-    #[tokio::test(flavor = "multi_thread")]
-    async fn test_save_local_txs_backup() {
-        let temp_dir = tempfile::tempdir().unwrap();
-        let transactions_path = temp_dir.path().join(FILENAME).with_extension(EXTENSION);
-        let tx_bytes = hex!("02f87201830655c2808505ef61f08482565f94388c818ca8b9251b393131c08a736a67ccb192978801049e39c4b5b1f580c001a01764ace353514e8abdfb92446de356b260e3c1225b73fc4c8876a6258d12a129a04f02294aa61ca7676061cd99f29275491218b4754b46a0248e5e42bc5091f507");
-        let tx = PooledTransactionsElement::decode_enveloped(&mut &tx_bytes[..]).unwrap();
+    const TX_BYTES: [u8; 117] = hex!("02f87201830655c2808505ef61f08482565f94388c818ca8b9251b393131c08a736a67ccb192978801049e39c4b5b1f580c001a01764ace353514e8abdfb92446de356b260e3c1225b73fc4c8876a6258d12a129a04f02294aa61ca7676061cd99f29275491218b4754b46a0248e5e42bc5091f507");
+
+    /// Returns a pool that accepts the transaction of [`TX_BYTES`], and the transaction.
+    fn pool_with_funded_sender() -> (
+        Pool<
+            crate::validate::EthTransactionValidator<MockEthProvider, EthPooledTransaction>,
+            CoinbaseTipOrdering<EthPooledTransaction>,
+            InMemoryBlobStore,
+        >,
+        EthPooledTransaction,
+    ) {
+        let tx = PooledTransactionsElement::decode_enveloped(&mut &TX_BYTES[..]).unwrap();
         let provider = MockEthProvider::default();
         let transaction = EthPooledTransaction::from_recovered_pooled_transaction(
             tx.try_into_ecrecovered().unwrap(),
         );
-        let tx_to_cmp = transaction.clone();
         let sender = hex!("1f9090aaE28b8a3dCeaDf281B0F12828e676c326").into();
         provider.add_account(sender, ExtendedAccount::new(42, U256::MAX));
         let blob_store = InMemoryBlobStore::default();
         let validator = EthTransactionValidatorBuilder::new(MAINNET.clone())
             .build(provider, blob_store.clone());
 
-        let txpool = Pool::new(
-            validator.clone(),
-            CoinbaseTipOrdering::default(),
-            blob_store.clone(),
-            Default::default(),
-        );
+        let txpool =
+            Pool::new(validator, CoinbaseTipOrdering::default(), blob_store, Default::default());
+        (txpool, transaction)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_save_local_txs_backup() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let transactions_path = temp_dir.path().join(FILENAME).with_extension(EXTENSION);
+        let (txpool, transaction) = pool_with_funded_sender();
+        let tx_to_cmp = transaction.clone();
 
         txpool.add_transaction(TransactionOrigin::Local, transaction.clone()).await.unwrap();
 
@@ -746,4 +879,69 @@ mod tests {
 
         temp_dir.close().unwrap();
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_journal_local_txs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let transactions_path = temp_dir.path().join(FILENAME).with_extension(EXTENSION);
+        let (txpool, transaction) = pool_with_funded_sender();
+
+        let handle = tokio::runtime::Handle::current();
+        let manager = TaskManager::new(handle);
+        let config = LocalTransactionBackupConfig::with_local_txs_backup(transactions_path.clone());
+        manager.executor().spawn_critical_with_graceful_shutdown_signal("test task", |shutdown| {
+            backup_local_transactions_task(shutdown, txpool.clone(), config)
+        });
+
+        let wait_for = |exists: bool| {
+            let transactions_path = transactions_path.clone();
+            async move {
+                while transactions_path.exists() != exists {
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                }
+            }
+        };
+
+        // written without a shutdown once the local transaction is added
+        let hash = txpool.add_transaction(TransactionOrigin::Local, transaction).await.unwrap();
+        tokio::time::timeout(std::time::Duration::from_secs(5), wait_for(true)).await.unwrap();
+        let data = fs::read(&transactions_path).unwrap();
+        let txs = decode_transactions_backup(&data).unwrap();
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].hash(), hash);
+
+        // removed once there are no local transactions anymore
+        txpool.remove_transactions(vec![hash]);
+        tokio::time::timeout(std::time::Duration::from_secs(5), wait_for(false)).await.unwrap();
+
+        manager.graceful_shutdown();
+        assert!(!transactions_path.exists());
+
+        temp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn test_decode_transactions_backup_skips_corrupt_entries() {
+        let tx = TransactionSigned::decode_enveloped(&mut &TX_BYTES[..]).unwrap();
+
+        let mut payload = Vec::new();
+        alloy_rlp::Encodable::encode(&tx, &mut payload);
+        // an EIP-1559 transaction without fields
+        alloy_rlp::Encodable::encode(
+            &reth_primitives::Bytes::from_static(&[0x02, 0xc0]),
+            &mut payload,
+        );
+        alloy_rlp::Encodable::encode(&tx, &mut payload);
+        let mut data = Vec::new();
+        alloy_rlp::Header { list: true, payload_length: payload.len() }.encode(&mut data);
+        data.extend_from_slice(&payload);
+
+        assert_eq!(decode_transactions_backup(&data).unwrap(), vec![tx.clone(), tx.clone()]);
+
+        // the last transaction was not written completely
+        assert_eq!(decode_transactions_backup(&data[..data.len() - 1]).unwrap(), vec![tx]);
+
+        // not a list of transactions
+        assert!(decode_transactions_backup(&[0x80]).is_err());
+    }
 }
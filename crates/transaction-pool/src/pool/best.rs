@@ -12,6 +12,9 @@ use std::{
 use tokio::sync::broadcast::{error::TryRecvError, Receiver};
 use tracing::debug;
 
+/// The gas used by a plain transfer, no transaction can use less.
+const MIN_TRANSACTION_GAS: u64 = 21_000;
+
 /// An iterator that returns transactions that can be executed on the current state (*best*
 /// transactions).
 ///
@@ -264,6 +267,82 @@ impl<I: fmt::Debug, P> fmt::Debug for BestTransactionFilter<I, P> {
     }
 }
 
+/// A [`BestTransactions`](crate::traits::BestTransactions) implementation that only yields
+/// transactions that fit into a block with the given gas limit.
+///
+/// Transactions are budgeted by their gas limit, like a block builder does before executing them.
+/// A transaction that no longer fits is marked as invalid, which also skips all of its descendants:
+/// [`BestTransactions::mark_invalid`](crate::traits::BestTransactions::mark_invalid).
+///
+/// The iterator ends once the remaining gas is below the gas of a plain transfer.
+#[derive(Debug)]
+pub struct BestTransactionsWithGasLimit<I> {
+    best: I,
+    gas_limit: u64,
+    cumulative_gas_limit: u64,
+}
+
+impl<I> BestTransactionsWithGasLimit<I> {
+    /// Creates a new [`BestTransactionsWithGasLimit`] that yields transactions of `best` until the
+    /// given block gas limit is exhausted.
+    pub const fn new(best: I, gas_limit: u64) -> Self {
+        Self { best, gas_limit, cumulative_gas_limit: 0 }
+    }
+
+    /// Returns the sum of the gas limits of all yielded transactions.
+    pub const fn cumulative_gas_limit(&self) -> u64 {
+        self.cumulative_gas_limit
+    }
+}
+
+impl<I, T> Iterator for BestTransactionsWithGasLimit<I>
+where
+    I: crate::traits::BestTransactions<Item = Arc<ValidPoolTransaction<T>>>,
+    T: PoolTransaction,
+{
+    type Item = Arc<ValidPoolTransaction<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let remaining = self.gas_limit - self.cumulative_gas_limit;
+            if remaining < MIN_TRANSACTION_GAS {
+                return None
+            }
+
+            let best = self.best.next()?;
+            if best.gas_limit() > remaining {
+                // tx does not fit into the block, skip it and its descendants
+                self.best.mark_invalid(&best);
+                continue
+            }
+            self.cumulative_gas_limit += best.gas_limit();
+            return Some(best)
+        }
+    }
+}
+
+impl<I, T> crate::traits::BestTransactions for BestTransactionsWithGasLimit<I>
+where
+    I: crate::traits::BestTransactions<Item = Arc<ValidPoolTransaction<T>>>,
+    T: PoolTransaction,
+{
+    fn mark_invalid(&mut self, tx: &Self::Item) {
+        crate::traits::BestTransactions::mark_invalid(&mut self.best, tx)
+    }
+
+    fn no_updates(&mut self) {
+        self.best.no_updates()
+    }
+
+    fn skip_blobs(&mut self) {
+        self.set_skip_blobs(true)
+    }
+
+    fn set_skip_blobs(&mut self, skip_blobs: bool) {
+        self.best.set_skip_blobs(skip_blobs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,6 +67,7 @@
 
 use crate::{
     error::{PoolError, PoolErrorKind, PoolResult},
+    filter::GossipTransactionFilter,
     identifier::{SenderId, SenderIdentifiers, TransactionId},
     pool::{
         listener::PoolEventBroadcast,
@@ -103,7 +104,7 @@ use crate::{
     traits::{GetPooledTransactionLimit, NewBlobSidecar, TransactionListenerKind},
     validate::ValidTransaction,
 };
-pub use best::BestTransactionFilter;
+pub use best::{BestTransactionFilter, BestTransactionsWithGasLimit};
 pub use blob::{blob_tx_priority, fee_delta};
 pub use events::{FullTransactionEvent, TransactionEvent};
 pub use listener::{AllTransactionsEvents, TransactionEvents};
@@ -149,6 +150,8 @@ where
     blob_transaction_sidecar_listener: Mutex<Vec<BlobTransactionSidecarListener>>,
     /// Metrics for the blob store
     blob_store_metrics: BlobStoreMetrics,
+    /// Optional filter applied to gossiped transactions before validation.
+    gossip_filter: RwLock<Option<Box<dyn GossipTransactionFilter<T::Transaction>>>>,
 }
 
 // === impl PoolInner ===
@@ -172,6 +175,7 @@ where
             config,
             blob_store,
             blob_store_metrics: Default::default(),
+            gossip_filter: Default::default(),
         }
     }
 
@@ -230,6 +234,24 @@ where
         &self.validator
     }
 
+    /// Sets the filter that is applied to gossiped transactions before they're validated.
+    pub(crate) fn set_gossip_filter(
+        &self,
+        filter: Option<Box<dyn GossipTransactionFilter<T::Transaction>>>,
+    ) {
+        *self.gossip_filter.write() = filter;
+    }
+
+    /// Returns `true` if the transaction should be dropped before validation.
+    ///
+    /// Only [`TransactionOrigin::External`] transactions are subject to the gossip filter.
+    pub(crate) fn is_filtered(&self, origin: TransactionOrigin, tx: &T::Transaction) -> bool {
+        if !origin.is_external() {
+            return false
+        }
+        self.gossip_filter.read().as_ref().is_some_and(|filter| !filter.keep(origin, tx))
+    }
+
     /// Adds a new transaction listener to the pool that gets notified about every new _pending_
     /// transaction inserted into the pool
     pub fn add_pending_listener(&self, kind: TransactionListenerKind) -> mpsc::Receiver<TxHash> {
@@ -679,6 +701,15 @@ where
         }
     }
 
+    /// Returns all transactions of the given sender in the pool
+    pub(crate) fn all_transactions_by_sender(
+        &self,
+        sender: Address,
+    ) -> AllPoolTransactions<T::Transaction> {
+        let sender_id = self.get_sender_id(sender);
+        self.get_pool_data().all_transactions_by_sender(sender_id)
+    }
+
     /// Removes and returns all matching transactions from the pool.
     pub(crate) fn remove_transactions(
         &self,
@@ -14,7 +14,7 @@ use crate::{
         update::{Destination, PoolUpdate},
         AddedPendingTransaction, AddedTransaction, OnNewCanonicalStateOutcome,
     },
-    traits::{BestTransactionsAttributes, BlockInfo, PoolSize},
+    traits::{AllPoolTransactions, BestTransactionsAttributes, BlockInfo, PoolSize},
     PoolConfig, PoolResult, PoolTransaction, PriceBumpConfig, TransactionOrdering,
     ValidPoolTransaction, U256,
 };
@@ -328,6 +328,23 @@ impl<T: TransactionOrdering> TxPool<T> {
         self.basefee_pool.all().chain(self.queued_pool.all()).collect()
     }
 
+    /// Returns the pending and parked transactions of the given sender, grouped like
+    /// [`Self::pending_transactions`] and [`Self::queued_transactions`].
+    pub(crate) fn all_transactions_by_sender(
+        &self,
+        sender: SenderId,
+    ) -> AllPoolTransactions<T::Transaction> {
+        let mut all = AllPoolTransactions::default();
+        for (_, tx) in self.all_transactions.txs_iter(sender) {
+            match tx.subpool {
+                SubPool::Pending => all.pending.push(Arc::clone(&tx.transaction)),
+                SubPool::BaseFee | SubPool::Queued => all.queued.push(Arc::clone(&tx.transaction)),
+                SubPool::Blob => {}
+            }
+        }
+        all
+    }
+
     /// Returns queued and pending transactions for the specified sender
     pub fn queued_and_pending_txs_by_sender(
         &self,
@@ -548,6 +565,10 @@ impl<T: TransactionOrdering> TxPool<T> {
                             Eip4844PoolTransactionError::Eip4844NonceGap.into(),
                         ),
                     )),
+                    InsertErr::NonceGap { transaction } => Err(PoolError::new(
+                        *transaction.hash(),
+                        PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::NonceGap),
+                    )),
                     InsertErr::Overdraft { transaction } => Err(PoolError::new(
                         *transaction.hash(),
                         PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::Overdraft),
@@ -914,6 +935,8 @@ pub(crate) struct AllTransactions<T: PoolTransaction> {
     price_bumps: PriceBumpConfig,
     /// How to handle [`TransactionOrigin::Local`](crate::TransactionOrigin) transactions.
     local_transactions_config: LocalTransactionConfig,
+    /// Whether non-local transactions with a nonce gap are rejected instead of being queued.
+    reject_nonce_gaps: bool,
     /// All Transactions metrics
     metrics: AllTransactionsMetrics,
 }
@@ -925,6 +948,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
             max_account_slots: config.max_account_slots,
             price_bumps: config.price_bumps,
             local_transactions_config: config.local_transactions_config.clone(),
+            reject_nonce_gaps: config.reject_nonce_gaps,
             ..Default::default()
         }
     }
@@ -1322,6 +1346,12 @@ impl<T: PoolTransaction> AllTransactions<T> {
         Ok(transaction)
     }
 
+    /// Returns `true` if a transaction with the given ancestor would have a nonce gap, either
+    /// because the ancestor is missing or because the ancestor itself has a nonce gap.
+    fn has_nonce_gap(&self, ancestor: Option<TransactionId>) -> bool {
+        ancestor.is_some_and(|id| self.txs.get(&id).map_or(true, |tx| tx.state.has_nonce_gap()))
+    }
+
     /// Enforces additional constraints for blob transactions before attempting to insert:
     ///    - new blob transactions must not have any nonce gaps
     ///    - blob transactions cannot go into overdraft
@@ -1483,6 +1513,15 @@ impl<T: PoolTransaction> AllTransactions<T> {
             inserted_tx_id.sender,
         );
 
+        // non-local transactions that would be queued because of a nonce gap are rejected if
+        // configured
+        if self.reject_nonce_gaps &&
+            !self.local_transactions_config.is_local(transaction.origin, transaction.sender()) &&
+            self.has_nonce_gap(ancestor)
+        {
+            return Err(InsertErr::NonceGap { transaction: Arc::new(transaction) })
+        }
+
         // before attempting to insert a blob transaction, we need to ensure that additional
         // constraints are met that only apply to blob transactions
         if transaction.is_eip4844() {
@@ -1688,6 +1727,7 @@ impl<T: PoolTransaction> Default for AllTransactions<T> {
             pending_fees: Default::default(),
             price_bumps: Default::default(),
             local_transactions_config: Default::default(),
+            reject_nonce_gaps: false,
             metrics: Default::default(),
         }
     }
@@ -1722,6 +1762,8 @@ pub(crate) enum InsertErr<T: PoolTransaction> {
     },
     /// Attempted to insert a blob transaction with a nonce gap
     BlobTxHasNonceGap { transaction: Arc<ValidPoolTransaction<T>> },
+    /// Attempted to insert a non-local transaction with a nonce gap while nonce gaps are rejected
+    NonceGap { transaction: Arc<ValidPoolTransaction<T>> },
     /// Attempted to insert a transaction that would overdraft the sender's balance at the time of
     /// insertion.
     Overdraft { transaction: Arc<ValidPoolTransaction<T>> },
@@ -2234,6 +2276,28 @@ mod tests {
         assert_eq!(inserted.subpool, SubPool::Pending);
     }
 
+    #[test]
+    fn test_all_transactions_by_sender() {
+        let on_chain_balance = U256::MAX;
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let tx = MockTransaction::eip1559().inc_price().inc_limit();
+        let pending = f.validated(tx.clone());
+        // nonce gap, so this is queued
+        let queued = f.validated(tx.skip(1));
+        let other = f.validated(MockTransaction::eip1559().inc_price().inc_limit());
+        let sender = pending.sender_id();
+        for tx in [pending.clone(), queued.clone(), other] {
+            pool.add_transaction(tx, on_chain_balance, on_chain_nonce).unwrap();
+        }
+
+        let all = pool.all_transactions_by_sender(sender);
+        assert_eq!(all.pending.iter().map(|tx| *tx.hash()).collect::<Vec<_>>(), [*pending.hash()]);
+        assert_eq!(all.queued.iter().map(|tx| *tx.hash()).collect::<Vec<_>>(), [*queued.hash()]);
+    }
+
     #[test]
     fn test_simple_insert() {
         let on_chain_balance = U256::ZERO;
@@ -2458,6 +2522,41 @@ mod tests {
         assert!(first_in_pool.state.contains(TxState::NO_NONCE_GAPS));
     }
 
+    #[test]
+    fn reject_external_nonce_gaps() {
+        let on_chain_balance = U256::from(10_000);
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool =
+            AllTransactions::new(&PoolConfig { reject_nonce_gaps: true, ..Default::default() });
+        let tx = MockTransaction::eip1559().inc_nonce().inc_price().inc_limit();
+
+        // a gossiped transaction with a nonce gap is rejected
+        let external = f.validated(tx.clone());
+        let err = pool.insert_tx(external, on_chain_balance, on_chain_nonce).unwrap_err();
+        assert!(matches!(err, InsertErr::NonceGap { .. }), "{err:?}");
+        assert!(pool.is_empty());
+
+        // the same transaction submitted locally is queued
+        let local = f.validated_with_origin(TransactionOrigin::Local, tx.clone());
+        let InsertOk { state, move_to, .. } =
+            pool.insert_tx(local, on_chain_balance, on_chain_nonce).unwrap();
+        assert!(!state.contains(TxState::NO_NONCE_GAPS));
+        assert_eq!(move_to, SubPool::Queued);
+
+        // descendants of a queued transaction still have a nonce gap
+        let next = f.validated(tx.next());
+        let err = pool.insert_tx(next, on_chain_balance, on_chain_nonce).unwrap_err();
+        assert!(matches!(err, InsertErr::NonceGap { .. }), "{err:?}");
+
+        // closing the gap is always allowed
+        let prev = f.validated(tx.prev());
+        pool.insert_tx(prev, on_chain_balance, on_chain_nonce).unwrap();
+        let next = f.validated(tx.next());
+        pool.insert_tx(next, on_chain_balance, on_chain_nonce).unwrap();
+        assert_eq!(pool.len(), 3);
+    }
+
     // insert nonce then nonce - 1
     #[test]
     fn insert_with_updates() {
@@ -3,7 +3,9 @@
 use crate::{
     blobstore::BlobStoreError,
     error::PoolResult,
-    pool::{state::SubPool, BestTransactionFilter, TransactionEvents},
+    pool::{
+        state::SubPool, BestTransactionFilter, BestTransactionsWithGasLimit, TransactionEvents,
+    },
     validate::ValidPoolTransaction,
     AllTransactionsEvents,
 };
@@ -263,6 +265,54 @@ pub trait TransactionPool: Send + Sync + Clone {
         best_transactions_attributes: BestTransactionsAttributes,
     ) -> Box<dyn BestTransactions<Item = Arc<ValidPoolTransaction<Self::Transaction>>>>;
 
+    /// Returns an iterator that yields the EIP-4844 blob transactions that are ready for block
+    /// production with the tracked fees.
+    ///
+    /// Blob transactions are only pending if both the base fee and the blob fee are satisfied, so
+    /// this yields the same blob transactions as [`TransactionPool::best_transactions`], in the
+    /// same order, without any of the other transactions.
+    ///
+    /// By default, this filters [`Self::best_transactions`] with a [`BestTransactionFilter`].
+    ///
+    /// Consumer: Block production
+    fn best_blob_transactions(
+        &self,
+    ) -> Box<dyn BestTransactions<Item = Arc<ValidPoolTransaction<Self::Transaction>>>>
+    where
+        Self::Transaction: 'static,
+    {
+        Box::new(BestTransactionFilter::new(
+            self.best_transactions(),
+            |tx: &Arc<ValidPoolTransaction<Self::Transaction>>| tx.is_eip4844(),
+        ))
+    }
+
+    /// Returns an iterator that yields the transactions a block with the given gas limit would
+    /// include, in the order they would be included.
+    ///
+    /// Transactions of a sender are yielded in nonce order, transactions of different senders by
+    /// the priority of the pool's ordering. Transactions that violate the base fee or blob fee of
+    /// the given attributes, or that no longer fit into the block, are skipped together with their
+    /// descendants.
+    ///
+    /// By default, this wraps [`Self::best_transactions_with_attributes`] in a
+    /// [`BestTransactionsWithGasLimit`].
+    ///
+    /// Consumer: Block production
+    fn best_transactions_with_gas_limit(
+        &self,
+        best_transactions_attributes: BestTransactionsAttributes,
+        gas_limit: u64,
+    ) -> Box<dyn BestTransactions<Item = Arc<ValidPoolTransaction<Self::Transaction>>>>
+    where
+        Self::Transaction: 'static,
+    {
+        Box::new(BestTransactionsWithGasLimit::new(
+            self.best_transactions_with_attributes(best_transactions_attributes),
+            gas_limit,
+        ))
+    }
+
     /// Returns all transactions that can be included in the next block.
     ///
     /// This is primarily used for the `txpool_` RPC namespace:
@@ -289,6 +339,24 @@ pub trait TransactionPool: Send + Sync + Clone {
     /// Consumer: RPC
     fn all_transactions(&self) -> AllPoolTransactions<Self::Transaction>;
 
+    /// Returns all transactions of the given sender that are currently in the pool grouped by
+    /// whether they are ready for inclusion in the next block or not.
+    ///
+    /// This is the same as [Self::all_transactions] restricted to a single sender. By default, this
+    /// filters [Self::all_transactions], implementations should only look up the sender's
+    /// transactions instead of iterating over the entire pool.
+    ///
+    /// Consumer: RPC
+    fn all_transactions_by_sender(
+        &self,
+        sender: Address,
+    ) -> AllPoolTransactions<Self::Transaction> {
+        let AllPoolTransactions { mut pending, mut queued } = self.all_transactions();
+        pending.retain(|tx| tx.sender() == sender);
+        queued.retain(|tx| tx.sender() == sender);
+        AllPoolTransactions { pending, queued }
+    }
+
     /// Removes all transactions corresponding to the given hashes.
     ///
     /// Also removes all _dependent_ transactions.
@@ -541,6 +609,7 @@ pub struct NewBlobSidecar {
 /// Depending on where the transaction was picked up, it affects how the transaction is handled
 /// internally, e.g. limits for simultaneous transaction of one sender.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TransactionOrigin {
     /// Transaction is coming from a local source.
     Local,
@@ -713,6 +782,24 @@ pub trait BestTransactions: Iterator + Send {
     }
 }
 
+impl<T: BestTransactions + ?Sized> BestTransactions for Box<T> {
+    fn mark_invalid(&mut self, transaction: &Self::Item) {
+        (**self).mark_invalid(transaction)
+    }
+
+    fn no_updates(&mut self) {
+        (**self).no_updates()
+    }
+
+    fn skip_blobs(&mut self) {
+        (**self).skip_blobs()
+    }
+
+    fn set_skip_blobs(&mut self, skip_blobs: bool) {
+        (**self).set_skip_blobs(skip_blobs)
+    }
+}
+
 /// A no-op implementation that yields no transactions.
 impl<T> BestTransactions for std::iter::Empty<T> {
     fn mark_invalid(&mut self, _tx: &T) {}
@@ -0,0 +1,58 @@
+//! Best transactions tests
+
+use reth_primitives::{Address, B256};
+use reth_transaction_pool::{
+    test_utils::{MockTransaction, TestPoolBuilder},
+    BestTransactionsAttributes, PoolTransaction, TransactionOrigin, TransactionPool,
+};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn best_transactions_with_gas_limit() {
+    let txpool = TestPoolBuilder::default();
+    let base_fee: u64 = 100;
+
+    let tx = |sender: Address, nonce: u64, priority_fee: u128, gas_limit: u64| {
+        MockTransaction::eip1559()
+            .with_sender(sender)
+            .with_nonce(nonce)
+            .with_max_fee(1_000)
+            .with_priority_fee(priority_fee)
+            .with_gas_limit(gas_limit)
+    };
+    let [a, b, c, d, e] = std::array::from_fn(|_| Address::random());
+    let a0 = tx(a, 0, 10, 30_000);
+    // only includable after `a0`, despite the highest tip
+    let a1 = tx(a, 1, 50, 30_000);
+    let b0 = tx(b, 0, 20, 50_000);
+    let c0 = tx(c, 0, 30, 60_000);
+    // pays the highest tip, but can't pay the base fee
+    let d0 = tx(d, 0, 40, 21_000).with_max_fee(base_fee as u128 - 1);
+    let e0 = tx(e, 0, 5, 21_000);
+
+    let transactions = vec![a0.clone(), a1.clone(), b0.clone(), c0.clone(), d0, e0.clone()];
+    for result in txpool.add_transactions(TransactionOrigin::External, transactions).await {
+        result.unwrap();
+    }
+
+    let hashes = |gas_limit: u64| {
+        txpool
+            .best_transactions_with_gas_limit(
+                BestTransactionsAttributes::base_fee(base_fee),
+                gas_limit,
+            )
+            .map(|tx| *tx.hash())
+            .collect::<Vec<B256>>()
+    };
+
+    // all transactions fit
+    assert_eq!(
+        hashes(30_000_000),
+        vec![*c0.hash(), *b0.hash(), *a0.hash(), *a1.hash(), *e0.hash()]
+    );
+
+    // `a0` no longer fits after `c0` and `b0`, which also excludes `a1`, but `e0` still fits
+    assert_eq!(hashes(135_000), vec![*c0.hash(), *b0.hash(), *e0.hash()]);
+
+    // nothing fits after `c0`
+    assert_eq!(hashes(80_000), vec![*c0.hash()]);
+}
@@ -38,3 +38,19 @@ async fn blobs_exclusive() {
         _ => unreachable!(),
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn best_blob_transactions() {
+    let txpool = TestPoolBuilder::default();
+    let mut mock_tx_factory = MockTransactionFactory::default();
+    let blob_tx = mock_tx_factory.create_eip4844();
+    let eip1559_tx = mock_tx_factory.create_eip1559();
+
+    txpool.add_transaction(TransactionOrigin::External, blob_tx.transaction.clone()).await.unwrap();
+    txpool.add_transaction(TransactionOrigin::External, eip1559_tx.transaction).await.unwrap();
+    assert_eq!(txpool.best_transactions().count(), 2);
+
+    let mut best_blobs = txpool.best_blob_transactions();
+    assert_eq!(best_blobs.next().unwrap().transaction.get_hash(), blob_tx.transaction.get_hash());
+    assert!(best_blobs.next().is_none());
+}
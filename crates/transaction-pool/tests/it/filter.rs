@@ -0,0 +1,48 @@
+//! Gossip filter tests
+
+use reth_transaction_pool::{
+    error::PoolErrorKind,
+    test_utils::{MockTransactionFactory, TestPoolBuilder},
+    MinGasPriceFilter, PoolTransaction, TransactionOrigin, TransactionPool,
+};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn gossip_filter_drops_external_but_not_local() {
+    let txpool = TestPoolBuilder::default();
+    let mut mock_tx_factory = MockTransactionFactory::default();
+    let tx = mock_tx_factory.create_eip1559().transaction;
+
+    txpool.set_gossip_filter(MinGasPriceFilter::new(tx.max_fee_per_gas() + 1));
+
+    let err = txpool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap_err();
+    assert_eq!(err.hash, *tx.hash());
+    assert!(matches!(err.kind, PoolErrorKind::FilteredOnArrival));
+    assert!(!err.is_bad_transaction());
+    assert!(txpool.is_empty());
+
+    // the same transaction submitted locally bypasses the filter
+    let hash = txpool.add_transaction(TransactionOrigin::Local, tx.clone()).await.unwrap();
+    assert_eq!(hash, *tx.hash());
+    assert_eq!(txpool.len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn gossip_filter_preserves_batch_order() {
+    let txpool = TestPoolBuilder::default();
+    let mut mock_tx_factory = MockTransactionFactory::default();
+    let cheap = mock_tx_factory.create_eip1559().transaction;
+    let threshold = cheap.max_fee_per_gas() + 1;
+    let expensive = mock_tx_factory.create_eip1559().transaction.with_max_fee(threshold);
+
+    txpool.set_gossip_filter(MinGasPriceFilter::new(threshold));
+
+    let results = txpool
+        .add_transactions(TransactionOrigin::External, vec![cheap.clone(), expensive.clone()])
+        .await;
+    assert_eq!(results.len(), 2);
+    assert!(matches!(results[0].as_ref().unwrap_err().kind, PoolErrorKind::FilteredOnArrival));
+    assert_eq!(results[1].as_ref().unwrap(), expensive.hash());
+
+    txpool.clear_gossip_filter();
+    assert!(txpool.add_transaction(TransactionOrigin::External, cheap).await.is_ok());
+}
@@ -1,10 +1,14 @@
 //! transaction-pool integration tests
 
+#[cfg(feature = "test-utils")]
+mod best;
 #[cfg(feature = "test-utils")]
 mod blobs;
 #[cfg(feature = "test-utils")]
 mod evict;
 #[cfg(feature = "test-utils")]
+mod filter;
+#[cfg(feature = "test-utils")]
 mod listeners;
 #[cfg(feature = "test-utils")]
 mod pending;
@@ -0,0 +1,67 @@
+//! Compares account proofs on a fresh database transaction with proofs after a [`TrieWarmup`].
+//!
+//! The page cache of the OS is not dropped between iterations, so the cold proofs only start
+//! without the pages the transaction already touched. To measure the speedup after a cold start,
+//! drop the page cache before running the benchmark, e.g. with
+//! `sync && echo 3 > /proc/sys/vm/drop_caches` on Linux, and compare the first iterations.
+#![allow(missing_docs, unreachable_pub)]
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use reth_db::tables;
+use reth_db_api::transaction::DbTxMut;
+use reth_primitives::{keccak256, Account, Address, U256};
+use reth_provider::test_utils::create_test_provider_factory;
+use reth_trie::{proof::Proof, StateRoot, TrieWarmup};
+
+pub fn trie_warmup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Trie Warmup");
+    group.sample_size(20);
+
+    for size in [1_000, 10_000, 100_000] {
+        let factory = create_test_provider_factory();
+        let addresses = (0..size).map(|_| Address::random()).collect::<Vec<_>>();
+        {
+            let provider = factory.provider_rw().unwrap();
+            let tx = provider.tx_ref();
+            for (nonce, address) in addresses.iter().enumerate() {
+                let account =
+                    Account { nonce: nonce as u64, balance: U256::from(1), bytecode_hash: None };
+                tx.put::<tables::HashedAccounts>(keccak256(address), account).unwrap();
+            }
+            let (_, updates) = StateRoot::from_tx(tx).root_with_updates().unwrap();
+            updates.flush(tx).unwrap();
+            provider.commit().unwrap();
+        }
+        let target = addresses[size / 2];
+
+        group.bench_function(BenchmarkId::new("warmup", size), |b| {
+            b.iter_batched(
+                || factory.provider().unwrap(),
+                |provider| TrieWarmup::new(provider.tx_ref()).run().unwrap(),
+                BatchSize::PerIteration,
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("cold proof", size), |b| {
+            b.iter_batched(
+                || factory.provider().unwrap(),
+                |provider| Proof::new(provider.tx_ref()).account_proof(target, &[]).unwrap(),
+                BatchSize::PerIteration,
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("warmed proof", size), |b| {
+            b.iter_batched(
+                || {
+                    let provider = factory.provider().unwrap();
+                    TrieWarmup::new(provider.tx_ref()).run().unwrap();
+                    provider
+                },
+                |provider| Proof::new(provider.tx_ref()).account_proof(target, &[]).unwrap(),
+                BatchSize::PerIteration,
+            )
+        });
+    }
+}
+
+criterion_group!(trie_warmup_benches, trie_warmup);
+criterion_main!(trie_warmup_benches);
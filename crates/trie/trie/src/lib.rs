@@ -50,6 +50,10 @@ pub use progress::{IntermediateStateRootState, StateRootProgress};
 /// Trie calculation stats.
 pub mod stats;
 
+/// Preloading of the upper trie levels.
+mod warmup;
+pub use warmup::{TrieWarmup, DEFAULT_TRIE_WARMUP_DEPTH};
+
 // re-export for convenience
 pub use reth_trie_types::*;
 
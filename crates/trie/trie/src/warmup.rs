@@ -0,0 +1,119 @@
+use crate::{
+    trie_cursor::{DatabaseAccountTrieCursor, TrieCursor},
+    Nibbles,
+};
+use reth_db::{tables, DatabaseError};
+use reth_db_api::transaction::DbTx;
+use tracing::trace;
+
+/// The default number of account trie levels that are read by [`TrieWarmup`].
+pub const DEFAULT_TRIE_WARMUP_DEPTH: usize = 4;
+
+/// Reads the upper levels of the account trie so that the database pages holding them are loaded
+/// into the page cache.
+///
+/// After a cold start or a snapshot restore, the first state root calculations and proofs have to
+/// fetch every branch node they touch from disk. The upper levels of the trie are touched by
+/// almost every calculation, so reading them ahead of time speeds up the first operations. The
+/// warmup only reads from the database and does not alter any state.
+#[derive(Debug)]
+pub struct TrieWarmup<'a, TX> {
+    /// A reference to the database transaction.
+    tx: &'a TX,
+    /// The number of trie levels to read.
+    depth: usize,
+}
+
+impl<'a, TX> TrieWarmup<'a, TX> {
+    /// Create a new [`TrieWarmup`] instance with the [default depth](DEFAULT_TRIE_WARMUP_DEPTH).
+    pub const fn new(tx: &'a TX) -> Self {
+        Self { tx, depth: DEFAULT_TRIE_WARMUP_DEPTH }
+    }
+
+    /// Set the number of trie levels to read.
+    pub const fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+}
+
+impl<'a, TX: DbTx> TrieWarmup<'a, TX> {
+    /// Reads all account trie nodes with a path of at most `depth` nibbles.
+    ///
+    /// Subtries below the configured depth are skipped by seeking past them. Returns the number of
+    /// nodes that were read.
+    pub fn run(self) -> Result<usize, DatabaseError> {
+        let mut cursor =
+            DatabaseAccountTrieCursor::new(self.tx.cursor_read::<tables::AccountsTrie>()?);
+
+        let mut nodes = 0;
+        let mut entry = cursor.seek(Nibbles::default())?;
+        while let Some((key, _)) = entry {
+            if key.len() <= self.depth {
+                nodes += 1;
+                // the smallest key greater than this one is its first possible child
+                let mut next = key;
+                next.push(0);
+                entry = cursor.seek(next)?;
+            } else {
+                // skip the remaining nodes of the subtrie below the configured depth
+                entry = match Nibbles::from_nibbles_unchecked(&key[..self.depth]).increment() {
+                    Some(next) => cursor.seek(next)?,
+                    None => None,
+                };
+            }
+        }
+
+        trace!(target: "trie::warmup", depth = self.depth, nodes, "Warmed up account trie");
+        Ok(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateRoot;
+    use reth_db_api::{cursor::DbCursorRO, transaction::DbTxMut};
+    use reth_primitives::{keccak256, Account, U256};
+    use reth_provider::test_utils::create_test_provider_factory;
+
+    #[test]
+    fn warmup_reads_upper_trie_levels() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+
+        for i in 0..2_000u64 {
+            let account = Account { nonce: i, balance: U256::from(i), bytecode_hash: None };
+            tx.put::<tables::HashedAccounts>(keccak256(i.to_be_bytes()), account).unwrap();
+        }
+        let (root, updates) = StateRoot::from_tx(tx).root_with_updates().unwrap();
+        updates.flush(tx).unwrap();
+
+        let stored = tx
+            .cursor_read::<tables::AccountsTrie>()
+            .unwrap()
+            .walk(None)
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .collect::<Vec<_>>();
+        assert!(stored.iter().any(|(key, _)| key.0.len() > 1));
+
+        for depth in 0..=3 {
+            let expected = stored.iter().filter(|(key, _)| key.0.len() <= depth).count();
+            assert_eq!(TrieWarmup::new(tx).with_depth(depth).run().unwrap(), expected);
+        }
+        assert_eq!(TrieWarmup::new(tx).with_depth(64).run().unwrap(), stored.len());
+
+        // warming up does not alter the trie
+        let after = tx
+            .cursor_read::<tables::AccountsTrie>()
+            .unwrap()
+            .walk(None)
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(after, stored);
+        assert_eq!(StateRoot::from_tx(tx).root().unwrap(), root);
+    }
+}
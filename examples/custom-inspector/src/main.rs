@@ -62,7 +62,7 @@ fn main() {
                                     call_request,
                                     BlockNumberOrTag::Latest.into(),
                                     EvmOverrides::default(),
-                                    move |db, env| {
+                                    move |_, db, env| {
                                         let mut dummy_inspector = DummyInspector::default();
                                         {
                                             // configure the evm with the custom inspector